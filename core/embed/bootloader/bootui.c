@@ -20,7 +20,17 @@
 #include <string.h>
 
 #include "bootui.h"
+#include "common.h"
 #include "display.h"
+#include "mini_printf.h"
+#include "version.h"
+
+#ifdef TREZOR_UI2
+#include "genhdr/rust_ui_bootloader.h"
+#include STM32_HAL_H
+#else
+#include "rust_ui.h"
+
 #include "icon_cancel.h"
 #include "icon_confirm.h"
 #include "icon_done.h"
@@ -31,8 +41,7 @@
 #include "icon_safeplace.h"
 #include "icon_welcome.h"
 #include "icon_wipe.h"
-#include "mini_printf.h"
-#include "version.h"
+#endif
 
 #if defined TREZOR_MODEL_T
 #include "touch.h"
@@ -62,19 +71,6 @@
 #define COLOR_WELCOME_BG COLOR_WHITE  // welcome background
 #define COLOR_WELCOME_FG COLOR_BLACK  // welcome foreground
 
-// common shared functions
-
-static void ui_confirm_cancel_buttons(void) {
-  display_bar_radius(9, 184, 108, 50, COLOR_BL_FAIL, COLOR_BL_BG, 4);
-  display_icon(9 + (108 - 16) / 2, 184 + (50 - 16) / 2, 16, 16,
-               toi_icon_cancel + 12, sizeof(toi_icon_cancel) - 12, COLOR_BL_BG,
-               COLOR_BL_FAIL);
-  display_bar_radius(123, 184, 108, 50, COLOR_BL_DONE, COLOR_BL_BG, 4);
-  display_icon(123 + (108 - 19) / 2, 184 + (50 - 16) / 2, 20, 16,
-               toi_icon_confirm + 12, sizeof(toi_icon_confirm) - 12,
-               COLOR_BL_BG, COLOR_BL_DONE);
-}
-
 static const char *format_ver(const char *format, uint32_t version) {
   static char ver_str[64];
   mini_snprintf(ver_str, sizeof(ver_str), format, (int)(version & 0xFF),
@@ -84,6 +80,15 @@ static const char *format_ver(const char *format, uint32_t version) {
   return ver_str;
 }
 
+// general functions
+
+void ui_fadein(void) { display_fade(0, BACKLIGHT_NORMAL, 1000); }
+
+void ui_fadeout(void) {
+  display_fade(BACKLIGHT_NORMAL, 0, 500);
+  display_clear();
+}
+
 // boot UI
 
 static uint16_t boot_background;
@@ -143,16 +148,228 @@ void ui_screen_boot_click(void) {
   display_refresh();
 }
 
+#ifdef TREZOR_UI2
+
+// ids of the bootloader menu items
+#define MENU_CALIBRATE_TOUCH 0x10
+#define MENU_REBOOT 0x11
+
+// the vendor string is not terminated in the header
+static const char *vendor_str(const vendor_header *const vhdr) {
+  static char vstr[64];
+  size_t len = MIN(vhdr->vstr_len, sizeof(vstr) - 1);
+  memcpy(vstr, vhdr->vstr, len);
+  vstr[len] = 0;
+  return vstr;
+}
+
+static void ui_intro(const char *title, const char *message,
+                     uint32_t fw_state) {
+  static const menu_item_t items[] = {
+      {MENU_CALIBRATE_TOUCH, "Calibrate touch", true},
+      {MENU_REBOOT, "Reboot", true},
+  };
+  char bld_version[16];
+  mini_snprintf(bld_version, sizeof(bld_version), "%d.%d.%d", VERSION_MAJOR,
+                VERSION_MINOR, VERSION_PATCH);
+
+  for (;;) {
+    uint32_t result = screen_intro(title, message, fw_state);
+    if (result != INTRO_RESULT_MENU) {
+      // INTRO_RESULT_HOST, or the error screen was shown
+      break;
+    }
+    ui_fadeout();
+    switch (screen_menu(bld_version, items, sizeof(items) / sizeof(items[0]))) {
+      case MENU_CALIBRATE_TOUCH:
+        ui_fadeout();
+        screen_touch_calibration();
+        break;
+      case MENU_REBOOT:
+        NVIC_SystemReset();
+        break;
+      default:
+        break;
+    }
+    ui_fadeout();
+  }
+
+  ui_fadeout();
+  screen_connect();
+  ui_fadein();
+}
+
+void ui_screen_welcome(secbool firmware_corrupted) {
+  // no ui_fadeout(); - we already start from black screen
+  ui_intro("WELCOME", "Go to trezor.io/start",
+           (sectrue == firmware_corrupted) ? FW_CORRUPTED : FW_MISSING);
+}
+
+void ui_screen_intro(const vendor_header *const vhdr,
+                     const image_header *const hdr) {
+  char title[32];
+  mini_snprintf(title, sizeof(title), "BOOTLOADER %d.%d.%d", VERSION_MAJOR,
+                VERSION_MINOR, VERSION_PATCH);
+  if (vhdr && hdr) {
+    char message[96];
+    const char *ver_str = format_ver("%d.%d.%d", hdr->version);
+    mini_snprintf(message, sizeof(message), "Firmware %s by %s", ver_str,
+                  vendor_str(vhdr));
+    ui_intro(title, message, FW_VALID);
+  } else {
+    ui_intro(title, "No firmware", FW_MISSING);
+  }
+}
+
+void ui_screen_wait_for_host(const vendor_header *const vhdr,
+                             const image_header *const hdr) {
+  screen_connect();
+}
+
+void ui_screen_wait_for_host_animate(void) { screen_connect_animate(); }
+
+static int confirm_result(uint32_t result) {
+  return (result == CONFIRM_RESULT_CONFIRM) ? INPUT_CONFIRM : INPUT_CANCEL;
+}
+
+int ui_screen_install_confirm_upgrade(const vendor_header *const vhdr,
+                                      const image_header *const hdr,
+                                      const image_header *const current_hdr) {
+  uint32_t current_version = current_hdr ? current_hdr->version : 0;
+  return confirm_result(screen_install_confirm_version(
+      current_version, hdr->version, vendor_str(vhdr), "", ""));
+}
+
+int ui_screen_install_confirm_newvendor_or_downgrade_wipe(
+    const vendor_header *const vhdr, const image_header *const hdr,
+    const image_header *const current_hdr, secbool downgrade_wipe) {
+  if (sectrue == downgrade_wipe) {
+    uint32_t current_version = current_hdr ? current_hdr->version : 0;
+    return confirm_result(screen_install_confirm_version(
+        current_version, hdr->version, vendor_str(vhdr),
+        "Seed will be erased!", ""));
+  }
+  const char *ver_str =
+      format_ver("Install firmware version %d.%d.%d?", hdr->version);
+  return confirm_result(screen_install_confirm("CHANGE FW VENDOR", ver_str,
+                                               vendor_str(vhdr),
+                                               "Seed will be erased!", ""));
+}
+
+void ui_screen_install_start(void) { screen_install_progress(0, true); }
+
+void ui_screen_install_progress_erase(int pos, int len) {
+  screen_install_progress(250 * pos / len, false);
+}
+
+void ui_screen_install_progress_upload(int pos) {
+  screen_install_progress(pos, false);
+}
+
+void ui_screen_install_check_stall(void) { screen_progress_check_stall(); }
+
+int ui_screen_wipe_confirm(void) {
+  return confirm_result(screen_wipe_confirm());
+}
+
+void ui_screen_wipe(void) { screen_wipe_progress(0, true); }
+
+void ui_screen_wipe_progress(int pos, int len) {
+  screen_wipe_progress(1000 * pos / len, false);
+}
+
+void ui_screen_done(int restart_seconds, secbool full_redraw) {
+  const char *str;
+  char count_str[24];
+  if (restart_seconds >= 1) {
+    mini_snprintf(count_str, sizeof(count_str), "Restarting in %d s",
+                  restart_seconds);
+    str = count_str;
+  } else {
+    str = "Unplug the device.";
+  }
+  screen_install_success(str, sectrue == full_redraw);
+}
+
+void ui_screen_wipe_done(void) {
+  screen_wipe_success(WIPED_SEED | WIPED_PIN | WIPED_SETTINGS);
+}
+
+void ui_screen_fail(void) { screen_install_fail(); }
+
+void ui_screen_wipe_fail(void) { screen_wipe_fail(); }
+
+#ifdef USE_BATTERY
+void ui_screen_install_low_battery(int soc) {
+  screen_install_low_battery(soc);
+}
+#endif
+
+#else
+
+// common shared functions
+
+static void ui_confirm_cancel_buttons(void) {
+  display_bar_radius(9, 184, 108, 50, COLOR_BL_FAIL, COLOR_BL_BG, 4);
+  display_icon(9 + (108 - 16) / 2, 184 + (50 - 16) / 2, 16, 16,
+               toi_icon_cancel + 12, sizeof(toi_icon_cancel) - 12, COLOR_BL_BG,
+               COLOR_BL_FAIL);
+  display_bar_radius(123, 184, 108, 50, COLOR_BL_DONE, COLOR_BL_BG, 4);
+  display_icon(123 + (108 - 19) / 2, 184 + (50 - 16) / 2, 20, 16,
+               toi_icon_confirm + 12, sizeof(toi_icon_confirm) - 12,
+               COLOR_BL_BG, COLOR_BL_DONE);
+}
+
+static int ui_user_input(int zones) {
+  for (;;) {
+#if defined TREZOR_MODEL_T
+    uint32_t evt = touch_click();
+    uint16_t x = touch_unpack_x(evt);
+    uint16_t y = touch_unpack_y(evt);
+    // clicked on Cancel button
+    if ((zones & INPUT_CANCEL) && x >= 9 && x < 9 + 108 && y > 184 &&
+        y < 184 + 50) {
+      return INPUT_CANCEL;
+    }
+    // clicked on Confirm button
+    if ((zones & INPUT_CONFIRM) && x >= 123 && x < 123 + 108 && y > 184 &&
+        y < 184 + 50) {
+      return INPUT_CONFIRM;
+    }
+    // clicked on Long Confirm button
+    if ((zones & INPUT_LONG_CONFIRM) && x >= 9 && x < 9 + 222 && y > 184 &&
+        y < 184 + 50) {
+      return INPUT_LONG_CONFIRM;
+    }
+    // clicked on Info icon
+    if ((zones & INPUT_INFO) && x >= 16 && x < 16 + 32 && y > 54 &&
+        y < 54 + 32) {
+      return INPUT_INFO;
+    }
+#elif defined TREZOR_MODEL_R
+    uint32_t evt = button_read();
+    if (evt == (BTN_LEFT | BTN_EVT_DOWN)) {
+      return INPUT_CANCEL;
+    }
+    if (evt == (BTN_RIGHT | BTN_EVT_DOWN)) {
+      return INPUT_CONFIRM;
+    }
+#else
+#error Unknown Trezor model
+#endif
+  }
+}
+
 // welcome UI
 
-void ui_screen_welcome_first(void) {
+static void ui_screen_welcome_first(void) {
   display_icon(0, 0, 240, 240, toi_icon_logo + 12, sizeof(toi_icon_logo) - 12,
                COLOR_WELCOME_FG, COLOR_WELCOME_BG);
   PIXELDATA_DIRTY();
   display_refresh();
 }
 
-void ui_screen_welcome_second(void) {
+static void ui_screen_welcome_second(void) {
   display_bar(0, 0, DISPLAY_RESX, DISPLAY_RESY, COLOR_WELCOME_BG);
   display_icon((DISPLAY_RESX - 200) / 2, (DISPLAY_RESY - 60) / 2, 200, 60,
                toi_icon_safeplace + 12, sizeof(toi_icon_safeplace) - 12,
@@ -161,7 +378,7 @@ void ui_screen_welcome_second(void) {
   display_refresh();
 }
 
-void ui_screen_welcome_third(void) {
+static void ui_screen_welcome_third(void) {
   display_bar(0, 0, DISPLAY_RESX, DISPLAY_RESY, COLOR_WELCOME_BG);
   display_icon((DISPLAY_RESX - 180) / 2, (DISPLAY_RESY - 30) / 2 - 5, 180, 30,
                toi_icon_welcome + 12, sizeof(toi_icon_welcome) - 12,
@@ -172,6 +389,24 @@ void ui_screen_welcome_third(void) {
   display_refresh();
 }
 
+void ui_screen_welcome(secbool firmware_corrupted) {
+  // no ui_fadeout(); - we already start from black screen
+  ui_screen_welcome_first();
+  ui_fadein();
+
+  hal_delay(1000);
+
+  ui_fadeout();
+  ui_screen_welcome_second();
+  ui_fadein();
+
+  hal_delay(1000);
+
+  ui_fadeout();
+  ui_screen_welcome_third();
+  ui_fadein();
+}
+
 // info UI
 
 static int display_vendor_string(const char *text, int textlen,
@@ -193,7 +428,7 @@ static int display_vendor_string(const char *text, int textlen,
   display_refresh();
 }
 
-void ui_screen_firmware_info(const vendor_header *const vhdr,
+static void ui_screen_firmware_info(const vendor_header *const vhdr,
                              const image_header *const hdr) {
   display_bar(0, 0, DISPLAY_RESX, DISPLAY_RESY, COLOR_BL_BG);
   const char *ver_str = format_ver("Bootloader %d.%d.%d", VERSION_UINT32);
@@ -215,10 +450,25 @@ void ui_screen_firmware_info(const vendor_header *const vhdr,
   display_refresh();
 }
 
+void ui_screen_intro(const vendor_header *const vhdr,
+                     const image_header *const hdr) {
+  // no ui_fadeout(); - we already start from black screen
+  ui_screen_firmware_info(vhdr, hdr);
+  ui_fadein();
+}
+
+void ui_screen_wait_for_host(const vendor_header *const vhdr,
+                             const image_header *const hdr) {
+  ui_screen_firmware_info(vhdr, hdr);
+}
+
+void ui_screen_wait_for_host_animate(void) {}
+
 // install UI
 
-void ui_screen_install_confirm_upgrade(const vendor_header *const vhdr,
-                                       const image_header *const hdr) {
+int ui_screen_install_confirm_upgrade(const vendor_header *const vhdr,
+                                      const image_header *const hdr,
+                                      const image_header *const current_hdr) {
   display_bar(0, 0, DISPLAY_RESX, DISPLAY_RESY, COLOR_BL_BG);
   display_text(16, 32, "Firmware update", -1, FONT_NORMAL, COLOR_BL_FG,
                COLOR_BL_BG);
@@ -233,11 +483,13 @@ void ui_screen_install_confirm_upgrade(const vendor_header *const vhdr,
   ui_confirm_cancel_buttons();
   PIXELDATA_DIRTY();
   display_refresh();
+  ui_fadein();
+  return ui_user_input(INPUT_CONFIRM | INPUT_CANCEL);
 }
 
-void ui_screen_install_confirm_newvendor_or_downgrade_wipe(
+int ui_screen_install_confirm_newvendor_or_downgrade_wipe(
     const vendor_header *const vhdr, const image_header *const hdr,
-    secbool downgrade_wipe) {
+    const image_header *const current_hdr, secbool downgrade_wipe) {
   display_bar(0, 0, DISPLAY_RESX, DISPLAY_RESY, COLOR_BL_BG);
   display_text(
       16, 32,
@@ -256,12 +508,15 @@ void ui_screen_install_confirm_newvendor_or_downgrade_wipe(
   ui_confirm_cancel_buttons();
   PIXELDATA_DIRTY();
   display_refresh();
+  ui_fadein();
+  return ui_user_input(INPUT_CONFIRM | INPUT_CANCEL);
 }
 
 void ui_screen_install_start(void) {
   display_bar(0, 0, DISPLAY_RESX, DISPLAY_RESY, COLOR_BL_BG);
-  display_loader(0, false, -20, COLOR_BL_PROCESS, COLOR_BL_BG, toi_icon_install,
-                 sizeof(toi_icon_install), COLOR_BL_FG);
+  display_loader(0, false, PROGRESS_LOADER_Y_OFFSET, COLOR_BL_PROCESS,
+                 COLOR_BL_BG, toi_icon_install, sizeof(toi_icon_install),
+                 COLOR_BL_FG);
   display_text_center(DISPLAY_RESX / 2, DISPLAY_RESY - 24,
                       "Installing firmware", -1, FONT_NORMAL, COLOR_BL_FG,
                       COLOR_BL_BG);
@@ -270,24 +525,28 @@ void ui_screen_install_start(void) {
 }
 
 void ui_screen_install_progress_erase(int pos, int len) {
-  display_loader(250 * pos / len, false, -20, COLOR_BL_PROCESS, COLOR_BL_BG,
-                 toi_icon_install, sizeof(toi_icon_install), COLOR_BL_FG);
+  display_loader(250 * pos / len, false, PROGRESS_LOADER_Y_OFFSET,
+                 COLOR_BL_PROCESS, COLOR_BL_BG, toi_icon_install,
+                 sizeof(toi_icon_install), COLOR_BL_FG);
 
   PIXELDATA_DIRTY();
   display_refresh();
 }
 
 void ui_screen_install_progress_upload(int pos) {
-  display_loader(pos, false, -20, COLOR_BL_PROCESS, COLOR_BL_BG,
-                 toi_icon_install, sizeof(toi_icon_install), COLOR_BL_FG);
+  display_loader(pos, false, PROGRESS_LOADER_Y_OFFSET, COLOR_BL_PROCESS,
+                 COLOR_BL_BG, toi_icon_install, sizeof(toi_icon_install),
+                 COLOR_BL_FG);
 
   PIXELDATA_DIRTY();
   display_refresh();
 }
 
+void ui_screen_install_check_stall(void) {}
+
 // wipe UI
 
-void ui_screen_wipe_confirm(void) {
+int ui_screen_wipe_confirm(void) {
   display_bar(0, 0, DISPLAY_RESX, DISPLAY_RESY, COLOR_BL_BG);
   display_text(16, 32, "Wipe device", -1, FONT_NORMAL, COLOR_BL_FG,
                COLOR_BL_BG);
@@ -304,12 +563,15 @@ void ui_screen_wipe_confirm(void) {
   ui_confirm_cancel_buttons();
   PIXELDATA_DIRTY();
   display_refresh();
+  ui_fadein();
+  return ui_user_input(INPUT_CONFIRM | INPUT_CANCEL);
 }
 
 void ui_screen_wipe(void) {
   display_bar(0, 0, DISPLAY_RESX, DISPLAY_RESY, COLOR_BL_BG);
-  display_loader(0, false, -20, COLOR_BL_PROCESS, COLOR_BL_BG, toi_icon_wipe,
-                 sizeof(toi_icon_wipe), COLOR_BL_FG);
+  display_loader(0, false, PROGRESS_LOADER_Y_OFFSET, COLOR_BL_PROCESS,
+                 COLOR_BL_BG, toi_icon_wipe, sizeof(toi_icon_wipe),
+                 COLOR_BL_FG);
   display_text_center(DISPLAY_RESX / 2, DISPLAY_RESY - 24, "Wiping device", -1,
                       FONT_NORMAL, COLOR_BL_FG, COLOR_BL_BG);
   PIXELDATA_DIRTY();
@@ -317,8 +579,9 @@ void ui_screen_wipe(void) {
 }
 
 void ui_screen_wipe_progress(int pos, int len) {
-  display_loader(1000 * pos / len, false, -20, COLOR_BL_PROCESS, COLOR_BL_BG,
-                 toi_icon_wipe, sizeof(toi_icon_wipe), COLOR_BL_FG);
+  display_loader(1000 * pos / len, false, PROGRESS_LOADER_Y_OFFSET,
+                 COLOR_BL_PROCESS, COLOR_BL_BG, toi_icon_wipe,
+                 sizeof(toi_icon_wipe), COLOR_BL_FG);
 
   PIXELDATA_DIRTY();
   display_refresh();
@@ -339,8 +602,9 @@ void ui_screen_done(int restart_seconds, secbool full_redraw) {
   if (sectrue == full_redraw) {
     display_bar(0, 0, DISPLAY_RESX, DISPLAY_RESY, COLOR_BL_BG);
   }
-  display_loader(1000, false, -20, COLOR_BL_DONE, COLOR_BL_BG, toi_icon_done,
-                 sizeof(toi_icon_done), COLOR_BL_FG);
+  display_loader(1000, false, PROGRESS_LOADER_Y_OFFSET, COLOR_BL_DONE,
+                 COLOR_BL_BG, toi_icon_done, sizeof(toi_icon_done),
+                 COLOR_BL_FG);
   if (secfalse == full_redraw) {
     display_bar(0, DISPLAY_RESY - 24 - 18, 240, 23, COLOR_BL_BG);
   }
@@ -351,12 +615,15 @@ void ui_screen_done(int restart_seconds, secbool full_redraw) {
   display_refresh();
 }
 
+void ui_screen_wipe_done(void) { ui_screen_done(0, sectrue); }
+
 // error UI
 
 void ui_screen_fail(void) {
   display_bar(0, 0, DISPLAY_RESX, DISPLAY_RESY, COLOR_BL_BG);
-  display_loader(1000, false, -20, COLOR_BL_FAIL, COLOR_BL_BG, toi_icon_fail,
-                 sizeof(toi_icon_fail), COLOR_BL_FG);
+  display_loader(1000, false, PROGRESS_LOADER_Y_OFFSET, COLOR_BL_FAIL,
+                 COLOR_BL_BG, toi_icon_fail, sizeof(toi_icon_fail),
+                 COLOR_BL_FG);
   display_text_center(DISPLAY_RESX / 2, DISPLAY_RESY - 24,
                       "Failed! Please, reconnect.", -1, FONT_NORMAL,
                       COLOR_BL_FG, COLOR_BL_BG);
@@ -365,51 +632,22 @@ void ui_screen_fail(void) {
   display_refresh();
 }
 
-// general functions
+void ui_screen_wipe_fail(void) { ui_screen_fail(); }
 
-void ui_fadein(void) { display_fade(0, BACKLIGHT_NORMAL, 1000); }
+#ifdef USE_BATTERY
+void ui_screen_install_low_battery(int soc) {
+  char soc_str[24];
+  mini_snprintf(soc_str, sizeof(soc_str), "Battery low (%d%%)", soc);
+  display_bar(0, 0, DISPLAY_RESX, DISPLAY_RESY, COLOR_BL_BG);
+  display_loader(1000, false, PROGRESS_LOADER_Y_OFFSET, COLOR_BL_FAIL,
+                 COLOR_BL_BG, toi_icon_fail, sizeof(toi_icon_fail),
+                 COLOR_BL_FG);
+  display_text_center(DISPLAY_RESX / 2, DISPLAY_RESY - 24, soc_str, -1,
+                      FONT_NORMAL, COLOR_BL_FG, COLOR_BL_BG);
 
-void ui_fadeout(void) {
-  display_fade(BACKLIGHT_NORMAL, 0, 500);
-  display_clear();
+  PIXELDATA_DIRTY();
+  display_refresh();
 }
+#endif
 
-int ui_user_input(int zones) {
-  for (;;) {
-#if defined TREZOR_MODEL_T
-    uint32_t evt = touch_click();
-    uint16_t x = touch_unpack_x(evt);
-    uint16_t y = touch_unpack_y(evt);
-    // clicked on Cancel button
-    if ((zones & INPUT_CANCEL) && x >= 9 && x < 9 + 108 && y > 184 &&
-        y < 184 + 50) {
-      return INPUT_CANCEL;
-    }
-    // clicked on Confirm button
-    if ((zones & INPUT_CONFIRM) && x >= 123 && x < 123 + 108 && y > 184 &&
-        y < 184 + 50) {
-      return INPUT_CONFIRM;
-    }
-    // clicked on Long Confirm button
-    if ((zones & INPUT_LONG_CONFIRM) && x >= 9 && x < 9 + 222 && y > 184 &&
-        y < 184 + 50) {
-      return INPUT_LONG_CONFIRM;
-    }
-    // clicked on Info icon
-    if ((zones & INPUT_INFO) && x >= 16 && x < 16 + 32 && y > 54 &&
-        y < 54 + 32) {
-      return INPUT_INFO;
-    }
-#elif defined TREZOR_MODEL_R
-    uint32_t evt = button_read();
-    if (evt == (BTN_LEFT | BTN_EVT_DOWN)) {
-      return INPUT_CANCEL;
-    }
-    if (evt == (BTN_RIGHT | BTN_EVT_DOWN)) {
-      return INPUT_CONFIRM;
-    }
-#else
-#error Unknown Trezor model
 #endif
-  }
-}
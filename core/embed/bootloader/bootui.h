@@ -28,29 +28,44 @@ void ui_screen_boot(const vendor_header* const vhdr,
 void ui_screen_boot_wait(int wait_seconds);
 void ui_screen_boot_click(void);
 
-void ui_screen_welcome_first(void);
-void ui_screen_welcome_second(void);
-void ui_screen_welcome_third(void);
+// Screens shown when entering the bootloader, they return once the device is
+// visible and ready to talk to the host.
+void ui_screen_welcome(secbool firmware_corrupted);
+void ui_screen_intro(const vendor_header* const vhdr,
+                     const image_header* const hdr);
 
-void ui_screen_firmware_info(const vendor_header* const vhdr,
+void ui_screen_wait_for_host(const vendor_header* const vhdr,
                              const image_header* const hdr);
+void ui_screen_wait_for_host_animate(void);
 
-void ui_screen_install_confirm_upgrade(const vendor_header* const vhdr,
-                                       const image_header* const hdr);
-void ui_screen_install_confirm_newvendor_or_downgrade_wipe(
+// Confirmations fade the screen in and return INPUT_CONFIRM or INPUT_CANCEL.
+int ui_screen_install_confirm_upgrade(const vendor_header* const vhdr,
+                                      const image_header* const hdr,
+                                      const image_header* const current_hdr);
+int ui_screen_install_confirm_newvendor_or_downgrade_wipe(
     const vendor_header* const vhdr, const image_header* const hdr,
-    secbool downgrade_wipe);
+    const image_header* const current_hdr, secbool downgrade_wipe);
 void ui_screen_install_start(void);
 void ui_screen_install_progress_erase(int pos, int len);
 void ui_screen_install_progress_upload(int pos);
+// Called on USB read timeouts during the upload, warns about a stalled
+// transfer.
+void ui_screen_install_check_stall(void);
 
-void ui_screen_wipe_confirm(void);
+int ui_screen_wipe_confirm(void);
 void ui_screen_wipe(void);
 void ui_screen_wipe_progress(int pos, int len);
 
 void ui_screen_done(int restart_seconds, secbool full_redraw);
+void ui_screen_wipe_done(void);
 
 void ui_screen_fail(void);
+void ui_screen_wipe_fail(void);
+
+#ifdef USE_BATTERY
+// Refusal to install the firmware with the battery charged to `soc` percent.
+void ui_screen_install_low_battery(int soc);
+#endif
 
 void ui_fadein(void);
 void ui_fadeout(void);
@@ -62,6 +77,4 @@ void ui_fadeout(void);
 #define INPUT_INFO 0x08          // Info icon
 // clang-format on
 
-int ui_user_input(int zones);
-
 #endif
@@ -20,6 +20,9 @@
 #include <string.h>
 #include <sys/types.h>
 
+#ifdef USE_BATTERY
+#include "battery.h"
+#endif
 #include "common.h"
 #include "compiler_traits.h"
 #include "display.h"
@@ -102,11 +105,18 @@ static secbool bootloader_usb_loop(const vendor_header *const vhdr,
   usb_init_all((vhdr == NULL && hdr == NULL) ? sectrue : secfalse);
 
   uint8_t buf[USB_PACKET_SIZE];
+  // the install progress is on the screen until the upload ends
+  secbool uploading = secfalse;
 
   for (;;) {
     int r = usb_webusb_read_blocking(USB_IFACE_NUM, buf, USB_PACKET_SIZE,
                                      USB_TIMEOUT);
     if (r != USB_PACKET_SIZE) {
+      if (sectrue == uploading) {
+        ui_screen_install_check_stall();
+      } else {
+        ui_screen_wait_for_host_animate();
+      }
       continue;
     }
     uint16_t msg_id;
@@ -124,12 +134,10 @@ static secbool bootloader_usb_loop(const vendor_header *const vhdr,
         break;
       case 5:  // WipeDevice
         ui_fadeout();
-        ui_screen_wipe_confirm();
-        ui_fadein();
-        int response = ui_user_input(INPUT_CONFIRM | INPUT_CANCEL);
+        int response = ui_screen_wipe_confirm();
         if (INPUT_CANCEL == response) {
           ui_fadeout();
-          ui_screen_firmware_info(vhdr, hdr);
+          ui_screen_wait_for_host(vhdr, hdr);
           ui_fadein();
           send_user_abort(USB_IFACE_NUM, "Wipe cancelled");
           break;
@@ -140,14 +148,14 @@ static secbool bootloader_usb_loop(const vendor_header *const vhdr,
         r = process_msg_WipeDevice(USB_IFACE_NUM, msg_size, buf);
         if (r < 0) {  // error
           ui_fadeout();
-          ui_screen_fail();
+          ui_screen_wipe_fail();
           ui_fadein();
           usb_stop();
           usb_deinit();
           return secfalse;  // shutdown
         } else {            // success
           ui_fadeout();
-          ui_screen_done(0, sectrue);
+          ui_screen_wipe_done();
           ui_fadein();
           usb_stop();
           usb_deinit();
@@ -159,7 +167,9 @@ static secbool bootloader_usb_loop(const vendor_header *const vhdr,
         break;
       case 7:  // FirmwareUpload
         r = process_msg_FirmwareUpload(USB_IFACE_NUM, msg_size, buf);
-        if (r < 0 && r != UPLOAD_ERR_USER_ABORT) {  // error, but not user abort
+        uploading = (r > 0) ? sectrue : secfalse;
+        if (r < 0 && r != UPLOAD_ERR_USER_ABORT &&
+            r != UPLOAD_ERR_BATTERY_LOW) {  // error, but not user abort
           ui_fadeout();
           ui_screen_fail();
           ui_fadein();
@@ -268,6 +278,10 @@ int main(void) {
   rgb_led_init();
 #endif
 
+#ifdef USE_BATTERY
+  battery_init();
+#endif
+
   mpu_config_bootloader();
 
 #if PRODUCTION
@@ -306,9 +320,12 @@ int main(void) {
   vendor_header vhdr;
   // detect whether the device contains a valid firmware
   secbool firmware_present = sectrue;
+  // a firmware which does not pass the checks below is reported as corrupted
+  secbool firmware_corrupted = sectrue;
 
   if (sectrue != read_vendor_header((const uint8_t *)FIRMWARE_START, &vhdr)) {
     firmware_present = secfalse;
+    firmware_corrupted = secfalse;
   }
 
   if (sectrue == firmware_present) {
@@ -342,22 +359,7 @@ int main(void) {
   // start the bootloader if no or broken firmware found ...
   if (firmware_present != sectrue) {
     // show intro animation
-
-    // no ui_fadeout(); - we already start from black screen
-    ui_screen_welcome_first();
-    ui_fadein();
-
-    hal_delay(1000);
-
-    ui_fadeout();
-    ui_screen_welcome_second();
-    ui_fadein();
-
-    hal_delay(1000);
-
-    ui_fadeout();
-    ui_screen_welcome_third();
-    ui_fadein();
+    ui_screen_welcome(firmware_corrupted);
 
     // erase storage
     ensure(flash_erase_sectors(STORAGE_SECTORS, STORAGE_SECTORS_COUNT, NULL),
@@ -372,9 +374,7 @@ int main(void) {
   // ... or if user touched the screen on start
   // ... or we have stay_in_bootloader flag to force it
   if (touched || stay_in_bootloader == sectrue) {
-    // no ui_fadeout(); - we already start from black screen
-    ui_screen_firmware_info(&vhdr, hdr);
-    ui_fadein();
+    ui_screen_intro(&vhdr, hdr);
 
     // and start the usb loop
     if (bootloader_usb_loop(&vhdr, hdr) != sectrue) {
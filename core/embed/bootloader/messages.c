@@ -24,6 +24,10 @@
 #include <pb_encode.h>
 #include "messages.pb.h"
 
+#ifdef USE_BATTERY
+#include "battery.h"
+#include "genhdr/rust_ui_bootloader.h"
+#endif
 #include "common.h"
 #include "flash.h"
 #include "image.h"
@@ -530,6 +534,22 @@ int process_msg_FirmwareUpload(uint8_t iface_num, uint32_t msg_size,
         return UPLOAD_ERR_INVALID_IMAGE_HEADER_SIG;
       }
 
+#ifdef USE_BATTERY
+      // nothing has been erased yet, refuse to start if the battery could
+      // run out halfway through
+      int32_t soc = battery_soc();
+      if (soc >= 0 && soc < BATTERY_MIN_INSTALL) {
+        ui_fadeout();
+        ui_screen_install_low_battery(soc);
+        ui_fadein();
+        MSG_SEND_INIT(Failure);
+        MSG_SEND_ASSIGN_VALUE(code, FailureType_Failure_ProcessError);
+        MSG_SEND_ASSIGN_STRING(message, "Battery low");
+        MSG_SEND(Failure);
+        return UPLOAD_ERR_BATTERY_LOW;
+      }
+#endif
+
       memcpy(&hdr, received_hdr, sizeof(hdr));
 
       vendor_header current_vhdr;
@@ -566,21 +586,17 @@ int process_msg_FirmwareUpload(uint8_t iface_num, uint32_t msg_size,
       } else if (sectrue == is_upgrade) {
         // firmware upgrade
         ui_fadeout();
-        ui_screen_install_confirm_upgrade(&vhdr, &hdr);
-        ui_fadein();
-        response = ui_user_input(INPUT_CONFIRM | INPUT_CANCEL);
+        response = ui_screen_install_confirm_upgrade(&vhdr, &hdr, current_hdr);
       } else {
         // downgrade with wipe or new firmware vendor
         ui_fadeout();
-        ui_screen_install_confirm_newvendor_or_downgrade_wipe(
-            &vhdr, &hdr, is_downgrade_wipe);
-        ui_fadein();
-        response = ui_user_input(INPUT_CONFIRM | INPUT_CANCEL);
+        response = ui_screen_install_confirm_newvendor_or_downgrade_wipe(
+            &vhdr, &hdr, current_hdr, is_downgrade_wipe);
       }
 
       if (INPUT_CANCEL == response) {
         ui_fadeout();
-        ui_screen_firmware_info(&current_vhdr, current_hdr);
+        ui_screen_wait_for_host(&current_vhdr, current_hdr);
         ui_fadein();
         send_user_abort(iface_num, "Firmware install cancelled");
         return UPLOAD_ERR_USER_ABORT;
@@ -40,6 +40,7 @@ enum {
   UPLOAD_ERR_USER_ABORT = -7,
   UPLOAD_ERR_FIRMWARE_TOO_BIG = -8,
   UPLOAD_ERR_INVALID_CHUNK_HASH = -9,
+  UPLOAD_ERR_BATTERY_LOW = -10,
 };
 
 enum {
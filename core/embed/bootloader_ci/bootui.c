@@ -27,6 +27,7 @@
 #include "icon_welcome.h"
 #include "icon_wipe.h"
 #include "mini_printf.h"
+#include "rust_ui.h"
 
 #define BACKLIGHT_NORMAL 150
 
@@ -61,36 +62,41 @@ void ui_screen_welcome_third(void) {
 
 void ui_screen_install_start(void) {
   display_bar(0, 0, DISPLAY_RESX, DISPLAY_RESY, COLOR_BL_BG);
-  display_loader(0, false, -20, COLOR_BL_PROCESS, COLOR_BL_BG, toi_icon_install,
-                 sizeof(toi_icon_install), COLOR_BL_FG);
+  display_loader(0, false, PROGRESS_LOADER_Y_OFFSET, COLOR_BL_PROCESS,
+                 COLOR_BL_BG, toi_icon_install, sizeof(toi_icon_install),
+                 COLOR_BL_FG);
   display_text_center(DISPLAY_RESX / 2, DISPLAY_RESY - 24,
                       "Installing firmware", -1, FONT_NORMAL, COLOR_BL_FG,
                       COLOR_BL_BG);
 }
 
 void ui_screen_install_progress_erase(int pos, int len) {
-  display_loader(250 * pos / len, false, -20, COLOR_BL_PROCESS, COLOR_BL_BG,
-                 toi_icon_install, sizeof(toi_icon_install), COLOR_BL_FG);
+  display_loader(250 * pos / len, false, PROGRESS_LOADER_Y_OFFSET,
+                 COLOR_BL_PROCESS, COLOR_BL_BG, toi_icon_install,
+                 sizeof(toi_icon_install), COLOR_BL_FG);
 }
 
 void ui_screen_install_progress_upload(int pos) {
-  display_loader(pos, false, -20, COLOR_BL_PROCESS, COLOR_BL_BG,
-                 toi_icon_install, sizeof(toi_icon_install), COLOR_BL_FG);
+  display_loader(pos, false, PROGRESS_LOADER_Y_OFFSET, COLOR_BL_PROCESS,
+                 COLOR_BL_BG, toi_icon_install, sizeof(toi_icon_install),
+                 COLOR_BL_FG);
 }
 
 // wipe UI
 
 void ui_screen_wipe(void) {
   display_bar(0, 0, DISPLAY_RESX, DISPLAY_RESY, COLOR_BL_BG);
-  display_loader(0, false, -20, COLOR_BL_PROCESS, COLOR_BL_BG, toi_icon_wipe,
-                 sizeof(toi_icon_wipe), COLOR_BL_FG);
+  display_loader(0, false, PROGRESS_LOADER_Y_OFFSET, COLOR_BL_PROCESS,
+                 COLOR_BL_BG, toi_icon_wipe, sizeof(toi_icon_wipe),
+                 COLOR_BL_FG);
   display_text_center(DISPLAY_RESX / 2, DISPLAY_RESY - 24, "Wiping device", -1,
                       FONT_NORMAL, COLOR_BL_FG, COLOR_BL_BG);
 }
 
 void ui_screen_wipe_progress(int pos, int len) {
-  display_loader(1000 * pos / len, false, -20, COLOR_BL_PROCESS, COLOR_BL_BG,
-                 toi_icon_wipe, sizeof(toi_icon_wipe), COLOR_BL_FG);
+  display_loader(1000 * pos / len, false, PROGRESS_LOADER_Y_OFFSET,
+                 COLOR_BL_PROCESS, COLOR_BL_BG, toi_icon_wipe,
+                 sizeof(toi_icon_wipe), COLOR_BL_FG);
 }
 
 // done UI
@@ -108,8 +114,9 @@ void ui_screen_done(int restart_seconds, secbool full_redraw) {
   if (sectrue == full_redraw) {
     display_bar(0, 0, DISPLAY_RESX, DISPLAY_RESY, COLOR_BL_BG);
   }
-  display_loader(1000, false, -20, COLOR_BL_DONE, COLOR_BL_BG, toi_icon_done,
-                 sizeof(toi_icon_done), COLOR_BL_FG);
+  display_loader(1000, false, PROGRESS_LOADER_Y_OFFSET, COLOR_BL_DONE,
+                 COLOR_BL_BG, toi_icon_done, sizeof(toi_icon_done),
+                 COLOR_BL_FG);
   if (secfalse == full_redraw) {
     display_bar(0, DISPLAY_RESY - 24 - 18, 240, 23, COLOR_BL_BG);
   }
@@ -121,8 +128,9 @@ void ui_screen_done(int restart_seconds, secbool full_redraw) {
 
 void ui_screen_fail(void) {
   display_bar(0, 0, DISPLAY_RESX, DISPLAY_RESY, COLOR_BL_BG);
-  display_loader(1000, false, -20, COLOR_BL_FAIL, COLOR_BL_BG, toi_icon_fail,
-                 sizeof(toi_icon_fail), COLOR_BL_FG);
+  display_loader(1000, false, PROGRESS_LOADER_Y_OFFSET, COLOR_BL_FAIL,
+                 COLOR_BL_BG, toi_icon_fail, sizeof(toi_icon_fail),
+                 COLOR_BL_FG);
   display_text_center(DISPLAY_RESX / 2, DISPLAY_RESY - 24,
                       "Failed! Please, reconnect.", -1, FONT_NORMAL,
                       COLOR_BL_FG, COLOR_BL_BG);
@@ -24,6 +24,10 @@
 #include "display.h"
 #include "embed/extmod/trezorobj.h"
 
+#if defined TREZOR_EMULATOR && defined TREZOR_MODEL_T
+#include "keyboard.h"
+#define KEYBOARD_IFACE (252)
+#endif
 #define USB_DATA_IFACE (253)
 #define BUTTON_IFACE (254)
 #define TOUCH_IFACE (255)
@@ -46,6 +50,7 @@ extern bool usb_connected_previously;
 ///                   - for button event (T1), tuple of:
 ///                     (event type, button number)
 ///                   - for USB read event, received bytes
+///                   - for keyboard event (emulator), key number
 ///
 ///     If timeout occurs, False is returned, True otherwise.
 ///     """
@@ -117,7 +122,18 @@ STATIC mp_obj_t mod_trezorio_poll(mp_obj_t ifaces, mp_obj_t list_ref,
           ret->items[1] = MP_OBJ_FROM_PTR(tuple);
           return mp_const_true;
         }
-      } else if (iface == USB_DATA_IFACE) {
+      }
+#if defined TREZOR_EMULATOR
+      else if (iface == KEYBOARD_IFACE) {
+        const uint32_t key = keyboard_read();
+        if (key) {
+          ret->items[0] = MP_OBJ_NEW_SMALL_INT(i);
+          ret->items[1] = MP_OBJ_NEW_SMALL_INT(key);
+          return mp_const_true;
+        }
+      }
+#endif
+      else if (iface == USB_DATA_IFACE) {
         bool usb_connected = usb_configured() == sectrue ? true : false;
         if (usb_connected != usb_connected_previously) {
           usb_connected_previously = usb_connected;
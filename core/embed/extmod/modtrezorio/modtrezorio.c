@@ -63,6 +63,7 @@ bool usb_connected_previously = true;
 /// TOUCH_START: int  # event id of touch start event
 /// TOUCH_MOVE: int  # event id of touch move event
 /// TOUCH_END: int  # event id of touch end event
+/// TOUCH_CANCEL: int  # event id of touch cancelled by a second contact
 
 /// BUTTON: int  # interface id of button events
 /// BUTTON_PRESSED: int  # button down event
@@ -71,6 +72,7 @@ bool usb_connected_previously = true;
 /// BUTTON_RIGHT: int  # button number of right button
 
 /// USB_CHECK: int # interface id for check of USB data connection
+/// KEYBOARD: int  # interface id of keyboard events (emulator only)
 
 /// WireInterface = Union[HID, WebUSB]
 
@@ -86,6 +88,11 @@ STATIC const mp_rom_map_elem_t mp_module_trezorio_globals_table[] = {
     {MP_ROM_QSTR(MP_QSTR_TOUCH_START), MP_ROM_INT((TOUCH_START >> 24) & 0xFFU)},
     {MP_ROM_QSTR(MP_QSTR_TOUCH_MOVE), MP_ROM_INT((TOUCH_MOVE >> 24) & 0xFFU)},
     {MP_ROM_QSTR(MP_QSTR_TOUCH_END), MP_ROM_INT((TOUCH_END >> 24) & 0xFFU)},
+    {MP_ROM_QSTR(MP_QSTR_TOUCH_CANCEL),
+     MP_ROM_INT((TOUCH_CANCEL >> 24) & 0xFFU)},
+#if defined TREZOR_EMULATOR
+    {MP_ROM_QSTR(MP_QSTR_KEYBOARD), MP_ROM_INT(KEYBOARD_IFACE)},
+#endif
 #elif defined TREZOR_MODEL_1 || defined TREZOR_MODEL_R
     {MP_ROM_QSTR(MP_QSTR_BUTTON), MP_ROM_INT(BUTTON_IFACE)},
     {MP_ROM_QSTR(MP_QSTR_BUTTON_PRESSED),
@@ -0,0 +1,42 @@
+/*
+ * This file is part of the Trezor project, https://trezor.io/
+ *
+ * Copyright (c) SatoshiLabs
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+#ifndef __ERROR_KIND_H__
+#define __ERROR_KIND_H__
+
+// Kind of a fatal error, decides the stable code shown on the error screen.
+// Shared by the hardware and emulator builds, the Rust bindings are generated
+// from it. Keep in sync with `ErrorCode` in embed/rust/src/ui/error_code.rs.
+typedef enum {
+  ERROR_KIND_FATAL = 0,
+  ERROR_KIND_RUST_PANIC = 1,
+  ERROR_KIND_UNWRAP_FAILED = 2,
+  ERROR_KIND_ASSERT_FAILED = 3,
+  ERROR_KIND_FAULT_DETECTED = 4,
+  ERROR_KIND_UNCAUGHT_EXCEPTION = 5,
+  ERROR_KIND_CLOCK_SECURITY = 6,
+  ERROR_KIND_HARD_FAULT = 7,
+  ERROR_KIND_MEM_MANAGE = 8,
+  ERROR_KIND_STACK_OVERFLOW = 9,
+  ERROR_KIND_BUS_FAULT = 10,
+  ERROR_KIND_USAGE_FAULT = 11,
+  ERROR_KIND_STACK_SMASHING = 12,
+} error_kind_t;
+
+#endif
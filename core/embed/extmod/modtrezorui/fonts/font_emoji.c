@@ -0,0 +1,68 @@
+// This file is generated by core/tools/codegen/gen_emoji.py
+
+#include <stddef.h>
+
+#include "font_emoji.h"
+#include "fonts.h"
+
+// clang-format off
+
+// - the first two bytes are width and height of the glyph
+// - the third, fourth and fifth bytes are advance, bearingX and bearingY of the horizontal metrics of the glyph
+// - the rest is packed glyph data in the bit depth of the fonts
+
+#if TREZOR_FONT_BPP == 4
+static const uint8_t Font_Emoji_glyph_26A1[] = { 14, 14, 16, 1, 14, 0, 0, 0, 0, 255, 240, 0, 0, 0, 0, 15, 255, 0, 0, 0, 0, 0, 255, 240, 0, 0, 0, 0, 15, 255, 0, 0, 0, 0, 0, 255, 240, 0, 0, 0, 0, 15, 255, 255, 255, 255, 0, 0, 255, 255, 255, 255, 240, 0, 0, 0, 0, 15, 255, 0, 0, 0, 0, 0, 255, 240, 0, 0, 0, 0, 15, 255, 0, 0, 0, 0, 0, 255, 240, 0, 0, 0, 0, 15, 255, 0, 0, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0 };
+static const uint8_t Font_Emoji_glyph_2714[] = { 14, 14, 16, 1, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 0, 0, 0, 15, 255, 0, 0, 0, 0, 0, 255, 240, 0, 0, 0, 0, 15, 255, 0, 0, 0, 0, 0, 255, 240, 0, 255, 0, 0, 15, 255, 0, 0, 255, 240, 0, 255, 240, 0, 0, 15, 255, 15, 255, 0, 0, 0, 0, 255, 255, 240, 0, 0, 0, 0, 15, 255, 0, 0, 0, 0, 0, 0, 240, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0 };
+static const uint8_t Font_Emoji_glyph_2764[] = { 14, 14, 16, 1, 14, 0, 0, 0, 0, 0, 0, 0, 0, 255, 240, 0, 15, 255, 0, 15, 255, 255, 0, 255, 255, 240, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 15, 255, 255, 255, 255, 255, 240, 0, 255, 255, 255, 255, 255, 0, 0, 15, 255, 255, 255, 240, 0, 0, 0, 255, 255, 255, 0, 0, 0, 0, 15, 255, 240, 0, 0, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0 };
+static const uint8_t Font_Emoji_glyph_2B50[] = { 14, 14, 16, 1, 14, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 255, 0, 0, 0, 0, 0, 15, 255, 240, 0, 0, 0, 0, 15, 255, 240, 0, 0, 255, 255, 255, 255, 255, 255, 255, 15, 255, 255, 255, 255, 255, 240, 0, 255, 255, 255, 255, 255, 0, 0, 15, 255, 255, 255, 240, 0, 0, 15, 255, 255, 255, 240, 0, 0, 255, 255, 0, 255, 255, 0, 0, 255, 240, 0, 15, 255, 0, 15, 240, 0, 0, 0, 15, 240, 15, 0, 0, 0, 0, 0, 240, 0, 0, 0, 0, 0, 0, 0 };
+static const uint8_t Font_Emoji_glyph_1F44D[] = { 14, 14, 16, 1, 14, 0, 0, 0, 255, 0, 0, 0, 0, 0, 15, 255, 0, 0, 0, 0, 0, 15, 255, 0, 0, 0, 0, 0, 255, 240, 0, 0, 0, 0, 15, 255, 255, 255, 255, 240, 255, 15, 255, 255, 255, 255, 240, 255, 15, 255, 255, 255, 255, 0, 255, 15, 255, 255, 255, 255, 240, 255, 15, 255, 255, 255, 255, 0, 255, 15, 255, 255, 255, 255, 240, 255, 15, 255, 255, 255, 255, 0, 255, 15, 255, 255, 255, 240, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0 };
+static const uint8_t Font_Emoji_glyph_1F4B0[] = { 14, 14, 16, 1, 14, 0, 0, 255, 255, 255, 0, 0, 0, 0, 15, 255, 240, 0, 0, 0, 0, 0, 255, 0, 0, 0, 0, 0, 255, 255, 255, 0, 0, 0, 255, 255, 255, 255, 255, 0, 15, 255, 255, 0, 255, 255, 240, 255, 255, 240, 255, 15, 255, 255, 255, 255, 255, 0, 255, 255, 255, 255, 255, 255, 240, 240, 255, 255, 255, 255, 240, 255, 15, 255, 255, 255, 255, 255, 0, 255, 255, 255, 15, 255, 255, 255, 255, 255, 240, 0, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0 };
+static const uint8_t Font_Emoji_glyph_1F511[] = { 14, 14, 16, 1, 14, 0, 255, 255, 0, 0, 0, 0, 15, 255, 255, 240, 0, 0, 0, 255, 240, 15, 255, 0, 0, 0, 255, 240, 15, 255, 0, 0, 0, 15, 255, 255, 240, 0, 0, 0, 0, 255, 255, 240, 0, 0, 0, 0, 0, 255, 240, 0, 0, 0, 0, 0, 255, 255, 0, 0, 0, 0, 0, 255, 240, 0, 0, 0, 0, 0, 255, 255, 240, 0, 0, 0, 0, 255, 240, 0, 0, 0, 0, 0, 255, 255, 0, 0, 0, 0, 0, 255, 240, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0 };
+static const uint8_t Font_Emoji_glyph_1F512[] = { 14, 14, 16, 1, 14, 0, 0, 255, 255, 255, 0, 0, 0, 15, 240, 0, 15, 240, 0, 0, 255, 0, 0, 0, 255, 0, 0, 255, 0, 0, 0, 255, 0, 0, 255, 0, 0, 0, 255, 0, 15, 255, 255, 255, 255, 255, 240, 15, 255, 255, 255, 255, 255, 240, 15, 255, 255, 0, 255, 255, 240, 15, 255, 255, 0, 255, 255, 240, 15, 255, 255, 240, 255, 255, 240, 15, 255, 255, 240, 255, 255, 240, 15, 255, 255, 255, 255, 255, 240, 15, 255, 255, 255, 255, 255, 240, 0, 0, 0, 0, 0, 0, 0 };
+static const uint8_t Font_Emoji_glyph_1F525[] = { 14, 14, 16, 1, 14, 0, 0, 0, 240, 0, 0, 0, 0, 0, 0, 255, 0, 0, 0, 0, 0, 15, 255, 0, 0, 0, 0, 0, 255, 255, 0, 15, 0, 0, 15, 255, 255, 0, 255, 0, 0, 255, 255, 255, 240, 255, 240, 0, 255, 255, 255, 255, 255, 240, 15, 255, 255, 15, 255, 255, 240, 15, 255, 240, 0, 255, 255, 240, 15, 255, 240, 0, 255, 255, 240, 15, 255, 0, 0, 15, 255, 240, 0, 255, 240, 0, 255, 255, 0, 0, 15, 255, 255, 255, 240, 0, 0, 0, 0, 0, 0, 0, 0 };
+static const uint8_t Font_Emoji_glyph_1F600[] = { 14, 14, 16, 1, 14, 0, 0, 255, 255, 255, 0, 0, 0, 255, 255, 255, 255, 255, 0, 15, 255, 255, 255, 255, 255, 240, 15, 255, 15, 255, 240, 255, 240, 255, 255, 15, 255, 240, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 255, 0, 0, 0, 0, 0, 255, 255, 240, 0, 0, 0, 15, 255, 15, 255, 0, 0, 0, 255, 240, 15, 255, 255, 255, 255, 255, 240, 0, 255, 255, 255, 255, 255, 0, 0, 0, 255, 255, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0 };
+static const uint8_t Font_Emoji_glyph_1F680[] = { 14, 14, 16, 1, 14, 0, 0, 0, 0, 0, 15, 255, 0, 0, 0, 0, 15, 255, 255, 0, 0, 0, 0, 255, 255, 255, 0, 0, 0, 255, 255, 255, 240, 0, 0, 15, 255, 240, 15, 240, 0, 15, 255, 255, 0, 15, 0, 15, 255, 255, 255, 255, 0, 0, 0, 15, 255, 255, 240, 0, 0, 0, 240, 255, 255, 0, 0, 0, 15, 240, 15, 240, 0, 0, 0, 15, 255, 15, 0, 0, 0, 0, 255, 255, 0, 0, 0, 0, 0, 255, 240, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0 };
+
+#elif TREZOR_FONT_BPP == 1
+static const uint8_t Font_Emoji_glyph_26A1[] = { 7, 7, 8, 0, 7, 8, 32, 243, 193, 4, 16, 0 };
+static const uint8_t Font_Emoji_glyph_2714[] = { 7, 7, 8, 0, 7, 2, 12, 52, 199, 4, 0, 0 };
+static const uint8_t Font_Emoji_glyph_2764[] = { 7, 7, 8, 0, 7, 69, 255, 251, 227, 130, 0, 0 };
+static const uint8_t Font_Emoji_glyph_2B50[] = { 7, 7, 8, 0, 7, 16, 115, 251, 231, 200, 128, 0 };
+static const uint8_t Font_Emoji_glyph_1F44D[] = { 7, 7, 8, 0, 7, 16, 99, 255, 239, 223, 128, 0 };
+static const uint8_t Font_Emoji_glyph_1F4B0[] = { 7, 7, 8, 0, 7, 56, 113, 247, 255, 255, 223, 0 };
+static const uint8_t Font_Emoji_glyph_1F511[] = { 7, 7, 8, 0, 7, 97, 225, 193, 131, 6, 8, 0 };
+static const uint8_t Font_Emoji_glyph_1F512[] = { 7, 7, 8, 0, 7, 56, 137, 247, 254, 255, 223, 0 };
+static const uint8_t Font_Emoji_glyph_1F525[] = { 7, 7, 8, 0, 7, 16, 97, 211, 254, 233, 142, 0 };
+static const uint8_t Font_Emoji_glyph_1F600[] = { 7, 7, 8, 0, 7, 125, 255, 255, 252, 111, 142, 0 };
+static const uint8_t Font_Emoji_glyph_1F680[] = { 7, 7, 8, 0, 7, 6, 60, 211, 199, 24, 32, 0 };
+
+#else
+#error Unsupported TREZOR_FONT_BPP value
+#endif
+
+static const struct {
+  uint32_t codepoint;
+  const uint8_t *glyph;
+} Font_Emoji[] = {
+    {0x26A1, Font_Emoji_glyph_26A1},
+    {0x2714, Font_Emoji_glyph_2714},
+    {0x2764, Font_Emoji_glyph_2764},
+    {0x2B50, Font_Emoji_glyph_2B50},
+    {0x1F44D, Font_Emoji_glyph_1F44D},
+    {0x1F4B0, Font_Emoji_glyph_1F4B0},
+    {0x1F511, Font_Emoji_glyph_1F511},
+    {0x1F512, Font_Emoji_glyph_1F512},
+    {0x1F525, Font_Emoji_glyph_1F525},
+    {0x1F600, Font_Emoji_glyph_1F600},
+    {0x1F680, Font_Emoji_glyph_1F680},
+};
+
+const uint8_t *font_emoji_get_glyph(uint32_t codepoint) {
+  for (size_t i = 0; i < sizeof(Font_Emoji) / sizeof(Font_Emoji[0]); i++) {
+    if (Font_Emoji[i].codepoint == codepoint) {
+      return Font_Emoji[i].glyph;
+    }
+  }
+  return NULL;
+}
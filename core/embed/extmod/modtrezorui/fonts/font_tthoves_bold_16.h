@@ -6,5 +6,6 @@
 #define Font_TTHoves_Bold_16_HEIGHT 16
 #define Font_TTHoves_Bold_16_MAX_HEIGHT 17
 #define Font_TTHoves_Bold_16_BASELINE 4
+#define Font_TTHoves_Bold_16_BPP 4
 extern const uint8_t* const Font_TTHoves_Bold_16[126 + 1 - 32];
 extern const uint8_t Font_TTHoves_Bold_16_glyph_nonprintable[];
@@ -6,5 +6,6 @@
 #define Font_TTHoves_DemiBold_18_HEIGHT 18
 #define Font_TTHoves_DemiBold_18_MAX_HEIGHT 19
 #define Font_TTHoves_DemiBold_18_BASELINE 4
+#define Font_TTHoves_DemiBold_18_BPP 4
 extern const uint8_t* const Font_TTHoves_DemiBold_18[126 + 1 - 32];
 extern const uint8_t Font_TTHoves_DemiBold_18_glyph_nonprintable[];
@@ -18,33 +18,57 @@
  */
 
 #include "fonts.h"
+#include "font_emoji.h"
 
-static uint8_t convert_char(const uint8_t c) {
-  static char last_was_utf8 = 0;
+// Decode UTF-8 byte by byte. Returns the ASCII character, 0 to skip the byte
+// or 0x7F for a non-printable character. The last byte of a multi-byte
+// sequence also yields 0x7F and sets `codepoint` to the decoded value, all
+// the previous bytes are skipped.
+static uint8_t convert_char(const uint8_t c, uint32_t *codepoint) {
+  static uint32_t utf8_codepoint = 0;
+  static int utf8_remaining = 0;
 
   // non-printable ASCII character
   if (c < ' ') {
-    last_was_utf8 = 0;
+    utf8_remaining = 0;
     return 0x7F;
   }
 
   // regular ASCII character
   if (c < 0x80) {
-    last_was_utf8 = 0;
+    utf8_remaining = 0;
     return c;
   }
 
   // UTF-8 handling: https://en.wikipedia.org/wiki/UTF-8#Encoding
 
   // bytes 11xxxxxx are first bytes of UTF-8 characters
-  if (c >= 0xC0) {
-    last_was_utf8 = 1;
+  if (c >= 0xF8) {
+    utf8_remaining = 0;
     return 0x7F;
   }
+  if (c >= 0xC0) {
+    if (c >= 0xF0) {
+      utf8_codepoint = c & 0x07;
+      utf8_remaining = 3;
+    } else if (c >= 0xE0) {
+      utf8_codepoint = c & 0x0F;
+      utf8_remaining = 2;
+    } else {
+      utf8_codepoint = c & 0x1F;
+      utf8_remaining = 1;
+    }
+    return 0;  // skip glyph, it is decided by the last byte
+  }
 
-  if (last_was_utf8) {
+  if (utf8_remaining) {
     // bytes 10xxxxxx can be successive UTF-8 characters ...
-    return 0;  // skip glyph
+    utf8_codepoint = (utf8_codepoint << 6) | (c & 0x3F);
+    if (--utf8_remaining) {
+      return 0;  // skip glyph
+    }
+    *codepoint = utf8_codepoint;
+    return 0x7F;
   } else {
     // ... or they are just non-printable ASCII characters
     return 0x7F;
@@ -117,10 +141,41 @@ int font_baseline(int font) {
   return 0;
 }
 
+int font_bpp(int font) {
+  switch (font) {
+#ifdef TREZOR_FONT_NORMAL_ENABLE
+    case FONT_NORMAL:
+      return FONT_NORMAL_BPP;
+#endif
+#ifdef TREZOR_FONT_DEMIBOLD_ENABLE
+    case FONT_DEMIBOLD:
+      return FONT_DEMIBOLD_BPP;
+#endif
+#ifdef TREZOR_FONT_BOLD_ENABLE
+    case FONT_BOLD:
+      return FONT_BOLD_BPP;
+#endif
+#ifdef TREZOR_FONT_MONO_ENABLE
+    case FONT_MONO:
+      return FONT_MONO_BPP;
+#endif
+  }
+  return 0;
+}
+
 const uint8_t *font_get_glyph(int font, uint8_t c) {
-  c = convert_char(c);
+  uint32_t codepoint = 0;
+  c = convert_char(c, &codepoint);
   if (!c) return 0;
 
+  // fall back to the emoji set for characters the fonts do not cover
+  if (codepoint) {
+    // variation selector and zero width joiner are not rendered
+    if (codepoint == 0xFE0F || codepoint == 0x200D) return 0;
+    const uint8_t *g = font_emoji_get_glyph(codepoint);
+    if (g) return g;
+  }
+
   // printable ASCII character
   if (c >= ' ' && c < 0x7F) {
     switch (font) {
@@ -43,6 +43,7 @@
 #define FONT_NORMAL_MAX_HEIGHT \
   FONT_DEFINE(TREZOR_FONT_NORMAL_ENABLE, _MAX_HEIGHT)
 #define FONT_NORMAL_BASELINE FONT_DEFINE(TREZOR_FONT_NORMAL_ENABLE, _BASELINE)
+#define FONT_NORMAL_BPP FONT_DEFINE(TREZOR_FONT_NORMAL_ENABLE, _BPP)
 #endif
 
 #ifdef TREZOR_FONT_DEMIBOLD_ENABLE
@@ -54,6 +55,7 @@
   FONT_DEFINE(TREZOR_FONT_DEMIBOLD_ENABLE, _MAX_HEIGHT)
 #define FONT_DEMIBOLD_BASELINE \
   FONT_DEFINE(TREZOR_FONT_DEMIBOLD_ENABLE, _BASELINE)
+#define FONT_DEMIBOLD_BPP FONT_DEFINE(TREZOR_FONT_DEMIBOLD_ENABLE, _BPP)
 #endif
 
 #ifdef TREZOR_FONT_MONO_ENABLE
@@ -63,6 +65,7 @@
 #define FONT_MONO_HEIGHT FONT_DEFINE(TREZOR_FONT_MONO_ENABLE, _HEIGHT)
 #define FONT_MONO_MAX_HEIGHT FONT_DEFINE(TREZOR_FONT_MONO_ENABLE, _MAX_HEIGHT)
 #define FONT_MONO_BASELINE FONT_DEFINE(TREZOR_FONT_MONO_ENABLE, _BASELINE)
+#define FONT_MONO_BPP FONT_DEFINE(TREZOR_FONT_MONO_ENABLE, _BPP)
 #endif
 
 #ifdef TREZOR_FONT_BOLD_ENABLE
@@ -72,6 +75,7 @@
 #define FONT_BOLD_HEIGHT FONT_DEFINE(TREZOR_FONT_BOLD_ENABLE, _HEIGHT)
 #define FONT_BOLD_MAX_HEIGHT FONT_DEFINE(TREZOR_FONT_BOLD_ENABLE, _MAX_HEIGHT)
 #define FONT_BOLD_BASELINE FONT_DEFINE(TREZOR_FONT_BOLD_ENABLE, _BASELINE)
+#define FONT_BOLD_BPP FONT_DEFINE(TREZOR_FONT_BOLD_ENABLE, _BPP)
 #endif
 
 #define MAX_FONT_H(A, B) ((A) > (B) ? (A) : (B))
@@ -105,6 +109,7 @@
 int font_height(int font);
 int font_max_height(int font);
 int font_baseline(int font);
+int font_bpp(int font);
 const uint8_t *font_get_glyph(int font, uint8_t c);
 
 #endif  //_FONTS_H
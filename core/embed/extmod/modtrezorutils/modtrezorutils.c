@@ -36,6 +36,10 @@
 #include "flash.h"
 #include "usb.h"
 
+#ifdef USE_BATTERY
+#include "battery.h"
+#endif
+
 #ifndef TREZOR_EMULATOR
 #include "image.h"
 #endif
@@ -233,6 +237,28 @@ STATIC mp_obj_t mod_trezorutils_usb_data_connected() {
 STATIC MP_DEFINE_CONST_FUN_OBJ_0(mod_trezorutils_usb_data_connected_obj,
                                  mod_trezorutils_usb_data_connected);
 
+/// def battery_status() -> tuple[int, bool] | None:
+///     """
+///     Returns the state of charge of the battery in percent and whether it is
+///     being charged, or None if the device has no battery or the fuel gauge
+///     could not be read.
+///     """
+STATIC mp_obj_t mod_trezorutils_battery_status() {
+#ifdef USE_BATTERY
+  int32_t soc = battery_soc();
+  if (soc >= 0) {
+    mp_obj_t tuple[2] = {
+        mp_obj_new_int(MIN(soc, 100)),
+        battery_charging() == sectrue ? mp_const_true : mp_const_false,
+    };
+    return mp_obj_new_tuple(2, tuple);
+  }
+#endif
+  return mp_const_none;
+}
+STATIC MP_DEFINE_CONST_FUN_OBJ_0(mod_trezorutils_battery_status_obj,
+                                 mod_trezorutils_battery_status);
+
 STATIC mp_obj_str_t mod_trezorutils_revision_obj = {
     {&mp_type_bytes}, 0, sizeof(SCM_REVISION) - 1, (const byte *)SCM_REVISION};
 
@@ -257,6 +283,8 @@ STATIC const mp_rom_map_elem_t mp_module_trezorutils_globals_table[] = {
      MP_ROM_PTR(&mod_trezorutils_reboot_to_bootloader_obj)},
     {MP_ROM_QSTR(MP_QSTR_usb_data_connected),
      MP_ROM_PTR(&mod_trezorutils_usb_data_connected_obj)},
+    {MP_ROM_QSTR(MP_QSTR_battery_status),
+     MP_ROM_PTR(&mod_trezorutils_battery_status_obj)},
     // various built-in constants
     {MP_ROM_QSTR(MP_QSTR_SCM_REVISION),
      MP_ROM_PTR(&mod_trezorutils_revision_obj)},
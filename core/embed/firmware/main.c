@@ -35,6 +35,9 @@
 #include "ports/stm32/gccollect.h"
 #include "ports/stm32/pendsv.h"
 
+#ifdef USE_BATTERY
+#include "battery.h"
+#endif
 #include "bl_check.h"
 #include "board_capabilities.h"
 #include "common.h"
@@ -126,6 +129,10 @@ int main(void) {
   sdcard_init();
 #endif
 
+#ifdef USE_BATTERY
+  battery_init();
+#endif
+
   display_clear();
 
 #if !defined TREZOR_MODEL_1
@@ -177,7 +184,8 @@ int main(void) {
 // MicroPython default exception handler
 
 void __attribute__((noreturn)) nlr_jump_fail(void *val) {
-  error_shutdown("Internal error", "(UE)", NULL, NULL);
+  error_shutdown_kind(ERROR_KIND_UNCAUGHT_EXCEPTION, "Internal error", "(UE)",
+                      NULL, NULL);
 }
 
 // interrupt handlers
@@ -185,28 +193,34 @@ void __attribute__((noreturn)) nlr_jump_fail(void *val) {
 void NMI_Handler(void) {
   // Clock Security System triggered NMI
   if ((RCC->CIR & RCC_CIR_CSSF) != 0) {
-    error_shutdown("Internal error", "(CS)", NULL, NULL);
+    error_shutdown_kind(ERROR_KIND_CLOCK_SECURITY, "Internal error", "(CS)",
+                        NULL, NULL);
   }
 }
 
 void HardFault_Handler(void) {
-  error_shutdown("Internal error", "(HF)", NULL, NULL);
+  error_shutdown_kind(ERROR_KIND_HARD_FAULT, "Internal error", "(HF)", NULL,
+                      NULL);
 }
 
 void MemManage_Handler_MM(void) {
-  error_shutdown("Internal error", "(MM)", NULL, NULL);
+  error_shutdown_kind(ERROR_KIND_MEM_MANAGE, "Internal error", "(MM)", NULL,
+                      NULL);
 }
 
 void MemManage_Handler_SO(void) {
-  error_shutdown("Internal error", "(SO)", NULL, NULL);
+  error_shutdown_kind(ERROR_KIND_STACK_OVERFLOW, "Internal error", "(SO)", NULL,
+                      NULL);
 }
 
 void BusFault_Handler(void) {
-  error_shutdown("Internal error", "(BF)", NULL, NULL);
+  error_shutdown_kind(ERROR_KIND_BUS_FAULT, "Internal error", "(BF)", NULL,
+                      NULL);
 }
 
 void UsageFault_Handler(void) {
-  error_shutdown("Internal error", "(UF)", NULL, NULL);
+  error_shutdown_kind(ERROR_KIND_USAGE_FAULT, "Internal error", "(UF)", NULL,
+                      NULL);
 }
 
 __attribute__((noreturn)) void reboot_to_bootloader() {
@@ -8,6 +8,9 @@ fn main() {
     #[cfg(feature = "micropython")]
     generate_micropython_bindings();
     generate_trezorhal_bindings();
+    #[cfg(feature = "bootloader")]
+    generate_bootloader_header();
+    export_firmware_version();
     #[cfg(feature = "test")]
     link_core_objects();
 }
@@ -32,6 +35,87 @@ fn board() -> String {
     }
 }
 
+/// Exposes firmware version from `version.h` to the crate as the
+/// `TREZOR_FIRMWARE_VERSION` environment variable, e.g. "2.5.3".
+fn export_firmware_version() {
+    // Tell cargo to invalidate the built crate whenever the header changes.
+    println!("cargo:rerun-if-changed=../firmware/version.h");
+
+    let header = std::fs::read_to_string("../firmware/version.h").unwrap();
+    let get = |name: &str| {
+        header
+            .lines()
+            .find_map(|line| {
+                line.strip_prefix("#define ")?
+                    .strip_prefix(name)?
+                    .trim()
+                    .parse::<u8>()
+                    .ok()
+            })
+            .unwrap()
+    };
+    println!(
+        "cargo:rustc-env=TREZOR_FIRMWARE_VERSION={}.{}.{}",
+        get("VERSION_MAJOR"),
+        get("VERSION_MINOR"),
+        get("VERSION_PATCH")
+    );
+}
+
+/// Generates `rust_ui_bootloader.h` with the prototypes of the bootloader
+/// screens and the values they take and return, so that the C bootloader
+/// cannot get out of sync with them. The header is written to
+/// `BOOTLOADER_GENHDR_DIR`, set by the bootloader build, or to `OUT_DIR`.
+#[cfg(feature = "bootloader")]
+fn generate_bootloader_header() {
+    const SOURCES: [&str; 4] = [
+        "src/ui/model_tt/bootloader/mod.rs",
+        "src/ui/model_tt/bootloader/result.rs",
+        "src/ui/model_tt/bootloader/intro.rs",
+        "src/ui/model_tt/bootloader/menu.rs",
+    ];
+
+    let mut config = cbindgen::Config {
+        language: cbindgen::Language::C,
+        header: Some(String::from(
+            "// Generated by build.rs from the Rust bootloader screens, do not edit.",
+        )),
+        pragma_once: true,
+        no_includes: true,
+        sys_includes: vec![String::from("stdbool.h"), String::from("stdint.h")],
+        style: cbindgen::Style::Type,
+        ..Default::default()
+    };
+    config.enumeration.prefix_with_name = true;
+    config.enumeration.rename_variants = cbindgen::RenameRule::ScreamingSnakeCase;
+    config.export.include = vec![
+        String::from("ConfirmResult"),
+        String::from("IntroResult"),
+        String::from("CalibrationResult"),
+    ];
+    // Public constants of the screens which are not part of the interface.
+    config.export.exclude = vec![String::from("MAX_ITEMS"), String::from("TITLE_AREA_HEIGHT")];
+    config
+        .export
+        .rename
+        .insert(String::from("MenuItem"), String::from("menu_item_t"));
+
+    let mut builder = cbindgen::Builder::new().with_config(config);
+    for source in SOURCES {
+        // Tell cargo to regenerate the header whenever the screens change.
+        println!("cargo:rerun-if-changed={}", source);
+        builder = builder.with_src(source);
+    }
+    println!("cargo:rerun-if-env-changed=BOOTLOADER_GENHDR_DIR");
+    let out_dir =
+        env::var("BOOTLOADER_GENHDR_DIR").unwrap_or_else(|_| env::var("OUT_DIR").unwrap());
+    std::fs::create_dir_all(&out_dir).unwrap();
+    builder
+        .generate()
+        .expect("Unable to generate bootloader header")
+        .write_to_file(PathBuf::from(out_dir).join("rust_ui_bootloader.h"));
+}
+
 /// Generates Rust module that exports QSTR constants used in firmware.
 #[cfg(feature = "micropython")]
 fn generate_qstr_bindings() {
@@ -249,6 +333,7 @@ fn generate_trezorhal_bindings() {
         .header("trezorhal.h")
         // common
         .allowlist_var("HW_ENTROPY_DATA")
+        .allowlist_type("error_kind_t")
         // secbool
         .allowlist_type("secbool")
         .must_use_type("secbool")
@@ -256,6 +341,12 @@ fn generate_trezorhal_bindings() {
         .allowlist_var("secfalse")
         // flash
         .allowlist_function("flash_init")
+        .allowlist_function("flash_get_address")
+        .allowlist_function("flash_unlock_write")
+        .allowlist_function("flash_lock_write")
+        .allowlist_function("flash_erase_sectors")
+        .allowlist_function("flash_write_word")
+        .allowlist_var("FLASH_SECTOR_SETTINGS")
         // storage
         .allowlist_var("EXTERNAL_SALT_SIZE")
         .allowlist_var("FLAG_PUBLIC")
@@ -301,6 +392,7 @@ fn generate_trezorhal_bindings() {
         .allowlist_function("font_height")
         .allowlist_function("font_max_height")
         .allowlist_function("font_baseline")
+        .allowlist_function("font_bpp")
         .allowlist_function("font_get_glyph")
         // uzlib
         .allowlist_function("uzlib_uncompress_init")
@@ -316,10 +408,20 @@ fn generate_trezorhal_bindings() {
         .allowlist_function("random_uniform")
         // rgb led
         .allowlist_function("rgb_led_set_color")
+        // ambient light
+        .allowlist_function("ambient_light_read")
+        // battery
+        .allowlist_function("battery_soc")
+        .allowlist_function("battery_voltage_mv")
+        .allowlist_function("battery_charging")
+        // touch
+        .allowlist_function("touch_read")
+        .allowlist_function("touch_get_contact")
         // time
         .allowlist_function("hal_delay")
         .allowlist_function("hal_ticks_ms")
         // dma2d
+        .allowlist_function("dma2d_setup_16bpp")
         .allowlist_function("dma2d_setup_4bpp")
         .allowlist_function("dma2d_setup_4bpp_over_4bpp")
         .allowlist_function("dma2d_setup_4bpp_over_16bpp")
@@ -17,11 +17,19 @@ static void _librust_qstrs(void) {
   MP_QSTR_CANCELLED;
   MP_QSTR_INFO;
   MP_QSTR_disable_animation;
+  MP_QSTR_set_color_blind;
+  MP_QSTR_set_reduced_motion;
+  MP_QSTR_set_bold_text;
+  MP_QSTR_enable_auto_brightness;
+  MP_QSTR_is_lookalike;
+  MP_QSTR_set_manual_clock;
+  MP_QSTR_advance_clock;
   MP_QSTR_jpeg_info;
   MP_QSTR_jpeg_test;
   MP_QSTR_confirm_action;
   MP_QSTR_confirm_homescreen;
   MP_QSTR_confirm_blob;
+  MP_QSTR_confirm_address;
   MP_QSTR_confirm_properties;
   MP_QSTR_confirm_coinjoin;
   MP_QSTR_confirm_fido;
@@ -37,6 +45,7 @@ static void _librust_qstrs(void) {
   MP_QSTR_show_checklist;
   MP_QSTR_show_error;
   MP_QSTR_show_qr;
+  MP_QSTR_show_animated_qr;
   MP_QSTR_show_success;
   MP_QSTR_show_warning;
   MP_QSTR_show_info;
@@ -49,10 +58,22 @@ static void _librust_qstrs(void) {
   MP_QSTR_select_word;
   MP_QSTR_select_word_count;
   MP_QSTR_show_busyscreen;
+  MP_QSTR_show_battery_charge;
+  MP_QSTR_soc;
+  MP_QSTR_charging;
+  MP_QSTR_bootloader_screens;
+  MP_QSTR_render_bootloader_screen;
+  MP_QSTR_screen;
   MP_QSTR_show_group_share_success;
   MP_QSTR_show_homescreen;
   MP_QSTR_show_lockscreen;
   MP_QSTR_show_remaining_shares;
+  MP_QSTR_show_group_grid;
+  MP_QSTR_group_threshold;
+  MP_QSTR_groups;
+  MP_QSTR_show_recovery_progress;
+  MP_QSTR_words_entered;
+  MP_QSTR_word_count;
   MP_QSTR_show_share_words;
   MP_QSTR_show_progress;
 
@@ -61,18 +82,26 @@ static void _librust_qstrs(void) {
   MP_QSTR_button_event;
   MP_QSTR_progress_event;
   MP_QSTR_usb_event;
+  MP_QSTR_status_event;
+  MP_QSTR_recovery_event;
+  MP_QSTR_key_event;
   MP_QSTR_timer;
   MP_QSTR_paint;
   MP_QSTR_request_complete_repaint;
   MP_QSTR_trace;
+  MP_QSTR_trace_bounds;
   MP_QSTR_bounds;
   MP_QSTR_page_count;
+  MP_QSTR_is_sensitive;
+  MP_QSTR_request_attention;
 
   MP_QSTR_title;
   MP_QSTR_subtitle;
   MP_QSTR_action;
   MP_QSTR_description;
   MP_QSTR_extra;
+  MP_QSTR_address;
+  MP_QSTR_identicon;
   MP_QSTR_verb;
   MP_QSTR_verb_cancel;
   MP_QSTR_hold;
@@ -99,6 +128,8 @@ static void _librust_qstrs(void) {
   MP_QSTR_count;
   MP_QSTR_min_count;
   MP_QSTR_max_count;
+  MP_QSTR_frames;
+  MP_QSTR_keypad;
   MP_QSTR_items;
   MP_QSTR_image;
   MP_QSTR_active;
@@ -1,6 +1,15 @@
+#include <stdbool.h>
 #include "common.h"
 
 void loader_uncompress_r(int32_t y_offset, uint16_t fg_color, uint16_t bg_color,
                          uint16_t icon_color, int32_t progress,
                          int32_t indeterminate, const uint8_t* icon_data,
                          uint32_t icon_data_size);
+// vertical offset of the loader on the progress screens, taken from the theme
+extern const int32_t PROGRESS_LOADER_Y_OFFSET;
+
+void screen_fatal_error_rust(error_kind_t kind, const char* expr,
+                             const char* msg, const char* file, int line);
+void screen_error_shutdown_rust(error_kind_t kind, const char* line1,
+                                const char* line2, const char* line3,
+                                const char* line4);
@@ -19,6 +19,8 @@ mod micropython;
 #[cfg(feature = "protobuf")]
 mod protobuf;
 mod storage;
+#[cfg(feature = "micropython")]
+mod strutil;
 mod time;
 #[cfg(feature = "ui_debug")]
 mod trace;
@@ -27,6 +29,25 @@ mod trace;
 #[macro_use]
 pub mod ui;
 
+/// Show the panic on the error screen and turn the device off. Without UI,
+/// fall back to the C fatal error handler.
+fn show_panic(msg: &str, file: &str, line: u32) -> ! {
+    #[cfg(feature = "ui")]
+    {
+        ui::screens::screen_fatal_error("", msg, file, line, ui::error_code::ErrorCode::RustPanic);
+        trezorhal::common::shutdown();
+    }
+    #[cfg(not(feature = "ui"))]
+    trezorhal::common::__fatal_error_kind(
+        trezorhal::common::ERROR_KIND_RUST_PANIC,
+        "",
+        msg,
+        file,
+        line,
+        "",
+    );
+}
+
 #[cfg(feature = "debug")]
 #[panic_handler]
 /// More detailed panic handling. The difference against
@@ -36,9 +57,9 @@ fn panic_debug(panic_info: &core::panic::PanicInfo) -> ! {
     // Filling at least the file and line information, if available.
     // TODO: find out how to display message from panic_info.message()
     if let Some(location) = panic_info.location() {
-        trezorhal::common::__fatal_error("", "rs", location.file(), location.line(), "");
+        show_panic("rs", location.file(), location.line());
     } else {
-        trezorhal::common::__fatal_error("", "rs", "", 0, "");
+        show_panic("rs", "", 0);
     }
 }
 
@@ -46,17 +67,45 @@ fn panic_debug(panic_info: &core::panic::PanicInfo) -> ! {
 #[cfg(not(test))]
 #[cfg(any(not(feature = "test"), feature = "clippy"))]
 #[panic_handler]
-/// Default panic handling. Not showing any details - thus saving flash space.
-fn panic(_info: &core::panic::PanicInfo) -> ! {
-    // TODO: as of Rust 1.63 / nightly 2022-08, ignoring the `_info` parameter does
-    // not help with saving flash space -- the `fmt` machinery still gets
-    // compiled in. We can avoid that by using unstable Cargo arguments:
+/// Default panic handling. Instead of the file name, only its short hash
+/// together with the line number is shown - thus saving screen space while
+/// still allowing to find the location in the sources.
+fn panic(info: &core::panic::PanicInfo) -> ! {
+    // TODO: as of Rust 1.63 / nightly 2022-08, using only the location and not the
+    // message of the panic does not help with saving flash space -- the `fmt`
+    // machinery still gets compiled in. We can avoid that by using unstable
+    // Cargo arguments:
     //   -Zbuild-std=core -Zbuild-std-features=panic_immediate_abort
     // Doing that will compile every panic!() to a single udf instruction which
     // raises a Hard Fault on hardware.
     //
     // Otherwise, use `unwrap!` macro from trezorhal.
-    trezorhal::common::__fatal_error("", "rs", "", 0, "");
+    let mut buf = [0; 8];
+    let (hash, line) = match info.location() {
+        Some(location) => (
+            location_hash(location.file(), location.line(), &mut buf),
+            location.line(),
+        ),
+        None => ("", 0),
+    };
+    show_panic("rs", hash, line);
+}
+
+/// Short hexadecimal FNV-1a hash of the panic location, stable across builds
+/// of the same sources.
+#[cfg(not(feature = "debug"))]
+fn location_hash(file: &str, line: u32, buf: &mut [u8; 8]) -> &str {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+    let mut hash: u32 = 0x811c9dc5;
+    for byte in file.bytes().chain(line.to_le_bytes()) {
+        hash ^= byte as u32;
+        hash = hash.wrapping_mul(0x01000193);
+    }
+    for (i, b) in buf.iter_mut().enumerate() {
+        *b = HEX[(hash >> (28 - 4 * i)) as usize & 0xf];
+    }
+    // SAFETY: `buf` contains only ASCII hex digits.
+    unsafe { core::str::from_utf8_unchecked(buf) }
 }
 
 #[cfg(not(target_arch = "arm"))]
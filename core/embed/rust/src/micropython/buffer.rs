@@ -1,6 +1,6 @@
 use core::{convert::TryFrom, ops::Deref, ptr, slice, str};
 
-use crate::{error::Error, micropython::obj::Obj};
+use crate::{error::Error, micropython::obj::Obj, strutil};
 
 use super::ffi;
 
@@ -93,6 +93,17 @@ impl StrBuffer {
             off: self.off + off,
         }
     }
+
+    pub fn truncate(&self, len: usize) -> Self {
+        let len: u16 = unwrap!(len.try_into());
+        assert!(len <= self.len);
+        assert!(self.as_ref().is_char_boundary(len.into()));
+        Self {
+            ptr: self.ptr,
+            len,
+            off: self.off,
+        }
+    }
 }
 
 impl Default for StrBuffer {
@@ -116,12 +127,17 @@ impl TryFrom<Obj> for StrBuffer {
             // MicroPython _should_ ensure that values of type `str` are UTF-8.
             // Rust seems to be stricter in what it considers UTF-8 though.
             // If there is a mismatch, we return an error.
-            let bytes = new.as_bytes();
-            if str::from_utf8(bytes).is_err() {
-                return Err(Error::TypeError);
-            }
+            let text = str::from_utf8(new.as_bytes()).map_err(|_| Error::TypeError)?;
 
-            Ok(new)
+            // Compose combining sequences so that the same text renders the same
+            // regardless of how the host encoded it. Needs a copy in that case.
+            if strutil::is_nfc_quick(text) {
+                Ok(new)
+            } else {
+                Self::alloc_with(strutil::nfc_len(text), |buffer| {
+                    strutil::nfc_write(text, buffer)
+                })
+            }
         } else {
             Err(Error::TypeError)
         }
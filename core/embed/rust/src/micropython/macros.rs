@@ -171,9 +171,10 @@ macro_rules! obj_type {
     }};
 }
 
-/// Construct an extmod definition.
+/// Construct an extmod definition. Entries can be conditional, e.g. on
+/// `#[cfg(feature = "ui_debug")]`.
 macro_rules! obj_module {
-    ($($key:expr => $val:expr),*) => ({
+    ($($(#[$attr:meta])* $key:path => $val:expr),*) => ({
         #[allow(unused_unsafe)]
         #[allow(unused_doc_comments)]
         unsafe {
@@ -186,6 +187,7 @@ macro_rules! obj_module {
                 },
                 map: Map::from_fixed_static(&[
                     $(
+                        $(#[$attr])*
                         Map::at($key, $val),
                     )*
                 ])
@@ -200,31 +202,7 @@ macro_rules! obj_module {
                 globals: &DICT as *const _ as *mut _,
             }
     }});
-    ($($key:expr => $val:expr),* ,) => ({
-        obj_module!($($key => $val),*)
+    ($($(#[$attr:meta])* $key:path => $val:expr),* ,) => ({
+        obj_module!($($(#[$attr])* $key => $val),*)
     });
 }
-
-/// Print arbitrary amounts of slices into a terminal.
-/// Does not include a newline at the end.
-/// Does not do anything when not in debugging mode.
-#[allow(unused_macros)] // Should be used only for debugging purposes
-macro_rules! print {
-    ($($string:expr),+) => {
-        #[cfg(feature = "debug")]
-        {
-            $(crate::micropython::print::print($string);)+
-        }
-    }
-}
-
-/// Print arbitrary amounts of slices into a terminal.
-/// Includes a newline at the end.
-/// Does not do anything when not in debugging mode.
-#[allow(unused_macros)] // Should be used only for debugging purposes
-macro_rules! println {
-    ($($string:expr),+) => {
-        // Just delegating to print! and adding a newline
-        print!($($string),+, "\n");
-    }
-}
@@ -0,0 +1,255 @@
+//! Unicode normalization of strings passed to the UI.
+//!
+//! Full NFC would need the complete Unicode composition tables, which is too
+//! much flash for a device that renders mostly ASCII anyway. Instead, we
+//! compose sequences of an ASCII letter followed by a combining diacritical
+//! mark into the precomposed characters of the Latin-1 Supplement and Latin
+//! Extended-A blocks. This makes e.g. "e\u{0301}" and "\u{00E9}" the same
+//! string, measured and rendered identically. Canonical reordering of multiple
+//! marks is not performed.
+
+/// Range of combining diacritical marks, strings without these are already
+/// composed as far as we are concerned.
+const COMBINING_MARKS: core::ops::RangeInclusive<char> = '\u{0300}'..='\u{036F}';
+
+/// Base letter, combining mark minus U+0300 and the precomposed character,
+/// sorted by the first two columns.
+const COMPOSITIONS: &[(u8, u8, u16)] = &[
+    (b'A', 0x00, 0x00C0), // À
+    (b'A', 0x01, 0x00C1), // Á
+    (b'A', 0x02, 0x00C2), // Â
+    (b'A', 0x03, 0x00C3), // Ã
+    (b'A', 0x04, 0x0100), // Ā
+    (b'A', 0x06, 0x0102), // Ă
+    (b'A', 0x08, 0x00C4), // Ä
+    (b'A', 0x0A, 0x00C5), // Å
+    (b'A', 0x28, 0x0104), // Ą
+    (b'C', 0x01, 0x0106), // Ć
+    (b'C', 0x02, 0x0108), // Ĉ
+    (b'C', 0x07, 0x010A), // Ċ
+    (b'C', 0x0C, 0x010C), // Č
+    (b'C', 0x27, 0x00C7), // Ç
+    (b'D', 0x0C, 0x010E), // Ď
+    (b'E', 0x00, 0x00C8), // È
+    (b'E', 0x01, 0x00C9), // É
+    (b'E', 0x02, 0x00CA), // Ê
+    (b'E', 0x04, 0x0112), // Ē
+    (b'E', 0x06, 0x0114), // Ĕ
+    (b'E', 0x07, 0x0116), // Ė
+    (b'E', 0x08, 0x00CB), // Ë
+    (b'E', 0x0C, 0x011A), // Ě
+    (b'E', 0x28, 0x0118), // Ę
+    (b'G', 0x02, 0x011C), // Ĝ
+    (b'G', 0x06, 0x011E), // Ğ
+    (b'G', 0x07, 0x0120), // Ġ
+    (b'G', 0x27, 0x0122), // Ģ
+    (b'H', 0x02, 0x0124), // Ĥ
+    (b'I', 0x00, 0x00CC), // Ì
+    (b'I', 0x01, 0x00CD), // Í
+    (b'I', 0x02, 0x00CE), // Î
+    (b'I', 0x03, 0x0128), // Ĩ
+    (b'I', 0x04, 0x012A), // Ī
+    (b'I', 0x06, 0x012C), // Ĭ
+    (b'I', 0x07, 0x0130), // İ
+    (b'I', 0x08, 0x00CF), // Ï
+    (b'I', 0x28, 0x012E), // Į
+    (b'J', 0x02, 0x0134), // Ĵ
+    (b'K', 0x27, 0x0136), // Ķ
+    (b'L', 0x01, 0x0139), // Ĺ
+    (b'L', 0x0C, 0x013D), // Ľ
+    (b'L', 0x27, 0x013B), // Ļ
+    (b'N', 0x01, 0x0143), // Ń
+    (b'N', 0x03, 0x00D1), // Ñ
+    (b'N', 0x0C, 0x0147), // Ň
+    (b'N', 0x27, 0x0145), // Ņ
+    (b'O', 0x00, 0x00D2), // Ò
+    (b'O', 0x01, 0x00D3), // Ó
+    (b'O', 0x02, 0x00D4), // Ô
+    (b'O', 0x03, 0x00D5), // Õ
+    (b'O', 0x04, 0x014C), // Ō
+    (b'O', 0x06, 0x014E), // Ŏ
+    (b'O', 0x08, 0x00D6), // Ö
+    (b'O', 0x0B, 0x0150), // Ő
+    (b'R', 0x01, 0x0154), // Ŕ
+    (b'R', 0x0C, 0x0158), // Ř
+    (b'R', 0x27, 0x0156), // Ŗ
+    (b'S', 0x01, 0x015A), // Ś
+    (b'S', 0x02, 0x015C), // Ŝ
+    (b'S', 0x0C, 0x0160), // Š
+    (b'S', 0x27, 0x015E), // Ş
+    (b'T', 0x0C, 0x0164), // Ť
+    (b'T', 0x27, 0x0162), // Ţ
+    (b'U', 0x00, 0x00D9), // Ù
+    (b'U', 0x01, 0x00DA), // Ú
+    (b'U', 0x02, 0x00DB), // Û
+    (b'U', 0x03, 0x0168), // Ũ
+    (b'U', 0x04, 0x016A), // Ū
+    (b'U', 0x06, 0x016C), // Ŭ
+    (b'U', 0x08, 0x00DC), // Ü
+    (b'U', 0x0A, 0x016E), // Ů
+    (b'U', 0x0B, 0x0170), // Ű
+    (b'U', 0x28, 0x0172), // Ų
+    (b'W', 0x02, 0x0174), // Ŵ
+    (b'Y', 0x01, 0x00DD), // Ý
+    (b'Y', 0x02, 0x0176), // Ŷ
+    (b'Y', 0x08, 0x0178), // Ÿ
+    (b'Z', 0x01, 0x0179), // Ź
+    (b'Z', 0x07, 0x017B), // Ż
+    (b'Z', 0x0C, 0x017D), // Ž
+    (b'a', 0x00, 0x00E0), // à
+    (b'a', 0x01, 0x00E1), // á
+    (b'a', 0x02, 0x00E2), // â
+    (b'a', 0x03, 0x00E3), // ã
+    (b'a', 0x04, 0x0101), // ā
+    (b'a', 0x06, 0x0103), // ă
+    (b'a', 0x08, 0x00E4), // ä
+    (b'a', 0x0A, 0x00E5), // å
+    (b'a', 0x28, 0x0105), // ą
+    (b'c', 0x01, 0x0107), // ć
+    (b'c', 0x02, 0x0109), // ĉ
+    (b'c', 0x07, 0x010B), // ċ
+    (b'c', 0x0C, 0x010D), // č
+    (b'c', 0x27, 0x00E7), // ç
+    (b'd', 0x0C, 0x010F), // ď
+    (b'e', 0x00, 0x00E8), // è
+    (b'e', 0x01, 0x00E9), // é
+    (b'e', 0x02, 0x00EA), // ê
+    (b'e', 0x04, 0x0113), // ē
+    (b'e', 0x06, 0x0115), // ĕ
+    (b'e', 0x07, 0x0117), // ė
+    (b'e', 0x08, 0x00EB), // ë
+    (b'e', 0x0C, 0x011B), // ě
+    (b'e', 0x28, 0x0119), // ę
+    (b'g', 0x02, 0x011D), // ĝ
+    (b'g', 0x06, 0x011F), // ğ
+    (b'g', 0x07, 0x0121), // ġ
+    (b'g', 0x27, 0x0123), // ģ
+    (b'h', 0x02, 0x0125), // ĥ
+    (b'i', 0x00, 0x00EC), // ì
+    (b'i', 0x01, 0x00ED), // í
+    (b'i', 0x02, 0x00EE), // î
+    (b'i', 0x03, 0x0129), // ĩ
+    (b'i', 0x04, 0x012B), // ī
+    (b'i', 0x06, 0x012D), // ĭ
+    (b'i', 0x08, 0x00EF), // ï
+    (b'i', 0x28, 0x012F), // į
+    (b'j', 0x02, 0x0135), // ĵ
+    (b'k', 0x27, 0x0137), // ķ
+    (b'l', 0x01, 0x013A), // ĺ
+    (b'l', 0x0C, 0x013E), // ľ
+    (b'l', 0x27, 0x013C), // ļ
+    (b'n', 0x01, 0x0144), // ń
+    (b'n', 0x03, 0x00F1), // ñ
+    (b'n', 0x0C, 0x0148), // ň
+    (b'n', 0x27, 0x0146), // ņ
+    (b'o', 0x00, 0x00F2), // ò
+    (b'o', 0x01, 0x00F3), // ó
+    (b'o', 0x02, 0x00F4), // ô
+    (b'o', 0x03, 0x00F5), // õ
+    (b'o', 0x04, 0x014D), // ō
+    (b'o', 0x06, 0x014F), // ŏ
+    (b'o', 0x08, 0x00F6), // ö
+    (b'o', 0x0B, 0x0151), // ő
+    (b'r', 0x01, 0x0155), // ŕ
+    (b'r', 0x0C, 0x0159), // ř
+    (b'r', 0x27, 0x0157), // ŗ
+    (b's', 0x01, 0x015B), // ś
+    (b's', 0x02, 0x015D), // ŝ
+    (b's', 0x0C, 0x0161), // š
+    (b's', 0x27, 0x015F), // ş
+    (b't', 0x0C, 0x0165), // ť
+    (b't', 0x27, 0x0163), // ţ
+    (b'u', 0x00, 0x00F9), // ù
+    (b'u', 0x01, 0x00FA), // ú
+    (b'u', 0x02, 0x00FB), // û
+    (b'u', 0x03, 0x0169), // ũ
+    (b'u', 0x04, 0x016B), // ū
+    (b'u', 0x06, 0x016D), // ŭ
+    (b'u', 0x08, 0x00FC), // ü
+    (b'u', 0x0A, 0x016F), // ů
+    (b'u', 0x0B, 0x0171), // ű
+    (b'u', 0x28, 0x0173), // ų
+    (b'w', 0x02, 0x0175), // ŵ
+    (b'y', 0x01, 0x00FD), // ý
+    (b'y', 0x02, 0x0177), // ŷ
+    (b'y', 0x08, 0x00FF), // ÿ
+    (b'z', 0x01, 0x017A), // ź
+    (b'z', 0x07, 0x017C), // ż
+    (b'z', 0x0C, 0x017E), // ž
+];
+
+fn compose(base: char, mark: char) -> Option<char> {
+    let base = u8::try_from(base).ok()?;
+    let mark = u8::try_from(u32::from(mark).checked_sub(0x300)?).ok()?;
+    let index = COMPOSITIONS
+        .binary_search_by_key(&(base, mark), |&(b, m, _)| (b, m))
+        .ok()?;
+    char::from_u32(COMPOSITIONS[index].2.into())
+}
+
+/// Fast check whether `s` is already normalized, true for all ASCII strings.
+pub fn is_nfc_quick(s: &str) -> bool {
+    s.is_ascii() || !s.chars().any(|c| COMBINING_MARKS.contains(&c))
+}
+
+/// Characters of `s` with supported combining sequences composed.
+pub fn nfc_chars(s: &str) -> impl Iterator<Item = char> + '_ {
+    let mut chars = s.chars().peekable();
+    core::iter::from_fn(move || {
+        let base = chars.next()?;
+        if let Some(composed) = chars.peek().and_then(|&mark| compose(base, mark)) {
+            chars.next();
+            Some(composed)
+        } else {
+            Some(base)
+        }
+    })
+}
+
+/// Length of `s` in bytes after normalization, never longer than `s`.
+pub fn nfc_len(s: &str) -> usize {
+    nfc_chars(s).map(char::len_utf8).sum()
+}
+
+/// Write the normalized `s` into `buffer` which has to be exactly
+/// `nfc_len(s)` bytes long.
+pub fn nfc_write(s: &str, buffer: &mut [u8]) {
+    let mut pos = 0;
+    for c in nfc_chars(s) {
+        pos += c.encode_utf8(&mut buffer[pos..]).len();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nfc(s: &str) -> String {
+        let mut buffer = vec![0; nfc_len(s)];
+        nfc_write(s, &mut buffer);
+        String::from_utf8(buffer).unwrap()
+    }
+
+    #[test]
+    fn compositions_sorted() {
+        assert!(COMPOSITIONS
+            .windows(2)
+            .all(|w| (w[0].0, w[0].1) < (w[1].0, w[1].1)));
+    }
+
+    #[test]
+    fn nfc_compose() {
+        assert!(is_nfc_quick("Hello"));
+        assert!(is_nfc_quick("P\u{0159}\u{00ED}li\u{0161}"));
+        assert!(!is_nfc_quick("Pr\u{030C}i\u{0301}lis\u{030C}"));
+
+        assert_eq!(nfc("Hello"), "Hello");
+        assert_eq!(
+            nfc("Pr\u{030C}i\u{0301}lis\u{030C}"),
+            "P\u{0159}\u{00ED}li\u{0161}"
+        );
+        assert_eq!(nfc("\u{0301}e\u{0301}\u{0301}"), "\u{0301}\u{00E9}\u{0301}");
+        // Unsupported sequences are left untouched.
+        assert_eq!(nfc("q\u{0301}"), "q\u{0301}");
+    }
+}
@@ -83,6 +83,11 @@ pub struct Instant {
 
 impl Instant {
     pub fn now() -> Self {
+        // SAFETY: The value is copied out, the reference is dropped right away.
+        #[cfg(feature = "ui_debug")]
+        if let Some(millis) = unsafe { *manual_clock() } {
+            return Self { millis };
+        }
         Self {
             millis: time::ticks_ms(),
         }
@@ -126,6 +131,85 @@ impl Instant {
     }
 }
 
+/// Time of the manually advanced clock, `None` while `Instant::now()` follows
+/// the hardware ticks.
+#[cfg(all(feature = "ui_debug", not(test)))]
+static mut MANUAL_CLOCK: Option<u32> = None;
+
+// A test stopping the clock must not stop it for the tests running in the
+// other threads.
+#[cfg(test)]
+std::thread_local! {
+    static MANUAL_CLOCK: core::cell::UnsafeCell<Option<u32>> =
+        core::cell::UnsafeCell::new(None);
+}
+
+/// Returns the state of the manual clock.
+///
+/// # Safety
+///
+/// The caller has to guarantee that no other reference returned by this
+/// function is alive while the result is used.
+#[cfg(feature = "ui_debug")]
+unsafe fn manual_clock() -> &'static mut Option<u32> {
+    #[cfg(not(test))]
+    let ptr = core::ptr::addr_of_mut!(MANUAL_CLOCK);
+    #[cfg(test)]
+    let ptr = MANUAL_CLOCK.with(|clock| clock.get());
+    unsafe { &mut *ptr }
+}
+
+/// Stop `Instant::now()` at the current time, from then on it moves only by
+/// `advance_clock()`, so that tests can step animations deterministically.
+/// Disabling it goes back to the hardware ticks. Debug builds only.
+#[cfg(feature = "ui_debug")]
+pub fn set_manual_clock(enabled: bool) {
+    // SAFETY: Called from MicroPython in the UI task, `ticks_ms` does not
+    // touch the clock.
+    let clock = unsafe { manual_clock() };
+    if enabled {
+        clock.get_or_insert_with(time::ticks_ms);
+    } else {
+        *clock = None;
+    }
+}
+
+/// Move the manual clock forward by `duration`, no-op while it is disabled.
+#[cfg(feature = "ui_debug")]
+pub fn advance_clock(duration: Duration) {
+    // SAFETY: Called from MicroPython in the UI task, the reference is only
+    // used for the addition.
+    if let Some(millis) = unsafe { manual_clock() } {
+        *millis = millis.wrapping_add(duration.to_millis());
+    }
+}
+
+/// Instant `millis` after `start`, for tests feeding events at given times.
+#[cfg(test)]
+pub fn at(start: Instant, millis: u32) -> Instant {
+    unwrap!(start.checked_add(Duration::from_millis(millis)))
+}
+
+/// Manual clock of the calling test thread, stopped until the guard is
+/// dropped. Other tests running in parallel keep their own clocks.
+#[cfg(test)]
+pub struct ManualClock;
+
+#[cfg(test)]
+impl ManualClock {
+    pub fn start() -> Self {
+        set_manual_clock(true);
+        Self
+    }
+}
+
+#[cfg(test)]
+impl Drop for ManualClock {
+    fn drop(&mut self) {
+        set_manual_clock(false);
+    }
+}
+
 impl PartialOrd for Instant {
     fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
         Some(self.cmp(rhs))
@@ -160,4 +244,18 @@ mod tests {
         assert_eq!(later, Instant { millis: 0 });
         assert!(earlier < later);
     }
+
+    #[test]
+    fn manual_clock_steps() {
+        {
+            let _clock = ManualClock::start();
+            let start = Instant::now();
+            assert_eq!(Instant::now(), start);
+            advance_clock(Duration::from_millis(250));
+            let later = Instant::now();
+            assert_eq!(later.saturating_duration_since(start).to_millis(), 250);
+        }
+        // SAFETY: Temporary reference in the test thread.
+        assert_eq!(unsafe { *manual_clock() }, None);
+    }
 }
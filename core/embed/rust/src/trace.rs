@@ -38,6 +38,12 @@ impl Trace for usize {
     }
 }
 
+impl Trace for i16 {
+    fn trace(&self, t: &mut dyn Tracer) {
+        t.int(*self as i64);
+    }
+}
+
 impl<T> Trace for Option<T>
 where
     T: Trace,
@@ -50,10 +56,96 @@ where
     }
 }
 
+/// Marks a traced value as secret, e.g. PIN, passphrase or seed words. Its
+/// structure is traced as usual, but all strings, bytes and integers are
+/// replaced by `REDACTED` so that the secret does not leak into debug output.
+pub struct Secret<'a>(pub &'a dyn Trace);
+
+pub const REDACTED: &str = "<redacted>";
+
+impl Trace for Secret<'_> {
+    fn trace(&self, t: &mut dyn Tracer) {
+        self.0.trace(&mut Redactor(t));
+    }
+}
+
+/// Tracer adapter which forwards the structure of the traced value but hides
+/// its data.
+struct Redactor<'a>(&'a mut dyn Tracer);
+
+impl Tracer for Redactor<'_> {
+    fn int(&mut self, _i: i64) {
+        self.0.string(REDACTED);
+    }
+
+    fn bytes(&mut self, _b: &[u8]) {
+        self.0.string(REDACTED);
+    }
+
+    fn string(&mut self, _s: &str) {
+        self.0.string(REDACTED);
+    }
+
+    fn symbol(&mut self, name: &str) {
+        self.0.symbol(name);
+    }
+
+    fn open(&mut self, name: &str) {
+        self.0.open(name);
+    }
+
+    fn field(&mut self, name: &str, value: &dyn Trace) {
+        self.0.field(name, &Secret(value));
+    }
+
+    fn close(&mut self) {
+        self.0.close();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    struct Entry<'a> {
+        name: &'a str,
+        pin: &'a str,
+    }
+
+    impl Trace for Entry<'_> {
+        fn trace(&self, t: &mut dyn Tracer) {
+            t.open("Entry");
+            t.field("name", &self.name);
+            t.field("pin", &Secret(&self.pin));
+            t.close();
+        }
+    }
+
+    #[test]
+    fn secret_is_redacted() {
+        let mut t = Vec::new();
+        Entry {
+            name: "PIN",
+            pin: "1234",
+        }
+        .trace(&mut t);
+        assert_eq!(
+            String::from_utf8(t).unwrap(),
+            "<Entry name:PIN pin:<redacted> >"
+        );
+
+        let mut t = Vec::new();
+        Secret(&Entry {
+            name: "PIN",
+            pin: "1234",
+        })
+        .trace(&mut t);
+        assert_eq!(
+            String::from_utf8(t).unwrap(),
+            "<Entry name:<redacted> pin:<redacted> >"
+        );
+    }
+
     impl Tracer for Vec<u8> {
         fn int(&mut self, i: i64) {
             self.string(&i.to_string());
@@ -0,0 +1,7 @@
+use super::ffi;
+
+/// Measured illuminance in lux, `None` if the sensor could not be read.
+pub fn read() -> Option<u32> {
+    let lux = unsafe { ffi::ambient_light_read() };
+    lux.try_into().ok()
+}
@@ -0,0 +1,17 @@
+use super::ffi;
+
+/// State of charge in percent, `None` if the fuel gauge could not be read.
+pub fn soc() -> Option<u8> {
+    let soc = unsafe { ffi::battery_soc() };
+    soc.try_into().ok().map(|soc: u8| soc.min(100))
+}
+
+/// Battery voltage in millivolts, `None` if it could not be measured.
+pub fn voltage_mv() -> Option<u32> {
+    let voltage = unsafe { ffi::battery_voltage_mv() };
+    voltage.try_into().ok()
+}
+
+pub fn is_charging() -> bool {
+    unsafe { ffi::battery_charging() == ffi::sectrue }
+}
@@ -63,23 +63,14 @@ impl Wordlist {
         Self(&[])
     }
 
+    /// Words starting with `prefix`. The wordlist is sorted, so the words are
+    /// found by binary search instead of comparing every one of them.
     pub fn filter_prefix(&self, prefix: &str) -> Self {
-        let mut start = 0usize;
-        let mut end = self.0.len();
-        for (i, word) in self.0.iter().enumerate() {
-            // SAFETY: We assume our slice is an array of 0-terminated strings.
-            match unsafe { prefix_cmp(prefix, *word) } {
-                Ordering::Less => {
-                    start = i + 1;
-                }
-                Ordering::Greater => {
-                    end = i;
-                    break;
-                }
-                _ => {}
-            }
-        }
-        Self(&self.0[start..end])
+        // SAFETY: We assume our slice is an array of 0-terminated strings.
+        let cmp = |word: &*const cty::c_char| unsafe { prefix_cmp(prefix, *word) };
+        let start = self.0.partition_point(|word| cmp(word) == Ordering::Less);
+        let len = self.0[start..].partition_point(|word| cmp(word) == Ordering::Equal);
+        Self(&self.0[start..start + len])
     }
 
     pub fn get(&self, index: usize) -> Option<&'static str> {
@@ -189,6 +180,17 @@ mod tests {
         assert_eq!(filtered.get(filtered.len()), None);
     }
 
+    #[test]
+    fn test_filter_prefix_edges() {
+        let first = Wordlist::all().filter_prefix("abandon");
+        assert_eq!(first.iter().collect::<Vec<_>>(), vec!["abandon"]);
+        let last = Wordlist::all().filter_prefix("zoo");
+        assert_eq!(last.iter().collect::<Vec<_>>(), vec!["zoo"]);
+        assert_eq!(Wordlist::all().filter_prefix("zz").len(), 0);
+        assert_eq!(Wordlist::all().filter_prefix("aaa").len(), 0);
+        assert_eq!(Wordlist::empty().filter_prefix("a").len(), 0);
+    }
+
     #[test]
     fn test_filter_prefix_just_one() {
         let expected_result = vec!["stick"];
@@ -1,17 +1,53 @@
 mod ffi {
     extern "C" {
         // trezorhal/common.c
-        pub fn __fatal_error(
+        pub fn __fatal_error_kind(
+            kind: cty::c_int,
             expr: *const cty::c_char,
             msg: *const cty::c_char,
             file: *const cty::c_char,
             line: i32,
             func: *const cty::c_char,
         ) -> !;
+
+        // trezorhal/common.c
+        #[cfg(target_arch = "arm")]
+        pub fn shutdown();
+
+        // unix/common.c
+        #[cfg(not(target_arch = "arm"))]
+        #[link_name = "__shutdown"]
+        pub fn shutdown();
+    }
+}
+
+/// Kinds of fatal errors, `error_kind_t` in error_kind.h. The kind decides the
+/// error code shown on the screen.
+pub const ERROR_KIND_FATAL: cty::c_int = super::ffi::error_kind_t_ERROR_KIND_FATAL as _;
+pub const ERROR_KIND_RUST_PANIC: cty::c_int = super::ffi::error_kind_t_ERROR_KIND_RUST_PANIC as _;
+pub const ERROR_KIND_UNWRAP_FAILED: cty::c_int =
+    super::ffi::error_kind_t_ERROR_KIND_UNWRAP_FAILED as _;
+
+/// Turn the device off, e.g. after displaying an error screen.
+pub fn shutdown() -> ! {
+    unsafe { ffi::shutdown() };
+    loop {
+        core::hint::spin_loop();
     }
 }
 
 pub fn __fatal_error(expr: &str, msg: &str, file: &str, line: u32, func: &str) -> ! {
+    __fatal_error_kind(ERROR_KIND_FATAL, expr, msg, file, line, func)
+}
+
+pub fn __fatal_error_kind(
+    kind: cty::c_int,
+    expr: &str,
+    msg: &str,
+    file: &str,
+    line: u32,
+    func: &str,
+) -> ! {
     const MAX_LEN: usize = 50 + 1; // Leave space for the null terminator.
 
     fn as_cstr_buf(s: &str) -> [cty::c_char; MAX_LEN] {
@@ -32,7 +68,8 @@ pub fn __fatal_error(expr: &str, msg: &str, file: &str, line: u32, func: &str) -
     let func_buf = as_cstr_buf(func);
 
     unsafe {
-        ffi::__fatal_error(
+        ffi::__fatal_error_kind(
+            kind,
             expr_buf.as_ptr(),
             msg_buf.as_ptr(),
             file_buf.as_ptr(),
@@ -50,7 +87,7 @@ impl<T> UnwrapOrFatalError<T> for Option<T> {
     fn unwrap_or_fatal_error(self, expr: &str, msg: &str, file: &str, line: u32, func: &str) -> T {
         match self {
             Some(x) => x,
-            None => __fatal_error(expr, msg, file, line, func),
+            None => __fatal_error_kind(ERROR_KIND_UNWRAP_FAILED, expr, msg, file, line, func),
         }
     }
 }
@@ -59,7 +96,7 @@ impl<T, E> UnwrapOrFatalError<T> for Result<T, E> {
     fn unwrap_or_fatal_error(self, expr: &str, msg: &str, file: &str, line: u32, func: &str) -> T {
         match self {
             Ok(x) => x,
-            Err(_) => __fatal_error(expr, msg, file, line, func),
+            Err(_) => __fatal_error_kind(ERROR_KIND_UNWRAP_FAILED, expr, msg, file, line, func),
         }
     }
 }
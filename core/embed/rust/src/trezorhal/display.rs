@@ -72,6 +72,10 @@ pub fn text_baseline(font: i32) -> i16 {
     unsafe { ffi::font_baseline(font).try_into().unwrap_or(i16::MAX) }
 }
 
+pub fn text_bpp(font: i32) -> i16 {
+    unsafe { ffi::font_bpp(font).try_into().unwrap_or(i16::MAX) }
+}
+
 pub fn bar(x: i16, y: i16, w: i16, h: i16, fgcolor: u16) {
     unsafe { ffi::display_bar(x.into(), y.into(), w.into(), h.into(), fgcolor) }
 }
@@ -132,33 +136,87 @@ pub fn loader(
     }
 }
 
-#[inline(always)]
-#[cfg(all(feature = "model_tt", target_arch = "arm"))]
-pub fn pixeldata(c: u16) {
-    unsafe {
-        ffi::DISPLAY_DATA_ADDRESS.write_volatile((c & 0xff) as u8);
-        ffi::DISPLAY_DATA_ADDRESS.write_volatile((c >> 8) as u8);
+/// Low-level interface of a display panel controller.
+///
+/// UI code draws only through the free functions below, which delegate to the
+/// driver selected for the current model (`Driver`). Bringing up a new panel
+/// controller means implementing this trait and selecting it in `Driver`.
+pub trait DisplayDriver {
+    /// Initialize the controller.
+    fn init();
+    /// Select the area that subsequent `pixeldata` calls fill, row by row.
+    fn set_window(x0: u16, y0: u16, x1: u16, y1: u16);
+    /// Write a single RGB565 pixel into the current window.
+    fn pixeldata(c: u16);
+    /// Mark pixel data written directly to the controller as dirty.
+    fn pixeldata_dirty();
+    /// Wait for the vertical sync, so that drawing does not tear.
+    fn sync();
+    /// Transfer the written pixel data to the panel, if it is buffered.
+    fn flush();
+}
+
+/// Display controllers driven by the C display driver of the current board,
+/// i.e. ST7789V on model T and the monochromatic OLEDs.
+pub struct HalDisplay;
+
+impl DisplayDriver for HalDisplay {
+    fn init() {
+        unsafe { ffi::display_init() }
+    }
+
+    fn set_window(x0: u16, y0: u16, x1: u16, y1: u16) {
+        unsafe { ffi::display_set_window(x0, y0, x1, y1) }
+    }
+
+    #[inline(always)]
+    #[cfg(all(feature = "model_tt", target_arch = "arm"))]
+    fn pixeldata(c: u16) {
+        // ST7789V is connected through FMC, pixel data are written directly to the
+        // controller.
+        unsafe {
+            ffi::DISPLAY_DATA_ADDRESS.write_volatile((c & 0xff) as u8);
+            ffi::DISPLAY_DATA_ADDRESS.write_volatile((c >> 8) as u8);
+        }
+    }
+
+    #[inline(always)]
+    #[cfg(not(all(feature = "model_tt", target_arch = "arm")))]
+    fn pixeldata(c: u16) {
+        unsafe { ffi::display_pixeldata(c) }
+    }
+
+    fn pixeldata_dirty() {
+        unsafe { ffi::display_pixeldata_dirty() }
+    }
+
+    fn sync() {
+        unsafe { ffi::display_sync() }
     }
+
+    fn flush() {
+        unsafe { ffi::display_refresh() }
+    }
+}
+
+/// Driver of the display of the current model.
+pub type Driver = HalDisplay;
+
+pub fn init() {
+    Driver::init();
 }
 
 #[inline(always)]
-#[cfg(not(all(feature = "model_tt", target_arch = "arm")))]
 pub fn pixeldata(c: u16) {
-    unsafe {
-        ffi::display_pixeldata(c);
-    }
+    Driver::pixeldata(c);
 }
 
 pub fn pixeldata_dirty() {
-    unsafe {
-        ffi::display_pixeldata_dirty();
-    }
+    Driver::pixeldata_dirty();
 }
 
 pub fn set_window(x0: u16, y0: u16, x1: u16, y1: u16) {
-    unsafe {
-        ffi::display_set_window(x0, y0, x1, y1);
-    }
+    Driver::set_window(x0, y0, x1, y1);
 }
 
 pub fn get_offset() -> (i16, i16) {
@@ -171,7 +229,9 @@ pub fn get_offset() -> (i16, i16) {
 }
 
 pub fn sync() {
-    unsafe {
-        ffi::display_sync();
-    }
+    Driver::sync();
+}
+
+pub fn refresh() {
+    Driver::flush();
 }
@@ -1,5 +1,9 @@
 use super::ffi;
 
+pub fn dma2d_setup_16bpp() {
+    unsafe { ffi::dma2d_setup_16bpp() }
+}
+
 pub fn dma2d_setup_4bpp(fg_color: u16, bg_color: u16) {
     unsafe { ffi::dma2d_setup_4bpp(fg_color, bg_color) }
 }
@@ -0,0 +1,54 @@
+use super::ffi;
+use core::slice;
+
+/// Sector reserved for device settings which survive a wipe, e.g. touch
+/// calibration.
+pub const SECTOR_SETTINGS: u8 = ffi::FLASH_SECTOR_SETTINGS as u8;
+
+/// Flash error type.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FlashError {
+    /// Erasing the sector failed.
+    EraseFailed,
+    /// Write failed.
+    WriteFailed,
+}
+
+/// Memory-mapped view of `len` words starting at `offset` bytes into the
+/// sector, `None` if the range does not fit.
+pub fn read_words(sector: u8, offset: u32, len: usize) -> Option<&'static [u32]> {
+    let size = (len * 4) as u32;
+    let ptr = unsafe { ffi::flash_get_address(sector, offset, size) } as *const u32;
+    if ptr.is_null() {
+        return None;
+    }
+    // SAFETY: `flash_get_address` checked that the range lies inside the sector,
+    // flash is mapped for the whole lifetime of the program and sector offsets
+    // are word-aligned.
+    Some(unsafe { slice::from_raw_parts(ptr, len) })
+}
+
+/// Erase the sector and write `data` at its beginning.
+pub fn erase_and_write_words(sector: u8, data: &[u32]) -> Result<(), FlashError> {
+    if ffi::sectrue != unsafe { ffi::flash_unlock_write() } {
+        return Err(FlashError::WriteFailed);
+    }
+    let result = erase_and_write_unlocked(sector, data);
+    if ffi::sectrue != unsafe { ffi::flash_lock_write() } {
+        return Err(FlashError::WriteFailed);
+    }
+    result
+}
+
+fn erase_and_write_unlocked(sector: u8, data: &[u32]) -> Result<(), FlashError> {
+    if ffi::sectrue != unsafe { ffi::flash_erase_sectors(&sector, 1, None) } {
+        return Err(FlashError::EraseFailed);
+    }
+    for (i, word) in data.iter().enumerate() {
+        let offset = (i * 4) as u32;
+        if ffi::sectrue != unsafe { ffi::flash_write_word(sector, offset, *word) } {
+            return Err(FlashError::WriteFailed);
+        }
+    }
+    Ok(())
+}
@@ -1,3 +1,7 @@
+#[cfg(feature = "ambient_light")]
+pub mod ambient_light;
+#[cfg(feature = "battery")]
+pub mod battery;
 pub mod bip39;
 #[macro_use]
 #[allow(unused_macros)]
@@ -7,12 +11,15 @@ pub mod display;
 #[cfg(feature = "dma2d")]
 pub mod dma2d;
 mod ffi;
+pub mod flash;
 pub mod qr;
 pub mod random;
 #[cfg(feature = "model_tr")]
 pub mod rgb_led;
 pub mod slip39;
 pub mod storage;
+#[cfg(feature = "touch")]
+pub mod touch;
 pub mod usb;
 pub mod uzlib;
 
@@ -9,15 +9,34 @@ extern "C" {
         data: *const cty::uint16_t,
         scale: cty::uint8_t,
     );
+    fn qrcodegen_encodeBinary(
+        data_and_temp: *mut cty::uint8_t,
+        data_len: cty::size_t,
+        qrcode: *mut cty::uint8_t,
+        ecl: cty::c_int,
+        min_version: cty::c_int,
+        max_version: cty::c_int,
+        mask: cty::c_int,
+        boost_ecl: bool,
+    ) -> bool;
+    fn qrcodegen_getSize(qrcode: *const cty::uint8_t) -> cty::c_int;
+    fn qrcodegen_getModule(qrcode: *const cty::uint8_t, x: cty::c_int, y: cty::c_int) -> bool;
 }
 
+/// `qrcodegen_Ecc_MEDIUM`
+const QRCODEGEN_ECC_MEDIUM: cty::c_int = 1;
+/// `qrcodegen_Mask_AUTO`
+const QRCODEGEN_MASK_AUTO: cty::c_int = -1;
+const QRCODEGEN_VERSION_MIN: cty::c_int = 1;
+
 const NVERSIONS: usize = 10; // range of versions (=capacities) that we support
 const QR_WIDTHS: [u32; NVERSIONS] = [21, 25, 29, 33, 37, 41, 45, 49, 53, 57];
 const THRESHOLDS_BINARY: [usize; NVERSIONS] = [14, 26, 42, 62, 84, 106, 122, 152, 180, 213];
 const THRESHOLDS_ALPHANUM: [usize; NVERSIONS] = [20, 38, 61, 90, 122, 154, 178, 221, 262, 311];
 const ALPHANUM: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $*+-./:";
 
-const MAX_DATA: usize = THRESHOLDS_ALPHANUM[THRESHOLDS_ALPHANUM.len() - 1] + 1; //FIXME
+/// Size of the buffer needed by `render_qrcode`.
+pub const MAX_DATA: usize = THRESHOLDS_ALPHANUM[THRESHOLDS_ALPHANUM.len() - 1] + 1; //FIXME
 
 fn is_alphanum_only(data: &str) -> bool {
     data.chars().all(|c| ALPHANUM.contains(c))
@@ -32,18 +51,21 @@ fn qr_version_index(data: &str, thresholds: &[usize]) -> Option<usize> {
     None
 }
 
+/// Render `data` centered at `x`, `y`, using `buffer` of at least `MAX_DATA`
+/// bytes as scratch space.
 pub fn render_qrcode(
     x: i16,
     y: i16,
     data: &str,
     max_size: u32,
     case_sensitive: bool,
+    buffer: &mut [u8],
 ) -> Result<(), Error> {
     let data_len = data.len();
     let version_idx;
-    let mut buffer = [0u8; MAX_DATA];
+    let buffer = buffer.get_mut(..MAX_DATA).ok_or(Error::OutOfRange)?;
     assert!(data_len < buffer.len());
-    buffer.as_mut_slice()[..data_len].copy_from_slice(data.as_bytes());
+    buffer[..data_len].copy_from_slice(data.as_bytes());
 
     if case_sensitive && !is_alphanum_only(data) {
         version_idx = match qr_version_index(data, &THRESHOLDS_BINARY) {
@@ -73,3 +95,61 @@ pub fn render_qrcode(
         Ok(())
     }
 }
+
+/// Largest supported version, corresponding to `QR_WIDTHS`.
+const QR_MAX_VERSION: usize = NVERSIONS;
+/// `qrcodegen_BUFFER_LEN_FOR_VERSION(QR_MAX_VERSION)`
+pub const QR_BUFFER_LEN: usize =
+    (QR_WIDTHS[NVERSIONS - 1] * QR_WIDTHS[NVERSIONS - 1]) as usize / 8 + 2;
+
+/// Modules of an encoded QR code.
+pub struct QrMatrix {
+    data: [u8; QR_BUFFER_LEN],
+}
+
+impl QrMatrix {
+    /// Encode arbitrary bytes in the binary mode, using the smallest version
+    /// which fits them. `temp` of at least `QR_BUFFER_LEN` bytes is the scratch
+    /// space of the encoder.
+    pub fn encode(data: &[u8], temp: &mut [u8]) -> Result<Self, Error> {
+        let temp = temp.get_mut(..QR_BUFFER_LEN).ok_or(Error::OutOfRange)?;
+        let mut matrix = Self {
+            data: [0u8; QR_BUFFER_LEN],
+        };
+        temp.get_mut(..data.len())
+            .ok_or(Error::OutOfRange)?
+            .copy_from_slice(data);
+        // SAFETY: Both buffers have the length required for `QR_MAX_VERSION`,
+        // `temp` starts with `data.len()` bytes of the data.
+        let encoded = unsafe {
+            qrcodegen_encodeBinary(
+                temp.as_mut_ptr(),
+                data.len(),
+                matrix.data.as_mut_ptr(),
+                QRCODEGEN_ECC_MEDIUM,
+                QRCODEGEN_VERSION_MIN,
+                QR_MAX_VERSION as _,
+                QRCODEGEN_MASK_AUTO,
+                true,
+            )
+        };
+        if encoded {
+            Ok(matrix)
+        } else {
+            Err(Error::OutOfRange)
+        }
+    }
+
+    /// Number of modules on each side, without the quiet zone.
+    pub fn size(&self) -> i16 {
+        // SAFETY: `data` holds a successfully encoded QR code.
+        unsafe { qrcodegen_getSize(self.data.as_ptr()) as i16 }
+    }
+
+    /// Whether the module is dark. Modules out of range are light.
+    pub fn module(&self, x: i16, y: i16) -> bool {
+        // SAFETY: `data` holds a successfully encoded QR code,
+        // `qrcodegen_getModule` checks the coordinates.
+        unsafe { qrcodegen_getModule(self.data.as_ptr(), x.into(), y.into()) }
+    }
+}
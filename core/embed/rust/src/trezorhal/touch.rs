@@ -0,0 +1,32 @@
+use super::ffi;
+
+/// Read a pending touch event, returns its type (`TOUCH_START >> 24` etc.)
+/// and coordinates, or `None` if nothing happened since the last call.
+pub fn read() -> Option<(u32, u32, u32)> {
+    let event = unsafe { ffi::touch_read() };
+    if event == 0 {
+        return None;
+    }
+    Some((event >> 24, (event >> 12) & 0xFFF, event & 0xFFF))
+}
+
+/// Size and pressure of the contact reported with the last event, in
+/// controller-specific units.
+#[derive(Copy, Clone)]
+pub struct Contact {
+    pub area: u8,
+    pub weight: u8,
+}
+
+/// Contact of the last event returned by `read`, `None` if the controller
+/// does not report it.
+pub fn contact() -> Option<Contact> {
+    let contact = unsafe { ffi::touch_get_contact() };
+    if contact == 0 {
+        return None;
+    }
+    Some(Contact {
+        area: (contact >> 8) as u8,
+        weight: contact as u8,
+    })
+}
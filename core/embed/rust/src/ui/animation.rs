@@ -1,8 +1,43 @@
+use core::sync::atomic::{AtomicBool, Ordering};
+
 use crate::{
     time::{Duration, Instant},
-    ui::lerp::{InvLerp, Lerp},
+    ui::{
+        component::{Event, EventCtx},
+        lerp::{InvLerp, Lerp},
+    },
 };
 
+#[cfg(not(test))]
+static REDUCED_MOTION: AtomicBool = AtomicBool::new(false);
+
+// Tests run in parallel threads, a test asking for reduced motion must not
+// affect the animations of the others.
+#[cfg(test)]
+std::thread_local! {
+    static REDUCED_MOTION: AtomicBool = AtomicBool::new(false);
+}
+
+#[cfg(not(test))]
+fn with_reduced_motion<R>(f: impl FnOnce(&AtomicBool) -> R) -> R {
+    f(&REDUCED_MOTION)
+}
+
+#[cfg(test)]
+fn with_reduced_motion<R>(f: impl FnOnce(&AtomicBool) -> R) -> R {
+    REDUCED_MOTION.with(f)
+}
+
+/// Whether the user asked for less motion, decorative timelines then jump
+/// straight to their end values.
+pub fn reduced_motion() -> bool {
+    with_reduced_motion(|flag| flag.load(Ordering::Relaxed))
+}
+
+pub fn set_reduced_motion(enabled: bool) {
+    with_reduced_motion(|flag| flag.store(enabled, Ordering::Relaxed));
+}
+
 /// Running, time-based linear progression of a value.
 pub struct Animation<T> {
     /// Starting value.
@@ -60,3 +95,217 @@ impl<T> Animation<T> {
         }
     }
 }
+
+/// Shape of the progression of an animated value over time.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Easing {
+    Linear,
+    /// Starts slowly and accelerates.
+    EaseIn,
+    /// Starts quickly and decelerates.
+    EaseOut,
+    /// Accelerates until the half of the duration, then decelerates.
+    EaseInOut,
+    /// Hits the end value early and bounces off it a few times with decreasing
+    /// height, like a dropped ball. Never goes past the end value.
+    Bounce,
+}
+
+impl Easing {
+    /// Map the linear progress `t` in `0.0..=1.0` onto the curve.
+    pub fn apply(self, t: f32) -> f32 {
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => t * t,
+            Self::EaseOut => t * (2.0 - t),
+            Self::EaseInOut if t < 0.5 => 2.0 * t * t,
+            Self::EaseInOut => 1.0 - 2.0 * (1.0 - t) * (1.0 - t),
+            Self::Bounce => Self::bounce(t),
+        }
+    }
+
+    fn bounce(t: f32) -> f32 {
+        const N: f32 = 7.5625;
+        const D: f32 = 2.75;
+        if t < 1.0 / D {
+            N * t * t
+        } else if t < 2.0 / D {
+            let t = t - 1.5 / D;
+            N * t * t + 0.75
+        } else if t < 2.5 / D {
+            let t = t - 2.25 / D;
+            N * t * t + 0.9375
+        } else {
+            let t = t - 2.625 / D;
+            N * t * t + 0.984375
+        }
+    }
+}
+
+/// Animation of a value along an easing curve, advanced by the animation frame
+/// events of the component owning it instead of blocking the event loop.
+///
+/// Timelines are decorative by default and skip to their end with
+/// `reduced_motion()`. Timelines measuring time for the user, like the
+/// hold-to-confirm loaders, are created with `functional()`.
+pub struct Timeline<T> {
+    animation: Animation<T>,
+    easing: Easing,
+    functional: bool,
+    /// Set once the final value was returned from `event()`.
+    finished: bool,
+}
+
+impl<T> Timeline<T> {
+    pub fn new(from: T, to: T, duration: Duration, started: Instant) -> Self {
+        Self {
+            animation: Animation::new(from, to, duration, started),
+            easing: Easing::Linear,
+            functional: false,
+            finished: false,
+        }
+    }
+
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Run in real time regardless of `reduced_motion()`.
+    pub fn functional(mut self) -> Self {
+        self.functional = true;
+        self
+    }
+
+    /// Whether the timeline jumps straight to its end.
+    fn skips_to_end(&self) -> bool {
+        !self.functional && reduced_motion()
+    }
+
+    pub fn from(&self) -> T
+    where
+        T: Copy,
+    {
+        self.animation.from
+    }
+
+    pub fn to(&self) -> T
+    where
+        T: Copy,
+    {
+        self.animation.to
+    }
+
+    pub fn elapsed(&self, now: Instant) -> Duration {
+        self.animation.elapsed(now)
+    }
+
+    /// Whether the end was reached, always true for decorative timelines with
+    /// `reduced_motion()`.
+    pub fn is_finished(&self, now: Instant) -> bool {
+        self.skips_to_end() || self.elapsed(now) >= self.animation.duration
+    }
+
+    /// Value of the timeline at `now` instant, the end value of decorative
+    /// timelines with `reduced_motion()`.
+    pub fn value(&self, now: Instant) -> T
+    where
+        T: Lerp,
+    {
+        let linear = if self.skips_to_end() || self.animation.duration == Duration::ZERO {
+            1.0
+        } else {
+            (self.elapsed(now) / self.animation.duration).clamp(0.0, 1.0)
+        };
+        let factor = self.easing.apply(linear);
+        T::lerp(self.animation.from, self.animation.to, factor)
+    }
+
+    /// Seek the timeline such that `value` would be the current value. Exact
+    /// only for `Easing::Linear`, other curves are seeked as if they were
+    /// linear.
+    pub fn seek_to_value(&mut self, value: T)
+    where
+        T: InvLerp,
+    {
+        self.animation.seek_to_value(value);
+    }
+
+    /// Handle an animation frame `event`, returning the current value and
+    /// requesting the next frame until the end of the timeline is reached.
+    /// Other events, and frames after the end, are ignored.
+    pub fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<T>
+    where
+        T: Lerp,
+    {
+        if self.finished || !matches!(event, Event::Timer(EventCtx::ANIM_FRAME_TIMER)) {
+            return None;
+        }
+        let now = Instant::now();
+        if self.is_finished(now) {
+            self.finished = true;
+        } else {
+            ctx.request_anim_frame();
+        }
+        Some(self.value(now))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn easing_bounds() {
+        for easing in [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+            Easing::Bounce,
+        ] {
+            assert!(easing.apply(0.0).abs() < 0.001, "{:?}", easing);
+            assert!((easing.apply(1.0) - 1.0).abs() < 0.001, "{:?}", easing);
+        }
+        assert!(Easing::EaseIn.apply(0.25) < 0.25);
+        assert!(Easing::EaseOut.apply(0.25) > 0.25);
+        assert!((Easing::EaseInOut.apply(0.5) - 0.5).abs() < 0.001);
+        for i in 0..=100 {
+            let value = Easing::Bounce.apply(i as f32 / 100.0);
+            assert!((0.0..=1.0 + 0.001).contains(&value), "{}", value);
+        }
+    }
+
+    #[test]
+    fn timeline_value() {
+        let start = Instant::now();
+        let at = |millis| unwrap!(start.checked_add(Duration::from_millis(millis)));
+        let timeline = Timeline::new(0i16, 100, Duration::from_millis(100), start);
+        assert_eq!(timeline.value(start), 0);
+        assert_eq!(timeline.value(at(50)), 50);
+        assert_eq!(timeline.value(at(200)), 100);
+        assert!(!timeline.is_finished(at(99)));
+        assert!(timeline.is_finished(at(100)));
+
+        let timeline = timeline.with_easing(Easing::EaseIn);
+        assert_eq!(timeline.value(at(50)), 25);
+        assert_eq!(timeline.value(at(100)), 100);
+    }
+
+    #[test]
+    fn timeline_reduced_motion() {
+        let start = Instant::now();
+        let at = |millis| unwrap!(start.checked_add(Duration::from_millis(millis)));
+        set_reduced_motion(true);
+
+        let decorative = Timeline::new(0i16, 100, Duration::from_millis(100), start);
+        assert_eq!(decorative.value(at(10)), 100);
+        assert!(decorative.is_finished(at(10)));
+
+        let functional = decorative.functional();
+        assert_eq!(functional.value(at(10)), 10);
+        assert!(!functional.is_finished(at(10)));
+
+        set_reduced_motion(false);
+    }
+}
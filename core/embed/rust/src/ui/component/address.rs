@@ -0,0 +1,274 @@
+use crate::ui::{
+    component::{text::TextStyle, Component, Event, EventCtx, Never, Pad, Paginate},
+    display::{self, identicon, identicon::Identicon, Color},
+    geometry::{Offset, Point, Rect},
+};
+
+/// Characters in a chunk.
+const CHUNK_LEN: usize = 4;
+/// Chunks on a line, unless they do not fit the width.
+const CHUNKS_PER_LINE: usize = 4;
+/// Pixels on each side of an identicon cell.
+const IDENTICON_CELL: i16 = 4;
+/// Horizontal distance between the chunks and the identicon.
+const IDENTICON_SPACING: i16 = 8;
+
+/// Address split into chunks of `CHUNK_LEN` characters, `CHUNKS_PER_LINE` of
+/// them on a line, so that it can be compared with the address shown by the
+/// host a chunk at a time. Every other chunk is drawn in a different color to
+/// tell them apart even if the gaps between them are narrow. Lines get fewer
+/// chunks if the full ones are wider than the bounds, lines which do not fit
+/// the bounds vertically continue on the next page. The identicon of the
+/// address, if any, is drawn in the top right corner of every page.
+pub struct AddressDisplay<T> {
+    address: T,
+    area: Rect,
+    pad: Pad,
+    /// Font, color of the even chunks and background.
+    style: TextStyle,
+    /// Color of the odd chunks.
+    alt_color: Color,
+    identicon: Option<Identicon>,
+    page: usize,
+}
+
+impl<T: AsRef<str>> AddressDisplay<T> {
+    pub fn new(address: T, style: TextStyle, alt_color: Color) -> Self {
+        Self {
+            address,
+            area: Rect::zero(),
+            pad: Pad::with_background(style.background_color),
+            style,
+            alt_color,
+            identicon: None,
+            page: 0,
+        }
+    }
+
+    /// Draw `identicon` to the right of the chunks.
+    pub fn with_identicon(mut self, identicon: Identicon) -> Self {
+        self.identicon = Some(identicon);
+        self
+    }
+
+    /// Part of the area left for the chunks.
+    fn text_area(&self) -> Rect {
+        if self.identicon.is_some() {
+            let width = identicon::SIZE as i16 * IDENTICON_CELL + IDENTICON_SPACING;
+            self.area.split_right(width).0
+        } else {
+            self.area
+        }
+    }
+
+    /// Width of the widest chunk.
+    fn chunk_width(&self) -> i16 {
+        let font = self.style.text_font;
+        chunks(self.address.as_ref())
+            .map(|chunk| font.text_width(chunk))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Number of chunks which fit on a line without any gaps between them, at
+    /// least one and at most `CHUNKS_PER_LINE`.
+    fn chunks_per_line(&self) -> usize {
+        let fitting = self.text_area().width() / self.chunk_width().max(1);
+        (fitting.max(1) as usize).min(CHUNKS_PER_LINE)
+    }
+
+    fn line_count(&self) -> usize {
+        let chunk_count = chunks(self.address.as_ref()).count();
+        let chunks_per_line = self.chunks_per_line();
+        ((chunk_count + chunks_per_line - 1) / chunks_per_line).max(1)
+    }
+
+    fn lines_per_page(&self) -> usize {
+        let line_height = self.style.text_font.line_height().max(1);
+        (self.area.height() / line_height).max(1) as usize
+    }
+
+    /// Horizontal distance between the chunks, at most a space wide and
+    /// narrower if the full line would not fit otherwise.
+    fn spacing(&self) -> i16 {
+        let chunks_per_line = self.chunks_per_line() as i16;
+        if chunks_per_line < 2 {
+            return 0;
+        }
+        let line = chunks_per_line * self.chunk_width();
+        let gaps = chunks_per_line - 1;
+        let space = self.style.text_font.char_width(' ');
+        ((self.text_area().width() - line) / gaps).clamp(0, space)
+    }
+}
+
+/// Consecutive chunks of `CHUNK_LEN` characters of `text`, the last one can
+/// be shorter.
+fn chunks(text: &str) -> impl Iterator<Item = &str> {
+    let mut rest = text;
+    core::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        let end = rest
+            .char_indices()
+            .nth(CHUNK_LEN)
+            .map_or(rest.len(), |(i, _)| i);
+        let (chunk, tail) = rest.split_at(end);
+        rest = tail;
+        Some(chunk)
+    })
+}
+
+impl<T: AsRef<str>> Paginate for AddressDisplay<T> {
+    fn page_count(&mut self) -> usize {
+        let lines_per_page = self.lines_per_page();
+        (self.line_count() + lines_per_page - 1) / lines_per_page
+    }
+
+    fn change_page(&mut self, active_page: usize) {
+        self.page = active_page.min(self.page_count() - 1);
+        self.pad.clear();
+    }
+}
+
+impl<T: AsRef<str>> Component for AddressDisplay<T> {
+    type Msg = Never;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.area = bounds;
+        self.pad.place(bounds);
+        self.page = self.page.min(self.page_count() - 1);
+        bounds
+    }
+
+    fn event(&mut self, _ctx: &mut EventCtx, _event: Event) -> Option<Self::Msg> {
+        None
+    }
+
+    fn paint(&mut self) {
+        self.pad.paint();
+
+        let font = self.style.text_font;
+        let spacing = self.spacing();
+        let chunks_per_line = self.chunks_per_line();
+        let lines_per_page = self.lines_per_page();
+        let first = self.page * lines_per_page * chunks_per_line;
+        let last = first + lines_per_page * chunks_per_line;
+
+        let text_area = self.text_area();
+        let mut baseline = text_area.top_left() + Offset::y(font.text_height());
+        for (i, chunk) in chunks(self.address.as_ref())
+            .enumerate()
+            .take(last)
+            .skip(first)
+        {
+            if i % chunks_per_line == 0 && i != first {
+                baseline = Point::new(text_area.x0, baseline.y + font.line_height());
+            }
+            let color = if i % 2 == 0 {
+                self.style.text_color
+            } else {
+                self.alt_color
+            };
+            display::text(baseline, chunk, font, color, self.style.background_color);
+            baseline = baseline + Offset::x(font.text_width(chunk) + spacing);
+        }
+
+        if let Some(identicon) = &self.identicon {
+            let width = identicon::SIZE as i16 * IDENTICON_CELL;
+            identicon.paint(self.area.top_right() - Offset::x(width), IDENTICON_CELL);
+        }
+    }
+
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        sink(self.area)
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl<T: AsRef<str>> crate::trace::Trace for AddressDisplay<T> {
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.open("AddressDisplay");
+        t.field("address", &self.address.as_ref());
+        t.field("page", &self.page);
+        t.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::display::Font;
+
+    #[test]
+    fn address_chunks() {
+        let mut iter = chunks("bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq");
+        assert_eq!(iter.next(), Some("bc1q"));
+        assert_eq!(iter.next(), Some("ar0s"));
+        assert_eq!(iter.last(), Some("mdq"));
+        assert_eq!(chunks("").count(), 0);
+        assert_eq!(chunks("abcd").count(), 1);
+        assert!(chunks("ěščřžýáí").eq(["ěščř", "žýáí"]));
+    }
+
+    #[test]
+    fn address_overflow() {
+        let color = Color::rgb(0, 0, 0);
+        let style = TextStyle::new(Font::MONO, color, color, color, color);
+        let line_height = style.text_font.line_height();
+        let chunk_width = style.text_font.text_width("bc1q");
+        let address = "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq";
+        let placed = |width, lines| {
+            let mut display = AddressDisplay::new(address, style, color);
+            display.place(Rect::new(
+                Point::zero(),
+                Point::new(width, lines * line_height),
+            ));
+            display
+        };
+
+        // 11 chunks on three full lines
+        let mut wide = placed(4 * chunk_width + 3 * style.text_font.char_width(' '), 3);
+        assert_eq!(wide.chunks_per_line(), CHUNKS_PER_LINE);
+        assert_eq!(wide.spacing(), style.text_font.char_width(' '));
+        assert_eq!(wide.page_count(), 1);
+
+        // gaps shrink before the chunks move to the next line
+        let tight = placed(4 * chunk_width, 3);
+        assert_eq!(tight.chunks_per_line(), CHUNKS_PER_LINE);
+        assert_eq!(tight.spacing(), 0);
+
+        // lines too wide for the bounds are shortened, the rest is paged
+        let mut narrow = placed(2 * chunk_width, 3);
+        assert_eq!(narrow.chunks_per_line(), 2);
+        assert_eq!(narrow.line_count(), 6);
+        assert_eq!(narrow.page_count(), 2);
+        let mut tiny = placed(chunk_width / 2, 3);
+        assert_eq!(tiny.chunks_per_line(), 1);
+        assert_eq!(tiny.page_count(), 4);
+    }
+
+    #[test]
+    fn address_identicon() {
+        let color = Color::rgb(0, 0, 0);
+        let style = TextStyle::new(Font::MONO, color, color, color, color);
+        let address = "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359";
+        let width = 4 * style.text_font.text_width(&address[..4]);
+        let bounds = Rect::new(Point::zero(), Point::new(width, 100));
+
+        let mut plain = AddressDisplay::new(address, style, color);
+        plain.place(bounds);
+        assert_eq!(plain.chunks_per_line(), CHUNKS_PER_LINE);
+
+        // the chunks make room for the identicon
+        let identicon = Identicon::new("0xfb6916095ca1df60bb79ce92ce3ea74c37c5d359");
+        let mut display = AddressDisplay::new(address, style, color).with_identicon(identicon);
+        display.place(bounds);
+        assert_eq!(
+            display.text_area().x1,
+            width - identicon::SIZE as i16 * IDENTICON_CELL - IDENTICON_SPACING
+        );
+        assert!(display.chunks_per_line() < CHUNKS_PER_LINE);
+    }
+}
@@ -0,0 +1,229 @@
+//! Sequence of QR codes shown one after another, for payloads which do not fit
+//! a single code, e.g. PSBTs or descriptors exported as UR or BBQr parts. The
+//! parts are encoded by the caller. Fountain-coded formats can produce more
+//! parts than there are fragments of the payload, the caller chooses how many
+//! of them are cycled through.
+
+use heapless::Vec;
+
+use crate::{
+    error::Error,
+    time::Duration,
+    ui::{
+        component::{Component, Event, EventCtx, Never, Pad, QrCode, TimerToken},
+        display::Color,
+        geometry::Rect,
+    },
+};
+
+#[cfg(feature = "touch")]
+use crate::{
+    time::Instant,
+    ui::event::{Gesture, GestureRecognizer, GestureThresholds},
+};
+
+/// Maximum number of parts, all of them are encoded up front.
+pub const MAX_FRAMES: usize = 16;
+
+pub struct AnimatedQr {
+    area: Rect,
+    pad: Pad,
+    frames: Vec<QrCode, MAX_FRAMES>,
+    frame: usize,
+    interval: Duration,
+    timer: Option<TimerToken>,
+    paused: bool,
+    #[cfg(feature = "touch")]
+    gestures: GestureRecognizer,
+}
+
+impl AnimatedQr {
+    pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(200);
+
+    /// Encode the `frames`, failing if there are none, more than `MAX_FRAMES`
+    /// or any of them does not fit a QR code.
+    pub fn new<T: AsRef<str>>(frames: impl IntoIterator<Item = T>) -> Result<Self, Error> {
+        let mut codes = Vec::new();
+        for frame in frames {
+            let qr = QrCode::new(frame.as_ref().as_bytes())?;
+            codes.push(qr).map_err(|_| Error::OutOfRange)?;
+        }
+        if codes.is_empty() {
+            return Err(Error::OutOfRange);
+        }
+        Ok(Self {
+            area: Rect::zero(),
+            pad: Pad::with_background(Color::black()),
+            frames: codes,
+            frame: 0,
+            interval: Self::DEFAULT_INTERVAL,
+            timer: None,
+            paused: false,
+            #[cfg(feature = "touch")]
+            gestures: GestureRecognizer::new(GestureThresholds::DEFAULT),
+        })
+    }
+
+    /// Time each frame is shown for.
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Color of the screen around the codes, black by default.
+    pub fn with_background(mut self, color: Color) -> Self {
+        self.pad = Pad::with_background(color);
+        self.frames = core::mem::take(&mut self.frames)
+            .into_iter()
+            .map(|qr| qr.with_background(color))
+            .collect();
+        self
+    }
+
+    pub fn frame(&self) -> usize {
+        self.frame
+    }
+
+    pub fn frame_count(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Stop on the current frame, e.g. to let a scanner which missed it catch
+    /// up, or continue cycling.
+    pub fn set_paused(&mut self, ctx: &mut EventCtx, paused: bool) {
+        self.paused = paused;
+        self.timer = if paused {
+            None
+        } else {
+            Some(ctx.request_timer(self.interval))
+        };
+    }
+
+    fn next_frame(&mut self, ctx: &mut EventCtx) {
+        let modules = self.frames[self.frame].modules();
+        self.frame = (self.frame + 1) % self.frames.len();
+        // Codes of the same size overwrite each other completely.
+        if self.frames[self.frame].modules() != modules {
+            self.pad.clear();
+        }
+        ctx.request_paint();
+    }
+}
+
+impl Component for AnimatedQr {
+    type Msg = Never;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.area = bounds;
+        self.pad.place(bounds);
+        for qr in &mut self.frames {
+            qr.place(bounds);
+        }
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        match event {
+            Event::Attach if self.frames.len() > 1 && !self.paused => {
+                self.timer = Some(ctx.request_timer(self.interval));
+            }
+            Event::Timer(token) if Some(token) == self.timer => {
+                self.next_frame(ctx);
+                self.timer = Some(ctx.request_timer(self.interval));
+            }
+            #[cfg(feature = "touch")]
+            Event::Touch(touch) if self.frames.len() > 1 => {
+                // Tapping the code pauses and resumes the animation.
+                if let Some(Gesture::Tap(pos)) = self.gestures.touch(touch, Instant::now()) {
+                    if self.area.contains(pos) {
+                        self.set_paused(ctx, !self.paused);
+                    }
+                }
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn paint(&mut self) {
+        self.pad.paint();
+        self.frames[self.frame].paint();
+    }
+
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        sink(self.area)
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl crate::trace::Trace for AnimatedQr {
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.open("AnimatedQr");
+        t.field("frame", &self.frame);
+        t.field("frame_count", &self.frames.len());
+        t.field("paused", &(self.paused as usize));
+        t.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::constant::screen;
+
+    use super::*;
+
+    const PARTS: [&str; 3] = ["UR:BYTES/1-3/A", "UR:BYTES/2-3/B", "UR:BYTES/3-3/C"];
+
+    #[test]
+    fn animated_qr_frames() {
+        let qr = unwrap!(AnimatedQr::new(PARTS));
+        assert_eq!(qr.frame_count(), PARTS.len());
+        assert_eq!(qr.frame(), 0);
+
+        assert!(AnimatedQr::new([""; 0]).is_err());
+        assert!(AnimatedQr::new(["A"; MAX_FRAMES + 1]).is_err());
+        // Larger than the biggest supported version.
+        assert!(AnimatedQr::new(["A".repeat(1000)]).is_err());
+    }
+
+    #[test]
+    fn animated_qr_cycle() {
+        let mut qr = unwrap!(AnimatedQr::new(PARTS));
+        qr.place(screen());
+        let mut ctx = EventCtx::new();
+        qr.event(&mut ctx, Event::Attach);
+
+        for expected in [1, 2, 0] {
+            let (token, delay) = unwrap!(ctx.pop_timer());
+            assert_eq!(delay, AnimatedQr::DEFAULT_INTERVAL);
+            ctx.clear();
+            qr.event(&mut ctx, Event::Timer(token));
+            assert_eq!(qr.frame(), expected);
+            assert!(ctx.paint_requested());
+        }
+
+        // A paused code ignores the pending timer.
+        let (token, _) = unwrap!(ctx.pop_timer());
+        qr.set_paused(&mut ctx, true);
+        qr.event(&mut ctx, Event::Timer(token));
+        assert_eq!(qr.frame(), 0);
+        assert!(ctx.pop_timer().is_none());
+
+        qr.set_paused(&mut ctx, false);
+        let (token, _) = unwrap!(ctx.pop_timer());
+        qr.event(&mut ctx, Event::Timer(token));
+        assert_eq!(qr.frame(), 1);
+    }
+
+    #[test]
+    fn animated_qr_single_frame() {
+        let mut qr = unwrap!(AnimatedQr::new(["UR:BYTES/A"]));
+        let mut ctx = EventCtx::new();
+        qr.event(&mut ctx, Event::Attach);
+        assert!(ctx.pop_timer().is_none());
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{
+    time::{Duration, Instant},
+    ui::{
+        animation::Timeline,
+        component::{Event, EventCtx},
+        display,
+    },
+};
+
+/// Non-blocking transition of the backlight level, advanced by the animation
+/// frame events passed from the owning component.
+pub struct BacklightFade {
+    timeline: Option<Timeline<i32>>,
+}
+
+impl BacklightFade {
+    /// Time of the change by one level, same pace as the blocking
+    /// `display::fade_backlight`.
+    const DURATION_PER_LEVEL_US: u32 = 14_000 / 15;
+
+    pub const fn new() -> Self {
+        Self { timeline: None }
+    }
+
+    /// Start fading from the current level to `target`. The first step is
+    /// done on the next animation frame, i.e. after the next paint.
+    pub fn start(&mut self, ctx: &mut EventCtx, target: i32) {
+        let current = display::backlight();
+        let levels = current.abs_diff(target);
+        let duration = Duration::from_millis(levels * Self::DURATION_PER_LEVEL_US / 1000);
+        self.timeline = Some(Timeline::new(current, target, duration, Instant::now()));
+        ctx.request_anim_frame();
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.timeline.is_some()
+    }
+
+    pub fn event(&mut self, ctx: &mut EventCtx, event: Event) {
+        if let Some(timeline) = &mut self.timeline {
+            match timeline.event(ctx, event) {
+                Some(level) if level == timeline.to() => {
+                    display::set_backlight(level);
+                    self.timeline = None;
+                }
+                Some(level) => display::set_backlight(level),
+                None => {}
+            }
+        }
+    }
+}
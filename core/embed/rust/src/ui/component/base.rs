@@ -6,7 +6,7 @@ use crate::{
     time::Duration,
     ui::{
         component::{maybe::PaintOverlapping, Map},
-        display::Color,
+        display::{self, Color},
         geometry::{Offset, Rect},
     },
 };
@@ -15,7 +15,7 @@ use crate::{
 use crate::ui::event::ButtonEvent;
 #[cfg(feature = "touch")]
 use crate::ui::event::TouchEvent;
-use crate::ui::event::USBEvent;
+use crate::ui::event::{RecoveryEvent, StatusEvent, USBEvent};
 
 /// Type used by components that do not return any messages.
 ///
@@ -65,6 +65,9 @@ pub trait Component {
 /// `EventCtx::request_paint` in its `event` pass.
 pub struct Child<T> {
     component: T,
+    /// Area returned from the last `place` call, reported as damaged when the
+    /// inner component requests paint without specifying the area.
+    area: Rect,
     marked_for_paint: bool,
 }
 
@@ -72,6 +75,7 @@ impl<T> Child<T> {
     pub fn new(component: T) -> Self {
         Self {
             component,
+            area: Rect::zero(),
             marked_for_paint: true,
         }
     }
@@ -91,18 +95,31 @@ impl<T> Child<T> {
         F: FnOnce(&mut EventCtx, &mut T) -> U,
     {
         let prev_requested = mem::replace(&mut ctx.paint_requested, false);
+        let prev_unbounded = mem::replace(&mut ctx.paint_unbounded, false);
+        let prev_damage = ctx.damage.take();
         let result = component_func(ctx, &mut self.component);
         if ctx.paint_requested {
             // If a paint was requested anywhere in the inner component tree, we need to
             // mark ourselves for paint as well, and keep the `ctx` flag so it can
             // propagate upwards.
             self.marked_for_paint = true;
+            // Unless only more precise areas were reported from the inside, all of our
+            // area is going to be painted again.
+            if ctx.paint_unbounded || ctx.damage.is_none() {
+                ctx.paint_unbounded = false;
+                ctx.damage = None;
+                ctx.add_damage(self.area);
+            }
         } else {
             // Paint has not been requested in the *inner* component, so there's no need to
             // paint it, but we need to preserve the previous flag carried in `ctx` so it
             // properly propagates upwards (i.e. from our previous siblings).
             ctx.paint_requested = prev_requested;
         }
+        ctx.paint_unbounded |= prev_unbounded;
+        if let Some(damage) = prev_damage {
+            ctx.add_damage(damage);
+        }
         result
     }
 
@@ -124,7 +141,8 @@ where
     type Msg = T::Msg;
 
     fn place(&mut self, bounds: Rect) -> Rect {
-        self.component.place(bounds)
+        self.area = self.component.place(bounds);
+        self.area
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
@@ -140,7 +158,8 @@ where
     }
 
     fn paint(&mut self) {
-        if self.marked_for_paint {
+        // Nothing has changed outside of the clip region of a partial repaint.
+        if self.marked_for_paint && display::clip::is_visible(self.area) {
             self.marked_for_paint = false;
             self.component.paint();
         }
@@ -347,11 +366,16 @@ pub enum Event<'a> {
     #[cfg(feature = "touch")]
     Touch(TouchEvent),
     USB(USBEvent),
+    /// Device state shown in the status bar has changed.
+    Status(StatusEvent),
     /// Previously requested timer was triggered. This invalidates the timer
     /// token (another timer has to be requested).
     Timer(TimerToken),
     /// Advance progress bar. Progress screens only.
     Progress(u16, &'a str),
+    /// Shares entered during recovery have changed. Recovery progress screens
+    /// only.
+    Recovery(RecoveryEvent),
     /// Component has been attached to component tree. This event is sent once
     /// before any other events.
     Attach,
@@ -381,6 +405,11 @@ pub struct EventCtx {
     next_token: u32,
     place_requested: bool,
     paint_requested: bool,
+    /// Paint was requested by `request_paint` outside of the `Child` wrappers
+    /// seen so far, so the damaged area is not known.
+    paint_unbounded: bool,
+    /// Bounding box of the areas invalidated in the current event pass.
+    damage: Option<Rect>,
     anim_frame_scheduled: bool,
     page_count: Option<usize>,
 }
@@ -406,6 +435,8 @@ impl EventCtx {
             place_requested: true, // We need to perform a place pass in the beginning.
             paint_requested: false, /* We also need to paint, but this is supplemented by
                                     * `Child::marked_for_paint` being true. */
+            paint_unbounded: false,
+            damage: None,
             anim_frame_scheduled: false,
             page_count: None,
         }
@@ -429,6 +460,31 @@ impl EventCtx {
     /// again by the nearest `Child` wrapper.
     pub fn request_paint(&mut self) {
         self.paint_requested = true;
+        self.paint_unbounded = true;
+    }
+
+    /// Like `request_paint`, for components which know that only `area` of
+    /// their content has changed. Otherwise the whole area of the nearest
+    /// `Child` wrapper is considered damaged.
+    pub fn request_paint_area(&mut self, area: Rect) {
+        self.paint_requested = true;
+        self.add_damage(area);
+    }
+
+    /// Returns `true` if paint was requested during the current event pass.
+    pub fn paint_requested(&self) -> bool {
+        self.paint_requested
+    }
+
+    /// Bounding box of the screen areas that need to be painted again after the
+    /// current event pass, `None` if the area is not known because a component
+    /// outside of any `Child` wrapper requested paint, see `paint_requested`.
+    pub fn damage(&self) -> Option<Rect> {
+        if self.paint_unbounded {
+            None
+        } else {
+            self.damage
+        }
     }
 
     /// Request a timer event to be delivered after `deadline` elapses.
@@ -446,6 +502,12 @@ impl EventCtx {
         }
     }
 
+    /// Returns `true` if an animation frame was requested during the current
+    /// event pass.
+    pub fn anim_frame_requested(&self) -> bool {
+        self.anim_frame_scheduled
+    }
+
     pub fn set_page_count(&mut self, count: usize) {
         #[cfg(feature = "ui_debug")]
         assert!(self.page_count.is_none());
@@ -463,10 +525,20 @@ impl EventCtx {
     pub fn clear(&mut self) {
         self.place_requested = false;
         self.paint_requested = false;
+        self.paint_unbounded = false;
+        self.damage = None;
         self.anim_frame_scheduled = false;
         self.page_count = None;
     }
 
+    fn add_damage(&mut self, area: Rect) {
+        // Area of a component which has not been placed yet.
+        if area.is_empty() {
+            return;
+        }
+        self.damage = Some(self.damage.map_or(area, |damage| damage.union(area)));
+    }
+
     fn register_timer(&mut self, token: TimerToken, deadline: Duration) {
         if self.timers.push((token, deadline)).is_err() {
             // The timer queue is full, this would be a development error in the layout
@@ -488,3 +560,82 @@ impl EventCtx {
         token
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::geometry::Point;
+
+    use super::*;
+
+    /// Requests paint of `area`, or of everything if `None`, on every event.
+    struct Invalidate(Option<Rect>);
+
+    impl Component for Invalidate {
+        type Msg = Never;
+
+        fn place(&mut self, bounds: Rect) -> Rect {
+            bounds
+        }
+
+        fn event(&mut self, ctx: &mut EventCtx, _event: Event) -> Option<Self::Msg> {
+            match self.0 {
+                Some(area) => ctx.request_paint_area(area),
+                None => ctx.request_paint(),
+            }
+            None
+        }
+
+        fn paint(&mut self) {}
+    }
+
+    fn rect(x0: i16, y0: i16, x1: i16, y1: i16) -> Rect {
+        Rect::new(Point::new(x0, y0), Point::new(x1, y1))
+    }
+
+    #[test]
+    fn child_damage() {
+        let event = Event::Timer(EventCtx::ANIM_FRAME_TIMER);
+        let mut ctx = EventCtx::new();
+        let mut whole = Child::new(Invalidate(None));
+        whole.place(rect(0, 0, 10, 10));
+        whole.event(&mut ctx, event);
+        assert!(ctx.damage() == Some(rect(0, 0, 10, 10)));
+
+        // Areas of the siblings are merged, the precise area is kept.
+        let mut part = Child::new(Child::new(Invalidate(Some(rect(20, 20, 25, 25)))));
+        part.place(rect(20, 20, 40, 40));
+        part.event(&mut ctx, event);
+        assert!(ctx.damage() == Some(rect(0, 0, 25, 25)));
+
+        // Paint requested without an area covers the precise areas.
+        let mut outer = Child::new(Invalidate(None));
+        outer.place(rect(50, 50, 60, 60));
+        outer.mutate(&mut ctx, |ctx, _| {
+            ctx.request_paint_area(rect(50, 50, 51, 51));
+            ctx.request_paint();
+        });
+        assert!(ctx.damage() == Some(rect(0, 0, 60, 60)));
+
+        // Outside of any `Child` the area is not known.
+        ctx.request_paint();
+        assert!(ctx.damage().is_none());
+
+        ctx.clear();
+        assert!(ctx.damage().is_none());
+        assert!(!ctx.paint_requested());
+    }
+
+    #[test]
+    fn child_paint_clip() {
+        let mut ctx = EventCtx::new();
+        let mut child = Child::new(Invalidate(None));
+        child.place(rect(0, 0, 10, 10));
+        child.event(&mut ctx, Event::RequestPaint);
+
+        // Outside of the clip region the child stays marked for paint.
+        display::clip::with_clip(rect(10, 10, 20, 20), || child.paint());
+        assert!(child.marked_for_paint);
+        display::clip::with_clip(rect(5, 5, 20, 20), || child.paint());
+        assert!(!child.marked_for_paint);
+    }
+}
@@ -2,6 +2,7 @@ use crate::ui::{
     component::{Component, Event, EventCtx, Never},
     display::Font,
     geometry::{Alignment, Offset, Rect},
+    homoglyph,
 };
 
 use super::{text::TextStyle, TextLayout};
@@ -9,6 +10,8 @@ use super::{text::TextStyle, TextLayout};
 pub struct Label<T> {
     text: T,
     layout: TextLayout,
+    style: TextStyle,
+    lookalike_style: Option<TextStyle>,
 }
 
 impl<T> Label<T>
@@ -19,9 +22,19 @@ where
         Self {
             text,
             layout: TextLayout::new(style).with_align(align),
+            style,
+            lookalike_style: None,
         }
     }
 
+    /// Render the text using `style` if it can be mistaken for a different
+    /// identifier, see `homoglyph::is_lookalike`.
+    pub fn with_lookalike_style(mut self, style: TextStyle) -> Self {
+        self.lookalike_style = Some(style);
+        self.update_style();
+        self
+    }
+
     pub fn left_aligned(text: T, style: TextStyle) -> Self {
         Self::new(text, Alignment::Start, style)
     }
@@ -40,6 +53,19 @@ where
 
     pub fn set_text(&mut self, text: T) {
         self.text = text;
+        self.update_style();
+    }
+
+    /// Whether the lookalike style is set and used for the current text.
+    pub fn is_lookalike(&self) -> bool {
+        self.lookalike_style.is_some() && homoglyph::is_lookalike(self.text.as_ref())
+    }
+
+    fn update_style(&mut self) {
+        self.layout.style = match self.lookalike_style {
+            Some(style) if self.is_lookalike() => style,
+            _ => self.style,
+        };
     }
 
     pub fn font(&self) -> Font {
@@ -25,12 +25,15 @@ pub struct Marquee<T> {
     min_offset: i16,
     max_offset: i16,
     state: State,
+    /// Offset of the text as of the last event, painted by `paint`.
+    offset: i16,
     text: T,
     font: Font,
     fg: Color,
     bg: Color,
     duration: Duration,
     pause: Duration,
+    millis_per_letter: u32,
 }
 
 impl<T> Marquee<T>
@@ -44,15 +47,40 @@ where
             min_offset: 0,
             max_offset: 0,
             state: State::Initial,
+            offset: 0,
             text,
             font,
             fg,
             bg,
             duration: Duration::from_millis(2000),
             pause: Duration::from_millis(1000),
+            millis_per_letter: MILLIS_PER_LETTER_M,
         }
     }
 
+    /// Scrolling speed, as the time it takes to shift the text by the width of
+    /// letter "M".
+    pub fn with_speed(mut self, millis_per_letter: u32) -> Self {
+        self.millis_per_letter = millis_per_letter;
+        self
+    }
+
+    /// How long the text stays still when scrolled to either end.
+    pub fn with_pause(mut self, pause: Duration) -> Self {
+        self.pause = pause;
+        self
+    }
+
+    pub fn text(&self) -> &T {
+        &self.text
+    }
+
+    /// Whether the whole text fits the area, so that there is no need to
+    /// scroll it.
+    pub fn fits(&self) -> bool {
+        self.font.text_width(self.text.as_ref()) <= self.area.width()
+    }
+
     pub fn start(&mut self, ctx: &mut EventCtx, now: Instant) {
         if let State::Initial = self.state {
             let text_width = self.font.text_width(self.text.as_ref());
@@ -60,6 +88,7 @@ where
 
             self.min_offset = 0;
             self.max_offset = max_offset;
+            self.offset = self.min_offset;
 
             let anim = Animation::new(self.min_offset, max_offset, self.duration, now);
 
@@ -76,6 +105,7 @@ where
 
     pub fn reset(&mut self) {
         self.state = State::Initial;
+        self.offset = 0;
     }
 
     pub fn animation(&self) -> Option<&Animation<i16>> {
@@ -110,48 +140,10 @@ where
         self.animation().is_some()
     }
 
-    pub fn paint_anim(&mut self, offset: i16) {
-        display::marquee(
-            self.area,
-            self.text.as_ref(),
-            offset,
-            self.font,
-            self.fg,
-            self.bg,
-        );
-    }
-}
-
-impl<T> Component for Marquee<T>
-where
-    T: AsRef<str>,
-{
-    type Msg = Never;
-
-    fn place(&mut self, bounds: Rect) -> Rect {
-        let base_width = self.font.text_width("M");
-        let text_width = self.font.text_width(self.text.as_ref());
-        let area_width = bounds.width();
-
-        let shift_width = if area_width > text_width {
-            area_width - text_width
-        } else {
-            text_width - area_width
-        };
-
-        let mut duration = (MILLIS_PER_LETTER_M * shift_width as u32) / base_width as u32;
-        if duration < MILLIS_PER_LETTER_M {
-            duration = MILLIS_PER_LETTER_M;
-        }
-
-        self.duration = Duration::from_millis(duration);
-        self.area = bounds;
-        self.area
-    }
-
-    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
-        let now = Instant::now();
-
+    /// Advance the scrolling to `now`, the time the event was received at.
+    /// Containers which need the time themselves read the clock once and
+    /// pass the same time to `start` and here.
+    pub fn event_at(&mut self, ctx: &mut EventCtx, event: Event, now: Instant) {
         if let Event::Timer(token) = event {
             if self.pause_token == Some(token) {
                 match self.state {
@@ -174,7 +166,8 @@ where
             }
 
             if token == EventCtx::ANIM_FRAME_TIMER {
-                if self.is_animating() {
+                if let Some(offset) = self.progress(now) {
+                    self.offset = offset;
                     // We have something to paint, so request to be painted in the next pass.
                     ctx.request_paint();
                     // There is further progress in the animation, request an animation frame
@@ -199,31 +192,54 @@ where
                 }
             }
         }
-        None
     }
 
-    fn paint(&mut self) {
-        let now = Instant::now();
+    pub fn paint_anim(&mut self, offset: i16) {
+        display::marquee(
+            self.area,
+            self.text.as_ref(),
+            offset,
+            self.font,
+            self.fg,
+            self.bg,
+        );
+    }
+}
 
-        match self.state {
-            State::Initial => {
-                self.paint_anim(0);
-            }
-            State::PauseRight => {
-                self.paint_anim(self.min_offset);
-            }
-            State::PauseLeft => {
-                self.paint_anim(self.max_offset);
-            }
-            _ => {
-                let progress = self.progress(now);
-                if let Some(done) = progress {
-                    self.paint_anim(done as i16);
-                } else {
-                    self.paint_anim(0);
-                }
-            }
+impl<T> Component for Marquee<T>
+where
+    T: AsRef<str>,
+{
+    type Msg = Never;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        let base_width = self.font.text_width("M");
+        let text_width = self.font.text_width(self.text.as_ref());
+        let area_width = bounds.width();
+
+        let shift_width = if area_width > text_width {
+            area_width - text_width
+        } else {
+            text_width - area_width
+        };
+
+        let mut duration = (self.millis_per_letter * shift_width as u32) / base_width as u32;
+        if duration < self.millis_per_letter {
+            duration = self.millis_per_letter;
         }
+
+        self.duration = Duration::from_millis(duration);
+        self.area = bounds;
+        self.area
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        self.event_at(ctx, event, Instant::now());
+        None
+    }
+
+    fn paint(&mut self) {
+        self.paint_anim(self.offset);
     }
 }
 
@@ -238,3 +254,43 @@ where
         d.close();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{time::at, ui::geometry::Point};
+
+    #[test]
+    fn marquee_scrolls_and_pauses() {
+        let text = "Text which is too long for the area";
+        let mut marquee = Marquee::new(text, Font::NORMAL, Color::white(), Color::black())
+            .with_pause(Duration::from_millis(1000));
+        marquee.place(Rect::new(Point::zero(), Point::new(50, 20)));
+        let max_offset = 50 - Font::NORMAL.text_width(text);
+        let duration = marquee.duration.to_millis();
+        let frame = Event::Timer(EventCtx::ANIM_FRAME_TIMER);
+
+        let start = Instant::now();
+        let mut ctx = EventCtx::new();
+        marquee.start(&mut ctx, start);
+        assert!(marquee.is_animating());
+        assert_eq!(marquee.offset, 0);
+
+        marquee.event_at(&mut ctx, frame, at(start, duration / 2));
+        assert!(marquee.offset < 0 && marquee.offset > max_offset);
+
+        // stays at the end for the pause
+        marquee.event_at(&mut ctx, frame, at(start, duration));
+        assert_eq!(marquee.offset, max_offset);
+        assert!(!marquee.is_animating());
+        let pause = unwrap!(marquee.pause_token);
+
+        // and scrolls back after it
+        let back = at(start, duration + 1000);
+        marquee.event_at(&mut ctx, Event::Timer(pause), back);
+        assert!(marquee.is_animating());
+        marquee.event_at(&mut ctx, frame, at(back, duration));
+        assert_eq!(marquee.offset, 0);
+        assert!(!marquee.is_animating());
+    }
+}
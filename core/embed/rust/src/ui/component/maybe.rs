@@ -127,7 +127,7 @@ where
 /// and then paint over it.
 pub fn paint_overlapping(components: &mut [&mut dyn PaintOverlapping]) {
     let mut area = Rect::zero();
-    let mut color = Color::rgb(0, 0, 0);
+    let mut color = Color::black();
     for component in components.iter() {
         if let Some((clear_area, clear_color)) = component.cleared_area() {
             area = area.union(clear_area);
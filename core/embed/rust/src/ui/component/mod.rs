@@ -1,5 +1,8 @@
 #![forbid(unsafe_code)]
 
+pub mod address;
+pub mod animated_qr;
+pub mod backlight;
 pub mod base;
 pub mod border;
 pub mod empty;
@@ -8,13 +11,20 @@ pub mod label;
 pub mod map;
 pub mod marquee;
 pub mod maybe;
+pub mod modal;
 pub mod pad;
 pub mod paginated;
 pub mod painter;
 pub mod placed;
+pub mod qr_code;
+pub mod result_popup;
+pub mod split;
 pub mod text;
 pub mod timeout;
 
+pub use address::AddressDisplay;
+pub use animated_qr::AnimatedQr;
+pub use backlight::BacklightFade;
 pub use base::{Child, Component, ComponentExt, Event, EventCtx, Never, TimerToken};
 pub use border::Border;
 pub use empty::Empty;
@@ -22,10 +32,14 @@ pub use label::Label;
 pub use map::Map;
 pub use marquee::Marquee;
 pub use maybe::Maybe;
+pub use modal::{ModalStack, ModalStackMsg};
 pub use pad::Pad;
 pub use paginated::{PageMsg, Paginate};
 pub use painter::{qrcode_painter, Painter};
 pub use placed::{FixedHeightBar, GridPlaced};
+pub use qr_code::QrCode;
+pub use result_popup::{ResultButton, ResultPopupMsg};
+pub use split::Split;
 pub use text::{
     formatted::FormattedText,
     layout::{LineBreaking, PageBreaking, TextLayout},
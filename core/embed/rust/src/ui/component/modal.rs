@@ -0,0 +1,130 @@
+use crate::ui::{
+    component::{Child, Component, ComponentExt, Event, EventCtx, Pad},
+    display::Color,
+    geometry::Rect,
+};
+
+pub enum ModalStackMsg<T, U> {
+    Content(T),
+    Modal(U),
+}
+
+/// Content component with a modal overlay that can be shown on top of it.
+///
+/// Opening or closing the overlay only clears the area the overlay occupies and
+/// then repaints whichever component becomes visible, so the rest of the
+/// screen is not blanked and does not flash.
+pub struct ModalStack<T, U> {
+    content: Child<T>,
+    modal: Child<U>,
+    pad: Pad,
+    modal_shown: bool,
+}
+
+impl<T, U> ModalStack<T, U>
+where
+    T: Component,
+    U: Component,
+{
+    pub fn new(content: T, modal: U, background: Color) -> Self {
+        Self {
+            content: Child::new(content),
+            modal: Child::new(modal),
+            pad: Pad::with_background(background),
+            modal_shown: false,
+        }
+    }
+
+    pub fn is_modal_shown(&self) -> bool {
+        self.modal_shown
+    }
+
+    pub fn show_modal(&mut self, ctx: &mut EventCtx) {
+        self.set_modal_shown(ctx, true)
+    }
+
+    pub fn close_modal(&mut self, ctx: &mut EventCtx) {
+        self.set_modal_shown(ctx, false)
+    }
+
+    fn set_modal_shown(&mut self, ctx: &mut EventCtx, shown: bool) {
+        if self.modal_shown != shown {
+            self.modal_shown = shown;
+
+            // Only the area covered by the overlay is damaged, clear just that and
+            // repaint the newly visible component over it.
+            self.pad.clear();
+            if shown {
+                self.modal.request_complete_repaint(ctx);
+            } else {
+                self.content.request_complete_repaint(ctx);
+            }
+        }
+    }
+
+    pub fn content(&self) -> &T {
+        self.content.inner()
+    }
+
+    pub fn modal(&self) -> &U {
+        self.modal.inner()
+    }
+}
+
+impl<T, U> Component for ModalStack<T, U>
+where
+    T: Component,
+    U: Component,
+{
+    type Msg = ModalStackMsg<T::Msg, U::Msg>;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.content.place(bounds);
+        let modal_area = self.modal.place(bounds);
+        self.pad.place(modal_area);
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        if self.modal_shown {
+            self.modal.event(ctx, event).map(ModalStackMsg::Modal)
+        } else {
+            self.content.event(ctx, event).map(ModalStackMsg::Content)
+        }
+    }
+
+    fn paint(&mut self) {
+        self.pad.paint();
+        if self.modal_shown {
+            self.modal.paint();
+        } else {
+            self.content.paint();
+        }
+    }
+
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        sink(self.pad.area);
+        if self.modal_shown {
+            self.modal.bounds(sink);
+        } else {
+            self.content.bounds(sink);
+        }
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl<T, U> crate::trace::Trace for ModalStack<T, U>
+where
+    T: crate::trace::Trace,
+    U: crate::trace::Trace,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.open("ModalStack");
+        if self.modal_shown {
+            t.field("modal", &self.modal);
+        } else {
+            t.field("content", &self.content);
+        }
+        t.close();
+    }
+}
@@ -1,7 +1,6 @@
-use crate::ui::component::{
-    text::layout::{LayoutFit, TextNoOp},
-    FormattedText,
-};
+use heapless::Vec;
+
+use crate::ui::component::FormattedText;
 
 /// Common message type for pagination components.
 pub enum PageMsg<T, U> {
@@ -20,6 +19,57 @@ pub enum PageMsg<T, U> {
 pub trait Paginate {
     fn page_count(&mut self) -> usize;
     fn change_page(&mut self, active_page: usize);
+
+    /// Show the page `page`, or the last one if there are fewer pages, e.g. to
+    /// resume a flow at the page it was interrupted on. Returns the page shown.
+    fn go_to_page(&mut self, page: usize) -> usize {
+        let page = page.min(self.page_count().saturating_sub(1));
+        self.change_page(page);
+        page
+    }
+}
+
+/// Maximum number of pages whose beginnings are remembered by `PageBreaks`.
+const MAX_CACHED_PAGES: usize = 16;
+
+/// Beginnings of the pages of a paginated component, computed by a layout pass
+/// over the whole content and reused until the content or its bounds change,
+/// so that querying the page count and jumping to a page does not lay out the
+/// content again. Content with more than `MAX_CACHED_PAGES` pages is not
+/// cached.
+pub struct PageBreaks<O> {
+    /// `None` until computed, or if there are too many pages.
+    offsets: Option<Vec<O, MAX_CACHED_PAGES>>,
+}
+
+impl<O: Copy> PageBreaks<O> {
+    pub const fn new() -> Self {
+        Self { offsets: None }
+    }
+
+    /// Cache the page beginnings yielded by `pages`, starting with the first
+    /// page.
+    pub fn collect(pages: impl Iterator<Item = O>) -> Self {
+        let mut offsets = Vec::new();
+        for offset in pages {
+            if offsets.push(offset).is_err() {
+                return Self::new();
+            }
+        }
+        Self {
+            offsets: Some(offsets),
+        }
+    }
+
+    /// Forget the pages, e.g. after the content was changed.
+    pub fn invalidate(&mut self) {
+        self.offsets = None;
+    }
+
+    /// Beginnings of all the pages, `None` if not cached.
+    pub fn get(&self) -> Option<&[O]> {
+        self.offsets.as_deref()
+    }
 }
 
 impl<F, T> Paginate for FormattedText<F, T>
@@ -28,54 +78,53 @@ where
     T: AsRef<str>,
 {
     fn page_count(&mut self) -> usize {
+        if let Some(offsets) = self.page_offsets() {
+            return offsets.len();
+        }
+        let start = self.char_offset();
         let mut page_count = 1; // There's always at least one page.
         let mut char_offset = 0;
-
-        loop {
-            let fit = self.layout_content(&mut TextNoOp);
-            match fit {
-                LayoutFit::Fitting { .. } => {
-                    break; // TODO: We should consider if there's more content
-                           // to render.
-                }
-                LayoutFit::OutOfBounds {
-                    processed_chars, ..
-                } => {
-                    page_count += 1;
-                    char_offset += processed_chars;
-                    self.set_char_offset(char_offset);
-                }
-            }
+        while let Some(next) = self.next_page(char_offset) {
+            page_count += 1;
+            char_offset = next;
         }
-
-        // Reset the char offset back to the beginning.
-        self.set_char_offset(0);
-
+        self.set_char_offset(start);
         page_count
     }
 
     fn change_page(&mut self, to_page: usize) {
-        let mut active_page = 0;
+        if let Some(offsets) = self.page_offsets() {
+            let char_offset = offsets.get(to_page).or(offsets.last()).copied();
+            self.set_char_offset(char_offset.unwrap_or(0));
+            return;
+        }
         let mut char_offset = 0;
-
-        // Make sure we're starting from the beginning.
-        self.set_char_offset(char_offset);
-
-        while active_page < to_page {
-            let fit = self.layout_content(&mut TextNoOp);
-            match fit {
-                LayoutFit::Fitting { .. } => {
-                    break; // TODO: We should consider if there's more content
-                           // to render.
-                }
-                LayoutFit::OutOfBounds {
-                    processed_chars, ..
-                } => {
-                    active_page += 1;
-                    char_offset += processed_chars;
-                    self.set_char_offset(char_offset);
-                }
+        for _ in 0..to_page {
+            match self.next_page(char_offset) {
+                Some(next) => char_offset = next,
+                None => break,
             }
         }
+        self.set_char_offset(char_offset);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn page_breaks_cache() {
+        let pages = PageBreaks::collect([0, 10, 25].into_iter());
+        assert_eq!(pages.get(), Some(&[0, 10, 25][..]));
+
+        let mut pages = PageBreaks::collect(0..MAX_CACHED_PAGES);
+        assert_eq!(pages.get().map(<[_]>::len), Some(MAX_CACHED_PAGES));
+        pages.invalidate();
+        assert_eq!(pages.get(), None);
+
+        // too many pages
+        let pages = PageBreaks::collect(0..MAX_CACHED_PAGES + 1);
+        assert_eq!(pages.get(), None);
     }
 }
@@ -0,0 +1,264 @@
+use crate::{
+    error::Error,
+    trezorhal::qr::QrMatrix,
+    ui::{
+        component::{Component, Event, EventCtx, Never, Pad},
+        display::{self, Color},
+        event::{GestureRecognizer, GestureThresholds},
+        geometry::{Offset, Point, Rect},
+        pool::Block,
+    },
+};
+
+#[cfg(feature = "touch")]
+use crate::{
+    time::Instant,
+    ui::event::{Gesture, TouchEvent},
+};
+
+/// Scale of the enlarged code relative to the fitted one.
+const ZOOM: i16 = 2;
+
+/// QR code of arbitrary bytes, scaled to the largest whole number of pixels
+/// per module that fits the bounds and centered in them.
+///
+/// On touch screens a double tap toggles an enlarged view of the code, which
+/// is easier to scan when the code is dense, e.g. for long descriptors. The
+/// enlarged code is clipped to the bounds and can be moved by dragging.
+pub struct QrCode {
+    matrix: QrMatrix,
+    area: Rect,
+    /// Width of the light margin around the code, in modules.
+    quiet_zone: i16,
+    inverted: bool,
+    /// Clears the bounds when the code gets smaller again.
+    pad: Pad,
+    zoomed: bool,
+    /// Offset of the enlarged code from the center of the bounds.
+    pan: Offset,
+    /// Last position of a touch which started over the code.
+    drag: Option<Point>,
+    gestures: GestureRecognizer,
+}
+
+impl QrCode {
+    /// Recommended by the specification, smaller margins are usually readable
+    /// on a display as well.
+    pub const DEFAULT_QUIET_ZONE: i16 = 4;
+
+    pub fn new(data: &[u8]) -> Result<Self, Error> {
+        let mut temp = Block::alloc().ok_or(Error::AllocationFailed)?;
+        Ok(Self {
+            matrix: QrMatrix::encode(data, &mut temp[..])?,
+            area: Rect::zero(),
+            quiet_zone: Self::DEFAULT_QUIET_ZONE,
+            inverted: false,
+            pad: Pad::with_background(Color::black()),
+            zoomed: false,
+            pan: Offset::zero(),
+            drag: None,
+            gestures: GestureRecognizer::new(GestureThresholds::DEFAULT),
+        })
+    }
+
+    /// Color of the screen around the code, black by default.
+    pub fn with_background(mut self, color: Color) -> Self {
+        self.pad = Pad::with_background(color);
+        self
+    }
+
+    pub fn with_quiet_zone(mut self, modules: i16) -> Self {
+        self.quiet_zone = modules;
+        self
+    }
+
+    /// Number of modules on a side of the code, without the quiet zone.
+    pub fn modules(&self) -> i16 {
+        self.matrix.size()
+    }
+
+    /// Paint light modules on dark background.
+    pub fn inverted(mut self) -> Self {
+        self.inverted = true;
+        self
+    }
+
+    fn colors(&self) -> (Color, Color) {
+        if self.inverted {
+            (Color::white(), Color::black())
+        } else {
+            (Color::black(), Color::white())
+        }
+    }
+
+    /// Size of one module in pixels.
+    fn scale(&self) -> i16 {
+        let modules = self.matrix.size() + 2 * self.quiet_zone;
+        let scale = (self.area.width().min(self.area.height()) / modules).max(1);
+        if self.zoomed {
+            scale * ZOOM
+        } else {
+            scale
+        }
+    }
+
+    /// Size of the code including the quiet zone, in pixels.
+    fn code_size(&self) -> i16 {
+        (self.matrix.size() + 2 * self.quiet_zone) * self.scale()
+    }
+
+    pub fn is_zoomed(&self) -> bool {
+        self.zoomed
+    }
+
+    /// Enlarge the code around `pos`, or fit it to the bounds again.
+    #[cfg(feature = "touch")]
+    fn toggle_zoom(&mut self, ctx: &mut EventCtx, pos: Point) {
+        self.zoomed = !self.zoomed;
+        self.pan = Offset::zero();
+        if self.zoomed {
+            // Keep the tapped part of the code under the finger.
+            self.pan = clamp_pan(self.area.center() - pos, self.code_size(), self.area);
+        } else {
+            self.pad.clear();
+        }
+        ctx.request_paint();
+    }
+
+    #[cfg(feature = "touch")]
+    fn touch(&mut self, ctx: &mut EventCtx, event: TouchEvent) {
+        if let TouchEvent::TouchStart(pos) = event {
+            if !self.area.contains(pos) {
+                return;
+            }
+            self.drag = Some(pos);
+        }
+        // Ignore touches which started outside of the code.
+        let prev = match self.drag {
+            Some(prev) => prev,
+            None => return,
+        };
+        if let Some(Gesture::DoubleTap(pos)) = self.gestures.touch(event, Instant::now()) {
+            self.toggle_zoom(ctx, pos);
+        }
+        match event {
+            TouchEvent::TouchMove(pos) if self.zoomed => {
+                let pan = clamp_pan(self.pan + (pos - prev), self.code_size(), self.area);
+                if pan != self.pan {
+                    self.pan = pan;
+                    ctx.request_paint();
+                }
+                self.drag = Some(pos);
+            }
+            TouchEvent::TouchEnd(_) | TouchEvent::TouchCancel => self.drag = None,
+            _ => {}
+        }
+    }
+}
+
+/// Limit `pan` so that a code of `code_size` pixels centered in `area` and
+/// moved by `pan` still covers the whole area, or stays centered in the
+/// direction in which it is smaller.
+fn clamp_pan(pan: Offset, code_size: i16, area: Rect) -> Offset {
+    let max_x = ((code_size - area.width()) / 2).max(0);
+    let max_y = ((code_size - area.height()) / 2).max(0);
+    Offset::new(pan.x.clamp(-max_x, max_x), pan.y.clamp(-max_y, max_y))
+}
+
+impl Component for QrCode {
+    type Msg = Never;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.area = bounds;
+        self.pad.place(bounds);
+        self.pan = clamp_pan(self.pan, self.code_size(), bounds);
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        #[cfg(feature = "touch")]
+        if let Event::Touch(touch) = event {
+            self.touch(ctx, touch);
+        }
+        #[cfg(not(feature = "touch"))]
+        let _ = (ctx, event);
+        None
+    }
+
+    fn paint(&mut self) {
+        self.pad.paint();
+        let (dark, light) = self.colors();
+        let scale = self.scale();
+        let size = self.matrix.size();
+        let code = Rect::from_center_and_size(
+            self.area.center() + self.pan,
+            Offset::uniform(self.code_size()),
+        );
+        display::rect_fill(code.clamp(self.area), light);
+
+        let origin = code.top_left() + Offset::uniform(self.quiet_zone * scale);
+        for y in 0..size {
+            // Dark modules are painted in horizontal runs to save on the
+            // number of display operations.
+            let mut x = 0;
+            while x < size {
+                if !self.matrix.module(x, y) {
+                    x += 1;
+                    continue;
+                }
+                let start = x;
+                while x < size && self.matrix.module(x, y) {
+                    x += 1;
+                }
+                let run = Rect::from_top_left_and_size(
+                    origin + Offset::new(start * scale, y * scale),
+                    Offset::new((x - start) * scale, scale),
+                )
+                .clamp(self.area);
+                if !run.is_empty() {
+                    display::rect_fill(run, dark);
+                }
+            }
+        }
+    }
+
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        sink(self.area)
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl crate::trace::Trace for QrCode {
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.open("QrCode");
+        t.field("size", &(self.matrix.size() as usize));
+        t.field("zoomed", &(self.zoomed as usize));
+        t.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn qr_clamp_pan() {
+        let area = Rect::new(Point::new(0, 0), Point::new(200, 100));
+        // Code smaller than the area stays centered.
+        assert_eq!(clamp_pan(Offset::new(30, 30), 80, area), Offset::zero());
+        // Larger code can move until its edge reaches the edge of the area.
+        assert_eq!(
+            clamp_pan(Offset::new(30, -30), 300, area),
+            Offset::new(30, -30)
+        );
+        assert_eq!(
+            clamp_pan(Offset::new(80, -120), 300, area),
+            Offset::new(50, -100)
+        );
+        // Only wider than the area.
+        assert_eq!(
+            clamp_pan(Offset::new(10, 10), 150, area),
+            Offset::new(0, 10)
+        );
+    }
+}
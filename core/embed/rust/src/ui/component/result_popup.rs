@@ -0,0 +1,282 @@
+//! Result notification shared by the models. The icon and the button come
+//! from the model, the text styles and the spacing from its theme, see
+//! `ui::theme`.
+
+use crate::ui::{
+    component::{
+        text::{
+            paragraphs::{
+                paragraph_vec, Checklist, Paragraph, ParagraphSource, ParagraphStrType,
+                ParagraphVec, Paragraphs, VecExt,
+            },
+            StyleToken,
+        },
+        Child, Component, Event, EventCtx, Timeout, TimeoutMsg,
+    },
+    display::toif::Icon,
+    geometry::{Insets, LinearPlacement, Rect},
+    theme,
+};
+
+/// Items of the checklist over the capacity are dropped.
+pub const MAX_CHECKLIST_ITEMS: usize = 8;
+
+pub enum ResultPopupMsg {
+    /// Button was clicked or the timeout elapsed.
+    Confirmed,
+}
+
+/// Button of a model which closes the popup.
+pub trait ResultButton: Component {
+    fn is_clicked(msg: &Self::Msg) -> bool;
+}
+
+/// Full-screen result notification: icon, title, optional message, optional
+/// checklist, optional button and optional timeout. Used for success/failure
+/// screens in both the bootloader and the firmware.
+pub struct ResultPopup<T, I, B> {
+    image: Child<I>,
+    /// Title and message.
+    paragraphs: Paragraphs<ParagraphVec<T, 2>>,
+    checklist: Option<Child<Checklist<ParagraphVec<T, MAX_CHECKLIST_ITEMS>>>>,
+    checklist_height: i16,
+    button: Option<Child<B>>,
+    timeout: Option<Timeout>,
+}
+
+impl<T, I, B> ResultPopup<T, I, B>
+where
+    T: ParagraphStrType,
+    I: Component,
+    B: ResultButton,
+{
+    pub fn new(icon: I, title: T) -> Self {
+        Self {
+            image: Child::new(icon),
+            paragraphs: Paragraphs::new(paragraph_vec([Paragraph::new(
+                theme::text(StyleToken::Subtitle),
+                title,
+            )
+            .centered()]))
+            .with_placement(
+                LinearPlacement::vertical()
+                    .align_at_center()
+                    .with_spacing(Self::VALUE_SPACE),
+            ),
+            checklist: None,
+            checklist_height: 0,
+            button: None,
+            timeout: None,
+        }
+    }
+
+    pub fn with_message(mut self, message: T) -> Self {
+        if !message.as_ref().is_empty() {
+            self.paragraphs
+                .inner_mut()
+                .add(Paragraph::new(theme::text(StyleToken::Secondary), message).centered());
+        }
+        self
+    }
+
+    /// List of completed items, e.g. what was erased by the wipe, each of them
+    /// checked off with `check`.
+    pub fn with_checklist(
+        mut self,
+        current: Icon,
+        check: Icon,
+        items: impl IntoIterator<Item = T>,
+    ) -> Self {
+        let mut paragraphs = ParagraphVec::new();
+        for item in items {
+            paragraphs.add(Paragraph::new(theme::text(StyleToken::Body), item));
+        }
+        let done = paragraphs.len();
+        if done > 0 {
+            let item_height =
+                theme::text(StyleToken::Body).text_font.line_height() + theme::CHECKLIST_SPACING;
+            self.checklist_height = done as i16 * item_height;
+            self.checklist = Some(Child::new(Checklist::from_paragraphs(
+                current,
+                check,
+                done,
+                paragraphs
+                    .into_paragraphs()
+                    .with_spacing(theme::CHECKLIST_SPACING),
+            )));
+        }
+        self
+    }
+
+    pub fn with_button(mut self, button: B) -> Self {
+        self.button = Some(Child::new(button));
+        self
+    }
+
+    /// Close the popup automatically after `time_ms` milliseconds. Zero
+    /// disables the timeout.
+    pub fn with_timeout(mut self, time_ms: u32) -> Self {
+        self.timeout = (time_ms > 0).then(|| Timeout::new(time_ms));
+        self
+    }
+
+    pub const ICON_AREA_PADDING: i16 = 2;
+    pub const VALUE_SPACE: i16 = 5;
+    pub const CHECKLIST_INSET: i16 = 24;
+}
+
+impl<T, I, B> Component for ResultPopup<T, I, B>
+where
+    T: ParagraphStrType,
+    I: Component,
+    B: ResultButton,
+{
+    type Msg = ResultPopupMsg;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        let bounds = bounds
+            .inset(theme::borders())
+            .inset(Insets::top(Self::ICON_AREA_PADDING));
+
+        let content_area = if let Some(button) = self.button.as_mut() {
+            let (content_area, button_area) = bounds.split_bottom(theme::BUTTON_HEIGHT);
+            button.place(button_area);
+            content_area
+        } else {
+            bounds
+        };
+
+        let (image_area, content_area) = content_area.split_top(theme::RESULT_ICON_HEIGHT);
+
+        let content_area = if let Some(checklist) = self.checklist.as_mut() {
+            let (content_area, checklist_area) = content_area.split_bottom(self.checklist_height);
+            checklist.place(checklist_area.inset(Insets::sides(Self::CHECKLIST_INSET)));
+            content_area
+        } else {
+            content_area
+        };
+
+        self.image.place(image_area);
+        self.paragraphs.place(content_area);
+        self.timeout.place(Rect::zero());
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        self.image.event(ctx, event);
+        self.paragraphs.event(ctx, event);
+        self.checklist.event(ctx, event);
+        if let Some(TimeoutMsg::TimedOut) = self.timeout.event(ctx, event) {
+            return Some(ResultPopupMsg::Confirmed);
+        }
+        match self.button.event(ctx, event) {
+            Some(msg) if B::is_clicked(&msg) => Some(ResultPopupMsg::Confirmed),
+            _ => None,
+        }
+    }
+
+    fn paint(&mut self) {
+        self.image.paint();
+        self.paragraphs.paint();
+        self.checklist.paint();
+        self.button.paint();
+    }
+
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        self.image.bounds(sink);
+        self.paragraphs.bounds(sink);
+        self.checklist.bounds(sink);
+        self.button.bounds(sink);
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl<T, I, B> crate::trace::Trace for ResultPopup<T, I, B>
+where
+    T: ParagraphStrType,
+    I: crate::trace::Trace,
+    B: crate::trace::Trace,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.open("ResultPopup");
+        t.field("image", &self.image);
+        t.field("content", &self.paragraphs);
+        if let Some(checklist) = &self.checklist {
+            t.field("checklist", checklist);
+        }
+        if let Some(button) = &self.button {
+            t.field("button", button);
+        }
+        if let Some(timeout) = &self.timeout {
+            t.field("timeout", timeout);
+        }
+        t.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        time::Duration,
+        ui::{component::Empty, constant::screen},
+    };
+
+    use super::*;
+
+    /// Clicked by any timer, other events produce a message which is not a
+    /// click.
+    struct TestButton;
+
+    impl Component for TestButton {
+        type Msg = bool;
+
+        fn place(&mut self, bounds: Rect) -> Rect {
+            bounds
+        }
+
+        fn event(&mut self, _ctx: &mut EventCtx, event: Event) -> Option<bool> {
+            Some(matches!(event, Event::Timer(_)))
+        }
+
+        fn paint(&mut self) {}
+    }
+
+    impl ResultButton for TestButton {
+        fn is_clicked(msg: &bool) -> bool {
+            *msg
+        }
+    }
+
+    type TestPopup = ResultPopup<&'static str, Empty, TestButton>;
+
+    #[test]
+    fn result_popup_timeout() {
+        let mut popup = TestPopup::new(Empty, "DONE").with_timeout(1000);
+        popup.place(screen());
+        let mut ctx = EventCtx::new();
+        assert!(popup.event(&mut ctx, Event::Attach).is_none());
+        let (token, delay) = unwrap!(ctx.pop_timer());
+        assert_eq!(delay, Duration::from_millis(1000));
+        assert!(matches!(
+            popup.event(&mut ctx, Event::Timer(token)),
+            Some(ResultPopupMsg::Confirmed)
+        ));
+
+        let mut popup = TestPopup::new(Empty, "DONE").with_timeout(0);
+        popup.event(&mut ctx, Event::Attach);
+        assert!(ctx.pop_timer().is_none());
+    }
+
+    #[test]
+    fn result_popup_button() {
+        let mut popup = TestPopup::new(Empty, "DONE").with_button(TestButton);
+        popup.place(screen());
+        let mut ctx = EventCtx::new();
+        // Messages other than a click do not close the popup.
+        assert!(popup.event(&mut ctx, Event::Attach).is_none());
+        assert!(matches!(
+            popup.event(&mut ctx, Event::Timer(EventCtx::ANIM_FRAME_TIMER)),
+            Some(ResultPopupMsg::Confirmed)
+        ));
+    }
+}
@@ -0,0 +1,234 @@
+use crate::ui::{
+    component::{Component, Event, EventCtx},
+    geometry::Rect,
+};
+
+#[derive(Copy, Clone)]
+enum Axis {
+    Vertical,
+    Horizontal,
+}
+
+#[derive(Copy, Clone)]
+enum Size {
+    /// Fixed size of the first part.
+    First(i16),
+    /// Fixed size of the second part.
+    Second(i16),
+    /// First part gets `numerator / denominator` of the available space.
+    Fraction(i16, i16),
+}
+
+/// Places two components next to each other, splitting the bounds either
+/// vertically or horizontally. Declaring a screen as a tree of `Split`s
+/// replaces the usual hand-written `place` coordinate arithmetic:
+///
+/// ```ignore
+/// Split::top(TITLE_HEIGHT, title, Split::bottom(BUTTON_HEIGHT, content, buttons))
+/// ```
+///
+/// Both parts have to produce the same message type, use `ComponentExt::map`
+/// to convert them. Events are passed to both parts, if both of them emit a
+/// message, the one of the first part is returned.
+pub struct Split<T, U> {
+    first: T,
+    second: U,
+    axis: Axis,
+    size: Size,
+    spacing: i16,
+}
+
+impl<T, U> Split<T, U> {
+    const fn new(axis: Axis, size: Size, first: T, second: U) -> Self {
+        Self {
+            first,
+            second,
+            axis,
+            size,
+            spacing: 0,
+        }
+    }
+
+    /// `first` on top with fixed `height`, `second` below it.
+    pub const fn top(height: i16, first: T, second: U) -> Self {
+        Self::new(Axis::Vertical, Size::First(height), first, second)
+    }
+
+    /// `second` at the bottom with fixed `height`, `first` above it.
+    pub const fn bottom(height: i16, first: T, second: U) -> Self {
+        Self::new(Axis::Vertical, Size::Second(height), first, second)
+    }
+
+    /// `first` on the left with fixed `width`, `second` right of it.
+    pub const fn left(width: i16, first: T, second: U) -> Self {
+        Self::new(Axis::Horizontal, Size::First(width), first, second)
+    }
+
+    /// `second` on the right with fixed `width`, `first` left of it.
+    pub const fn right(width: i16, first: T, second: U) -> Self {
+        Self::new(Axis::Horizontal, Size::Second(width), first, second)
+    }
+
+    /// `first` above `second`, taking `numerator / denominator` of the height.
+    pub const fn rows(numerator: i16, denominator: i16, first: T, second: U) -> Self {
+        Self::new(
+            Axis::Vertical,
+            Size::Fraction(numerator, denominator),
+            first,
+            second,
+        )
+    }
+
+    /// `first` left of `second`, taking `numerator / denominator` of the
+    /// width.
+    pub const fn columns(numerator: i16, denominator: i16, first: T, second: U) -> Self {
+        Self::new(
+            Axis::Horizontal,
+            Size::Fraction(numerator, denominator),
+            first,
+            second,
+        )
+    }
+
+    /// Leave `spacing` pixels between the parts, taken from the part without
+    /// fixed size.
+    pub fn with_spacing(mut self, spacing: i16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    pub fn first(&self) -> &T {
+        &self.first
+    }
+
+    pub fn second(&self) -> &U {
+        &self.second
+    }
+
+    fn split(&self, bounds: Rect) -> (Rect, Rect) {
+        let total = match self.axis {
+            Axis::Vertical => bounds.height(),
+            Axis::Horizontal => bounds.width(),
+        };
+        let (first_size, second_size) = match self.size {
+            Size::First(size) => (size, total - size - self.spacing),
+            Size::Second(size) => (total - size - self.spacing, size),
+            Size::Fraction(numerator, denominator) => {
+                let size = (total - self.spacing) * numerator / denominator;
+                (size, total - size - self.spacing)
+            }
+        };
+        match self.axis {
+            Axis::Vertical => {
+                let (first, _) = bounds.split_top(first_size);
+                let (_, second) = bounds.split_bottom(second_size);
+                (first, second)
+            }
+            Axis::Horizontal => {
+                let (first, _) = bounds.split_left(first_size);
+                let (_, second) = bounds.split_right(second_size);
+                (first, second)
+            }
+        }
+    }
+}
+
+impl<T, U> Component for Split<T, U>
+where
+    T: Component,
+    U: Component<Msg = T::Msg>,
+{
+    type Msg = T::Msg;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        let (first_area, second_area) = self.split(bounds);
+        self.first.place(first_area);
+        self.second.place(second_area);
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        let first_msg = self.first.event(ctx, event);
+        let second_msg = self.second.event(ctx, event);
+        first_msg.or(second_msg)
+    }
+
+    fn paint(&mut self) {
+        self.first.paint();
+        self.second.paint();
+    }
+
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        self.first.bounds(sink);
+        self.second.bounds(sink);
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl<T, U> crate::trace::Trace for Split<T, U>
+where
+    T: crate::trace::Trace,
+    U: crate::trace::Trace,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.open("Split");
+        t.field("first", &self.first);
+        t.field("second", &self.second);
+        t.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::{component::Empty, constant::screen, geometry::Point};
+
+    /// Counts the events and emits `id` for each of them.
+    struct Counter {
+        id: u8,
+        events: usize,
+    }
+
+    impl Component for Counter {
+        type Msg = u8;
+
+        fn place(&mut self, bounds: Rect) -> Rect {
+            bounds
+        }
+
+        fn event(&mut self, _ctx: &mut EventCtx, _event: Event) -> Option<u8> {
+            self.events += 1;
+            Some(self.id)
+        }
+
+        fn paint(&mut self) {}
+    }
+
+    #[test]
+    fn split_areas() {
+        let bounds = Rect::new(Point::new(10, 20), Point::new(110, 220));
+
+        let (first, second) = Split::top(30, Empty, Empty).split(bounds);
+        assert!(first == Rect::new(Point::new(10, 20), Point::new(110, 50)));
+        assert!(second == Rect::new(Point::new(10, 50), Point::new(110, 220)));
+
+        let (first, second) = Split::right(40, Empty, Empty).with_spacing(6).split(bounds);
+        assert!(first == Rect::new(Point::new(10, 20), Point::new(64, 220)));
+        assert!(second == Rect::new(Point::new(70, 20), Point::new(110, 220)));
+
+        let (first, second) = Split::rows(1, 4, Empty, Empty).split(bounds);
+        assert!(first == Rect::new(Point::new(10, 20), Point::new(110, 70)));
+        assert!(second == Rect::new(Point::new(10, 70), Point::new(110, 220)));
+    }
+
+    #[test]
+    fn split_event_reaches_both_parts() {
+        let counter = |id| Counter { id, events: 0 };
+        let mut split = Split::top(30, counter(1), counter(2));
+        split.place(screen());
+        let mut ctx = EventCtx::new();
+        assert_eq!(split.event(&mut ctx, Event::Attach), Some(1));
+        assert_eq!(split.first().events, 1);
+        assert_eq!(split.second().events, 1);
+    }
+}
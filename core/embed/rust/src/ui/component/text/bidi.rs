@@ -0,0 +1,76 @@
+//! Simplified handling of bidirectional text. Right-to-left paragraphs are
+//! laid out as usual and mirrored within their bounds, every line is then
+//! rendered from the right edge. Embedded left-to-right runs, e.g. numbers or
+//! Latin words in Arabic text, keep their order, the rest of the full Unicode
+//! bidirectional algorithm is not implemented.
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum TextDirection {
+    LeftToRight,
+    RightToLeft,
+}
+
+/// Whether `ch` belongs to a script written from right to left, i.e. Hebrew,
+/// Arabic, Syriac, Thaana, NKo and related presentation forms.
+pub fn is_rtl(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{0590}'..='\u{08FF}' | '\u{FB1D}'..='\u{FDFF}' | '\u{FE70}'..='\u{FEFF}'
+    )
+}
+
+/// Letters and digits of the left-to-right scripts. Everything else, e.g.
+/// whitespace and punctuation, takes the direction of its surroundings.
+fn is_ltr(ch: char) -> bool {
+    ch.is_alphanumeric() && !is_rtl(ch)
+}
+
+/// Split a line of right-to-left text into runs in logical order. Each run is
+/// returned with a flag telling whether it is written from right to left. A
+/// left-to-right run spans from its first to its last strong character, so
+/// that e.g. "1 000" or "Trezor Suite" stays together.
+pub fn runs(text: &str) -> impl Iterator<Item = (&str, bool)> {
+    let mut rest = text;
+    core::iter::from_fn(move || {
+        let first = rest.chars().next()?;
+        let end = if is_ltr(first) {
+            // Up to the last strong left-to-right character before the next
+            // right-to-left one.
+            let run_end = rest.find(is_rtl).unwrap_or(rest.len());
+            rest[..run_end]
+                .char_indices()
+                .filter(|&(_, ch)| is_ltr(ch))
+                .last()
+                .map_or(run_end, |(i, ch)| i + ch.len_utf8())
+        } else {
+            rest.find(is_ltr).unwrap_or(rest.len())
+        };
+        let (run, remaining) = rest.split_at(end);
+        rest = remaining;
+        Some((run, !is_ltr(first)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect(text: &str) -> Vec<(&str, bool)> {
+        runs(text).collect()
+    }
+
+    #[test]
+    fn bidi_runs() {
+        assert_eq!(collect(""), []);
+        assert_eq!(collect("שלום עולם"), [("שלום עולם", true)]);
+        assert_eq!(collect("abc def"), [("abc def", false)]);
+        assert_eq!(
+            collect("שלח 1 000 BTC לכתובת"),
+            [("שלח ", true), ("1 000 BTC", false), (" לכתובת", true)]
+        );
+        assert_eq!(
+            collect("Trezor: مرحبا"),
+            [("Trezor", false), (": مرحبا", true)]
+        );
+    }
+}
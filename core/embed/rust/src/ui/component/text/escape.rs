@@ -0,0 +1,143 @@
+//! Visible rendering of characters which would otherwise not be seen, such as
+//! control characters, zero width characters or bidirectional overrides. These
+//! can be used to make two different strings, e.g. addresses, look the same,
+//! so styles for security-relevant text draw them as boxes containing their
+//! hexadecimal code instead.
+
+use crate::ui::{
+    display,
+    geometry::{Offset, Point, Rect},
+};
+
+use super::{iter::GlyphMetrics, layout::TextLayout};
+
+/// Horizontal space between the box and the code inside it.
+const BOX_PADDING: i16 = 2;
+
+pub fn is_invisible(ch: char) -> bool {
+    matches!(ch,
+        '\u{0}'..='\u{9}' | '\u{B}'..='\u{C}' | '\u{E}'..='\u{1F}' // C0 controls except LF, CR
+        | '\u{7F}'..='\u{9F}' // DEL and C1 controls
+        | '\u{AD}' // soft hyphen
+        | '\u{61C}' // arabic letter mark
+        | '\u{200B}'..='\u{200F}' // zero width characters, directional marks
+        | '\u{202A}'..='\u{202E}' // bidirectional embeddings and overrides
+        | '\u{2060}'..='\u{2064}' // word joiner, invisible operators
+        | '\u{2066}'..='\u{2069}' // bidirectional isolates
+        | '\u{FE00}'..='\u{FE0F}' // variation selectors
+        | '\u{FEFF}' // zero width no-break space
+    )
+}
+
+/// Hexadecimal code of `ch` shown in its box, at least two digits long.
+fn hex_code(ch: char, buf: &mut [u8; 6]) -> &str {
+    const HEX: &[u8; 16] = b"0123456789ABCDEF";
+    let code = ch as u32;
+    let mut len = 2;
+    while len < buf.len() && code >> (4 * len) != 0 {
+        len += 1;
+    }
+    for (i, digit) in buf[..len].iter_mut().enumerate() {
+        *digit = HEX[(code >> (4 * (len - 1 - i)) & 0xF) as usize];
+    }
+    unwrap!(core::str::from_utf8(&buf[..len]))
+}
+
+fn box_width(metrics: &impl GlyphMetrics, ch: char) -> i16 {
+    let mut buf = [0; 6];
+    let code_width: i16 = hex_code(ch, &mut buf)
+        .chars()
+        .map(|digit| metrics.char_width(digit))
+        .sum();
+    code_width + 2 * BOX_PADDING
+}
+
+/// Glyph metrics in which invisible characters take the width of their box.
+pub struct Escaped<F>(pub F);
+
+impl<F: GlyphMetrics> GlyphMetrics for Escaped<F> {
+    fn char_width(&self, ch: char) -> i16 {
+        if is_invisible(ch) {
+            box_width(&self.0, ch)
+        } else {
+            self.0.char_width(ch)
+        }
+    }
+
+    fn line_height(&self) -> i16 {
+        self.0.line_height()
+    }
+
+    fn ascent(&self) -> i16 {
+        self.0.ascent()
+    }
+
+    fn descent(&self) -> i16 {
+        self.0.descent()
+    }
+}
+
+/// Render `text` with baseline at `cursor`, drawing the runs of regular
+/// characters using `render_run` and the invisible ones as boxes.
+pub fn render(
+    mut cursor: Point,
+    layout: &TextLayout,
+    text: &str,
+    render_run: fn(Point, &TextLayout, &str),
+) {
+    let font = layout.style.text_font;
+    let mut run_start = 0;
+    for (i, ch) in text.char_indices() {
+        if !is_invisible(ch) {
+            continue;
+        }
+        let run = &text[run_start..i];
+        render_run(cursor, layout, run);
+        cursor.x += font.text_width(run);
+        run_start = i + ch.len_utf8();
+
+        let width = box_width(&font, ch);
+        let mut buf = [0; 6];
+        display::rect_stroke(
+            Rect::new(
+                Point::new(cursor.x, cursor.y - font.ascent()),
+                Point::new(cursor.x + width, cursor.y + font.descent()),
+            ),
+            layout.style.text_color,
+        );
+        display::text(
+            cursor + Offset::x(BOX_PADDING),
+            hex_code(ch, &mut buf),
+            font,
+            layout.style.text_color,
+            layout.style.background_color,
+        );
+        cursor.x += width;
+    }
+    render_run(cursor, layout, &text[run_start..]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_hex_code() {
+        let mut buf = [0; 6];
+        assert_eq!(hex_code('\u{0}', &mut buf), "00");
+        assert_eq!(hex_code('\u{1B}', &mut buf), "1B");
+        assert_eq!(hex_code('\u{AD}', &mut buf), "AD");
+        assert_eq!(hex_code('\u{200D}', &mut buf), "200D");
+        assert_eq!(hex_code('\u{FEFF}', &mut buf), "FEFF");
+    }
+
+    #[test]
+    fn escape_invisible() {
+        assert!(is_invisible('\u{202E}'));
+        assert!(is_invisible('\t'));
+        assert!(!is_invisible('\n'));
+        assert!(!is_invisible(' '));
+        assert!(!is_invisible('a'));
+        assert!(!is_invisible('ž'));
+    }
+}
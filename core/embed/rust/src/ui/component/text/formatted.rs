@@ -6,23 +6,28 @@ use core::{
 use heapless::LinearMap;
 
 use crate::ui::{
-    component::{Component, Event, EventCtx, Never},
-    display::{Color, Font},
+    component::{paginated::PageBreaks, Component, Event, EventCtx, Never},
+    display::{toif::Icon, Color, Font},
     geometry::Rect,
+    theme,
 };
 
 use super::layout::{
-    LayoutFit, LayoutSink, LineBreaking, Op, PageBreaking, TextLayout, TextRenderer, TextStyle,
+    LayoutFit, LayoutSink, LineBreaking, Op, PageBreaking, TextLayout, TextNoOp, TextRenderer,
+    TextStyle,
 };
 
 pub const MAX_ARGUMENTS: usize = 6;
+pub const MAX_ICONS: usize = 2;
 
 pub struct FormattedText<F, T> {
     layout: TextLayout,
     fonts: FormattedFonts,
     format: F,
     args: LinearMap<&'static str, T, MAX_ARGUMENTS>,
+    icons: LinearMap<&'static str, Icon, MAX_ICONS>,
     char_offset: usize,
+    pages: PageBreaks<usize>,
 }
 
 pub struct FormattedFonts {
@@ -36,6 +41,13 @@ pub struct FormattedFonts {
     pub mono: Font,
 }
 
+impl Default for FormattedFonts {
+    /// Fonts of the theme of the current model.
+    fn default() -> Self {
+        theme::FORMATTED
+    }
+}
+
 impl<F, T> FormattedText<F, T> {
     pub fn new(style: TextStyle, fonts: FormattedFonts, format: F) -> Self {
         Self {
@@ -43,7 +55,9 @@ impl<F, T> FormattedText<F, T> {
             fonts,
             layout: TextLayout::new(style),
             args: LinearMap::new(),
+            icons: LinearMap::new(),
             char_offset: 0,
+            pages: PageBreaks::new(),
         }
     }
 
@@ -55,8 +69,18 @@ impl<F, T> FormattedText<F, T> {
         self
     }
 
+    /// Render `icon` inline in place of the `{key}` argument.
+    pub fn with_icon(mut self, key: &'static str, icon: &'static [u8]) -> Self {
+        if self.icons.insert(key, Icon::new(icon)).is_err() {
+            #[cfg(feature = "ui_debug")]
+            panic!("text icons map is full");
+        }
+        self
+    }
+
     pub fn with_format(mut self, format: F) -> Self {
         self.format = format;
+        self.pages.invalidate();
         self
     }
 
@@ -89,6 +113,7 @@ impl<F, T> FormattedText<F, T> {
     }
 
     pub fn layout_mut(&mut self) -> &mut TextLayout {
+        self.pages.invalidate();
         &mut self.layout
     }
 }
@@ -107,10 +132,13 @@ where
                 Token::Argument("bold") => Some(Op::Font(self.fonts.bold)),
                 Token::Argument("normal") => Some(Op::Font(self.fonts.normal)),
                 Token::Argument("demibold") => Some(Op::Font(self.fonts.demibold)),
-                Token::Argument(argument) => self
-                    .args
-                    .get(argument)
-                    .map(|value| Op::Text(value.as_ref())),
+                Token::Argument(argument) => match self.icons.get(argument) {
+                    Some(icon) => Some(Op::Icon(*icon)),
+                    None => self
+                        .args
+                        .get(argument)
+                        .map(|value| Op::Text(value.as_ref())),
+                },
             }),
             self.char_offset,
         );
@@ -118,6 +146,42 @@ where
     }
 }
 
+impl<F, T> FormattedText<F, T>
+where
+    F: AsRef<str>,
+    T: AsRef<str>,
+{
+    /// Beginning of the page following the one starting at `char_offset`, or
+    /// `None` if it is the last one.
+    pub(crate) fn next_page(&mut self, char_offset: usize) -> Option<usize> {
+        self.set_char_offset(char_offset);
+        match self.layout_content(&mut TextNoOp) {
+            // Nothing fits on the page, better stop than loop forever.
+            LayoutFit::OutOfBounds {
+                processed_chars, ..
+            } if processed_chars > 0 => Some(char_offset + processed_chars),
+            _ => None,
+        }
+    }
+
+    /// Beginnings of the pages, computed and cached if needed. `None` if there
+    /// are too many pages to cache.
+    pub(crate) fn page_offsets(&mut self) -> Option<&[usize]> {
+        if self.pages.get().is_none() {
+            let start = self.char_offset;
+            let mut next = Some(0);
+            let pages = PageBreaks::collect(core::iter::from_fn(|| {
+                let offset = next?;
+                next = self.next_page(offset);
+                Some(offset)
+            }));
+            self.set_char_offset(start);
+            self.pages = pages;
+        }
+        self.pages.get()
+    }
+}
+
 impl<F, T> Component for FormattedText<F, T>
 where
     F: AsRef<str>,
@@ -127,6 +191,7 @@ where
 
     fn place(&mut self, bounds: Rect) -> Rect {
         self.layout.bounds = bounds;
+        self.pages.invalidate();
         self.layout.bounds
     }
 
@@ -135,7 +200,7 @@ where
     }
 
     fn paint(&mut self) {
-        self.layout_content(&mut TextRenderer);
+        self.layout_content(&mut TextRenderer::default());
     }
 
     fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
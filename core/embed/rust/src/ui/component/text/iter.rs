@@ -161,6 +161,10 @@ fn select_line_breaks(
 pub trait GlyphMetrics {
     fn char_width(&self, ch: char) -> i16;
     fn line_height(&self) -> i16;
+    /// Distance from the baseline to the top of the tallest glyph.
+    fn ascent(&self) -> i16;
+    /// Distance from the baseline to the bottom of the lowest glyph.
+    fn descent(&self) -> i16;
 }
 
 impl GlyphMetrics for Font {
@@ -171,6 +175,14 @@ impl GlyphMetrics for Font {
     fn line_height(&self) -> i16 {
         Font::line_height(*self)
     }
+
+    fn ascent(&self) -> i16 {
+        Font::ascent(*self)
+    }
+
+    fn descent(&self) -> i16 {
+        Font::descent(*self)
+    }
 }
 
 #[cfg(test)]
@@ -217,6 +229,14 @@ mod tests {
         fn line_height(&self) -> i16 {
             self.height
         }
+
+        fn ascent(&self) -> i16 {
+            self.height
+        }
+
+        fn descent(&self) -> i16 {
+            0
+        }
     }
 
     fn break_text(s: &str, w: i16) -> Vec<Span> {
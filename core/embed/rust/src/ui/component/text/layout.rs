@@ -1,10 +1,53 @@
-use super::iter::GlyphMetrics;
+#[cfg(feature = "text_shaping")]
+use core::fmt::Write;
+
+#[cfg(feature = "text_shaping")]
+use super::shaping;
+use super::{
+    bidi::{self, TextDirection},
+    escape::{self, Escaped},
+    iter::GlyphMetrics,
+    linebreak::is_break_opportunity,
+};
+#[cfg(feature = "text_shaping")]
+use crate::ui::pool::BlockString;
 use crate::ui::{
     display,
-    display::{Color, Font},
-    geometry::{Alignment, Dimensions, Offset, Point, Rect},
+    display::{toif::Icon, Color, Font},
+    geometry::{Alignment, Dimensions, Offset, Point, Rect, BOTTOM_LEFT},
+    grapheme,
 };
 
+/// Characters around a part of the text shaped on its own, see
+/// `shaping::Context`. Unshaped text does not depend on them.
+#[cfg(feature = "text_shaping")]
+pub type ShapingContext = shaping::Context;
+#[cfg(not(feature = "text_shaping"))]
+#[derive(Copy, Clone, Default)]
+pub struct ShapingContext;
+
+/// Context of `text[start..end]` within `text` surrounded by `context`.
+#[cfg(feature = "text_shaping")]
+fn sub_context(context: ShapingContext, text: &str, start: usize, end: usize) -> ShapingContext {
+    context.within(text, start, end)
+}
+
+#[cfg(not(feature = "text_shaping"))]
+fn sub_context(context: ShapingContext, _text: &str, _start: usize, _end: usize) -> ShapingContext {
+    context
+}
+
+/// Pairs of byte offset and glyph of `text`, see `shaping::clusters`.
+#[cfg(feature = "text_shaping")]
+fn clusters(text: &str, context: ShapingContext) -> impl Iterator<Item = (usize, char)> + '_ {
+    shaping::clusters(&shaping::ArabicShaper, text, context)
+}
+
+#[cfg(not(feature = "text_shaping"))]
+fn clusters(text: &str, _context: ShapingContext) -> impl Iterator<Item = (usize, char)> + '_ {
+    text.char_indices()
+}
+
 #[derive(Copy, Clone)]
 pub enum LineBreaking {
     /// Break line only at whitespace, if possible. If we don't find any
@@ -56,13 +99,42 @@ pub struct TextStyle {
 
     /// Foreground color used for drawing the hyphen.
     pub hyphen_color: Color,
+    /// Character inserted when breaking words, see
+    /// `LineBreaking::BreakWordsAndInsertHyphen`.
+    pub hyphen: char,
     /// Foreground color used for drawing the ellipsis.
     pub ellipsis_color: Color,
+    /// Text drawn at the end of a page to signify more content is available.
+    pub ellipsis: &'static str,
 
     /// Specifies which line-breaking strategy to use.
     pub line_breaking: LineBreaking,
     /// Specifies what to do at the end of the page.
     pub page_breaking: PageBreaking,
+    /// Draw control characters, zero width characters and bidirectional
+    /// overrides as boxes with their code, see `escape`. Meant for
+    /// security-relevant text like addresses.
+    pub escape_invisible: bool,
+    /// Avoid breaking pages so that a single line of a paragraph remains at the
+    /// bottom of a page (orphan) or at the top of the next one (widow).
+    pub widow_orphan_control: bool,
+
+    /// Horizontal offset of the first line of text.
+    pub first_line_indent: i16,
+    /// Horizontal offset of the lines following a line break, e.g. to align
+    /// continuation lines of a list item with the text after its bullet.
+    pub hanging_indent: i16,
+
+    /// Extra vertical space between lines, negative to tighten them.
+    pub line_spacing: i16,
+    /// Extra vertical space after a line ending with a line feed, i.e. between
+    /// paragraphs of the text, on top of `line_spacing`.
+    pub paragraph_spacing: i16,
+
+    /// Direction of the paragraph. Right-to-left text is laid out mirrored:
+    /// lines start at the right edge, hyphens and ellipses are placed at the
+    /// left one. See `bidi` for the handling of embedded left-to-right runs.
+    pub direction: TextDirection,
 }
 
 impl TextStyle {
@@ -78,12 +150,39 @@ impl TextStyle {
             text_color,
             background_color,
             hyphen_color,
+            hyphen: '-',
             ellipsis_color,
+            ellipsis: "...",
             line_breaking: LineBreaking::BreakAtWhitespace,
             page_breaking: PageBreaking::CutAndInsertEllipsis,
+            escape_invisible: false,
+            widow_orphan_control: false,
+            first_line_indent: 0,
+            hanging_indent: 0,
+            line_spacing: 0,
+            paragraph_spacing: 0,
+            direction: TextDirection::LeftToRight,
         }
     }
 
+    /// Same style with a different font, e.g. a bold variant of the body text.
+    pub const fn with_font(mut self, text_font: Font) -> Self {
+        self.text_font = text_font;
+        self
+    }
+
+    /// Same style with a different color of the text, keeping the colors of the
+    /// hyphens and ellipses.
+    pub const fn with_text_color(mut self, text_color: Color) -> Self {
+        self.text_color = text_color;
+        self
+    }
+
+    pub const fn with_background_color(mut self, background_color: Color) -> Self {
+        self.background_color = background_color;
+        self
+    }
+
     pub const fn with_line_breaking(mut self, line_breaking: LineBreaking) -> Self {
         self.line_breaking = line_breaking;
         self
@@ -93,6 +192,47 @@ impl TextStyle {
         self.page_breaking = page_breaking;
         self
     }
+
+    pub const fn with_escape_invisible(mut self, escape_invisible: bool) -> Self {
+        self.escape_invisible = escape_invisible;
+        self
+    }
+
+    pub const fn with_widow_orphan_control(mut self, widow_orphan_control: bool) -> Self {
+        self.widow_orphan_control = widow_orphan_control;
+        self
+    }
+
+    pub const fn with_indent(mut self, first_line_indent: i16, hanging_indent: i16) -> Self {
+        self.first_line_indent = first_line_indent;
+        self.hanging_indent = hanging_indent;
+        self
+    }
+
+    pub const fn with_spacing(mut self, line_spacing: i16, paragraph_spacing: i16) -> Self {
+        self.line_spacing = line_spacing;
+        self.paragraph_spacing = paragraph_spacing;
+        self
+    }
+
+    pub const fn with_direction(mut self, direction: TextDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Break words with a different character. Its own width is kept free at
+    /// the end of the line, so a narrow glyph leaves more room for the text.
+    pub const fn with_hyphen(mut self, hyphen: char) -> Self {
+        self.hyphen = hyphen;
+        self
+    }
+
+    /// Use a different ellipsis, e.g. a shorter one or a localized marker on
+    /// narrow displays. Characters missing from the font are not drawn.
+    pub const fn with_ellipsis(mut self, ellipsis: &'static str) -> Self {
+        self.ellipsis = ellipsis;
+        self
+    }
 }
 
 impl TextLayout {
@@ -118,16 +258,92 @@ impl TextLayout {
         self
     }
 
+    /// Layout of text which continues a paragraph started elsewhere, e.g. on
+    /// the previous page. Its first line is indented the same way as the
+    /// wrapped ones.
+    pub fn continued(mut self) -> Self {
+        self.style.first_line_indent = self.style.hanging_indent;
+        self
+    }
+
     pub fn initial_cursor(&self) -> Point {
-        self.bounds.top_left() + Offset::y(self.style.text_font.text_height() + self.padding_top)
+        self.bounds.top_left()
+            + Offset::new(
+                self.style.first_line_indent,
+                self.style.text_font.text_height() + self.padding_top,
+            )
     }
 
     pub fn fit_text(&self, text: &str) -> LayoutFit {
         self.layout_text(text, &mut self.initial_cursor(), &mut TextNoOp)
     }
 
+    /// Height of `text` laid out into the given width, not limited by the
+    /// current bounds. Useful for sizing components to their content.
+    pub fn measure_height(&self, text: &str, width: i16) -> i16 {
+        let layout = Self {
+            // Leave headroom for padding and cursor arithmetic.
+            bounds: Rect::new(Point::zero(), Point::new(width, i16::MAX / 2)),
+            ..*self
+        };
+        layout.fit_text(text).height()
+    }
+
+    /// Like `fit_text`, also returning the number of lines that fit into the
+    /// bounds.
+    pub fn fit_lines(&self, text: &str) -> (LayoutFit, usize) {
+        let mut counter = LineCounter(0);
+        let fit = self.layout_text(text, &mut self.initial_cursor(), &mut counter);
+        let lines = match fit {
+            LayoutFit::OutOfBounds {
+                processed_chars: 0, ..
+            } => 0,
+            _ => counter.0 + 1,
+        };
+        (fit, lines)
+    }
+
     pub fn render_text(&self, text: &str) {
-        self.layout_text(text, &mut self.initial_cursor(), &mut TextRenderer);
+        self.layout_text(
+            text,
+            &mut self.initial_cursor(),
+            &mut TextRenderer::default(),
+        );
+    }
+
+    /// Part of `text` to show on a single line of the bounds, followed by the
+    /// ellipsis if it is not the whole text. The text is cut at a word
+    /// boundary, unless even the first word does not fit.
+    pub fn fit_with_ellipsis<'t>(&self, text: &'t str) -> &'t str {
+        let font = self.style.text_font;
+        let width = self.bounds.width();
+        if font.text_width(text) <= width {
+            return text;
+        }
+        let available = width - font.text_width(self.style.ellipsis);
+        let (fitting, rest) = text.split_at(font.longest_prefix(available, text));
+        if rest.starts_with(char::is_whitespace) {
+            return fitting.trim_end();
+        }
+        match fitting.rfind(char::is_whitespace) {
+            Some(word_end) => fitting[..word_end].trim_end(),
+            None => fitting,
+        }
+    }
+
+    /// Beginning and end of `text` to show on a single line of the bounds,
+    /// with the ellipsis between them, e.g. for addresses where both ends
+    /// matter. The end is empty if the whole text fits.
+    pub fn fit_middle_with_ellipsis<'t>(&self, text: &'t str) -> (&'t str, &'t str) {
+        let font = self.style.text_font;
+        let width = self.bounds.width();
+        if font.text_width(text) <= width {
+            return (text, "");
+        }
+        let available = width - font.text_width(self.style.ellipsis);
+        let end = &text[text.len() - font.longest_suffix(available / 2, text)..];
+        let start = &text[..font.longest_prefix(available - font.text_width(end), text)];
+        (start, end)
     }
 
     pub fn layout_ops<'o>(
@@ -137,6 +353,7 @@ impl TextLayout {
         sink: &mut dyn LayoutSink,
     ) -> LayoutFit {
         let init_cursor = *cursor;
+        let mut first_baseline = init_cursor.y;
         let mut total_processed_chars = 0;
 
         for op in ops {
@@ -145,8 +362,31 @@ impl TextLayout {
                     self.style.text_color = color;
                 }
                 Op::Font(font) => {
+                    let on_first_line = cursor.y == first_baseline;
+                    let line_start = if on_first_line {
+                        init_cursor.x
+                    } else {
+                        self.bounds.x0 + self.style.hanging_indent
+                    };
+                    cursor.y = realign_baseline(
+                        cursor.y,
+                        cursor.x == line_start,
+                        self.style.text_font,
+                        font,
+                    );
+                    if on_first_line {
+                        first_baseline = cursor.y;
+                    }
                     self.style.text_font = font;
                 }
+                Op::Icon(icon) => {
+                    if !self.layout_icon(icon, cursor, sink) {
+                        return LayoutFit::OutOfBounds {
+                            processed_chars: total_processed_chars,
+                            height: self.layout_height(init_cursor, *cursor),
+                        };
+                    }
+                }
                 Op::Text(text) => match self.layout_text(text, cursor, sink) {
                     LayoutFit::Fitting {
                         processed_chars, ..
@@ -194,37 +434,94 @@ impl TextLayout {
 
         while !remaining_text.is_empty() {
             let remaining_width = self.bounds.x1 - cursor.x;
-            let span = Span::fit_horizontally(
-                remaining_text,
-                remaining_width,
-                self.style.text_font,
-                self.style.line_breaking,
-            );
+            // Lines are shaped with the letters before them, so that a word
+            // broken in the middle keeps the joined forms.
+            let start = text.len() - remaining_text.len();
+            let context = sub_context(ShapingContext::default(), text, start, text.len());
+            let span = if self.style.escape_invisible {
+                Span::fit_horizontally(
+                    remaining_text,
+                    context,
+                    remaining_width,
+                    Escaped(self.style.text_font),
+                    self.style.line_breaking,
+                    self.style.hyphen,
+                )
+            } else {
+                Span::fit_horizontally(
+                    remaining_text,
+                    context,
+                    remaining_width,
+                    self.style.text_font,
+                    self.style.line_breaking,
+                    self.style.hyphen,
+                )
+            };
 
             cursor.x += match self.align {
-                Alignment::Start => 0,
+                Alignment::Start | Alignment::Justified => 0,
                 Alignment::Center => (remaining_width - span.advance.x) / 2,
                 Alignment::End => remaining_width - span.advance.x,
             };
 
+            // Lines wrapped in the middle of a paragraph are stretched to the full
+            // width, keeping space for the hyphen.
+            let hyphen_width = self.style.text_font.char_width(self.style.hyphen);
+            let rest = &remaining_text[span.length..];
+            let hard_break = rest.starts_with('\n') || rest.starts_with('\r');
+            let justify = matches!(self.align, Alignment::Justified)
+                && !self.style.escape_invisible
+                && span.advance.y > 0
+                && !hard_break
+                && rest.len() > span.skip_next_chars;
+            let extra_width = if justify {
+                let hyphen = if span.insert_hyphen_before_line_break {
+                    hyphen_width
+                } else {
+                    0
+                };
+                (remaining_width - span.advance.x - hyphen).max(0)
+            } else {
+                0
+            };
+
             // Report the span at the cursor position.
-            sink.text(*cursor, self, &remaining_text[..span.length]);
+            let span_text = &remaining_text[..span.length];
+            let span_width = span.advance.x + extra_width;
+            let span_cursor = self.visual(*cursor, span_width);
+            sink.shaping_context(sub_context(
+                ShapingContext::default(),
+                text,
+                start,
+                start + span.length,
+            ));
+            if extra_width > 0 {
+                sink.justified_text(span_cursor, self, span_text, extra_width);
+            } else {
+                sink.text(span_cursor, self, span_text);
+            }
 
             // Continue with the rest of the remaining_text.
             remaining_text = &remaining_text[span.length + span.skip_next_chars..];
 
             // Advance the cursor horizontally.
-            cursor.x += span.advance.x;
+            cursor.x += span_width;
 
             if span.advance.y > 0 {
                 // We're advancing to the next line.
+                let paragraph_spacing = if rest.starts_with('\n') {
+                    self.style.paragraph_spacing
+                } else {
+                    0
+                };
+                let advance = span.advance.y + self.style.line_spacing + paragraph_spacing;
 
                 // Check if we should be appending a hyphen at this point.
                 if span.insert_hyphen_before_line_break {
-                    sink.hyphen(*cursor, self);
+                    sink.hyphen(self.visual(*cursor, hyphen_width), self);
                 }
                 // Check the amount of vertical space we have left.
-                if cursor.y + span.advance.y > bottom {
+                if cursor.y + advance > bottom {
                     if !remaining_text.is_empty() {
                         // Append ellipsis to indicate more content is available, but only if we
                         // haven't already appended a hyphen.
@@ -232,7 +529,9 @@ impl TextLayout {
                             matches!(self.style.page_breaking, PageBreaking::CutAndInsertEllipsis)
                                 && !span.insert_hyphen_before_line_break;
                         if should_append_ellipsis {
-                            sink.ellipsis(*cursor, self);
+                            let ellipsis_width =
+                                self.style.text_font.text_width(self.style.ellipsis);
+                            sink.ellipsis(self.visual(*cursor, ellipsis_width), self);
                         }
                         // TODO: This does not work in case we are the last
                         // fitting text token on the line, with more text tokens
@@ -248,8 +547,8 @@ impl TextLayout {
                     };
                 } else {
                     // Advance the cursor to the beginning of the next line.
-                    cursor.x = self.bounds.x0;
-                    cursor.y += span.advance.y;
+                    cursor.x = self.bounds.x0 + self.style.hanging_indent;
+                    cursor.y += advance;
 
                     // Report a line break. While rendering works using the cursor coordinates, we
                     // use explicit line-break reporting in the `Trace` impl.
@@ -264,6 +563,40 @@ impl TextLayout {
         }
     }
 
+    /// Lay out an inline icon at the cursor, wrapping it to the next line if it
+    /// does not fit on the current one. Returns `false` if there is no space
+    /// left for it.
+    fn layout_icon(&self, icon: Icon, cursor: &mut Point, sink: &mut dyn LayoutSink) -> bool {
+        let width = icon.toif.width();
+        let line_start = self.bounds.x0 + self.style.hanging_indent;
+        if cursor.x + width > self.bounds.x1 && cursor.x > line_start {
+            let bottom = (self.bounds.y1 - self.padding_bottom).max(self.bounds.y0);
+            let line_height = self.style.text_font.line_height() + self.style.line_spacing;
+            if cursor.y + line_height > bottom {
+                sink.out_of_bounds();
+                return false;
+            }
+            cursor.x = line_start;
+            cursor.y += line_height;
+            sink.line_break(*cursor);
+        }
+        sink.icon(self.visual(*cursor, width), self, icon);
+        cursor.x += width;
+        true
+    }
+
+    /// Position at which an item of `width` laid out at `cursor` is drawn.
+    /// Layout always advances the cursor from the left, right-to-left text is
+    /// mirrored within the bounds.
+    fn visual(&self, cursor: Point, width: i16) -> Point {
+        match self.style.direction {
+            TextDirection::LeftToRight => cursor,
+            TextDirection::RightToLeft => {
+                Point::new(self.bounds.x0 + self.bounds.x1 - cursor.x - width, cursor.y)
+            }
+        }
+    }
+
     fn layout_height(&self, init_cursor: Point, end_cursor: Point) -> i16 {
         self.padding_top
             + self.style.text_font.text_height()
@@ -300,9 +633,24 @@ impl LayoutFit {
 
 /// Visitor for text segment operations.
 pub trait LayoutSink {
+    /// Characters around the text of the following `text` or
+    /// `justified_text` call, for the sinks which shape the text.
+    fn shaping_context(&mut self, _context: ShapingContext) {}
     fn text(&mut self, _cursor: Point, _layout: &TextLayout, _text: &str) {}
+    /// Text of a justified line, `extra_width` needs to be distributed among
+    /// the spaces between words.
+    fn justified_text(
+        &mut self,
+        cursor: Point,
+        layout: &TextLayout,
+        text: &str,
+        _extra_width: i16,
+    ) {
+        self.text(cursor, layout, text)
+    }
     fn hyphen(&mut self, _cursor: Point, _layout: &TextLayout) {}
     fn ellipsis(&mut self, _cursor: Point, _layout: &TextLayout) {}
+    fn icon(&mut self, _cursor: Point, _layout: &TextLayout, _icon: Icon) {}
     fn line_break(&mut self, _cursor: Point) {}
     fn out_of_bounds(&mut self) {}
 }
@@ -311,10 +659,71 @@ pub struct TextNoOp;
 
 impl LayoutSink for TextNoOp {}
 
-pub struct TextRenderer;
+struct LineCounter(usize);
 
-impl LayoutSink for TextRenderer {
-    fn text(&mut self, cursor: Point, layout: &TextLayout, text: &str) {
+impl LayoutSink for LineCounter {
+    fn line_break(&mut self, _cursor: Point) {
+        self.0 += 1;
+    }
+}
+
+#[derive(Default)]
+pub struct TextRenderer {
+    /// Context of the text being rendered, see `LayoutSink::shaping_context`.
+    context: ShapingContext,
+}
+
+impl TextRenderer {
+    #[cfg(feature = "text_shaping")]
+    fn render_run(mut cursor: Point, layout: &TextLayout, text: &str, context: ShapingContext) {
+        if text.is_ascii() {
+            display::text(
+                cursor,
+                text,
+                layout.style.text_font,
+                layout.style.text_color,
+                layout.style.background_color,
+            );
+            return;
+        }
+        // Shape the run into a pool block and render it at once, glyph by glyph
+        // only if the pool is exhausted or the run does not fit.
+        if let Some(shaped) = Self::shape_into_block(text, context) {
+            display::text(
+                cursor,
+                &shaped,
+                layout.style.text_font,
+                layout.style.text_color,
+                layout.style.background_color,
+            );
+            return;
+        }
+        for glyph in Self::glyphs(text, context) {
+            let mut buf = [0; 4];
+            display::text(
+                cursor,
+                glyph.encode_utf8(&mut buf),
+                layout.style.text_font,
+                layout.style.text_color,
+                layout.style.background_color,
+            );
+            cursor.x += layout.style.text_font.char_width(glyph);
+        }
+    }
+
+    /// Glyphs of `text` written into a pool block, `None` if there is no free
+    /// block or they do not fit into one.
+    #[cfg(feature = "text_shaping")]
+    fn shape_into_block(text: &str, context: ShapingContext) -> Option<BlockString<'static>> {
+        let mut shaped = BlockString::alloc()?;
+        Self::glyphs(text, context)
+            .try_for_each(|glyph| shaped.write_char(glyph))
+            .ok()?;
+        Some(shaped)
+    }
+
+    #[cfg(not(feature = "text_shaping"))]
+    fn render_run(cursor: Point, layout: &TextLayout, text: &str, _context: ShapingContext) {
         display::text(
             cursor,
             text,
@@ -324,10 +733,112 @@ impl LayoutSink for TextRenderer {
         );
     }
 
+    /// Glyphs of `text` as measured by `Span::fit_horizontally`.
+    fn glyphs(text: &str, context: ShapingContext) -> impl Iterator<Item = char> + '_ {
+        clusters(text, context).map(|(_, glyph)| glyph)
+    }
+
+    fn run_width(font: Font, text: &str, context: ShapingContext) -> i16 {
+        Self::glyphs(text, context)
+            .map(|glyph| font.char_width(glyph))
+            .sum()
+    }
+
+    /// Render a line of right-to-left text ending at the right edge of the
+    /// span starting at `cursor`. Glyphs are placed from right to left, except
+    /// for the left-to-right runs which are rendered as a whole.
+    fn render_rtl(cursor: Point, layout: &TextLayout, text: &str, context: ShapingContext) {
+        let font = layout.style.text_font;
+        let mut x = cursor.x + Self::run_width(font, text, context);
+        let mut start = 0;
+        for (run, rtl) in bidi::runs(text) {
+            let run_context = sub_context(context, text, start, start + run.len());
+            start += run.len();
+            if !rtl {
+                x -= Self::run_width(font, run, run_context);
+                Self::render_run(Point::new(x, cursor.y), layout, run, run_context);
+                continue;
+            }
+            for glyph in Self::glyphs(run, run_context) {
+                x -= font.char_width(glyph);
+                let mut buf = [0; 4];
+                display::text(
+                    Point::new(x, cursor.y),
+                    glyph.encode_utf8(&mut buf),
+                    font,
+                    layout.style.text_color,
+                    layout.style.background_color,
+                );
+            }
+        }
+    }
+
+    fn render(cursor: Point, layout: &TextLayout, text: &str, context: ShapingContext) {
+        if layout.style.escape_invisible {
+            // Escaped text is meant for addresses and similar, always rendered
+            // from left to right.
+            escape::render(cursor, layout, text, |cursor, layout, run| {
+                Self::render_run(cursor, layout, run, ShapingContext::default())
+            });
+        } else if layout.style.direction == TextDirection::RightToLeft {
+            Self::render_rtl(cursor, layout, text, context);
+        } else {
+            Self::render_run(cursor, layout, text, context);
+        }
+    }
+}
+
+impl LayoutSink for TextRenderer {
+    fn shaping_context(&mut self, context: ShapingContext) {
+        self.context = context;
+    }
+
+    fn justified_text(&mut self, cursor: Point, layout: &TextLayout, text: &str, extra_width: i16) {
+        let font = layout.style.text_font;
+        let gaps = text.split(' ').count().saturating_sub(1) as i16;
+        if gaps == 0 {
+            self.text(cursor, layout, text);
+            return;
+        }
+        // Words are rendered including the space following them, the
+        // remainder of the division goes to the first gaps.
+        let rtl = layout.style.direction == TextDirection::RightToLeft;
+        let mut edge = if rtl {
+            cursor.x + Self::run_width(font, text, self.context) + extra_width
+        } else {
+            cursor.x
+        };
+        let mut start = 0;
+        for (i, word) in text.split_inclusive(' ').enumerate() {
+            let i = i as i16;
+            let context = sub_context(self.context, text, start, start + word.len());
+            start += word.len();
+            let width = Self::run_width(font, word, context);
+            let gap = if word.ends_with(' ') {
+                extra_width * (i + 1) / gaps - extra_width * i / gaps
+            } else {
+                0
+            };
+            if rtl {
+                edge -= width;
+                Self::render(Point::new(edge, cursor.y), layout, word, context);
+                edge -= gap;
+            } else {
+                Self::render(Point::new(edge, cursor.y), layout, word, context);
+                edge += width + gap;
+            }
+        }
+    }
+
+    fn text(&mut self, cursor: Point, layout: &TextLayout, text: &str) {
+        Self::render(cursor, layout, text, self.context);
+    }
+
     fn hyphen(&mut self, cursor: Point, layout: &TextLayout) {
+        let mut buf = [0; 4];
         display::text(
             cursor,
-            "-",
+            layout.style.hyphen.encode_utf8(&mut buf),
             layout.style.text_font,
             layout.style.hyphen_color,
             layout.style.background_color,
@@ -337,20 +848,46 @@ impl LayoutSink for TextRenderer {
     fn ellipsis(&mut self, cursor: Point, layout: &TextLayout) {
         display::text(
             cursor,
-            "...",
+            layout.style.ellipsis,
             layout.style.text_font,
             layout.style.ellipsis_color,
             layout.style.background_color,
         );
     }
+
+    fn icon(&mut self, cursor: Point, layout: &TextLayout, icon: Icon) {
+        icon.draw(
+            cursor,
+            BOTTOM_LEFT,
+            layout.style.text_color,
+            layout.style.background_color,
+        );
+    }
 }
 
 #[cfg(feature = "ui_debug")]
 pub mod trace {
+    use core::fmt::Write;
+
+    use heapless::String;
+
     use crate::ui::geometry::Point;
 
     use super::*;
 
+    /// Emitted at the end of a traced page if the content continues on the
+    /// next one.
+    pub const PAGE_BREAK: &str = "--- page break ---";
+
+    /// Emit a line identifying the traced page of paginated content, e.g.
+    /// `--- page 2/3 ---`. Page numbers are one-based.
+    pub fn page_marker(t: &mut dyn crate::trace::Tracer, index: usize, count: usize) {
+        let mut marker: String<32> = String::new();
+        unwrap!(write!(marker, "--- page {}/{} ---", index + 1, count));
+        t.string(&marker);
+        t.string("\n");
+    }
+
     pub struct TraceSink<'a>(pub &'a mut dyn crate::trace::Tracer);
 
     impl<'a> LayoutSink for TraceSink<'a> {
@@ -358,12 +895,17 @@ pub mod trace {
             self.0.string(text);
         }
 
-        fn hyphen(&mut self, _cursor: Point, _layout: &TextLayout) {
-            self.0.string("-");
+        fn hyphen(&mut self, _cursor: Point, layout: &TextLayout) {
+            let mut buf = [0; 4];
+            self.0.string(layout.style.hyphen.encode_utf8(&mut buf));
+        }
+
+        fn ellipsis(&mut self, _cursor: Point, layout: &TextLayout) {
+            self.0.string(layout.style.ellipsis);
         }
 
-        fn ellipsis(&mut self, _cursor: Point, _layout: &TextLayout) {
-            self.0.string("...");
+        fn icon(&mut self, _cursor: Point, _layout: &TextLayout, _icon: Icon) {
+            self.0.symbol("icon");
         }
 
         fn line_break(&mut self, _cursor: Point) {
@@ -380,6 +922,8 @@ pub enum Op<'a> {
     Color(Color),
     /// Set currently used font.
     Font(Font),
+    /// Render an icon inline with the text, using the current text color.
+    Icon(Icon),
 }
 
 impl<'a> Op<'a> {
@@ -390,6 +934,8 @@ impl<'a> Op<'a> {
         let mut skipped = 0;
 
         ops.filter_map(move |op| match op {
+            // Icons preceding the skipped text were already rendered.
+            Op::Icon(_) if skipped < skip_bytes => None,
             Op::Text(text) if skipped < skip_bytes => {
                 skipped = skipped.saturating_add(text.len());
                 if skipped > skip_bytes {
@@ -419,17 +965,35 @@ struct Span {
     insert_hyphen_before_line_break: bool,
 }
 
+/// Baseline of the line at `baseline` once the font changes from `from` to
+/// `to`, keeping the text of both fonts on a common baseline. A line with
+/// nothing on it yet is moved down to make room for the taller glyphs of `to`,
+/// text already on the line stays where it is.
+fn realign_baseline(
+    baseline: i16,
+    line_is_empty: bool,
+    from: impl GlyphMetrics,
+    to: impl GlyphMetrics,
+) -> i16 {
+    if line_is_empty {
+        baseline + (to.ascent() - from.ascent()).max(0)
+    } else {
+        baseline
+    }
+}
+
 impl Span {
     fn fit_horizontally(
         text: &str,
+        context: ShapingContext,
         max_width: i16,
         text_font: impl GlyphMetrics,
         breaking: LineBreaking,
+        hyphen: char,
     ) -> Self {
         const ASCII_LF: char = '\n';
         const ASCII_CR: char = '\r';
         const ASCII_SPACE: char = ' ';
-        const ASCII_HYPHEN: char = '-';
 
         fn is_whitespace(ch: char) -> bool {
             ch == ASCII_SPACE || ch == ASCII_LF || ch == ASCII_CR
@@ -437,7 +1001,7 @@ impl Span {
 
         let use_hyphens = !matches!(breaking, LineBreaking::BreakWordsNoHyphen);
         let hyphen_width = if use_hyphens {
-            text_font.char_width(ASCII_HYPHEN)
+            text_font.char_width(hyphen)
         } else {
             0
         };
@@ -454,9 +1018,10 @@ impl Span {
         };
 
         let mut span_width = 0;
-        let mut found_any_whitespace = false;
+        let mut found_any_break = false;
 
-        let mut char_indices_iter = text.char_indices().peekable();
+        // Measure the shaped glyphs, offsets still refer to the source text.
+        let mut char_indices_iter = clusters(text, context).peekable();
         // Iterating manually because we need a reference to the iterator inside the
         // loop.
         while let Some((i, ch)) = char_indices_iter.next() {
@@ -478,27 +1043,42 @@ impl Span {
                     // End of line, break immediately.
                     return line;
                 }
-                found_any_whitespace = true;
-            } else if span_width + char_width > max_width {
+                found_any_break = true;
+            } else if span_width.saturating_add(char_width) > max_width {
                 // Return the last breakpoint.
                 return line;
             } else {
-                let have_space_for_break = span_width + char_width + hyphen_width <= max_width;
-                let can_break_word =
-                    !matches!(breaking, LineBreaking::BreakAtWhitespace) || !found_any_whitespace;
-                if have_space_for_break && can_break_word {
-                    // Break after this character, append hyphen.
-                    line.length = match char_indices_iter.peek() {
-                        Some((idx, _)) => *idx,
-                        None => text.len(),
-                    };
+                let next = char_indices_iter.peek().copied();
+                let (next_index, next_char) = next.unwrap_or((text.len(), ASCII_SPACE));
+                if !is_whitespace(next_char) && is_break_opportunity(ch, next_char) {
+                    // Break after this character, e.g. a hyphen, without hyphen.
+                    line.length = next_index;
                     line.advance.x = span_width + char_width;
-                    line.insert_hyphen_before_line_break = use_hyphens;
+                    line.insert_hyphen_before_line_break = false;
                     line.skip_next_chars = 0;
+                    found_any_break = true;
+                } else {
+                    let have_space_for_break = span_width
+                        .saturating_add(char_width)
+                        .saturating_add(hyphen_width)
+                        <= max_width;
+                    let can_break_word =
+                        !matches!(breaking, LineBreaking::BreakAtWhitespace) || !found_any_break;
+                    // Never separate combining marks from their base character.
+                    let at_boundary = next.is_none() || grapheme::is_boundary(ch, next_char);
+                    if have_space_for_break && can_break_word && at_boundary {
+                        // Break after this character, append hyphen.
+                        line.length = next_index;
+                        line.advance.x = span_width + char_width;
+                        line.insert_hyphen_before_line_break = use_hyphens;
+                        line.skip_next_chars = 0;
+                    }
                 }
             }
 
-            span_width += char_width;
+            // Whitespace is accepted regardless of the width, do not overflow on
+            // very long runs of it.
+            span_width = span_width.saturating_add(char_width);
         }
 
         // The whole text is fitting.
@@ -513,6 +1093,9 @@ impl Span {
 
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "text_shaping")]
+    use crate::ui::pool::{Block, BLOCK_SIZE};
+
     use super::*;
 
     pub struct Fixed {
@@ -528,6 +1111,14 @@ mod tests {
         fn line_height(&self) -> i16 {
             self.height
         }
+
+        fn ascent(&self) -> i16 {
+            self.height
+        }
+
+        fn descent(&self) -> i16 {
+            0
+        }
     }
 
     const FIXED_FONT: Fixed = Fixed {
@@ -535,6 +1126,169 @@ mod tests {
         height: 1,
     };
 
+    fn style() -> TextStyle {
+        let color = Color::rgb(0, 0, 0);
+        TextStyle::new(Font::NORMAL, color, color, color, color)
+    }
+
+    /// Text laid out by `Recorder`, `<icon>` for icons.
+    struct Placed {
+        text: String,
+        cursor: Point,
+        /// Width added to the spaces of a justified line.
+        extra_width: i16,
+    }
+
+    /// Records everything laid out, in order.
+    struct Recorder(Vec<Placed>);
+
+    impl Recorder {
+        fn layout_text(layout: &TextLayout, text: &str) -> Self {
+            let mut recorder = Self(Vec::new());
+            layout.layout_text(text, &mut layout.initial_cursor(), &mut recorder);
+            recorder
+        }
+
+        fn layout_ops(layout: &TextLayout, ops: &[Op]) -> Self {
+            let mut recorder = Self(Vec::new());
+            layout.layout_ops(
+                &mut ops.iter().copied(),
+                &mut layout.initial_cursor(),
+                &mut recorder,
+            );
+            recorder
+        }
+
+        fn push(&mut self, text: &str, cursor: Point, extra_width: i16) {
+            self.0.push(Placed {
+                text: text.into(),
+                cursor,
+                extra_width,
+            });
+        }
+
+        fn extra_widths(&self) -> Vec<(&str, i16)> {
+            self.0
+                .iter()
+                .map(|p| (p.text.as_str(), p.extra_width))
+                .collect()
+        }
+
+        fn columns(&self) -> Vec<(&str, i16)> {
+            self.0
+                .iter()
+                .map(|p| (p.text.as_str(), p.cursor.x))
+                .collect()
+        }
+
+        fn positions(&self) -> Vec<(&str, Point)> {
+            self.0.iter().map(|p| (p.text.as_str(), p.cursor)).collect()
+        }
+
+        fn texts(&self) -> Vec<&str> {
+            self.0.iter().map(|p| p.text.as_str()).collect()
+        }
+    }
+
+    impl LayoutSink for Recorder {
+        fn text(&mut self, cursor: Point, _layout: &TextLayout, text: &str) {
+            self.push(text, cursor, 0);
+        }
+
+        fn justified_text(
+            &mut self,
+            cursor: Point,
+            _layout: &TextLayout,
+            text: &str,
+            extra_width: i16,
+        ) {
+            self.push(text, cursor, extra_width);
+        }
+
+        fn ellipsis(&mut self, cursor: Point, layout: &TextLayout) {
+            self.push(layout.style.ellipsis, cursor, 0);
+        }
+
+        fn icon(&mut self, cursor: Point, _layout: &TextLayout, _icon: Icon) {
+            self.push("<icon>", cursor, 0);
+        }
+    }
+
+    #[test]
+    fn test_realign_baseline() {
+        let font = |height| Fixed { width: 1, height };
+        // Empty line makes room for the taller font.
+        assert_eq!(realign_baseline(20, true, font(8), font(12)), 24);
+        // Text on the line keeps the common baseline.
+        assert_eq!(realign_baseline(20, false, font(8), font(12)), 20);
+        // Smaller font fits under the baseline of the line.
+        assert_eq!(realign_baseline(20, true, font(12), font(8)), 20);
+    }
+
+    #[test]
+    fn test_span_hyphen_width() {
+        /// Fixed width font with a wide `=`.
+        struct WideEquals;
+
+        impl GlyphMetrics for WideEquals {
+            fn char_width(&self, ch: char) -> i16 {
+                if ch == '=' {
+                    3
+                } else {
+                    1
+                }
+            }
+
+            fn line_height(&self) -> i16 {
+                1
+            }
+
+            fn ascent(&self) -> i16 {
+                1
+            }
+
+            fn descent(&self) -> i16 {
+                0
+            }
+        }
+
+        let fit = |breaking, hyphen| {
+            let span = Span::fit_horizontally(
+                "abcdefgh",
+                ShapingContext::default(),
+                5,
+                WideEquals,
+                breaking,
+                hyphen,
+            );
+            (span.length, span.insert_hyphen_before_line_break)
+        };
+        assert_eq!(fit(LineBreaking::BreakWordsAndInsertHyphen, '-'), (4, true));
+        // room is kept for the whole width of the hyphen
+        assert_eq!(fit(LineBreaking::BreakWordsAndInsertHyphen, '='), (2, true));
+        // no room is needed when the words are broken without hyphens
+        assert_eq!(fit(LineBreaking::BreakWordsNoHyphen, '='), (5, false));
+    }
+
+    #[test]
+    fn test_span_wide_whitespace() {
+        let wide = Fixed {
+            width: i16::MAX / 2 + 1,
+            height: 1,
+        };
+        let span = Span::fit_horizontally(
+            "   ab",
+            ShapingContext::default(),
+            5,
+            wide,
+            LineBreaking::BreakAtWhitespace,
+            '-',
+        );
+        // The width of the whitespace saturates instead of overflowing.
+        assert_eq!(span.length, 2);
+        assert_eq!(span.advance.x, i16::MAX);
+    }
+
     #[test]
     fn test_span() {
         assert_eq!(spans_from("hello", 5), vec![("hello", false)]);
@@ -574,6 +1328,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_break_opportunities() {
+        assert_eq!(
+            spans_from("well-known", 6),
+            vec![("well-", false), ("known", false)]
+        );
+        assert_eq!(
+            spans_from("trezor.io/start", 10),
+            vec![("trezor.io/", false), ("start", false)]
+        );
+        assert_eq!(
+            spans_from("\u{4E2D}\u{6587}\u{5B57}", 2),
+            vec![("\u{4E2D}\u{6587}", false), ("\u{5B57}", false)]
+        );
+        // combining marks stay with their base when breaking words
+        assert_eq!(
+            spans_from("e\u{301}e\u{301}e\u{301}", 3),
+            vec![("e\u{301}", true), ("e\u{301}", true), ("e\u{301}", false)]
+        );
+    }
+
     #[test]
     fn test_char_boundary() {
         assert_eq!(
@@ -582,15 +1357,258 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_rtl_mirroring() {
+        let style = style();
+        let bounds = Rect::new(Point::new(10, 0), Point::new(110, 50));
+        let layout = TextLayout::new(style).with_bounds(bounds);
+        assert_eq!(layout.visual(Point::new(40, 20), 30), Point::new(40, 20));
+
+        let layout =
+            TextLayout::new(style.with_direction(TextDirection::RightToLeft)).with_bounds(bounds);
+        // line start is at the right edge
+        assert_eq!(layout.visual(Point::new(10, 20), 30), Point::new(80, 20));
+        // hyphen after a span ending at 60 is drawn to the left of it
+        assert_eq!(layout.visual(Point::new(60, 20), 5), Point::new(55, 20));
+    }
+
+    #[test]
+    fn test_justified() {
+        let font = Font::NORMAL;
+        let width = "aaa aaa".chars().map(|ch| font.char_width(ch)).sum::<i16>() + 3;
+        let layout = TextLayout::new(style())
+            .with_bounds(Rect::new(Point::zero(), Point::new(width, 200)))
+            .with_align(Alignment::Justified);
+
+        let recorder = Recorder::layout_text(&layout, "aaa aaa aaa aaa\naaa aaa");
+        // neither the line before a hard break nor the last one is stretched
+        assert_eq!(
+            recorder.extra_widths(),
+            [("aaa aaa", 3), ("aaa aaa", 0), ("aaa aaa", 0)]
+        );
+    }
+
+    #[test]
+    fn test_spacing() {
+        let style = style();
+        let plain = TextLayout::new(style);
+        let spaced = TextLayout::new(style.with_spacing(3, 5));
+        let height = |layout: &TextLayout, text| layout.measure_height(text, 200);
+
+        assert_eq!(height(&spaced, "aaa"), height(&plain, "aaa"));
+        // line break within a paragraph
+        assert_eq!(height(&spaced, "aaa\raaa"), height(&plain, "aaa\raaa") + 3);
+        // paragraph break
+        assert_eq!(height(&spaced, "aaa\naaa"), height(&plain, "aaa\naaa") + 8);
+    }
+
+    #[test]
+    fn test_fit_with_ellipsis() {
+        let style = style();
+        let font = style.text_font;
+        let layout = |width| {
+            TextLayout::new(style).with_bounds(Rect::new(Point::zero(), Point::new(width, 100)))
+        };
+        let text = "Send to my savings";
+
+        assert_eq!(layout(font.text_width(text)).fit_with_ellipsis(text), text);
+        // cut in the middle of a word, ending at the previous one
+        let width = font.text_width("Send to my s") + font.text_width(style.ellipsis);
+        assert_eq!(layout(width).fit_with_ellipsis(text), "Send to my");
+        // cut right after a word
+        let width = font.text_width("Send to my ") + font.text_width(style.ellipsis);
+        assert_eq!(layout(width).fit_with_ellipsis(text), "Send to my");
+        // single word is broken
+        let width = font.text_width("Se") + font.text_width(style.ellipsis);
+        assert_eq!(layout(width).fit_with_ellipsis("Send"), "Se");
+
+        let address = "bc1qxy2kgdygjrsqtzq2n0yrf2493p83kkfjhx0wlh";
+        assert_eq!(
+            layout(font.text_width(address)).fit_middle_with_ellipsis(address),
+            (address, "")
+        );
+        let width = 100;
+        let (start, end) = layout(width).fit_middle_with_ellipsis(address);
+        assert!(address.starts_with(start) && address.ends_with(end));
+        assert!(!start.is_empty() && !end.is_empty());
+        assert!(
+            font.text_width(start) + font.text_width(style.ellipsis) + font.text_width(end)
+                <= width
+        );
+    }
+
+    #[test]
+    fn test_indent() {
+        let style = style();
+        let width = style.text_font.text_width("aaa aaa") + 10;
+        let record = |layout: TextLayout, text| {
+            let layout = layout.with_bounds(Rect::new(Point::zero(), Point::new(width, 200)));
+            Recorder::layout_text(&layout, text)
+        };
+
+        assert_eq!(
+            record(TextLayout::new(style), "aaa aaa").columns(),
+            [("aaa aaa", 0)]
+        );
+        // the indented first line is shorter
+        assert_eq!(
+            record(TextLayout::new(style.with_indent(20, 0)), "aaa aaa").columns(),
+            [("aaa", 20), ("aaa", 0)]
+        );
+        // wrapped lines and lines after a break are indented
+        let hanging = style.with_indent(0, 20);
+        assert_eq!(
+            record(TextLayout::new(hanging), "aaa aaa aaa\naaa").columns(),
+            [("aaa aaa", 0), ("aaa", 20), ("aaa", 20)]
+        );
+        // text continued from the previous page starts like a wrapped line
+        assert_eq!(
+            record(TextLayout::new(hanging).continued(), "aaa").columns(),
+            [("aaa", 20)]
+        );
+    }
+
+    #[test]
+    fn test_inline_icon() {
+        let style = style();
+        let font = style.text_font;
+        let icon = Icon::new(include_res!("model_tt/res/warn-icon.toif"));
+        let icon_width = icon.toif.width();
+        let layout = |width| {
+            TextLayout::new(style).with_bounds(Rect::new(Point::zero(), Point::new(width, 200)))
+        };
+        let first_line = TextLayout::new(style).initial_cursor().y;
+
+        // the text continues after the width of the icon
+        let layout_after = layout(icon_width + font.text_width("aaa"));
+        let recorder = Recorder::layout_ops(&layout_after, &[Op::Icon(icon), Op::Text("aaa")]);
+        assert_eq!(
+            recorder.positions(),
+            [
+                ("<icon>", Point::new(0, first_line)),
+                ("aaa", Point::new(icon_width, first_line))
+            ]
+        );
+        // icon which does not fit wraps to the next line
+        let layout_wrapped = layout(font.text_width("aaa aaa") + icon_width / 2);
+        let recorder =
+            Recorder::layout_ops(&layout_wrapped, &[Op::Text("aaa aaa"), Op::Icon(icon)]);
+        assert_eq!(
+            recorder.positions(),
+            [
+                ("aaa aaa", Point::new(0, first_line)),
+                ("<icon>", Point::new(0, first_line + font.line_height()))
+            ]
+        );
+
+        // icons before the skipped text are not repeated on the next page
+        let ops = [Op::Text("aaa"), Op::Icon(icon), Op::Text("bbb")];
+        let kinds = |skip| {
+            Op::skip_n_text_bytes(ops.iter().copied(), skip)
+                .map(|op| match op {
+                    Op::Text(text) => text,
+                    Op::Icon(_) => "<icon>",
+                    _ => "",
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(kinds(3), ["<icon>", "bbb"]);
+        assert_eq!(kinds(4), ["bb"]);
+    }
+
+    #[test]
+    fn test_custom_ellipsis() {
+        let style = style().with_ellipsis("..");
+        let font = style.text_font;
+
+        // room for a single line only
+        let width = font.text_width("aaa aaa") + 1;
+        let layout = TextLayout::new(style).with_bounds(Rect::new(
+            Point::zero(),
+            Point::new(width, font.text_height()),
+        ));
+        let recorder = Recorder::layout_text(&layout, "aaa aaa aaa");
+        assert_eq!(recorder.texts(), ["aaa aaa", ".."]);
+
+        // the shorter ellipsis leaves room for one more word
+        let text = "Send to my savings";
+        let width = font.text_width("Send to my") + font.text_width("..");
+        let fit = |style| {
+            TextLayout::new(style)
+                .with_bounds(Rect::new(Point::zero(), Point::new(width, 100)))
+                .fit_with_ellipsis(text)
+        };
+        assert_eq!(fit(style), "Send to my");
+        assert_eq!(fit(style.with_ellipsis("...")), "Send to");
+    }
+
+    #[cfg(feature = "text_shaping")]
+    #[test]
+    fn test_shaping_context() {
+        /// Records the text of each line with the context it was reported with.
+        struct Contexts(Vec<(String, ShapingContext)>, ShapingContext);
+
+        impl LayoutSink for Contexts {
+            fn shaping_context(&mut self, context: ShapingContext) {
+                self.1 = context;
+            }
+
+            fn text(&mut self, _cursor: Point, _layout: &TextLayout, text: &str) {
+                self.0.push((text.into(), self.1));
+            }
+        }
+
+        let font = Font::NORMAL;
+        let style = style().with_line_breaking(LineBreaking::BreakWordsNoHyphen);
+        let layout = TextLayout::new(style).with_bounds(Rect::new(
+            Point::zero(),
+            Point::new(font.text_width("abc"), 200),
+        ));
+
+        let mut contexts = Contexts(Vec::new(), ShapingContext::default());
+        layout.layout_text("abcdef", &mut layout.initial_cursor(), &mut contexts);
+        // the word broken in the middle is shaped as a whole
+        let context = |before, after| shaping::Context { before, after };
+        assert_eq!(
+            contexts.0,
+            [
+                ("abc".into(), context(None, Some('d'))),
+                ("def".into(), context(Some('c'), None)),
+            ]
+        );
+    }
+
+    #[cfg(feature = "text_shaping")]
+    #[test]
+    fn test_shaping_fallback() {
+        let text = "\u{0628}\u{0628}\u{0628}";
+        let context = ShapingContext::default();
+        let glyphs: String = TextRenderer::glyphs(text, context).collect();
+        assert_eq!(glyphs, "\u{FE91}\u{FE92}\u{FE90}");
+        let shaped = unwrap!(TextRenderer::shape_into_block(text, context));
+        assert_eq!(&*shaped, glyphs);
+        drop(shaped);
+
+        // The glyphs are rendered one by one if the pool is exhausted or they
+        // do not fit into a block.
+        let blocks: Vec<_> = core::iter::from_fn(Block::alloc).collect();
+        assert!(TextRenderer::shape_into_block(text, context).is_none());
+        drop(blocks);
+        let long = "\u{0628}".repeat(BLOCK_SIZE);
+        assert!(TextRenderer::shape_into_block(&long, context).is_none());
+    }
+
     fn spans_from(text: &str, max_width: i16) -> Vec<(&str, bool)> {
         let mut spans = vec![];
         let mut remaining_text = text;
         loop {
             let span = Span::fit_horizontally(
                 remaining_text,
+                ShapingContext::default(),
                 max_width,
                 FIXED_FONT,
                 LineBreaking::BreakAtWhitespace,
+                '-',
             );
             spans.push((
                 &remaining_text[..span.length],
@@ -0,0 +1,95 @@
+//! Line break opportunities other than whitespace, loosely following the
+//! Unicode line breaking algorithm (UAX #14): after hyphens and slashes, so
+//! that compound words and URLs wrap, and between ideographs, which are not
+//! separated by spaces in Chinese and Japanese. Lines are never broken inside
+//! a grapheme cluster.
+
+use crate::ui::grapheme;
+
+/// Whether a line can be broken between characters `prev` and `next`, none of
+/// which is whitespace.
+pub fn is_break_opportunity(prev: char, next: char) -> bool {
+    if !grapheme::is_boundary(prev, next) || is_no_break_before(next) || is_no_break_after(prev) {
+        return false;
+    }
+    match prev {
+        // Keep negative numbers and ranges like "-5" together.
+        '-' | '\u{2010}' | '\u{2013}' => !next.is_ascii_digit(),
+        // Keep the double slash of URLs together.
+        '/' => next != '/',
+        _ => is_ideographic(prev) || is_ideographic(next),
+    }
+}
+
+/// Characters written without spaces between them, each of which can start a
+/// new line.
+fn is_ideographic(ch: char) -> bool {
+    matches!(ch,
+        '\u{3040}'..='\u{309F}' // hiragana
+        | '\u{30A0}'..='\u{30FF}' // katakana
+        | '\u{3400}'..='\u{4DBF}' // CJK unified ideographs extension A
+        | '\u{4E00}'..='\u{9FFF}' // CJK unified ideographs
+        | '\u{AC00}'..='\u{D7AF}' // hangul syllables
+        | '\u{F900}'..='\u{FAFF}' // CJK compatibility ideographs
+    )
+}
+
+/// Closing punctuation and marks which must not start a line.
+fn is_no_break_before(ch: char) -> bool {
+    matches!(
+        ch,
+        ',' | '.'
+            | ':'
+            | ';'
+            | '!'
+            | '?'
+            | ')'
+            | ']'
+            | '}'
+            | '\u{3001}' // ideographic comma
+            | '\u{3002}' // ideographic full stop
+            | '\u{300D}' // right corner bracket
+            | '\u{300F}' // right white corner bracket
+            | '\u{3011}' // right black lenticular bracket
+            | '\u{30FC}' // katakana prolonged sound mark
+            | '\u{FF01}' // fullwidth exclamation mark
+            | '\u{FF09}' // fullwidth right parenthesis
+            | '\u{FF0C}' // fullwidth comma
+            | '\u{FF1A}' // fullwidth colon
+            | '\u{FF1F}' // fullwidth question mark
+    )
+}
+
+/// Opening punctuation which must not end a line.
+fn is_no_break_after(ch: char) -> bool {
+    matches!(
+        ch,
+        '(' | '['
+            | '{'
+            | '\u{300C}' // left corner bracket
+            | '\u{300E}' // left white corner bracket
+            | '\u{3010}' // left black lenticular bracket
+            | '\u{FF08}' // fullwidth left parenthesis
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn break_opportunities() {
+        assert!(is_break_opportunity('-', 'b'));
+        assert!(!is_break_opportunity('-', '5'));
+        assert!(is_break_opportunity('/', 'w'));
+        assert!(!is_break_opportunity('/', '/'));
+        assert!(!is_break_opportunity('a', 'b'));
+        // ideographs
+        assert!(is_break_opportunity('\u{4E2D}', '\u{6587}'));
+        assert!(is_break_opportunity('a', '\u{6587}'));
+        assert!(!is_break_opportunity('\u{4E2D}', '\u{3002}'));
+        assert!(!is_break_opportunity('\u{300C}', '\u{4E2D}'));
+        // combining mark stays with its base
+        assert!(!is_break_opportunity('-', '\u{301}'));
+    }
+}
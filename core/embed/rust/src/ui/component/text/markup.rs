@@ -0,0 +1,113 @@
+//! Compact markup for describing a list of paragraphs in a single string, so
+//! that callers over FFI do not need the paragraph structure of each screen
+//! hard-coded on the Rust side.
+//!
+//! - Every line is a paragraph, empty lines are skipped.
+//! - Text between `##` marks is bold. Every bold or normal run of a line
+//!   becomes a separate paragraph, e.g. `##Warning## Seed will be erased.`
+//!   results in two paragraphs.
+//! - Line consisting only of `---` starts a new page.
+
+use heapless::Vec;
+
+use super::{
+    paragraphs::{Paragraph, ParagraphStrType},
+    TextStyle,
+};
+
+const BOLD_MARK: &str = "##";
+const PAGE_BREAK: &str = "---";
+
+#[derive(Copy, Clone)]
+pub struct MarkupStyles {
+    pub normal: &'static TextStyle,
+    pub bold: &'static TextStyle,
+}
+
+/// Parse `text` into paragraphs with `styles`. Paragraphs over the capacity
+/// are dropped.
+pub fn parse<T, const N: usize>(text: &T, styles: MarkupStyles) -> Vec<Paragraph<T>, N>
+where
+    T: ParagraphStrType,
+{
+    let mut paragraphs = Vec::new();
+    // Paragraph is pushed once we know whether a page break follows it.
+    let mut pending: Option<Paragraph<T>> = None;
+    let mut line_start = 0;
+    for line in text.as_ref().split('\n') {
+        if line.trim() == PAGE_BREAK {
+            pending = pending.map(Paragraph::break_after);
+        } else {
+            let mut run_start = line_start;
+            for (i, run) in line.split(BOLD_MARK).enumerate() {
+                let trimmed = run.trim();
+                if !trimmed.is_empty() {
+                    let start = run_start + (run.len() - run.trim_start().len());
+                    let content = text.skip_prefix(start).take_prefix(trimmed.len());
+                    let style = if i % 2 == 1 {
+                        styles.bold
+                    } else {
+                        styles.normal
+                    };
+                    if let Some(paragraph) = pending.replace(Paragraph::new(style, content)) {
+                        if paragraphs.push(paragraph).is_err() {
+                            return paragraphs;
+                        }
+                    }
+                }
+                run_start += run.len() + BOLD_MARK.len();
+            }
+        }
+        line_start += line.len() + 1;
+    }
+    if let Some(paragraph) = pending {
+        // Dropped if the capacity is exceeded, same as the ones before.
+        paragraphs.push(paragraph).ok();
+    }
+    paragraphs
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::display::{Color, Font};
+
+    use super::*;
+
+    const COLOR: Color = Color::rgb(0, 0, 0);
+    static NORMAL: TextStyle = TextStyle::new(Font::NORMAL, COLOR, COLOR, COLOR, COLOR);
+    static BOLD: TextStyle = TextStyle::new(Font::BOLD, COLOR, COLOR, COLOR, COLOR);
+
+    fn parsed(text: &'static str) -> Vec<(&'static str, bool), 8> {
+        let styles = MarkupStyles {
+            normal: &NORMAL,
+            bold: &BOLD,
+        };
+        parse::<_, 8>(&text, styles)
+            .iter()
+            .map(|p| (*p.content(), core::ptr::eq(p.style(), &BOLD)))
+            .collect()
+    }
+
+    #[test]
+    fn markup_parse() {
+        assert!(parsed("").is_empty());
+        assert_eq!(parsed("Hello"), [("Hello", false)]);
+        assert_eq!(
+            parsed("##Warning## Seed will be erased.\n\nContinue?"),
+            [
+                ("Warning", true),
+                ("Seed will be erased.", false),
+                ("Continue?", false)
+            ]
+        );
+        assert_eq!(
+            parsed("First ##bold##\n---\n##Second page##"),
+            [("First", false), ("bold", true), ("Second page", true)]
+        );
+        // unterminated bold run continues until the end of the line
+        assert_eq!(
+            parsed("a ##b\nc"),
+            [("a", false), ("b", true), ("c", false)]
+        );
+    }
+}
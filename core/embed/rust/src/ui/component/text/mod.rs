@@ -1,6 +1,15 @@
+pub mod bidi;
+mod escape;
 pub mod formatted;
 mod iter;
 pub mod layout;
+mod linebreak;
+pub mod markup;
 pub mod paragraphs;
+pub mod props;
+#[cfg(feature = "text_shaping")]
+pub mod shaping;
+pub mod tokens;
 
 pub use layout::{LineBreaking, PageBreaking, TextStyle};
+pub use tokens::StyleToken;
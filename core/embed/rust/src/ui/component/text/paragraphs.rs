@@ -1,8 +1,10 @@
+use core::mem;
+
 use heapless::Vec;
 
 use crate::ui::{
-    component::{Component, Event, EventCtx, Never, Paginate},
-    display::toif::Icon,
+    component::{paginated::PageBreaks, Component, Event, EventCtx, Never, Paginate},
+    display::{self, toif::Icon, Color},
     geometry::{Alignment, Insets, LinearPlacement, Offset, Point, Rect, TOP_LEFT},
 };
 
@@ -21,19 +23,21 @@ pub const PARAGRAPH_TOP_SPACE: i16 = -1;
 /// Offset of paragraph bounding box bottom relative to bottom of its text.
 pub const PARAGRAPH_BOTTOM_SPACE: i16 = 5;
 
-pub type ParagraphVecLong<T> = Vec<Paragraph<T>, 32>;
-pub type ParagraphVecShort<T> = Vec<Paragraph<T>, 8>;
+/// List of paragraphs with capacity for `N` of them, chosen by each screen to
+/// match its content.
+pub type ParagraphVec<T, const N: usize> = Vec<Paragraph<T>, N>;
 
 /// Trait for internal representation of strings, which need to support
 /// converting to short-lived &str reference as well as creating a new string by
-/// skipping some number of bytes. Exists so that we can support `StrBuffer` as
-/// well as `&'static str`.
+/// skipping or taking some number of bytes. Exists so that we can support
+/// `StrBuffer` as well as `&'static str`.
 ///
 /// NOTE: do not implement this trait for `&'static str` in firmware. We always
 /// use StrBuffer because using multiple internal representations results in
 /// multiple copies of the code in flash memory.
 pub trait ParagraphStrType: AsRef<str> {
     fn skip_prefix(&self, bytes: usize) -> Self;
+    fn take_prefix(&self, bytes: usize) -> Self;
 }
 
 pub trait ParagraphSource {
@@ -55,12 +59,76 @@ pub trait ParagraphSource {
     }
 }
 
+impl<T: ParagraphStrType, const N: usize> ParagraphSource for Vec<Paragraph<T>, N> {
+    type StrType = T;
+
+    fn at(&self, index: usize, offset: usize) -> Paragraph<Self::StrType> {
+        let para = &self[index];
+        para.map(|content| content.skip_prefix(offset))
+    }
+
+    fn size(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T: ParagraphStrType, const N: usize> ParagraphSource for [Paragraph<T>; N] {
+    type StrType = T;
+
+    fn at(&self, index: usize, offset: usize) -> Paragraph<Self::StrType> {
+        let para = &self[index];
+        para.map(|content| content.skip_prefix(offset))
+    }
+
+    fn size(&self) -> usize {
+        self.len()
+    }
+}
+
+/// Paragraph sources whose paragraphs can be replaced in place, see
+/// `Paragraphs::update`.
+pub trait ParagraphSourceMut: ParagraphSource {
+    fn at_mut(&mut self, index: usize) -> &mut Paragraph<Self::StrType>;
+}
+
+impl<T: ParagraphStrType, const N: usize> ParagraphSourceMut for Vec<Paragraph<T>, N> {
+    fn at_mut(&mut self, index: usize) -> &mut Paragraph<T> {
+        &mut self[index]
+    }
+}
+
+impl<T: ParagraphStrType, const N: usize> ParagraphSourceMut for [Paragraph<T>; N] {
+    fn at_mut(&mut self, index: usize) -> &mut Paragraph<T> {
+        &mut self[index]
+    }
+}
+
+impl<T: ParagraphStrType> ParagraphSource for Paragraph<T> {
+    type StrType = T;
+
+    fn at(&self, index: usize, offset: usize) -> Paragraph<Self::StrType> {
+        assert_eq!(index, 0);
+        self.map(|content| content.skip_prefix(offset))
+    }
+
+    fn size(&self) -> usize {
+        1
+    }
+}
+
 pub struct Paragraphs<T> {
     area: Rect,
     placement: LinearPlacement,
     offset: PageOffset,
     visible: Vec<TextLayout, MAX_LINES>,
     source: T,
+    /// Bit mask of the paragraphs updated since the last paint, their old
+    /// content is cleared before painting.
+    updated: u32,
+    /// Background to clear all of the area with before painting, after an
+    /// update has moved the paragraphs.
+    clear: Option<Color>,
+    pages: PageBreaks<PageOffset>,
 }
 
 impl<T> Paragraphs<T>
@@ -76,6 +144,9 @@ where
             offset: PageOffset::default(),
             visible: Vec::new(),
             source,
+            updated: 0,
+            clear: None,
+            pages: PageBreaks::new(),
         }
     }
 
@@ -94,6 +165,7 @@ where
     }
 
     pub fn inner_mut(&mut self) -> &mut T {
+        self.pages.invalidate();
         &mut self.source
     }
 
@@ -133,6 +205,44 @@ where
         }
     }
 
+    /// Layout of the `index`-th paragraph if it is on the current page.
+    fn visible_layout(&self, index: usize) -> Option<&TextLayout> {
+        let mut visible = self.visible.iter();
+        let mut chr = self.offset.chr;
+        for par in self.offset.par..=index.min(self.source.size().saturating_sub(1)) {
+            // Same as in `foreach_visible`, empty paragraphs have no layout.
+            let empty = self.source.at(par, chr).content.as_ref().is_empty();
+            chr = 0;
+            if empty {
+                continue;
+            }
+            let layout = visible.next()?;
+            if par == index {
+                return Some(layout);
+            }
+        }
+        None
+    }
+
+    /// Index of the page currently shown, e.g. to return to it later.
+    pub fn current_page(&mut self) -> usize {
+        let offset = self.offset;
+        match self.page_offsets() {
+            Some(offsets) => offsets.iter().position(|o| *o == offset),
+            None => self.break_pages().position(|o| o == offset),
+        }
+        .unwrap_or(0)
+    }
+
+    /// Beginnings of the pages, computed and cached if needed. `None` if there
+    /// are too many pages to cache.
+    fn page_offsets(&mut self) -> Option<&[PageOffset]> {
+        if self.pages.get().is_none() {
+            self.pages = PageBreaks::collect(self.break_pages());
+        }
+        self.pages.get()
+    }
+
     fn break_pages(&self) -> PageBreakIterator<T> {
         PageBreakIterator {
             paragraphs: self,
@@ -167,6 +277,41 @@ where
     }
 }
 
+impl<T> Paragraphs<T>
+where
+    T: ParagraphSourceMut,
+{
+    /// Replace the content of the `index`-th paragraph, e.g. of a live counter
+    /// or a status line. If the layout of the current page stays the same, only
+    /// the area of the paragraph is reported as damaged. A page continuing the
+    /// updated paragraph starts at its beginning, as the old offset may not be
+    /// valid in the new content.
+    pub fn update(&mut self, ctx: &mut EventCtx, index: usize, content: T::StrType) {
+        self.source.at_mut(index).update(content);
+        self.pages.invalidate();
+        if index == self.offset.par {
+            self.offset.chr = 0;
+        }
+        let bounds_before: Vec<Rect, MAX_LINES> = self.visible.iter().map(|l| l.bounds).collect();
+        self.change_offset(self.offset);
+        let layout_changed = !self
+            .visible
+            .iter()
+            .map(|l| l.bounds)
+            .eq(bounds_before.iter().copied());
+        match self.visible_layout(index) {
+            Some(layout) if !layout_changed && index < u32::BITS as usize => {
+                self.updated |= 1 << index;
+                ctx.request_paint_area(layout.bounds);
+            }
+            _ => {
+                self.clear = Some(self.source.at(index, 0).style.background_color);
+                ctx.request_paint();
+            }
+        }
+    }
+}
+
 impl<T> Component for Paragraphs<T>
 where
     T: ParagraphSource,
@@ -175,6 +320,7 @@ where
 
     fn place(&mut self, bounds: Rect) -> Rect {
         self.area = bounds;
+        self.pages.invalidate();
         self.change_offset(self.offset);
         self.area
     }
@@ -184,6 +330,17 @@ where
     }
 
     fn paint(&mut self) {
+        // The rest of the screen might have been cleared by the parent, so all of
+        // the paragraphs are painted, outside of the damaged area they stay the same.
+        let updated = mem::replace(&mut self.updated, 0);
+        if let Some(color) = self.clear.take() {
+            display::rect_fill(self.area, color);
+        }
+        for index in (0..u32::BITS as usize).filter(|i| updated & (1 << i) != 0) {
+            if let Some(layout) = self.visible_layout(index) {
+                display::rect_fill(layout.bounds, layout.style.background_color);
+            }
+        }
         Self::foreach_visible(
             &self.source,
             &self.visible,
@@ -208,14 +365,23 @@ where
 {
     fn page_count(&mut self) -> usize {
         // There's always at least one page.
-        self.break_pages().count().max(1)
+        match self.page_offsets() {
+            Some(offsets) => offsets.len().max(1),
+            None => self.break_pages().count().max(1),
+        }
     }
 
     fn change_page(&mut self, to_page: usize) {
-        if let Some(offset) = self.break_pages().nth(to_page) {
+        let offset = match self.page_offsets() {
+            Some(offsets) => offsets.get(to_page).copied(),
+            None => self.break_pages().nth(to_page),
+        };
+        if let Some(offset) = offset {
+            ui_log!(Debug, "page {} starts at {:?}", to_page, offset);
             self.change_offset(offset)
         } else {
             // Should not happen, set index to first paragraph and render empty page.
+            ui_log!(Warn, "page {} out of range", to_page);
             self.offset = PageOffset::default();
             self.visible.clear()
         }
@@ -224,13 +390,20 @@ where
 
 #[cfg(feature = "ui_debug")]
 pub mod trace {
-    use crate::ui::component::text::layout::trace::TraceSink;
+    use crate::ui::component::text::layout::trace::{page_marker, TraceSink, PAGE_BREAK};
 
     use super::*;
 
     impl<T: ParagraphSource> crate::trace::Trace for Paragraphs<T> {
         fn trace(&self, t: &mut dyn crate::trace::Tracer) {
             t.open("Paragraphs");
+            // Paginated content is delimited by page markers so that tests can
+            // tell which page is being displayed and where it ends.
+            let page_count = self.break_pages().count().max(1);
+            let page_index = self.break_pages().position(|offset| offset == self.offset);
+            if page_count > 1 {
+                page_marker(t, page_index.unwrap_or(0), page_count);
+            }
             Self::foreach_visible(
                 &self.source,
                 &self.visible,
@@ -240,6 +413,10 @@ pub mod trace {
                     t.string("\n");
                 },
             );
+            if page_index.map_or(false, |index| index + 1 < page_count) {
+                t.string(PAGE_BREAK);
+                t.string("\n");
+            }
             t.close();
         }
     }
@@ -255,8 +432,9 @@ pub struct Paragraph<T> {
     align: Alignment,
     /// Place next paragraph on new page.
     break_after: bool,
-    /// Try to keep this and the next paragraph on the same page. NOTE: doesn't
-    /// work if two or more subsequent paragraphs have this flag.
+    /// Try to keep this and the next paragraph on the same page. Subsequent
+    /// paragraphs with this flag form a group which is kept together as a
+    /// whole.
     no_break: bool,
 }
 
@@ -276,6 +454,11 @@ impl<T> Paragraph<T> {
         self
     }
 
+    pub const fn justified(mut self) -> Self {
+        self.align = Alignment::Justified;
+        self
+    }
+
     pub const fn break_after(mut self) -> Self {
         self.break_after = true;
         self
@@ -290,6 +473,14 @@ impl<T> Paragraph<T> {
         &self.content
     }
 
+    pub fn style(&self) -> &'static TextStyle {
+        self.style
+    }
+
+    pub fn is_no_break(&self) -> bool {
+        self.no_break
+    }
+
     pub fn update(&mut self, content: T) {
         self.content = content
     }
@@ -351,25 +542,32 @@ impl PageOffset {
             return (self, Some(area), None);
         }
 
-        // Handle the `no_break` flag used to keep key-value pair on the same page.
-        if paragraph.no_break && self.chr == 0 {
-            if let Some(next_paragraph) =
-                (self.par + 1 < source.size()).then(|| source.at(self.par + 1, 0))
-            {
-                if Self::should_place_pair_on_next_page(
-                    &paragraph,
-                    &next_paragraph,
-                    area,
-                    full_height,
-                ) {
-                    return (self, None, None);
+        // Handle the `no_break` flag used to keep key-value pairs (or longer groups
+        // of paragraphs) on the same page.
+        if paragraph.no_break
+            && self.chr == 0
+            && self.par + 1 < source.size()
+            && Self::should_place_group_on_next_page(source, self.par, area, full_height)
+        {
+            return (self, None, None);
+        }
+
+        // Find out the dimensions of the paragraph at given char offset.
+        let mut layout = self.layout(&paragraph, area);
+        let mut fit = layout.fit_text(paragraph.content.as_ref());
+
+        // Move a line or the whole paragraph to the next page if the page break
+        // would leave a single line of the paragraph alone.
+        if paragraph.style.widow_orphan_control && matches!(fit, LayoutFit::OutOfBounds { .. }) {
+            match self.avoid_single_line(&paragraph, area, full_height) {
+                Some(adjusted) => {
+                    layout = adjusted;
+                    fit = layout.fit_text(paragraph.content.as_ref());
                 }
+                None => return (self, None, None),
             }
         }
 
-        // Find out the dimensions of the paragraph at given char offset.
-        let mut layout = paragraph.layout(area);
-        let fit = layout.fit_text(paragraph.content.as_ref());
         let (used, remaining_area) = area.split_top(fit.height());
         layout.bounds = used;
 
@@ -399,9 +597,78 @@ impl PageOffset {
         )
     }
 
-    fn should_place_pair_on_next_page<S: ParagraphStrType>(
-        this_paragraph: &Paragraph<S>,
-        next_paragraph: &Paragraph<S>,
+    /// Layout of the paragraph at this offset. Text at a non-zero character
+    /// offset continues from the previous page.
+    fn layout<S: ParagraphStrType>(self, paragraph: &Paragraph<S>, area: Rect) -> TextLayout {
+        let layout = paragraph.layout(area);
+        if self.chr > 0 {
+            layout.continued()
+        } else {
+            layout
+        }
+    }
+
+    /// Return the layout of a paragraph which does not fit into `area`, with
+    /// bounds shrunk if needed so that at least two lines continue on the
+    /// next page. Returns `None` if the whole paragraph should be moved to the
+    /// next page instead, so that it does not start with a single line at the
+    /// bottom of this one.
+    fn avoid_single_line<S: ParagraphStrType>(
+        self,
+        paragraph: &Paragraph<S>,
+        area: Rect,
+        full_height: i16,
+    ) -> Option<TextLayout> {
+        let text = paragraph.content.as_ref();
+        let layout = self.layout(paragraph, area);
+        let (fit, lines) = layout.fit_lines(text);
+        let processed_chars = match fit {
+            LayoutFit::OutOfBounds {
+                processed_chars, ..
+            } => processed_chars,
+            LayoutFit::Fitting { .. } => return Some(layout),
+        };
+        // Moving the paragraph only helps if there is some other content above it.
+        let can_move = self.chr == 0 && area.height() < full_height;
+
+        if lines == 1 && can_move {
+            // Orphan.
+            return None;
+        }
+
+        let (next_fit, next_lines) = paragraph
+            .layout(area.with_height(full_height))
+            .continued()
+            .fit_lines(&text[processed_chars..]);
+        let widow = next_lines == 1 && matches!(next_fit, LayoutFit::Fitting { .. });
+        if !widow {
+            Some(layout)
+        } else if lines > 2 {
+            // Leave one more line for the next page.
+            let line_height = paragraph.style.text_font.line_height();
+            Some(self.layout(
+                paragraph,
+                Rect {
+                    y1: area.y1 - line_height,
+                    ..area
+                },
+            ))
+        } else if can_move {
+            // Shortening the paragraph would leave an orphan.
+            None
+        } else {
+            Some(layout)
+        }
+    }
+
+    /// Decide whether the group of paragraphs starting at `first` should be
+    /// moved to the next page. The group consists of all the subsequent
+    /// paragraphs with the `no_break` flag and the one following them, e.g. a
+    /// key and its value. The last paragraph is treated as the value and all
+    /// the preceding ones as the key.
+    fn should_place_group_on_next_page<S: ParagraphStrType>(
+        source: &dyn ParagraphSource<StrType = S>,
+        first: usize,
         area: Rect,
         full_height: i16,
     ) -> bool {
@@ -412,16 +679,28 @@ impl PageOffset {
         }
 
         let full_area = area.with_height(full_height);
-        let key_height = this_paragraph
-            .layout(full_area)
-            .fit_text(this_paragraph.content.as_ref())
-            .height();
-        let val_height = next_paragraph
-            .layout(full_area)
-            .fit_text(next_paragraph.content.as_ref())
-            .height();
-        let screen_full_threshold = this_paragraph.style.text_font.line_height()
-            + next_paragraph.style.text_font.line_height();
+        let height = |paragraph: &Paragraph<S>| {
+            paragraph
+                .layout(full_area)
+                .fit_text(paragraph.content.as_ref())
+                .height()
+        };
+
+        let first_paragraph = source.at(first, 0);
+        let mut key_height = 0;
+        let mut last = first;
+        loop {
+            let paragraph = source.at(last, 0);
+            if !paragraph.no_break || last + 1 >= source.size() {
+                break;
+            }
+            key_height += height(&paragraph);
+            last += 1;
+        }
+        let last_paragraph = source.at(last, 0);
+        let val_height = height(&last_paragraph);
+        let screen_full_threshold = first_paragraph.style.text_font.line_height()
+            + last_paragraph.style.text_font.line_height();
 
         if key_height + val_height > remaining_height {
             return
@@ -431,7 +710,7 @@ impl PageOffset {
                 // More than 2 remaining lines so try to fit something -- but won't
                 // fit at least one line of value.
                 || (val_height > 0 && key_height > remaining_height)
-                // Whole property won't fit to the page, but it will fit on a page
+                // Whole group won't fit to the page, but it will fit on a page
                 // by itself.
                 || (key_height + val_height <= full_height);
         }
@@ -585,6 +864,45 @@ impl<T: ParagraphSource> crate::trace::Trace for Checklist<T> {
     }
 }
 
+/// Fails to compile if `M` paragraphs do not fit into the capacity `N`.
+struct AssertFits<const M: usize, const N: usize>;
+
+impl<const M: usize, const N: usize> AssertFits<M, N> {
+    const OK: () = assert!(M <= N, "paragraphs do not fit into the capacity");
+}
+
+/// List of capacity `N` with `paragraphs` known upfront, skipping the empty
+/// ones like `VecExt::add`. Exceeding the capacity is a compile error.
+pub fn paragraph_vec<T, const M: usize, const N: usize>(
+    paragraphs: [Paragraph<T>; M],
+) -> ParagraphVec<T, N>
+where
+    T: AsRef<str>,
+{
+    #[allow(clippy::let_unit_value)]
+    let () = AssertFits::<M, N>::OK;
+    let mut vec = ParagraphVec::new();
+    for paragraph in paragraphs {
+        vec.add(paragraph);
+    }
+    vec
+}
+
+/// Move `paragraphs` into a list of larger capacity `N`, e.g. to append more
+/// of them. Shrinking is a compile error.
+pub fn widen<T, const M: usize, const N: usize>(
+    paragraphs: ParagraphVec<T, M>,
+) -> ParagraphVec<T, N> {
+    #[allow(clippy::let_unit_value)]
+    let () = AssertFits::<M, N>::OK;
+    let mut vec = ParagraphVec::new();
+    for paragraph in paragraphs {
+        // Cannot fail, the capacity was checked above.
+        let _ = vec.push(paragraph);
+    }
+    vec
+}
+
 pub trait VecExt<T> {
     fn add(&mut self, paragraph: Paragraph<T>) -> &mut Self;
 }
@@ -604,3 +922,258 @@ where
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        trace::Trace,
+        ui::{display::Font, geometry::Point},
+    };
+
+    use super::*;
+
+    static STYLE: TextStyle = TextStyle::new(
+        Font::NORMAL,
+        Color::white(),
+        Color::black(),
+        Color::white(),
+        Color::white(),
+    );
+
+    impl ParagraphStrType for &'static str {
+        fn skip_prefix(&self, chars: usize) -> Self {
+            &self[chars..]
+        }
+
+        fn take_prefix(&self, bytes: usize) -> Self {
+            &self[..bytes]
+        }
+    }
+
+    fn trace(val: &impl Trace) -> String {
+        let mut t = std::vec::Vec::new();
+        val.trace(&mut t);
+        String::from_utf8(t).unwrap()
+    }
+
+    fn area(height: i16) -> Rect {
+        Rect::new(Point::zero(), Point::new(200, height))
+    }
+
+    #[test]
+    fn paragraphs_update() {
+        let mut paragraphs = Paragraphs::new([
+            Paragraph::new(&STYLE, "Status"),
+            Paragraph::new(&STYLE, "Counter: 1"),
+        ]);
+        paragraphs.place(area(200));
+        let mut ctx = EventCtx::new();
+
+        // Same layout, only the updated paragraph is damaged.
+        paragraphs.update(&mut ctx, 1, "Counter: 2");
+        assert!(ctx.paint_requested());
+        let damage = ctx.damage().unwrap();
+        assert!(area(200).intersects(damage) && damage != area(200));
+        assert!(trace(&paragraphs).contains("Counter: 2"));
+        assert!(paragraphs.clear.is_none());
+        ctx.clear();
+
+        // Text wrapping to more lines changes the layout of the page.
+        paragraphs.update(
+            &mut ctx,
+            1,
+            "Counter: 3, with a status message long enough to wrap to the next line.",
+        );
+        assert!(ctx.paint_requested());
+        assert!(ctx.damage().is_none());
+        assert!(paragraphs.clear.is_some());
+    }
+
+    #[test]
+    fn paragraphs_update_continued() {
+        let line_height = STYLE.text_font.line_height();
+        let mut paragraphs = Paragraphs::new([Paragraph::new(
+            &STYLE,
+            "A paragraph long enough to be broken into several pages of a single line.",
+        )]);
+        paragraphs.place(area(line_height));
+        assert!(paragraphs.page_count() > 1);
+        paragraphs.change_page(1);
+        assert!(paragraphs.offset.chr > 0);
+
+        // The old offset is past the end of the new content.
+        let mut ctx = EventCtx::new();
+        paragraphs.update(&mut ctx, 0, "Short.");
+        assert_eq!(paragraphs.offset, PageOffset::default());
+        assert!(trace(&paragraphs).contains("Short."));
+    }
+
+    #[test]
+    fn paragraphs_go_to_page() {
+        let line_height = STYLE.text_font.line_height();
+        let mut paragraphs = Paragraphs::new([Paragraph::new(
+            &STYLE,
+            "A paragraph long enough to be broken into several pages of a single line.",
+        )]);
+        paragraphs.place(area(line_height));
+        let page_count = paragraphs.page_count();
+        assert!(page_count > 1);
+
+        // Pages past the end resume at the last one.
+        assert_eq!(paragraphs.go_to_page(100), page_count - 1);
+        let last = paragraphs.offset;
+        assert!(last.chr > 0);
+
+        assert_eq!(paragraphs.go_to_page(0), 0);
+        assert_eq!(paragraphs.offset, PageOffset::default());
+        assert_eq!(paragraphs.go_to_page(page_count - 1), page_count - 1);
+        assert_eq!(paragraphs.offset, last);
+    }
+
+    /// Height of a paragraph with a single line of text.
+    fn line_height() -> i16 {
+        Paragraph::new(&STYLE, "Line")
+            .layout(area(200))
+            .fit_text("Line")
+            .height()
+    }
+
+    /// Paragraphs starting the second and further pages.
+    fn page_starts<const N: usize>(
+        paragraphs: [Paragraph<&'static str>; N],
+        lines: i16,
+    ) -> std::vec::Vec<usize> {
+        let mut paragraphs = Paragraphs::new(paragraphs);
+        paragraphs.place(area(lines * line_height()));
+        paragraphs
+            .break_pages()
+            .skip(1)
+            .map(|offset| offset.par)
+            .collect()
+    }
+
+    #[test]
+    fn paragraphs_no_break_group() {
+        let filler = Paragraph::new(&STYLE, "Filler");
+        let key = Paragraph::new(&STYLE, "Recipient");
+        let value = Paragraph::new(&STYLE, "0.01 BTC");
+
+        // Without the hint the value is left alone on the next page.
+        assert_eq!(page_starts([filler, filler, key, key, value], 4), [4]);
+        // The whole group moves to the next page.
+        assert_eq!(
+            page_starts([filler, filler, key.no_break(), key.no_break(), value], 4),
+            [2]
+        );
+        // A group which fits stays where it is.
+        assert_eq!(page_starts([filler, key.no_break(), value, filler], 3), [3]);
+    }
+
+    static CONTROLLED: TextStyle = TextStyle::new(
+        Font::NORMAL,
+        Color::white(),
+        Color::black(),
+        Color::white(),
+        Color::white(),
+    )
+    .with_widow_orphan_control(true);
+
+    /// Text of the pages after the first one. The paragraphs are narrow
+    /// enough to fit a single word per line, the pages are as tall as the
+    /// paragraphs of `page` together.
+    fn continued_pages<const N: usize>(
+        paragraphs: [Paragraph<&'static str>; N],
+        page: &[&str],
+    ) -> std::vec::Vec<(usize, &'static str)> {
+        let width = STYLE.text_font.text_width("Line Line") - 1;
+        let height = page
+            .iter()
+            .map(|text| {
+                Paragraph::new(&STYLE, *text)
+                    .layout(Rect::new(Point::zero(), Point::new(width, 200)))
+                    .fit_text(text)
+                    .height()
+            })
+            .sum();
+        let mut paragraphs = Paragraphs::new(paragraphs);
+        paragraphs.place(Rect::new(Point::zero(), Point::new(width, height)));
+        paragraphs
+            .break_pages()
+            .skip(1)
+            .map(|offset| {
+                let content = *paragraphs.inner().at(offset.par, 0).content();
+                (offset.par, &content[offset.chr..])
+            })
+            .collect()
+    }
+
+    #[test]
+    fn paragraphs_orphan() {
+        let text = "Line Line Line";
+        // Room for a single line below the first paragraph.
+        let page = ["Line Line", "Line"];
+        assert_eq!(
+            continued_pages(
+                [
+                    Paragraph::new(&STYLE, "Line Line"),
+                    Paragraph::new(&STYLE, text)
+                ],
+                &page
+            ),
+            [(1, "Line Line")]
+        );
+        // The first line is not left alone at the bottom of the page.
+        assert_eq!(
+            continued_pages(
+                [
+                    Paragraph::new(&STYLE, "Line Line"),
+                    Paragraph::new(&CONTROLLED, text)
+                ],
+                &page
+            ),
+            [(1, text)]
+        );
+    }
+
+    #[test]
+    fn paragraphs_widow() {
+        let text = "Line Line Line Line";
+        let page = ["Line Line Line"];
+        assert_eq!(
+            continued_pages([Paragraph::new(&STYLE, text)], &page),
+            [(0, "Line")]
+        );
+        // One more line goes to the next page to keep the last one company.
+        assert_eq!(
+            continued_pages([Paragraph::new(&CONTROLLED, text)], &page),
+            [(0, "Line Line")]
+        );
+    }
+
+    fn contents<const N: usize>(vec: &ParagraphVec<&'static str, N>) -> std::vec::Vec<&str> {
+        vec.iter().map(|p| *p.content()).collect()
+    }
+
+    #[test]
+    fn paragraph_vec_skips_empty() {
+        let vec: ParagraphVec<_, 3> = paragraph_vec([
+            Paragraph::new(&STYLE, "Title"),
+            Paragraph::new(&STYLE, ""),
+            Paragraph::new(&STYLE, "Message"),
+        ]);
+        assert_eq!(contents(&vec), ["Title", "Message"]);
+        assert_eq!(vec.capacity(), 3);
+    }
+
+    #[test]
+    fn paragraph_vec_widen() {
+        let vec: ParagraphVec<_, 2> = paragraph_vec([
+            Paragraph::new(&STYLE, "First"),
+            Paragraph::new(&STYLE, "Second"),
+        ]);
+        let mut wide: ParagraphVec<_, 4> = widen(vec);
+        wide.add(Paragraph::new(&STYLE, "Third"));
+        assert_eq!(contents(&wide), ["First", "Second", "Third"]);
+        assert_eq!(wide.capacity(), 4);
+    }
+}
@@ -0,0 +1,189 @@
+//! Lists of key-value properties, e.g. the details of a transaction or of a
+//! firmware image, rendered as paragraphs. Keys and values are styled the same
+//! way on every screen, a key always stays on the same page as the beginning
+//! of its value.
+
+use heapless::Vec;
+
+use crate::ui::theme;
+
+use super::{
+    paragraphs::{Paragraph, ParagraphSource, ParagraphStrType},
+    tokens::TextTheme,
+    TextStyle,
+};
+
+#[derive(Copy, Clone)]
+pub struct PropsStyle {
+    pub key: &'static TextStyle,
+    pub value: &'static TextStyle,
+    /// Used for values which are not meant to be read as words, e.g. addresses
+    /// or hashes.
+    pub value_mono: &'static TextStyle,
+}
+
+impl PropsStyle {
+    /// Keys styled as titles, values as body or mono text of `theme`.
+    pub const fn from_theme(theme: &TextTheme) -> Self {
+        Self {
+            key: theme.title,
+            value: theme.body,
+            value_mono: theme.mono,
+        }
+    }
+}
+
+impl Default for PropsStyle {
+    /// Style of the text theme selected for the current model.
+    fn default() -> Self {
+        Self::from_theme(theme::text_theme())
+    }
+}
+
+/// Storage of the properties, with the keys and values possibly missing.
+pub trait PropSource {
+    type StrType: ParagraphStrType;
+
+    /// Number of properties.
+    fn size(&self) -> usize;
+
+    /// Key of the `index`-th property with the first `offset` bytes skipped.
+    fn key(&self, index: usize, offset: usize) -> Option<Self::StrType>;
+
+    /// Value of the `index`-th property with the first `offset` bytes skipped,
+    /// together with the flag telling whether it is shown in monospace.
+    fn value(&self, index: usize, offset: usize) -> Option<(Self::StrType, bool)>;
+
+    /// Whether the `index`-th property has a value.
+    fn has_value(&self, index: usize) -> bool {
+        self.value(index, 0).is_some()
+    }
+}
+
+/// Properties as `(key, value, value_is_mono)` tuples.
+impl<T: ParagraphStrType, const N: usize> PropSource for Vec<(T, T, bool), N> {
+    type StrType = T;
+
+    fn size(&self) -> usize {
+        self.len()
+    }
+
+    fn key(&self, index: usize, offset: usize) -> Option<T> {
+        Some(self[index].0.skip_prefix(offset))
+    }
+
+    fn value(&self, index: usize, offset: usize) -> Option<(T, bool)> {
+        let (_, value, mono) = &self[index];
+        Some((value.skip_prefix(offset), *mono))
+    }
+
+    fn has_value(&self, _index: usize) -> bool {
+        true
+    }
+}
+
+impl<T: ParagraphStrType, const N: usize> PropSource for [(T, T, bool); N] {
+    type StrType = T;
+
+    fn size(&self) -> usize {
+        N
+    }
+
+    fn key(&self, index: usize, offset: usize) -> Option<T> {
+        Some(self[index].0.skip_prefix(offset))
+    }
+
+    fn value(&self, index: usize, offset: usize) -> Option<(T, bool)> {
+        let (_, value, mono) = &self[index];
+        Some((value.skip_prefix(offset), *mono))
+    }
+
+    fn has_value(&self, _index: usize) -> bool {
+        true
+    }
+}
+
+/// Paragraph source turning every property into a key paragraph followed by a
+/// value paragraph. Missing keys and values result in empty paragraphs.
+pub struct PropsList<S> {
+    source: S,
+    style: PropsStyle,
+}
+
+impl<S> PropsList<S>
+where
+    S: PropSource,
+{
+    pub fn new(source: S, style: PropsStyle) -> Self {
+        Self { source, style }
+    }
+}
+
+impl<S> ParagraphSource for PropsList<S>
+where
+    S: PropSource,
+    S::StrType: Default,
+{
+    type StrType = S::StrType;
+
+    fn at(&self, index: usize, offset: usize) -> Paragraph<Self::StrType> {
+        let prop = index / 2;
+        if index % 2 == 0 {
+            let key = self.source.key(prop, offset);
+            let has_value = self.source.has_value(prop);
+            let para = Paragraph::new(self.style.key, key.unwrap_or_default());
+            if has_value {
+                para.no_break()
+            } else {
+                para
+            }
+        } else {
+            match self.source.value(prop, offset) {
+                Some((value, true)) => Paragraph::new(self.style.value_mono, value),
+                Some((value, false)) => Paragraph::new(self.style.value, value),
+                None => Paragraph::new(self.style.value, Default::default()),
+            }
+        }
+    }
+
+    fn size(&self) -> usize {
+        2 * self.source.size()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::display::{Color, Font};
+
+    use super::*;
+
+    const COLOR: Color = Color::rgb(0, 0, 0);
+    static KEY: TextStyle = TextStyle::new(Font::BOLD, COLOR, COLOR, COLOR, COLOR);
+    static VALUE: TextStyle = TextStyle::new(Font::NORMAL, COLOR, COLOR, COLOR, COLOR);
+    static MONO: TextStyle = TextStyle::new(Font::MONO, COLOR, COLOR, COLOR, COLOR);
+
+    #[test]
+    fn props_list() {
+        let props = PropsList::new(
+            [("Amount", "1 BTC", false), ("Address", "bc1qxyz", true)],
+            PropsStyle {
+                key: &KEY,
+                value: &VALUE,
+                value_mono: &MONO,
+            },
+        );
+        assert_eq!(props.size(), 4);
+
+        let key = props.at(0, 0);
+        assert_eq!(*key.content(), "Amount");
+        assert!(core::ptr::eq(key.style(), &KEY));
+        assert!(key.is_no_break());
+
+        let value = props.at(1, 2);
+        assert_eq!(*value.content(), "BTC");
+        assert!(core::ptr::eq(value.style(), &VALUE));
+        assert!(!value.is_no_break());
+
+        assert!(core::ptr::eq(props.at(3, 0).style(), &MONO));
+    }
+}
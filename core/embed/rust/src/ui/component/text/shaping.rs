@@ -0,0 +1,277 @@
+//! Shaping of complex scripts. Text is split into clusters, each rendered as a
+//! single glyph, so that the layout can measure and break the shaped text
+//! while keeping byte offsets into the original string.
+//!
+//! Currently only Arabic is shaped: letters are replaced by their contextual
+//! presentation forms and lam-alef pairs by ligatures. Note that the fonts
+//! do not contain the Arabic glyphs yet.
+
+/// Characters next to a part of a longer text which is shaped on its own, e.g.
+/// a line or a word of a paragraph, so that the letters at its ends join the
+/// neighbouring ones the same as within the whole text. Combining marks are
+/// skipped, the characters are the closest base characters.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug)]
+pub struct Context {
+    pub before: Option<char>,
+    pub after: Option<char>,
+}
+
+impl Context {
+    /// Context of `text[start..end]` within `text`, which itself is shaped
+    /// within `self`.
+    pub fn within(self, text: &str, start: usize, end: usize) -> Self {
+        let before = text[..start].chars().rev().find(|&ch| !is_transparent(ch));
+        let after = text[end..].chars().find(|&ch| !is_transparent(ch));
+        Self {
+            before: before.or(self.before),
+            after: after.or(self.after),
+        }
+    }
+}
+
+/// Maps characters of the source text to the glyphs which get rendered.
+pub trait Shaper {
+    /// Shape the cluster starting at byte `offset` of `text`, which is
+    /// surrounded by `context`. Returns the glyph and length of the cluster
+    /// in bytes.
+    fn shape(&self, text: &str, offset: usize, context: Context) -> (char, usize);
+}
+
+/// Iterate over clusters of `text` as pairs of byte offset and glyph, same as
+/// `str::char_indices` would for unshaped text.
+pub fn clusters<'a>(
+    shaper: &'a dyn Shaper,
+    text: &'a str,
+    context: Context,
+) -> impl Iterator<Item = (usize, char)> + 'a {
+    let mut offset = 0;
+    core::iter::from_fn(move || {
+        if offset >= text.len() {
+            return None;
+        }
+        let (glyph, len) = shaper.shape(text, offset, context);
+        let cluster = (offset, glyph);
+        offset += len;
+        Some(cluster)
+    })
+}
+
+pub struct ArabicShaper;
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Joining {
+    /// Does not connect to the adjacent letters.
+    None,
+    /// Connects to the preceding letter only.
+    Right,
+    /// Connects to both the preceding and the following letter.
+    Dual,
+}
+
+const FIRST_LETTER: char = '\u{0621}';
+const LAM: char = '\u{0644}';
+
+/// Joining type and isolated presentation form of letters starting at
+/// `FIRST_LETTER`. The final, initial and medial forms follow the isolated
+/// one, in this order.
+#[rustfmt::skip]
+const LETTERS: [(Joining, u16); 42] = [
+    (Joining::None, 0xFE80),  // hamza
+    (Joining::Right, 0xFE81), // alef with madda above
+    (Joining::Right, 0xFE83), // alef with hamza above
+    (Joining::Right, 0xFE85), // waw with hamza above
+    (Joining::Right, 0xFE87), // alef with hamza below
+    (Joining::Dual, 0xFE89),  // yeh with hamza above
+    (Joining::Right, 0xFE8D), // alef
+    (Joining::Dual, 0xFE8F),  // beh
+    (Joining::Right, 0xFE93), // teh marbuta
+    (Joining::Dual, 0xFE95),  // teh
+    (Joining::Dual, 0xFE99),  // theh
+    (Joining::Dual, 0xFE9D),  // jeem
+    (Joining::Dual, 0xFEA1),  // hah
+    (Joining::Dual, 0xFEA5),  // khah
+    (Joining::Right, 0xFEA9), // dal
+    (Joining::Right, 0xFEAB), // thal
+    (Joining::Right, 0xFEAD), // reh
+    (Joining::Right, 0xFEAF), // zain
+    (Joining::Dual, 0xFEB1),  // seen
+    (Joining::Dual, 0xFEB5),  // sheen
+    (Joining::Dual, 0xFEB9),  // sad
+    (Joining::Dual, 0xFEBD),  // dad
+    (Joining::Dual, 0xFEC1),  // tah
+    (Joining::Dual, 0xFEC5),  // zah
+    (Joining::Dual, 0xFEC9),  // ain
+    (Joining::Dual, 0xFECD),  // ghain
+    (Joining::None, 0),       // unassigned
+    (Joining::None, 0),
+    (Joining::None, 0),
+    (Joining::None, 0),
+    (Joining::None, 0),
+    (Joining::Dual, 0),       // tatweel, has no forms
+    (Joining::Dual, 0xFED1),  // feh
+    (Joining::Dual, 0xFED5),  // qaf
+    (Joining::Dual, 0xFED9),  // kaf
+    (Joining::Dual, 0xFEDD),  // lam
+    (Joining::Dual, 0xFEE1),  // meem
+    (Joining::Dual, 0xFEE5),  // noon
+    (Joining::Dual, 0xFEE9),  // heh
+    (Joining::Right, 0xFEED), // waw
+    (Joining::Right, 0xFEEF), // alef maksura
+    (Joining::Dual, 0xFEF1),  // yeh
+];
+
+/// Alef variants and isolated form of their ligature with lam. The final form
+/// follows the isolated one.
+const LAM_ALEF: [(char, u16); 4] = [
+    ('\u{0622}', 0xFEF5),
+    ('\u{0623}', 0xFEF7),
+    ('\u{0625}', 0xFEF9),
+    ('\u{0627}', 0xFEFB),
+];
+
+fn letter(ch: char) -> Option<(Joining, u16)> {
+    let index = (ch as u32).checked_sub(FIRST_LETTER as u32)? as usize;
+    LETTERS.get(index).copied()
+}
+
+fn joining(ch: char) -> Joining {
+    letter(ch).map_or(Joining::None, |(joining, _)| joining)
+}
+
+/// Diacritics do not break the joining of the letters around them.
+fn is_transparent(ch: char) -> bool {
+    matches!(ch, '\u{064B}'..='\u{065F}' | '\u{0670}')
+}
+
+impl ArabicShaper {
+    fn joins_prev(before: &str, context: Context) -> bool {
+        before
+            .chars()
+            .rev()
+            .find(|&ch| !is_transparent(ch))
+            .or(context.before)
+            .map_or(false, |prev| joining(prev) == Joining::Dual)
+    }
+
+    fn joins_next(rest: &str, context: Context) -> bool {
+        rest.chars()
+            .find(|&ch| !is_transparent(ch))
+            .or(context.after)
+            .map_or(false, |next| joining(next) != Joining::None)
+    }
+}
+
+impl Shaper for ArabicShaper {
+    fn shape(&self, text: &str, offset: usize, context: Context) -> (char, usize) {
+        let (before, text) = text.split_at(offset);
+        let mut chars = text.chars();
+        let ch = unwrap!(chars.next());
+        let len = ch.len_utf8();
+
+        let (joining, isolated) = match letter(ch) {
+            Some((joining, isolated)) if isolated != 0 => (joining, isolated),
+            _ => return (ch, len),
+        };
+        let joins_prev = joining != Joining::None && Self::joins_prev(before, context);
+
+        if ch == LAM {
+            let next = chars.next();
+            if let Some((alef, ligature)) = LAM_ALEF.iter().find(|(alef, _)| Some(*alef) == next) {
+                let glyph = ligature + joins_prev as u16;
+                return (unwrap!(char::from_u32(glyph.into())), len + alef.len_utf8());
+            }
+        }
+
+        let joins_next = joining == Joining::Dual && Self::joins_next(&text[len..], context);
+        let form = match (joins_prev, joins_next) {
+            (false, false) => 0,
+            (true, false) => 1,
+            (false, true) => 2,
+            (true, true) => 3,
+        };
+        (unwrap!(char::from_u32((isolated + form).into())), len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shape(text: &str) -> Vec<(usize, char)> {
+        clusters(&ArabicShaper, text, Context::default()).collect()
+    }
+
+    #[test]
+    fn shaping_ascii() {
+        assert_eq!(shape("abc"), "abc".char_indices().collect::<Vec<_>>());
+        assert_eq!(shape(""), vec![]);
+    }
+
+    #[test]
+    fn shaping_forms() {
+        // beh beh beh: initial, medial, final
+        assert_eq!(
+            shape("\u{0628}\u{0628}\u{0628}"),
+            vec![(0, '\u{FE91}'), (2, '\u{FE92}'), (4, '\u{FE90}')]
+        );
+        // dal does not connect to the following beh, which stays isolated
+        assert_eq!(
+            shape("\u{062F}\u{0628}"),
+            vec![(0, '\u{FEA9}'), (2, '\u{FE8F}')]
+        );
+        // diacritic between the letters keeps them joined
+        assert_eq!(
+            shape("\u{0628}\u{064E}\u{0628}"),
+            vec![(0, '\u{FE91}'), (2, '\u{064E}'), (4, '\u{FE90}')]
+        );
+        // space breaks the joining
+        assert_eq!(
+            shape("\u{0628} \u{0628}"),
+            vec![(0, '\u{FE8F}'), (2, ' '), (3, '\u{FE8F}')]
+        );
+    }
+
+    #[test]
+    fn shaping_lam_alef() {
+        assert_eq!(shape("\u{0644}\u{0627}"), vec![(0, '\u{FEFB}')]);
+        // beh lam alef: ligature in final form
+        assert_eq!(
+            shape("\u{0628}\u{0644}\u{0627}"),
+            vec![(0, '\u{FE91}'), (2, '\u{FEFC}')]
+        );
+    }
+
+    #[test]
+    fn shaping_context() {
+        // beh beh beh split after the first letter keeps the forms of the
+        // whole text
+        let text = "\u{0628}\u{0628}\u{0628}";
+        let context = Context::default().within(text, 2, text.len());
+        assert_eq!(context.before, Some('\u{0628}'));
+        assert_eq!(
+            clusters(&ArabicShaper, &text[2..], context).collect::<Vec<_>>(),
+            vec![(0, '\u{FE92}'), (2, '\u{FE90}')]
+        );
+        let context = Context::default().within(text, 0, 2);
+        assert_eq!(
+            clusters(&ArabicShaper, &text[..2], context).collect::<Vec<_>>(),
+            vec![(0, '\u{FE91}')]
+        );
+        // the diacritic at the end of the first part is skipped
+        let text = "\u{0628}\u{064E}\u{0628}";
+        let context = Context::default().within(text, 4, text.len());
+        assert_eq!(context.before, Some('\u{0628}'));
+        // the context of the whole text is used at its ends
+        let outer = Context {
+            before: Some('\u{0628}'),
+            after: None,
+        };
+        assert_eq!(
+            outer.within(text, 0, 2),
+            Context {
+                before: Some('\u{0628}'),
+                after: Some('\u{0628}'),
+            }
+        );
+    }
+}
@@ -0,0 +1,64 @@
+//! Semantic text styles. Screens ask the theme of the model for the style of
+//! e.g. the body text instead of naming a concrete `TextStyle`, so that a
+//! change of the theme applies to all screens consistently.
+
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use super::TextStyle;
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum StyleToken {
+    /// Headings within the content, e.g. the title of a paragraph.
+    Title,
+    /// Main line of a dialog or of a message, below the title.
+    Subtitle,
+    /// Regular text.
+    Body,
+    /// Less important text accompanying the body, e.g. the description below
+    /// an icon.
+    Secondary,
+    /// Words highlighted within the body text, e.g. a value after its label.
+    Emphasis,
+    /// Data which is not meant to be read as words, e.g. addresses or amounts.
+    Mono,
+    /// Text the user needs to pay attention to.
+    Warning,
+}
+
+/// Resolution of the tokens to the styles of a theme.
+pub struct TextTheme {
+    pub title: &'static TextStyle,
+    pub subtitle: &'static TextStyle,
+    pub body: &'static TextStyle,
+    pub secondary: &'static TextStyle,
+    pub emphasis: &'static TextStyle,
+    pub mono: &'static TextStyle,
+    pub warning: &'static TextStyle,
+}
+
+impl TextTheme {
+    pub const fn style(&self, token: StyleToken) -> &'static TextStyle {
+        match token {
+            StyleToken::Title => self.title,
+            StyleToken::Subtitle => self.subtitle,
+            StyleToken::Body => self.body,
+            StyleToken::Secondary => self.secondary,
+            StyleToken::Emphasis => self.emphasis,
+            StyleToken::Mono => self.mono,
+            StyleToken::Warning => self.warning,
+        }
+    }
+}
+
+static BOLD_TEXT: AtomicBool = AtomicBool::new(false);
+
+/// Whether the user asked for text which is easier to read. The fonts come in
+/// a single size, the themes use heavier weights of them instead.
+pub fn bold_text() -> bool {
+    BOLD_TEXT.load(Ordering::Relaxed)
+}
+
+/// Use the bold text theme for the layouts created from now on.
+pub fn set_bold_text(enabled: bool) {
+    BOLD_TEXT.store(enabled, Ordering::Relaxed);
+}
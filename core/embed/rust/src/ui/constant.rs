@@ -1,7 +1,34 @@
 //! Reexporting the `constant` module according to the
 //! current feature (Trezor model)
 
+use super::geometry::Rect;
+
 #[cfg(all(feature = "model_tr", not(feature = "model_tt")))]
 pub use super::model_tr::constant::*;
 #[cfg(feature = "model_tt")]
 pub use super::model_tt::constant::*;
+
+/// Whether a horizontal extent of `len` fits on the screen.
+pub const fn fits_width(len: i16) -> bool {
+    0 <= len && len <= WIDTH
+}
+
+/// Whether a vertical extent of `len` fits on the screen.
+pub const fn fits_height(len: i16) -> bool {
+    0 <= len && len <= HEIGHT
+}
+
+/// Whether `rect` lies on the screen as a whole.
+pub const fn fits_screen(rect: Rect) -> bool {
+    rect.x0 >= 0
+        && rect.y0 >= 0
+        && rect.x0 <= rect.x1
+        && rect.y0 <= rect.y1
+        && rect.x1 <= WIDTH
+        && rect.y1 <= HEIGHT
+}
+
+const_assert!(
+    fits_width(LOADER_ICON_MAX_SIZE) && fits_height(LOADER_ICON_MAX_SIZE),
+    "loader icon does not fit the screen"
+);
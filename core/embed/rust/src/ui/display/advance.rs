@@ -0,0 +1,107 @@
+//! Glyph advances of printable ASCII characters, read from the font data on
+//! first use. Measuring text during layout then becomes a table lookup per
+//! character instead of a glyph lookup in C. Other characters are measured by
+//! C, which decodes UTF-8 and falls back to emoji.
+
+use crate::trezorhal::display;
+
+use super::Font;
+
+const FONT_COUNT: usize = 4;
+const FIRST: u8 = b' ';
+const COUNT: usize = 0x7F - FIRST as usize;
+
+struct Advances {
+    widths: [[u8; COUNT]; FONT_COUNT],
+    loaded: [bool; FONT_COUNT],
+}
+
+impl Advances {
+    const fn new() -> Self {
+        Self {
+            widths: [[0; COUNT]; FONT_COUNT],
+            loaded: [false; FONT_COUNT],
+        }
+    }
+
+    /// Advances of the printable ASCII characters in `font`, starting with
+    /// space. Read from the font data on first use.
+    fn table(&mut self, font: Font) -> &[u8; COUNT] {
+        let i = index(font);
+        if !self.loaded[i] {
+            for (offset, width) in self.widths[i].iter_mut().enumerate() {
+                let ch = FIRST + offset as u8;
+                *width = font.get_glyph(ch).map_or(0, |glyph| glyph.adv as u8);
+            }
+            self.loaded[i] = true;
+        }
+        &self.widths[i]
+    }
+}
+
+#[cfg(not(test))]
+static mut ADVANCES: Advances = Advances::new();
+
+// Tests measure text from several threads at once, each of them fills its own
+// table.
+#[cfg(test)]
+std::thread_local! {
+    static ADVANCES: core::cell::UnsafeCell<Advances> =
+        core::cell::UnsafeCell::new(Advances::new());
+}
+
+/// Returns the table of advances.
+///
+/// # Safety
+///
+/// The caller has to guarantee that no other reference returned by this
+/// function is alive while the result is used.
+unsafe fn advances() -> &'static mut Advances {
+    #[cfg(not(test))]
+    let ptr = core::ptr::addr_of_mut!(ADVANCES);
+    #[cfg(test)]
+    let ptr = ADVANCES.with(|advances| advances.get());
+    unsafe { &mut *ptr }
+}
+
+fn index(font: Font) -> usize {
+    match font {
+        Font::NORMAL => 0,
+        Font::BOLD => 1,
+        Font::MONO => 2,
+        Font::DEMIBOLD => 3,
+    }
+}
+
+/// Width of `text` in `font`, the same as `display_text_width` in C.
+pub fn text_width(font: Font, text: &str) -> i16 {
+    // SAFETY: `text_width` is the only user of the table and it does not
+    // recurse, the reference is dropped before returning. Firmware measures
+    // text only from the UI task and every test thread has a table of its own.
+    let table = unsafe { advances() }.table(font);
+    let mut width: i16 = 0;
+    for byte in text.bytes() {
+        match table.get(byte.wrapping_sub(FIRST) as usize) {
+            Some(advance) => width = width.saturating_add(*advance as i16),
+            None => return display::text_width(text, font.into()),
+        }
+    }
+    width
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advance_matches_c() {
+        for font in [Font::NORMAL, Font::BOLD, Font::MONO, Font::DEMIBOLD] {
+            for text in ["", "Hello, world!", "0.1 BTC", "ěščř\u{1F600}"] {
+                assert_eq!(
+                    text_width(font, text),
+                    display::text_width(text, font.into())
+                );
+            }
+        }
+    }
+}
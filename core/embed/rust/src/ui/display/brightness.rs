@@ -0,0 +1,170 @@
+//! Automatic backlight control driven by the ambient light sensor.
+
+use crate::trezorhal::ambient_light;
+
+use super::{backlight, set_backlight};
+
+/// Illuminance (lux) at and above which the maximum brightness is used.
+const LUX_MAX: u32 = 500;
+/// Maximum backlight change per update, keeps the transitions smooth.
+const STEP: i32 = 4;
+
+struct AutoBrightness {
+    enabled: bool,
+    min: i32,
+    max: i32,
+    /// Number of nested `pause()` calls.
+    paused: u8,
+}
+
+impl AutoBrightness {
+    const fn new() -> Self {
+        Self {
+            enabled: false,
+            min: 0,
+            max: 0,
+            paused: 0,
+        }
+    }
+}
+
+#[cfg(not(test))]
+static mut STATE: AutoBrightness = AutoBrightness::new();
+
+// Backlight fades in tests running in parallel must not pause each other.
+#[cfg(test)]
+std::thread_local! {
+    static STATE: core::cell::UnsafeCell<AutoBrightness> =
+        core::cell::UnsafeCell::new(AutoBrightness::new());
+}
+
+/// Returns the state of the automatic brightness.
+///
+/// # Safety
+///
+/// The caller has to guarantee that no other reference returned by this
+/// function is alive while the result is used.
+unsafe fn state() -> &'static mut AutoBrightness {
+    #[cfg(not(test))]
+    let ptr = core::ptr::addr_of_mut!(STATE);
+    #[cfg(test)]
+    let ptr = STATE.with(|state| state.get());
+    unsafe { &mut *ptr }
+}
+
+/// Start adjusting the backlight between `min` and `max` according to the
+/// ambient light.
+pub fn enable(min: i32, max: i32) {
+    // SAFETY: Called from the UI task only, nothing else is called while the
+    // reference is alive.
+    let state = unsafe { state() };
+    state.enabled = true;
+    state.min = min.min(max);
+    state.max = max.max(min);
+}
+
+pub fn disable() {
+    // SAFETY: Temporary reference, called from the UI task only.
+    unsafe { state() }.enabled = false;
+}
+
+/// Stop adjusting the backlight until the matching `resume()`, e.g. while the
+/// backlight is being faded.
+pub fn pause() {
+    // SAFETY: Called from `fade_backlight` in the UI task, the reference does
+    // not outlive the counter update.
+    let state = unsafe { state() };
+    state.paused = state.paused.saturating_add(1);
+}
+
+pub fn resume() {
+    // SAFETY: See `pause`.
+    let state = unsafe { state() };
+    state.paused = state.paused.saturating_sub(1);
+}
+
+/// Whether the backlight should be adjusted now, i.e. enabled and not paused
+/// or `animating`.
+fn active(animating: bool) -> bool {
+    // SAFETY: Temporary reference, called from the UI task only.
+    let state = unsafe { state() };
+    state.enabled && state.paused == 0 && !animating
+}
+
+/// Move the backlight one step towards the level corresponding to the current
+/// ambient light. Does nothing while paused or `animating`, so that the change
+/// of brightness does not interfere with the animation.
+pub fn update(animating: bool) {
+    if !active(animating) {
+        return;
+    }
+    if let Some(lux) = ambient_light::read() {
+        // SAFETY: Temporary reference, the bounds are copied out before the
+        // backlight is touched.
+        let (min, max) = unsafe {
+            let state = state();
+            (state.min, state.max)
+        };
+        let current = backlight();
+        let next = next_level(current, target_level(lux, min, max));
+        if next != current {
+            set_backlight(next);
+        }
+    }
+}
+
+fn target_level(lux: u32, min: i32, max: i32) -> i32 {
+    min + (max - min) * lux.min(LUX_MAX) as i32 / LUX_MAX as i32
+}
+
+/// Level at most `STEP` away from `current` in the direction of `target`.
+fn next_level(current: i32, target: i32) -> i32 {
+    current + (target - current).clamp(-STEP, STEP)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn brightness_target() {
+        assert_eq!(target_level(0, 45, 255), 45);
+        assert_eq!(target_level(LUX_MAX / 2, 45, 255), 150);
+        assert_eq!(target_level(LUX_MAX, 45, 255), 255);
+        assert_eq!(target_level(100 * LUX_MAX, 45, 255), 255);
+    }
+
+    #[test]
+    fn brightness_step() {
+        assert_eq!(next_level(100, 150), 100 + STEP);
+        assert_eq!(next_level(150, 100), 150 - STEP);
+        assert_eq!(next_level(149, 150), 150);
+        assert_eq!(next_level(150, 150), 150);
+    }
+
+    #[test]
+    fn brightness_pause() {
+        assert!(!active(false));
+
+        enable(255, 45);
+        // SAFETY: Temporary reference, the test thread owns its state.
+        let bounds = unsafe {
+            let state = state();
+            (state.min, state.max)
+        };
+        assert_eq!(bounds, (45, 255));
+        assert!(active(false));
+        assert!(!active(true));
+
+        // fades can be nested
+        pause();
+        pause();
+        resume();
+        assert!(!active(false));
+        resume();
+        assert!(active(false));
+
+        disable();
+        assert!(!active(false));
+    }
+}
@@ -0,0 +1,73 @@
+//! Clip region of a partial repaint, see `EventCtx::damage`.
+
+use crate::ui::geometry::Rect;
+
+#[cfg(not(test))]
+static mut CLIP: Option<Rect> = None;
+
+// Tests running in parallel must not clip each other.
+#[cfg(test)]
+std::thread_local! {
+    static CLIP: core::cell::UnsafeCell<Option<Rect>> = core::cell::UnsafeCell::new(None);
+}
+
+/// Returns the current clip region.
+///
+/// # Safety
+///
+/// The caller has to guarantee that no other reference returned by this
+/// function is alive while the result is used.
+unsafe fn state() -> &'static mut Option<Rect> {
+    #[cfg(not(test))]
+    let ptr = core::ptr::addr_of_mut!(CLIP);
+    #[cfg(test)]
+    let ptr = CLIP.with(|clip| clip.get());
+    unsafe { &mut *ptr }
+}
+
+/// Area outside of which nothing has to be painted, `None` if the whole screen
+/// is being painted.
+pub fn clip() -> Option<Rect> {
+    // SAFETY: The reference does not leave this function.
+    unsafe { *state() }
+}
+
+/// Call `func` painting only the parts of the screen within `area`. Filled
+/// rectangles are cut to `area` and `Child` components outside of it are not
+/// painted at all, other primitives are not clipped.
+pub fn with_clip<T>(area: Rect, func: impl FnOnce() -> T) -> T {
+    // SAFETY: The reference does not leave this function.
+    let prev = unsafe { state().replace(area) };
+    let result = func();
+    // SAFETY: The reference does not leave this function.
+    unsafe { *state() = prev };
+    result
+}
+
+/// Whether anything in `area` has to be painted.
+pub fn is_visible(area: Rect) -> bool {
+    clip().map_or(true, |clip| clip.intersects(area))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::geometry::Point;
+
+    use super::*;
+
+    fn rect(x0: i16, y0: i16, x1: i16, y1: i16) -> Rect {
+        Rect::new(Point::new(x0, y0), Point::new(x1, y1))
+    }
+
+    #[test]
+    fn clip_visible() {
+        let area = rect(10, 10, 20, 20);
+        assert!(is_visible(area));
+        with_clip(rect(0, 0, 10, 10), || {
+            assert!(!is_visible(area));
+            with_clip(rect(15, 0, 16, 30), || assert!(is_visible(area)));
+            assert!(clip() == Some(rect(0, 0, 10, 10)));
+        });
+        assert!(clip().is_none());
+    }
+}
@@ -0,0 +1,213 @@
+//! Off-screen rendering of a part of the screen, so that e.g. a changing
+//! digit or a line of typed text is not visible half-drawn on the panel.
+
+use crate::ui::{
+    constant,
+    display::{get_color_table, get_offset, pixeldata_dirty, set_window, Color, Font},
+    geometry::{Offset, Point, Rect},
+    pool::{Block, BLOCK_SIZE},
+};
+
+#[cfg(not(feature = "dma2d"))]
+use crate::ui::display::pixeldata;
+
+#[cfg(feature = "dma2d")]
+use crate::trezorhal::dma2d::{dma2d_setup_16bpp, dma2d_start, dma2d_wait_for_transfer};
+
+/// Bytes of a RGB565 pixel.
+const PIXEL_BYTES: usize = 2;
+
+/// Paint `area` off-screen with `func` and write it to the panel in a single
+/// pass, so that the old content is replaced without being cleared first. The
+/// buffer is a block of the UI pool, larger areas are painted in horizontal
+/// bands: `func` is called for each band, starting with the top one, and its
+/// drawing is clipped to the band. Returns `false` without calling `func` if
+/// no block is available or a single row does not fit into it, the caller
+/// should then paint the area directly.
+pub fn render(area: Rect, background: Color, mut func: impl FnMut(&mut FrameBuffer)) -> bool {
+    let rows = band_rows(area.width());
+    if area.is_empty() || rows == 0 {
+        return false;
+    }
+    let mut block = match Block::alloc() {
+        Some(block) => block,
+        None => return false,
+    };
+    for band in bands(area, rows) {
+        let len = band.width() as usize * band.height() as usize * PIXEL_BYTES;
+        let mut fb = FrameBuffer {
+            area: band,
+            pixels: &mut block[..len],
+        };
+        fb.clear(background);
+        func(&mut fb);
+        fb.flush();
+    }
+    true
+}
+
+/// Number of rows of `width` pixels fitting into a pool block.
+fn band_rows(width: i16) -> i16 {
+    let row_bytes = width.max(1) as usize * PIXEL_BYTES;
+    (BLOCK_SIZE / row_bytes).min(i16::MAX as usize) as i16
+}
+
+/// Split `area` into horizontal bands of at most `rows` rows, top to bottom.
+fn bands(area: Rect, rows: i16) -> impl Iterator<Item = Rect> {
+    (area.y0..area.y1).step_by(rows as usize).map(move |y0| {
+        Rect::new(
+            Point::new(area.x0, y0),
+            Point::new(area.x1, y0.saturating_add(rows).min(area.y1)),
+        )
+    })
+}
+
+/// RGB565 pixels of a band of the screen, in the byte order of the panel.
+/// Drawing only changes the buffer, see `render`.
+pub struct FrameBuffer<'a> {
+    area: Rect,
+    pixels: &'a mut [u8],
+}
+
+impl FrameBuffer<'_> {
+    /// Part of the screen covered by the buffer.
+    pub fn area(&self) -> Rect {
+        self.area
+    }
+
+    pub fn clear(&mut self, color: Color) {
+        let bytes = u16::from(color).to_le_bytes();
+        for pixel in self.pixels.chunks_exact_mut(PIXEL_BYTES) {
+            pixel.copy_from_slice(&bytes);
+        }
+    }
+
+    /// Fill the part of `r` within the buffer.
+    pub fn rect_fill(&mut self, r: Rect, color: Color) {
+        let r = r.clamp(self.area);
+        for y in r.y0..r.y1 {
+            for x in r.x0..r.x1 {
+                self.set_pixel(Point::new(x, y), color);
+            }
+        }
+    }
+
+    /// Set a single pixel, ignored if outside of the buffer.
+    pub fn pixel(&mut self, p: Point, color: Color) {
+        if self.area.contains(p) {
+            self.set_pixel(p, color);
+        }
+    }
+
+    /// Draw single-byte characters of `text`, same as `display::text`.
+    pub fn text(
+        &mut self,
+        baseline: Point,
+        text: &str,
+        font: Font,
+        fg_color: Color,
+        bg_color: Color,
+    ) {
+        let colortable = get_color_table(fg_color, bg_color);
+        let mut pos = baseline;
+        for byte in text.bytes() {
+            if let Some(glyph) = font.get_glyph(byte) {
+                let top_left = pos + Offset::new(glyph.bearing_x, -glyph.bearing_y);
+                let glyph_area =
+                    Rect::from_top_left_and_size(top_left, Offset::new(glyph.width, glyph.height));
+                let r = glyph_area.clamp(self.area);
+                for y in r.y0..r.y1 {
+                    for x in r.x0..r.x1 {
+                        let p = Point::new(x, y);
+                        let c = glyph.get_pixel_data(p - top_left);
+                        self.set_pixel(p, colortable[c as usize]);
+                    }
+                }
+                pos = pos + Offset::x(glyph.adv);
+            }
+        }
+    }
+
+    /// Write the buffer to its area of the panel. Nothing is written if the
+    /// area does not fit on the screen, cropping the rows would need a
+    /// separate window for each of them.
+    fn flush(&self) {
+        let window = self.area.translate(get_offset());
+        if window.clamp(constant::screen()) != window {
+            return;
+        }
+        set_window(window);
+        self.write_pixels();
+        pixeldata_dirty();
+    }
+
+    #[cfg(feature = "dma2d")]
+    fn write_pixels(&self) {
+        let row_bytes = self.area.width() as usize * PIXEL_BYTES;
+        dma2d_setup_16bpp();
+        for row in self.pixels.chunks(row_bytes) {
+            dma2d_wait_for_transfer();
+            dma2d_start(row, self.area.width());
+        }
+        dma2d_wait_for_transfer();
+    }
+
+    #[cfg(not(feature = "dma2d"))]
+    fn write_pixels(&self) {
+        for pixel in self.pixels.chunks_exact(PIXEL_BYTES) {
+            pixeldata(u16::from_le_bytes([pixel[0], pixel[1]]).into());
+        }
+    }
+
+    fn set_pixel(&mut self, p: Point, color: Color) {
+        let offset = p - self.area.top_left();
+        let index = offset.y as usize * self.area.width() as usize + offset.x as usize;
+        let start = index * PIXEL_BYTES;
+        self.pixels[start..start + PIXEL_BYTES].copy_from_slice(&u16::from(color).to_le_bytes());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BLACK: Color = Color::rgb(0, 0, 0);
+    const WHITE: Color = Color::rgb(0xFF, 0xFF, 0xFF);
+
+    #[test]
+    fn framebuffer_fill() {
+        let area = Rect::new(Point::new(10, 10), Point::new(14, 12));
+        let mut pixels = [0u8; 8 * PIXEL_BYTES];
+        let mut fb = FrameBuffer {
+            area,
+            pixels: &mut pixels,
+        };
+        fb.clear(BLACK);
+        fb.rect_fill(Rect::new(Point::new(0, 0), Point::new(12, 11)), WHITE);
+        fb.pixel(Point::new(13, 11), WHITE);
+        fb.pixel(Point::new(14, 11), WHITE);
+
+        let filled: std::vec::Vec<bool> = pixels
+            .chunks_exact(PIXEL_BYTES)
+            .map(|p| u16::from_le_bytes([p[0], p[1]]) == u16::from(WHITE))
+            .collect();
+        assert_eq!(
+            filled,
+            [true, true, false, false, false, false, false, true]
+        );
+    }
+
+    #[test]
+    fn framebuffer_bands() {
+        // Rows of the screen width fit a block twice.
+        assert_eq!(band_rows(240), 2);
+        assert_eq!(band_rows(20), 25);
+        assert_eq!(band_rows(1000), 0);
+
+        let area = Rect::new(Point::new(0, 10), Point::new(20, 15));
+        let bands: std::vec::Vec<Rect> = bands(area, 2).collect();
+        assert_eq!(bands.len(), 3);
+        assert!(bands[0] == Rect::new(Point::new(0, 10), Point::new(20, 12)));
+        assert!(bands[2] == Rect::new(Point::new(0, 14), Point::new(20, 15)));
+    }
+}
@@ -0,0 +1,198 @@
+//! Blockies identicons, small symmetric pictures derived from an address, the
+//! same as the ones shown by Ethereum wallets and block explorers. Comparing
+//! the picture with the one on the host is a quick check in addition to
+//! reading the address. Wallets seed the generator with the lowercase hex
+//! address, callers have to do the same to get matching pictures.
+
+use crate::ui::geometry::{Offset, Point, Rect};
+
+use super::{rect_fill, Color};
+
+/// Number of cells on each side.
+pub const SIZE: usize = 8;
+
+/// Cells of the left half, the right half mirrors them.
+const HALF: usize = (SIZE + 1) / 2;
+
+/// Fractional bits of the fixed-point numbers the colors are computed with,
+/// enough for the same results as the floating-point reference.
+const FRAC_BITS: u32 = 28;
+
+/// One in the fixed-point representation.
+const ONE: u64 = 1 << FRAC_BITS;
+
+/// Xorshift generator of the reference implementation, seeded by the address.
+struct Rand {
+    seed: [i32; 4],
+}
+
+impl Rand {
+    fn new(seed: &str) -> Self {
+        let mut state = [0i32; 4];
+        for (i, unit) in seed.encode_utf16().enumerate() {
+            let s = &mut state[i % 4];
+            *s = (*s << 5).wrapping_sub(*s).wrapping_add(unit as i32);
+        }
+        Self { seed: state }
+    }
+
+    /// Next unsigned state, the reference implementation divides it by 2^31
+    /// to get a number in `[0, 2)`.
+    fn next(&mut self) -> u64 {
+        let [s0, s1, s2, s3] = self.seed;
+        let t = s0 ^ (s0 << 11);
+        let next = s3 ^ (s3 >> 19) ^ t ^ (t >> 8);
+        self.seed = [s1, s2, s3, next];
+        next as u32 as u64
+    }
+
+    /// Random color, the same as CSS `hsl()` of the reference implementation
+    /// with out-of-range saturation and lightness clamped.
+    fn color(&mut self) -> Color {
+        let hue = ((self.next() * 360) >> 31) % 360;
+        let saturation = (to_fixed(self.next() * 60) + 40 * ONE) / 100;
+        let lightness = to_fixed((0..4).map(|_| self.next()).sum::<u64>() * 25) / 100;
+        let (r, g, b) = hsl_to_rgb(hue, saturation.min(ONE), lightness.min(ONE));
+        Color::rgb(r, g, b)
+    }
+}
+
+/// Fixed-point representation of `value / 2^31`.
+fn to_fixed(value: u64) -> u64 {
+    value >> (31 - FRAC_BITS)
+}
+
+/// Convert `hue` in degrees, `saturation` and `lightness` in fixed point to
+/// RGB channels.
+fn hsl_to_rgb(hue: u64, saturation: u64, lightness: u64) -> (u8, u8, u8) {
+    let t2 = if lightness <= ONE / 2 {
+        (lightness * (saturation + ONE)) >> FRAC_BITS
+    } else {
+        lightness + saturation - ((lightness * saturation) >> FRAC_BITS)
+    };
+    let t1 = lightness * 2 - t2;
+    let channel = |hue: u64| {
+        let hue = if hue > 360 { hue - 360 } else { hue };
+        let value = if hue < 60 {
+            t1 + (t2 - t1) * hue / 60
+        } else if hue < 180 {
+            t2
+        } else if hue < 240 {
+            t1 + (t2 - t1) * (240 - hue) / 60
+        } else {
+            t1
+        };
+        ((value * 255 + ONE / 2) >> FRAC_BITS) as u8
+    };
+    (channel(hue + 120), channel(hue), channel(hue + 240))
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum Cell {
+    Background,
+    Foreground,
+    Spot,
+}
+
+pub struct Identicon {
+    color: Color,
+    background: Color,
+    spot: Color,
+    cells: [[Cell; SIZE]; SIZE],
+}
+
+impl Identicon {
+    pub fn new(seed: &str) -> Self {
+        let mut rand = Rand::new(seed);
+        let color = rand.color();
+        let background = rand.color();
+        let spot = rand.color();
+        let mut cells = [[Cell::Background; SIZE]; SIZE];
+        for row in cells.iter_mut() {
+            for x in 0..HALF {
+                // Reference multiplies the number in `[0, 2)` by 2.3.
+                let cell = match rand.next() * 23 / (10 << 31) {
+                    0 => Cell::Background,
+                    1 => Cell::Foreground,
+                    _ => Cell::Spot,
+                };
+                row[x] = cell;
+                row[SIZE - 1 - x] = cell;
+            }
+        }
+        Self {
+            color,
+            background,
+            spot,
+            cells,
+        }
+    }
+
+    pub fn cell_color(&self, x: usize, y: usize) -> Color {
+        match self.cells[y][x] {
+            Cell::Background => self.background,
+            Cell::Foreground => self.color,
+            Cell::Spot => self.spot,
+        }
+    }
+
+    /// Paint the identicon with `cell_size` pixels per cell, its top left
+    /// corner at `top_left`.
+    pub fn paint(&self, top_left: Point, cell_size: i16) {
+        for y in 0..SIZE {
+            for x in 0..SIZE {
+                let cell_top_left =
+                    top_left + Offset::new(x as i16 * cell_size, y as i16 * cell_size);
+                let cell = Rect::from_top_left_and_size(cell_top_left, Offset::uniform(cell_size));
+                rect_fill(cell, self.cell_color(x, y));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identicon_pattern() {
+        use Cell::{Background as B, Foreground as F, Spot as S};
+
+        // Same as the reference implementation.
+        let identicon = Identicon::new("0xfb6916095ca1df60bb79ce92ce3ea74c37c5d359");
+        assert_eq!(identicon.cells[0], [F, B, B, B, B, B, B, F]);
+        assert_eq!(identicon.cells[3], [F, S, F, F, F, F, S, F]);
+        assert_eq!(identicon.cells[5], [B, S, F, S, S, F, S, B]);
+        for row in identicon.cells.iter() {
+            assert!(row.iter().eq(row.iter().rev()));
+        }
+
+        let other = Identicon::new("0xfb6916095ca1df60bb79ce92ce3ea74c37c5d358");
+        assert!(identicon.cells != other.cells);
+    }
+
+    #[test]
+    fn identicon_colors() {
+        // Same as the reference implementation.
+        let identicon = Identicon::new("0xfb6916095ca1df60bb79ce92ce3ea74c37c5d359");
+        assert_eq!(identicon.color, Color::rgb(250, 173, 21));
+        assert_eq!(identicon.background, Color::rgb(231, 237, 51));
+        assert_eq!(identicon.spot, Color::rgb(115, 105, 248));
+        assert_eq!(identicon.cell_color(0, 0), identicon.color);
+        assert_eq!(identicon.cell_color(1, 0), identicon.background);
+        assert_eq!(identicon.cell_color(1, 3), identicon.spot);
+    }
+
+    #[test]
+    fn identicon_hsl() {
+        let half = ONE / 2;
+        assert_eq!(hsl_to_rgb(0, ONE, half), (255, 0, 0));
+        assert_eq!(hsl_to_rgb(120, ONE, half), (0, 255, 0));
+        assert_eq!(hsl_to_rgb(240, ONE, half), (0, 0, 255));
+        assert_eq!(hsl_to_rgb(60, ONE, half), (255, 255, 0));
+        assert_eq!(hsl_to_rgb(30, ONE, half), (255, 128, 0));
+        assert_eq!(hsl_to_rgb(200, 0, half), (128, 128, 128));
+        assert_eq!(hsl_to_rgb(200, ONE, ONE), (255, 255, 255));
+        assert_eq!(hsl_to_rgb(200, ONE, 0), (0, 0, 0));
+    }
+}
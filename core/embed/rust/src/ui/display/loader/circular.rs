@@ -1,6 +1,6 @@
 use crate::ui::{
     constant, display,
-    display::Color,
+    display::{Color, LoaderGeometry},
     geometry::{Offset, Point, Rect},
 };
 
@@ -10,26 +10,55 @@ use crate::trezorhal::{
     dma2d::{dma2d_setup_4bpp_over_4bpp, dma2d_start_blend, dma2d_wait_for_transfer},
 };
 
-use crate::ui::{
-    constant::{screen, LOADER_OUTER},
-    display::toif::Icon,
-};
-
-const LOADER_SIZE: i32 = (LOADER_OUTER * 2.0) as i32;
+use crate::ui::display::toif::Icon;
 
-const OUTER: f32 = constant::LOADER_OUTER;
-const INNER: f32 = constant::LOADER_INNER;
 const ICON_MAX_SIZE: i16 = constant::LOADER_ICON_MAX_SIZE;
 
-const IN_INNER_ANTI: i32 = ((INNER - 0.5) * (INNER - 0.5)) as i32;
-const INNER_MIN: i32 = ((INNER + 0.5) * (INNER + 0.5)) as i32;
-const INNER_MAX: i32 = ((INNER + 1.5) * (INNER + 1.5)) as i32;
-const INNER_OUTER_ANTI: i32 = ((INNER + 2.5) * (INNER + 2.5)) as i32;
-const OUTER_OUT_ANTI: i32 = ((OUTER - 1.5) * (OUTER - 1.5)) as i32;
-const OUTER_MAX: i32 = ((OUTER - 0.5) * (OUTER - 0.5)) as i32;
+/// Squared distances from the center at which the parts of the ring and their
+/// antialiased edges begin and end.
+struct Radii {
+    in_inner_anti: i32,
+    inner_min: i32,
+    inner_max: i32,
+    inner_outer_anti: i32,
+    outer_out_anti: i32,
+    outer_max: i32,
+}
+
+impl Radii {
+    fn new(geometry: LoaderGeometry) -> Self {
+        let outer = geometry.radius as f32;
+        let inner = geometry.inner_radius() as f32;
+        let squared = |r: f32| (r * r) as i32;
+        Self {
+            in_inner_anti: squared(inner - 0.5),
+            inner_min: squared(inner + 0.5),
+            inner_max: squared(inner + 1.5),
+            inner_outer_anti: squared(inner + 2.5),
+            outer_out_anti: squared(outer - 1.5),
+            outer_max: squared(outer - 0.5),
+        }
+    }
+}
+
+/// Parts of the square `r` around the loader which get drawn, each in its own
+/// window. With `keep_center`, the ring around `center_area` is split into
+/// the parts above, left of, right of and below it.
+fn loader_parts(geometry: LoaderGeometry, r: Rect) -> [Rect; 4] {
+    if geometry.keep_center {
+        let hole = geometry.center_area();
+        let (top, rest) = r.split_top(hole.y0 - r.y0);
+        let (middle, bottom) = rest.split_top(hole.height());
+        let (left, rest) = middle.split_left(hole.x0 - r.x0);
+        let (_, right) = rest.split_left(hole.width());
+        [top, left, right, bottom]
+    } else {
+        [r, Rect::zero(), Rect::zero(), Rect::zero()]
+    }
+}
 
 pub fn loader_circular_uncompress(
-    y_offset: i16,
+    geometry: LoaderGeometry,
     fg_color: Color,
     bg_color: Color,
     progress: u16,
@@ -44,33 +73,33 @@ pub fn loader_circular_uncompress(
             let mut icon_data = [0_u8; ((ICON_MAX_SIZE * ICON_MAX_SIZE) / 2) as usize];
             icon.toif.uncompress(&mut icon_data);
             let i = Some((icon_data.as_ref(), color, toif_size));
-            loader_rust(y_offset, fg_color, bg_color, progress, indeterminate, i);
+            loader_rust(geometry, fg_color, bg_color, progress, indeterminate, i);
         } else {
-            loader_rust(y_offset, fg_color, bg_color, progress, indeterminate, None);
+            loader_rust(geometry, fg_color, bg_color, progress, indeterminate, None);
         }
     } else {
-        loader_rust(y_offset, fg_color, bg_color, progress, indeterminate, None);
+        loader_rust(geometry, fg_color, bg_color, progress, indeterminate, None);
     }
 }
 
 pub fn loader_circular(
     progress: u16,
-    y_offset: i16,
+    geometry: LoaderGeometry,
     fg_color: Color,
     bg_color: Color,
     icon: Option<(Icon, Color)>,
 ) {
-    loader_circular_uncompress(y_offset, fg_color, bg_color, progress, false, icon);
+    loader_circular_uncompress(geometry, fg_color, bg_color, progress, false, icon);
 }
 
 pub fn loader_circular_indeterminate(
     progress: u16,
-    y_offset: i16,
+    geometry: LoaderGeometry,
     fg_color: Color,
     bg_color: Color,
     icon: Option<(Icon, Color)>,
 ) {
-    loader_circular_uncompress(y_offset, fg_color, bg_color, progress, true, icon);
+    loader_circular_uncompress(geometry, fg_color, bg_color, progress, true, icon);
 }
 
 #[inline(always)]
@@ -110,6 +139,7 @@ fn get_loader_vectors(indeterminate: bool, progress: u16) -> (Point, Point) {
 
 #[inline(always)]
 fn loader_get_pixel_color_idx(
+    radii: &Radii,
     show_all: bool,
     inverted: bool,
     end_vector: Point,
@@ -142,31 +172,32 @@ fn loader_get_pixel_color_idx(
     // - r_inner)/(r_outer-r_inner) is negligible
     if show_all || included {
         //active part
-        if d <= IN_INNER_ANTI {
+        if d <= radii.in_inner_anti {
             0
-        } else if d <= INNER_MIN {
-            ((15 * (d - IN_INNER_ANTI)) / (INNER_MIN - IN_INNER_ANTI)) as u8
-        } else if d <= OUTER_OUT_ANTI {
+        } else if d <= radii.inner_min {
+            ((15 * (d - radii.in_inner_anti)) / (radii.inner_min - radii.in_inner_anti)) as u8
+        } else if d <= radii.outer_out_anti {
             15
-        } else if d <= OUTER_MAX {
-            (15 - ((15 * (d - OUTER_OUT_ANTI)) / (OUTER_MAX - OUTER_OUT_ANTI))) as u8
+        } else if d <= radii.outer_max {
+            (15 - ((15 * (d - radii.outer_out_anti)) / (radii.outer_max - radii.outer_out_anti)))
+                as u8
         } else {
             0
         }
     } else {
         //inactive part
-        if d <= IN_INNER_ANTI {
+        if d <= radii.in_inner_anti {
             0
-        } else if d <= INNER_MIN {
-            ((15 * (d - IN_INNER_ANTI)) / (INNER_MIN - IN_INNER_ANTI)) as u8
-        } else if d <= INNER_MAX {
+        } else if d <= radii.inner_min {
+            ((15 * (d - radii.in_inner_anti)) / (radii.inner_min - radii.in_inner_anti)) as u8
+        } else if d <= radii.inner_max {
             15
-        } else if d <= INNER_OUTER_ANTI {
-            (15 - ((10 * (d - INNER_MAX)) / (INNER_OUTER_ANTI - INNER_MAX))) as u8
-        } else if d <= OUTER_OUT_ANTI {
+        } else if d <= radii.inner_outer_anti {
+            (15 - ((10 * (d - radii.inner_max)) / (radii.inner_outer_anti - radii.inner_max))) as u8
+        } else if d <= radii.outer_out_anti {
             5
-        } else if d <= OUTER_MAX {
-            5 - ((5 * (d - OUTER_OUT_ANTI)) / (OUTER_MAX - OUTER_OUT_ANTI)) as u8
+        } else if d <= radii.outer_max {
+            5 - ((5 * (d - radii.outer_out_anti)) / (radii.outer_max - radii.outer_out_anti)) as u8
         } else {
             0
         }
@@ -175,19 +206,16 @@ fn loader_get_pixel_color_idx(
 
 #[cfg(not(feature = "dma2d"))]
 pub fn loader_rust(
-    y_offset: i16,
+    geometry: LoaderGeometry,
     fg_color: Color,
     bg_color: Color,
     progress: u16,
     indeterminate: bool,
     icon: Option<(&[u8], Color, Offset)>,
 ) {
-    let center = screen().center() + Offset::new(0, y_offset);
-    let r = Rect::from_center_and_size(center, Offset::uniform(LOADER_OUTER as i16 * 2));
-    let clamped = r.clamp(constant::screen());
-    display::set_window(clamped);
-
-    let center = r.center();
+    let radii = Radii::new(geometry);
+    let center = geometry.center();
+    let r = Rect::from_center_and_size(center, Offset::uniform(geometry.radius * 2));
 
     let colortable = display::get_color_table(fg_color, bg_color);
     let mut icon_colortable = colortable;
@@ -198,7 +226,7 @@ pub fn loader_rust(
     let mut icon_width = 0;
     let mut icon_data = [].as_ref();
 
-    if let Some((data, color, size)) = icon {
+    if let Some((data, color, size)) = icon.filter(|_| !geometry.keep_center) {
         if size.x <= ICON_MAX_SIZE && size.y <= ICON_MAX_SIZE {
             icon_width = size.x;
             icon_area = Rect::from_center_and_size(center, size);
@@ -215,38 +243,47 @@ pub fn loader_rust(
 
     let n_start = Point::new(-start_vector.y, start_vector.x);
 
-    for y_c in r.y0..r.y1 {
-        for x_c in r.x0..r.x1 {
-            let p = Point::new(x_c, y_c);
-            let mut icon_pixel = false;
-
-            let mut underlying_color = bg_color;
-
-            if use_icon && icon_area_clamped.contains(p) {
-                let x = x_c - center.x;
-                let y = y_c - center.y;
-                if (x as i32 * x as i32 + y as i32 * y as i32) <= IN_INNER_ANTI {
-                    let x_i = x_c - icon_area.x0;
-                    let y_i = y_c - icon_area.y0;
-
-                    let data = icon_data[(((x_i & 0xFE) + (y_i * icon_width)) / 2) as usize];
-                    if (x_i & 0x01) == 0 {
-                        underlying_color = icon_colortable[(data & 0xF) as usize];
-                    } else {
-                        underlying_color = icon_colortable[(data >> 4) as usize];
+    for part in loader_parts(geometry, r) {
+        let clamped = part.clamp(constant::screen());
+        if clamped.width() <= 0 || clamped.height() <= 0 {
+            continue;
+        }
+        display::set_window(clamped);
+
+        for y_c in clamped.y0..clamped.y1 {
+            for x_c in clamped.x0..clamped.x1 {
+                let p = Point::new(x_c, y_c);
+                let mut icon_pixel = false;
+
+                let mut underlying_color = bg_color;
+
+                if use_icon && icon_area_clamped.contains(p) {
+                    let x = x_c - center.x;
+                    let y = y_c - center.y;
+                    if (x as i32 * x as i32 + y as i32 * y as i32) <= radii.in_inner_anti {
+                        let x_i = x_c - icon_area.x0;
+                        let y_i = y_c - icon_area.y0;
+
+                        let data = icon_data[(((x_i & 0xFE) + (y_i * icon_width)) / 2) as usize];
+                        let level = if (x_i & 0x01) == 0 {
+                            data & 0xF
+                        } else {
+                            data >> 4
+                        };
+                        underlying_color = display::grayscale_pixel(&icon_colortable, level, p);
+                        icon_pixel = true;
                     }
-                    icon_pixel = true;
                 }
-            }
 
-            if clamped.contains(p) && !icon_pixel {
-                let pix_c_idx = loader_get_pixel_color_idx(
-                    show_all, inverted, end_vector, n_start, x_c, y_c, center,
-                );
-                underlying_color = colortable[pix_c_idx as usize];
-            }
+                if !icon_pixel {
+                    let pix_c_idx = loader_get_pixel_color_idx(
+                        &radii, show_all, inverted, end_vector, n_start, x_c, y_c, center,
+                    );
+                    underlying_color = colortable[pix_c_idx as usize];
+                }
 
-            display::pixeldata(underlying_color);
+                display::pixeldata(underlying_color);
+            }
         }
     }
 
@@ -255,19 +292,16 @@ pub fn loader_rust(
 
 #[cfg(feature = "dma2d")]
 pub fn loader_rust(
-    y_offset: i16,
+    geometry: LoaderGeometry,
     fg_color: Color,
     bg_color: Color,
     progress: u16,
     indeterminate: bool,
     icon: Option<(&[u8], Color, Offset)>,
 ) {
-    let center = screen().center() + Offset::new(0, y_offset);
-    let r = Rect::from_center_and_size(center, Offset::uniform(LOADER_OUTER as i16 * 2));
-    let clamped = r.clamp(constant::screen());
-    display::set_window(clamped);
-
-    let center = r.center();
+    let radii = Radii::new(geometry);
+    let center = geometry.center();
+    let r = Rect::from_center_and_size(center, Offset::uniform(geometry.radius * 2));
 
     let mut use_icon = false;
     let mut icon_area = Rect::zero();
@@ -277,12 +311,12 @@ pub fn loader_rust(
     let mut icon_color = Color::from_u16(0);
     let mut icon_data = [].as_ref();
 
-    if let Some((data, color, size)) = icon {
+    if let Some((data, color, size)) = icon.filter(|_| !geometry.keep_center) {
         if size.x <= ICON_MAX_SIZE && size.y <= ICON_MAX_SIZE {
             icon_width = size.x;
             icon_area = Rect::from_center_and_size(center, size);
             icon_area_clamped = icon_area.clamp(constant::screen());
-            icon_offset = (icon_area_clamped.x0 - r.x0) / 2;
+            icon_offset = (icon_area_clamped.x0 - r.clamp(constant::screen()).x0) / 2;
             icon_color = color;
             icon_data = data;
             use_icon = true;
@@ -303,60 +337,68 @@ pub fn loader_rust(
 
     dma2d_setup_4bpp_over_4bpp(fg_color.into(), bg_color.into(), icon_color.into());
 
-    for y_c in r.y0..r.y1 {
-        let mut icon_buffer = &mut *empty_line;
-        let icon_buffer_used;
-        let loader_buffer;
-
-        if y_c % 2 == 0 {
-            icon_buffer_used = &mut *ib1;
-            loader_buffer = &mut *b1;
-        } else {
-            icon_buffer_used = &mut *ib2;
-            loader_buffer = &mut *b2;
+    for part in loader_parts(geometry, r) {
+        let clamped = part.clamp(constant::screen());
+        if clamped.width() <= 0 || clamped.height() <= 0 {
+            continue;
         }
+        dma2d_wait_for_transfer();
+        display::set_window(clamped);
 
-        if use_icon && y_c >= icon_area_clamped.y0 && y_c < icon_area_clamped.y1 {
-            let y_i = y_c - icon_area.y0;
-
-            // Optimally, we should cut corners of the icon if it happens to be large enough
-            // to invade loader area. but this would require calculation of circle chord
-            // length (since we need to limit data copied to the buffer),
-            // which requires expensive SQRT. Therefore, when using this method of loader
-            // drawing, special care needs to be taken to ensure that the icons
-            // have transparent corners.
+        for y_c in clamped.y0..clamped.y1 {
+            let mut icon_buffer = &mut *empty_line;
+            let icon_buffer_used;
+            let loader_buffer;
 
-            icon_buffer_used.buffer[icon_offset as usize..(icon_offset + icon_width / 2) as usize]
-                .copy_from_slice(
-                    &icon_data[(y_i * (icon_width / 2)) as usize
-                        ..((y_i + 1) * (icon_width / 2)) as usize],
-                );
-            icon_buffer = icon_buffer_used;
-        }
+            if y_c % 2 == 0 {
+                icon_buffer_used = &mut *ib1;
+                loader_buffer = &mut *b1;
+            } else {
+                icon_buffer_used = &mut *ib2;
+                loader_buffer = &mut *b2;
+            }
 
-        let mut pix_c_idx_prev: u8 = 0;
+            if use_icon && y_c >= icon_area_clamped.y0 && y_c < icon_area_clamped.y1 {
+                let y_i = y_c - icon_area.y0;
+
+                // Optimally, we should cut corners of the icon if it happens to be large
+                // enough to invade loader area. but this would require calculation of
+                // circle chord length (since we need to limit data copied to the
+                // buffer), which requires expensive SQRT. Therefore, when using this
+                // method of loader drawing, special care needs to be taken to ensure
+                // that the icons have transparent corners.
+
+                icon_buffer_used.buffer
+                    [icon_offset as usize..(icon_offset + icon_width / 2) as usize]
+                    .copy_from_slice(
+                        &icon_data[(y_i * (icon_width / 2)) as usize
+                            ..((y_i + 1) * (icon_width / 2)) as usize],
+                    );
+                icon_buffer = icon_buffer_used;
+            }
 
-        for x_c in r.x0..r.x1 {
-            let p = Point::new(x_c, y_c);
+            let mut pix_c_idx_prev: u8 = 0;
 
-            let pix_c_idx = if clamped.contains(p) {
-                loader_get_pixel_color_idx(
-                    show_all, inverted, end_vector, n_start, x_c, y_c, center,
-                )
-            } else {
-                0
-            };
+            for x_c in clamped.x0..clamped.x1 {
+                let pix_c_idx = loader_get_pixel_color_idx(
+                    &radii, show_all, inverted, end_vector, n_start, x_c, y_c, center,
+                );
 
-            let x = x_c - r.x0;
-            if x % 2 == 0 {
-                pix_c_idx_prev = pix_c_idx;
-            } else {
-                loader_buffer.buffer[(x >> 1) as usize] = pix_c_idx_prev | pix_c_idx << 4;
+                let x = x_c - clamped.x0;
+                if x % 2 == 0 {
+                    pix_c_idx_prev = pix_c_idx;
+                } else {
+                    loader_buffer.buffer[(x >> 1) as usize] = pix_c_idx_prev | pix_c_idx << 4;
+                }
+            }
+            // The parts next to the kept center can be an odd number of pixels wide.
+            if clamped.width() % 2 == 1 {
+                loader_buffer.buffer[(clamped.width() >> 1) as usize] = pix_c_idx_prev;
             }
-        }
 
-        dma2d_wait_for_transfer();
-        dma2d_start_blend(&icon_buffer.buffer, &loader_buffer.buffer, clamped.width());
+            dma2d_wait_for_transfer();
+            dma2d_start_blend(&icon_buffer.buffer, &loader_buffer.buffer, clamped.width());
+        }
     }
 
     dma2d_wait_for_transfer();
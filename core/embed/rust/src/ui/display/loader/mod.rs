@@ -1,10 +1,18 @@
 mod circular;
+mod percent;
 mod rectangular;
 mod starry;
 
-use crate::ui::display::{Color, Icon};
+use crate::ui::{
+    constant::screen,
+    display::{Color, Icon},
+    geometry::{Offset, Point, Rect},
+    theme::{LOADER, PROGRESS_LOADER},
+};
 use core::slice::from_raw_parts;
 
+pub use percent::LoaderPercent;
+
 #[cfg(feature = "model_tt")]
 use crate::ui::display::loader::circular::{
     loader_circular as determinate, loader_circular_indeterminate as indeterminate,
@@ -17,26 +25,87 @@ use crate::ui::display::loader::starry::loader_starry_indeterminate as indetermi
 pub const LOADER_MIN: u16 = 0;
 pub const LOADER_MAX: u16 = 1000;
 
+/// Size and position of the loader, see `theme::LOADER` of the model for the
+/// default one.
+#[derive(Copy, Clone)]
+pub struct LoaderGeometry {
+    /// Outer radius of the circular loader. Other loaders use it as the size
+    /// of the whole animation.
+    pub radius: i16,
+    /// Width of the ring of the circular loader. The remaining inner part is
+    /// the size of the rectangular loader.
+    pub thickness: i16,
+    /// Vertical offset of the center from the center of the screen.
+    pub y_offset: i16,
+    /// Leave `center_area` untouched when drawing the circular loader, so
+    /// that its content does not need to be repainted, see `LoaderPercent`.
+    /// The icon is not drawn then, the center is up to the caller.
+    pub keep_center: bool,
+}
+
+impl LoaderGeometry {
+    pub const fn new(radius: i16, thickness: i16) -> Self {
+        Self {
+            radius,
+            thickness,
+            y_offset: 0,
+            keep_center: false,
+        }
+    }
+
+    pub const fn with_y_offset(self, y_offset: i16) -> Self {
+        Self { y_offset, ..self }
+    }
+
+    pub const fn with_center_kept(self) -> Self {
+        Self {
+            keep_center: true,
+            ..self
+        }
+    }
+
+    pub const fn inner_radius(&self) -> i16 {
+        self.radius - self.thickness
+    }
+
+    pub fn center(&self) -> Point {
+        screen().center() + Offset::y(self.y_offset)
+    }
+
+    /// Square inscribed in the inner circle of the loader.
+    pub fn center_area(&self) -> Rect {
+        // Side of the inscribed square is sqrt(2) times the radius.
+        let side = self.inner_radius() * 14 / 10;
+        Rect::from_center_and_size(self.center(), Offset::uniform(side))
+    }
+}
+
 pub fn loader(
     progress: u16,
-    y_offset: i16,
+    geometry: LoaderGeometry,
     fg_color: Color,
     bg_color: Color,
     icon: Option<(Icon, Color)>,
 ) {
-    determinate(progress, y_offset, fg_color, bg_color, icon);
+    determinate(progress, geometry, fg_color, bg_color, icon);
 }
 
 pub fn loader_indeterminate(
     progress: u16,
-    y_offset: i16,
+    geometry: LoaderGeometry,
     fg_color: Color,
     bg_color: Color,
     icon: Option<(Icon, Color)>,
 ) {
-    indeterminate(progress, y_offset, fg_color, bg_color, icon);
+    indeterminate(progress, geometry, fg_color, bg_color, icon);
 }
 
+/// Vertical offset of the progress loader drawn by the C bootloader UI, see
+/// `theme::PROGRESS_LOADER`.
+//TODO: remove when loader is no longer called from C
+#[no_mangle]
+pub static PROGRESS_LOADER_Y_OFFSET: cty::int32_t = PROGRESS_LOADER.y_offset as _;
+
 //TODO: remove when loader is no longer called from C
 #[no_mangle]
 pub extern "C" fn loader_uncompress_r(
@@ -60,9 +129,10 @@ pub extern "C" fn loader_uncompress_r(
         None
     };
 
+    let geometry = LOADER.with_y_offset(y_offset as _);
     if indeterminate == 0 {
-        loader(progress as _, y_offset as _, fg, bg, i);
+        loader(progress as _, geometry, fg, bg, i);
     } else {
-        loader_indeterminate(progress as _, y_offset as _, fg, bg, i);
+        loader_indeterminate(progress as _, geometry, fg, bg, i);
     }
 }
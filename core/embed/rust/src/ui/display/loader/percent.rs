@@ -0,0 +1,141 @@
+use crate::ui::{
+    display::{self, framebuffer, Color, Font, Icon},
+    geometry::{Alignment, Offset, Point, Rect},
+};
+
+use super::{LoaderGeometry, LOADER_MAX};
+
+/// Space between the icon and the percentage below it.
+const ICON_SPACING: i16 = 4;
+/// Number of digits of the percentage, "100" being the longest.
+const DIGITS: usize = 3;
+
+/// Percentage of the progress drawn in `LoaderGeometry::center_area`,
+/// optionally below an icon. The loader needs to be drawn with the center kept,
+/// so that only the digits which changed since the last paint get repainted.
+pub struct LoaderPercent {
+    font: Font,
+    icon: Option<(Icon, Color)>,
+    /// Digits currently on the screen, with leading zeroes as spaces. `None` if
+    /// the whole center needs to be painted.
+    shown: Option<[u8; DIGITS]>,
+}
+
+impl LoaderPercent {
+    pub const fn new(font: Font) -> Self {
+        Self {
+            font,
+            icon: None,
+            shown: None,
+        }
+    }
+
+    pub fn with_icon(mut self, icon: Icon, color: Color) -> Self {
+        self.icon = Some((icon, color));
+        self
+    }
+
+    /// Paint the whole center on the next call to `paint`, e.g. after the
+    /// screen got cleared.
+    pub fn invalidate(&mut self) {
+        self.shown = None;
+    }
+
+    pub fn paint(&mut self, progress: u16, geometry: LoaderGeometry, fg: Color, bg: Color) {
+        let digits = Self::digits(progress);
+        // With DMA2D the loader is drawn including its center.
+        let full = self.shown.is_none() || cfg!(feature = "dma2d") || !geometry.keep_center;
+
+        let area = geometry.center_area();
+        let digit_width = ('0'..='9')
+            .map(|digit| self.font.char_width(digit))
+            .max()
+            .unwrap_or(0);
+        let sign_width = self.font.char_width('%');
+        let icon_height = self
+            .icon
+            .map_or(0, |(icon, _)| icon.toif.height() + ICON_SPACING);
+        let text_width = digit_width * DIGITS as i16 + sign_width;
+        let block_height = icon_height + self.font.ascent();
+        let top_left = Point::new(
+            area.center().x - text_width / 2,
+            area.center().y - block_height / 2,
+        );
+        let baseline = top_left.y + block_height;
+
+        if full {
+            display::rect_fill(area, bg);
+            if let Some((icon, color)) = self.icon {
+                icon.draw(
+                    Point::new(area.center().x, top_left.y),
+                    (Alignment::Center, Alignment::Start),
+                    color,
+                    bg,
+                );
+            }
+            display::text(
+                Point::new(top_left.x + digit_width * DIGITS as i16, baseline),
+                "%",
+                self.font,
+                fg,
+                bg,
+            );
+        }
+
+        for (i, &digit) in digits.iter().enumerate() {
+            if !full && self.shown.map_or(false, |shown| shown[i] == digit) {
+                continue;
+            }
+            let cell = Rect::from_top_left_and_size(
+                Point::new(
+                    top_left.x + digit_width * i as i16,
+                    baseline - self.font.ascent(),
+                ),
+                Offset::new(digit_width, self.font.ascent()),
+            );
+            let digit = char::from(digit);
+            let x = cell.x0 + (digit_width - self.font.char_width(digit)) / 2;
+            let mut buf = [0; 4];
+            let text = digit.encode_utf8(&mut buf);
+            // Render the cell off-screen so that the digit is not blinking when
+            // repainted on every progress change.
+            let rendered = framebuffer::render(cell, bg, |fb| {
+                fb.text(Point::new(x, baseline), text, self.font, fg, bg)
+            });
+            if !rendered {
+                display::rect_fill(cell, bg);
+                display::text(Point::new(x, baseline), text, self.font, fg, bg);
+            }
+        }
+        self.shown = Some(digits);
+    }
+
+    /// Decimal digits of the percentage, without leading zeroes.
+    fn digits(progress: u16) -> [u8; DIGITS] {
+        let mut percent = progress.min(LOADER_MAX) / (LOADER_MAX / 100);
+        let mut digits = [b' '; DIGITS];
+        for digit in digits.iter_mut().rev() {
+            *digit = b'0' + (percent % 10) as u8;
+            percent /= 10;
+            if percent == 0 {
+                break;
+            }
+        }
+        digits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loader_percent_digits() {
+        assert_eq!(&LoaderPercent::digits(0), b"  0");
+        assert_eq!(&LoaderPercent::digits(9), b"  0");
+        assert_eq!(&LoaderPercent::digits(10), b"  1");
+        assert_eq!(&LoaderPercent::digits(420), b" 42");
+        assert_eq!(&LoaderPercent::digits(1000), b"100");
+        assert_eq!(&LoaderPercent::digits(2000), b"100");
+    }
+}
@@ -1,19 +1,20 @@
 use crate::ui::{
-    constant::{screen, LOADER_INNER},
+    constant::screen,
     display,
-    display::{Color, Icon},
+    display::{Color, Icon, LoaderGeometry},
     geometry::{Offset, Rect},
 };
 
 pub fn loader_rectangular(
     progress: u16,
-    y_offset: i16,
+    geometry: LoaderGeometry,
     fg_color: Color,
     bg_color: Color,
     icon: Option<(Icon, Color)>,
 ) {
-    let area = Rect::from_center_and_size(screen().center(), Offset::uniform(LOADER_INNER as _))
-        .translate(Offset::y(y_offset));
+    let area =
+        Rect::from_center_and_size(screen().center(), Offset::uniform(geometry.inner_radius()))
+            .translate(Offset::y(geometry.y_offset));
 
     display::rect_rounded2_partial(
         area,
@@ -1,6 +1,6 @@
 use crate::ui::{
-    constant::{screen, LOADER_OUTER},
-    display::{rect_fill, rect_fill_rounded, rect_fill_rounded1, Color, Icon},
+    constant::screen,
+    display::{rect_fill, rect_fill_rounded, rect_fill_rounded1, Color, Icon, LoaderGeometry},
     geometry::{Offset, Point, Rect, CENTER},
 };
 use core::f32::consts::SQRT_2;
@@ -28,13 +28,13 @@ fn star_large(center: Point, fg: Color, bg: Color) {
 
 pub fn loader_starry_indeterminate(
     progress: u16,
-    y_offset: i16,
+    geometry: LoaderGeometry,
     fg_color: Color,
     bg_color: Color,
     icon: Option<(Icon, Color)>,
 ) {
-    let area = Rect::from_center_and_size(screen().center(), Offset::uniform(LOADER_OUTER as _))
-        .translate(Offset::y(y_offset));
+    let area = Rect::from_center_and_size(screen().center(), Offset::uniform(geometry.radius))
+        .translate(Offset::y(geometry.y_offset));
 
     rect_fill(area, bg_color);
 
@@ -1,3 +1,9 @@
+mod advance;
+#[cfg(feature = "ambient_light")]
+pub mod brightness;
+pub mod clip;
+pub mod framebuffer;
+pub mod identicon;
 pub mod loader;
 #[cfg(feature = "jpeg")]
 pub mod tjpgd;
@@ -14,22 +20,25 @@ use crate::trezorhal::{
         dma2d_setup_4bpp_over_16bpp, dma2d_setup_4bpp_over_4bpp, dma2d_start_blend,
         dma2d_wait_for_transfer,
     },
-    uzlib::UZLIB_WINDOW_SIZE,
 };
 #[cfg(not(feature = "dma2d"))]
 use crate::ui::geometry::TOP_LEFT;
+#[cfg(feature = "dma2d")]
+use crate::ui::pool;
 
 use crate::{
     error::Error,
     time::Duration,
     trezorhal::{buffers::get_text_buffer, display, qr, time, uzlib::UzlibContext},
-    ui::{component::image::Image, lerp::Lerp},
+    ui::{component::image::Image, grapheme, lerp::Lerp},
 };
 use core::slice;
 
 pub use crate::ui::display::toif::Icon;
 #[cfg(any(feature = "model_tt", feature = "model_tr"))]
-pub use loader::{loader, loader_indeterminate, LOADER_MAX, LOADER_MIN};
+pub use loader::{
+    loader, loader_indeterminate, LoaderGeometry, LoaderPercent, LOADER_MAX, LOADER_MIN,
+};
 
 pub fn backlight() -> i32 {
     display::backlight(-1)
@@ -39,10 +48,15 @@ pub fn set_backlight(val: i32) {
     display::backlight(val);
 }
 
+/// Blocking fade of the backlight to `target`. Components should use the
+/// non-blocking `component::BacklightFade` instead.
 pub fn fade_backlight(target: i32) {
     const BACKLIGHT_DELAY: Duration = Duration::from_millis(14);
     const BACKLIGHT_STEP: usize = 15;
 
+    #[cfg(feature = "ambient_light")]
+    brightness::pause();
+
     let current = backlight();
     if current < target {
         for val in (current..target).step_by(BACKLIGHT_STEP) {
@@ -55,9 +69,19 @@ pub fn fade_backlight(target: i32) {
             time::sleep(BACKLIGHT_DELAY);
         }
     }
+
+    #[cfg(feature = "ambient_light")]
+    brightness::resume();
 }
 
 pub fn rect_fill(r: Rect, fg_color: Color) {
+    let r = match clip::clip() {
+        Some(clip) => r.clamp(clip),
+        None => r,
+    };
+    if r.is_empty() {
+        return;
+    }
     display::bar(r.x0, r.y0, r.width(), r.height(), fg_color.into());
 }
 
@@ -142,7 +166,7 @@ impl<'a> TextOverlay<'a> {
 
             let p_inner = p_rel - char_area.top_left();
             let overlay_data = g.get_pixel_data(p_inner);
-            return Color::lerp(underlying, fg, overlay_data as f32 / 15_f32);
+            return Color::blend(underlying, fg, overlay_data);
         }
 
         underlying
@@ -271,11 +295,12 @@ pub fn rect_rounded2_partial(
                 let y_i = p.y - icon_area.y0;
 
                 let data = icon_data[(((x_i & 0xFE) + (y_i * icon_width)) / 2) as usize];
-                if (x_i & 0x01) == 0 {
-                    pixeldata(icon_colortable[(data & 0xF) as usize]);
+                let level = if (x_i & 0x01) == 0 {
+                    data & 0xF
                 } else {
-                    pixeldata(icon_colortable[(data >> 4) as usize]);
-                }
+                    data >> 4
+                };
+                pixeldata(grayscale_pixel(&icon_colortable, level, p));
                 icon_pixel = true;
             }
 
@@ -488,8 +513,8 @@ pub fn text_over_image(
 
     set_window(clamped);
 
-    let mut window = [0; UZLIB_WINDOW_SIZE];
-    let mut ctx = image.toif.decompression_context(Some(&mut window));
+    let mut window = unwrap!(pool::Block::alloc(), "UI pool exhausted");
+    let mut ctx = image.toif.decompression_context(Some(&mut *window));
 
     dma2d_setup_4bpp_over_16bpp(text_color.into());
 
@@ -597,11 +622,11 @@ pub fn icon_over_icon(
 
     set_window(clamped);
 
-    let mut window_bg = [0; UZLIB_WINDOW_SIZE];
-    let mut ctx_bg = UzlibContext::new(icon_bg.toif.zdata(), Some(&mut window_bg));
+    let mut window_bg = unwrap!(pool::Block::alloc(), "UI pool exhausted");
+    let mut ctx_bg = UzlibContext::new(icon_bg.toif.zdata(), Some(&mut *window_bg));
 
-    let mut window_fg = [0; UZLIB_WINDOW_SIZE];
-    let mut ctx_fg = UzlibContext::new(icon_fg.toif.zdata(), Some(&mut window_fg));
+    let mut window_fg = unwrap!(pool::Block::alloc(), "UI pool exhausted");
+    let mut ctx_fg = UzlibContext::new(icon_fg.toif.zdata(), Some(&mut *window_fg));
 
     dma2d_setup_4bpp_over_4bpp(color_icon_bg.into(), bg_color.into(), color_icon_fg.into());
 
@@ -793,8 +818,48 @@ pub fn dotted_line(start: Point, width: i16, color: Color) {
     }
 }
 
+/// Straight line from `start` to `end` (both inclusive), `thickness` pixels
+/// wide.
+pub fn line(start: Point, end: Point, thickness: i16, color: Color) {
+    let half = thickness / 2;
+    for p in line_points(start, end) {
+        display::bar(p.x - half, p.y - half, thickness, thickness, color.into());
+    }
+}
+
+/// Length of the line from `start` to `end` in drawn points, i.e. the larger
+/// of its horizontal and vertical extents.
+pub fn line_length(start: Point, end: Point) -> i16 {
+    let d = end - start;
+    d.x.abs().max(d.y.abs())
+}
+
+/// Point at `length` points along the line from `start` to `end`.
+pub fn line_point(start: Point, end: Point, length: i16) -> Point {
+    let total = line_length(start, end);
+    if total == 0 {
+        return start;
+    }
+    let length = length.clamp(0, total) as i32;
+    let d = end - start;
+    let scale = |v: i16| (v as i32 * length / total as i32) as i16;
+    start + Offset::new(scale(d.x), scale(d.y))
+}
+
+fn line_points(start: Point, end: Point) -> impl Iterator<Item = Point> {
+    (0..=line_length(start, end)).map(move |i| line_point(start, end, i))
+}
+
 pub fn qrcode(center: Point, data: &str, max_size: u32, case_sensitive: bool) -> Result<(), Error> {
-    qr::render_qrcode(center.x, center.y, data, max_size, case_sensitive)
+    let mut buffer = pool::Block::alloc().ok_or(Error::AllocationFailed)?;
+    qr::render_qrcode(
+        center.x,
+        center.y,
+        data,
+        max_size,
+        case_sensitive,
+        &mut buffer[..],
+    )
 }
 
 pub fn text(baseline: Point, text: &str, font: Font, fg_color: Color, bg_color: Color) {
@@ -859,22 +924,52 @@ pub fn sync() {
     display::sync();
 }
 
+pub fn refresh() {
+    display::refresh();
+}
+
 pub fn get_color_table(fg_color: Color, bg_color: Color) -> [Color; 16] {
     let mut table: [Color; 16] = [Color::from_u16(0); 16];
 
     for (i, item) in table.iter_mut().enumerate() {
-        *item = Color::lerp(bg_color, fg_color, i as f32 / 15_f32);
+        *item = Color::blend(bg_color, fg_color, i as u8);
     }
 
     table
 }
 
+/// 4x4 Bayer matrix used for ordered dithering.
+const BAYER_4X4: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// Whether a pixel at `p` with 4-bit grayscale `level` (0 = background, 15 =
+/// foreground) is lit when approximated by ordered dithering on a monochromatic
+/// display. Levels 0 and 15 are never and always lit, respectively.
+pub fn dither_lit(level: u8, p: Point) -> bool {
+    let threshold = BAYER_4X4[(p.y & 3) as usize][(p.x & 3) as usize] as u16;
+    level as u16 * 16 > threshold * 15 + 7
+}
+
+/// Color of the pixel at `p` with 4-bit grayscale `level` of `colortable`, see
+/// `get_color_table`. Monochromatic displays cannot show the shades between
+/// the background and the foreground color, they are dithered instead.
+pub fn grayscale_pixel(colortable: &[Color; 16], level: u8, p: Point) -> Color {
+    if !constant::MONO_DISPLAY {
+        colortable[level as usize]
+    } else if dither_lit(level, p) {
+        colortable[15]
+    } else {
+        colortable[0]
+    }
+}
+
 pub struct Glyph {
     pub width: i16,
     pub height: i16,
     pub adv: i16,
     pub bearing_x: i16,
     pub bearing_y: i16,
+    /// Bits per pixel of `data`, the pixels are unpacked to 4-bit alpha.
+    bpp: i16,
     data: &'static [u8],
 }
 
@@ -888,12 +983,13 @@ impl Glyph {
     /// - contains valid glyph metadata
     /// - data has appropriate size
     /// - data must have static lifetime
-    pub unsafe fn load(data: *const u8) -> Self {
+    /// - data is packed with `bpp` bits per pixel
+    pub unsafe fn load(data: *const u8, bpp: i16) -> Self {
         unsafe {
             let width = *data.offset(0) as i16;
             let height = *data.offset(1) as i16;
 
-            let data_bits = constant::FONT_BPP * width * height;
+            let data_bits = bpp * width * height;
 
             let data_bytes = if data_bits % 8 == 0 {
                 data_bits / 8
@@ -907,6 +1003,7 @@ impl Glyph {
                 adv: *data.offset(2) as i16,
                 bearing_x: *data.offset(3) as i16,
                 bearing_y: *data.offset(4) as i16,
+                bpp,
                 data: slice::from_raw_parts(data.offset(5), data_bytes as usize),
             }
         }
@@ -954,10 +1051,11 @@ impl Glyph {
         c_data >> 4
     }
 
+    /// Alpha of the pixel at `p`, from 0 (background) to 15 (foreground).
     pub fn get_pixel_data(&self, p: Offset) -> u8 {
         let a = p.x + p.y * self.width;
 
-        match constant::FONT_BPP {
+        match self.bpp {
             1 => self.unpack_bpp1(a),
             2 => self.unpack_bpp2(a),
             4 => self.unpack_bpp4(a),
@@ -986,7 +1084,7 @@ impl From<Font> for i32 {
 
 impl Font {
     pub fn text_width(self, text: &str) -> i16 {
-        display::text_width(text, self.into())
+        advance::text_width(self, text)
     }
 
     pub fn char_width(self, ch: char) -> i16 {
@@ -1005,17 +1103,38 @@ impl Font {
         display::text_baseline(self.into())
     }
 
+    /// Distance from the baseline to the top of the tallest glyph.
+    pub fn ascent(self) -> i16 {
+        self.text_max_height() - self.text_baseline()
+    }
+
+    /// Distance from the baseline to the bottom of the lowest glyph.
+    pub fn descent(self) -> i16 {
+        self.text_baseline()
+    }
+
     pub fn line_height(self) -> i16 {
         constant::LINE_SPACE + self.text_height()
     }
 
+    /// Bits per pixel of the glyph bitmaps, as generated into the font data.
+    pub fn bpp(self) -> i16 {
+        display::text_bpp(self.into())
+    }
+
+    /// Whether the glyphs carry alpha, i.e. their edges are blended with the
+    /// background instead of being either on or off.
+    pub fn anti_aliased(self) -> bool {
+        self.bpp() > 1
+    }
+
     pub fn get_glyph(self, char_byte: u8) -> Option<Glyph> {
         let gl_data = display::get_char_glyph(char_byte, self.into());
 
         if gl_data.is_null() {
             return None;
         }
-        unsafe { Some(Glyph::load(gl_data)) }
+        unsafe { Some(Glyph::load(gl_data, self.bpp())) }
     }
 
     pub fn display_text(self, text: &str, baseline: Point, fg_color: Color, bg_color: Color) {
@@ -1030,24 +1149,51 @@ impl Font {
         }
     }
 
-    /// Get the length of the longest suffix from a given `text`
-    /// that will fit into the area `width` pixels wide.
-    pub fn longest_suffix(self, width: i16, text: &str) -> usize {
+    /// Get the length in bytes of the longest prefix from a given `text`
+    /// that will fit into the area `width` pixels wide. The prefix consists of
+    /// whole grapheme clusters.
+    pub fn longest_prefix(self, width: i16, text: &str) -> usize {
         let mut text_width = 0;
-        for (chars_from_right, c) in text.chars().rev().enumerate() {
-            let c_width = self.char_width(c);
-            if text_width + c_width > width {
-                // Another character cannot be fitted, we're done.
-                return chars_from_right;
+        let mut prefix_len = 0;
+        for cluster in grapheme::graphemes(text) {
+            let cluster_width = self.text_width(cluster);
+            if text_width + cluster_width > width {
+                break;
             }
-            text_width += c_width;
+            text_width += cluster_width;
+            prefix_len += cluster.len();
         }
+        prefix_len
+    }
 
-        text.len() // it fits in its entirety
+    /// Get the length in bytes of the longest suffix from a given `text`
+    /// that will fit into the area `width` pixels wide. The suffix consists of
+    /// whole grapheme clusters.
+    pub fn longest_suffix(self, width: i16, text: &str) -> usize {
+        let mut text_width = 0;
+        let mut suffix_len = 0;
+        for cluster in grapheme::graphemes(text).rev() {
+            let cluster_width = self.text_width(cluster);
+            if text_width + cluster_width > width {
+                // Another cluster cannot be fitted, we're done.
+                break;
+            }
+            text_width += cluster_width;
+            suffix_len += cluster.len();
+        }
+        suffix_len
     }
 }
 
-#[derive(Copy, Clone, PartialEq, Eq)]
+/// Colors with luminance at or above this value are rendered as lit pixels on
+/// monochromatic displays.
+pub const MONO_THRESHOLD: u32 = 128;
+
+/// RGB565 color. On monochromatic displays, colors are reduced to black or
+/// white by thresholding their luminance (see `Color::to_mono`) when they are
+/// sent to the display, so that components can be written once for both kinds
+/// of displays.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub struct Color(u16);
 
 impl Color {
@@ -1055,6 +1201,14 @@ impl Color {
         Self(val)
     }
 
+    pub const fn white() -> Self {
+        Self::rgb(0xFF, 0xFF, 0xFF)
+    }
+
+    pub const fn black() -> Self {
+        Self::rgb(0, 0, 0)
+    }
+
     pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
         let r = (r as u16 & 0xF8) << 8;
         let g = (g as u16 & 0xFC) << 3;
@@ -1080,8 +1234,28 @@ impl Color {
         (self.0 << 3) as u8 & 0xF8
     }
 
+    /// Whether the color is rendered as a lit pixel on monochromatic displays.
+    pub const fn is_lit(self) -> bool {
+        self.luminance() >= MONO_THRESHOLD
+    }
+
+    /// Reduce the color to either black or white.
+    pub const fn to_mono(self) -> Self {
+        if self.is_lit() {
+            Self::white()
+        } else {
+            Self::black()
+        }
+    }
+
+    /// Raw value as sent to the display, reduced to black or white on
+    /// monochromatic displays.
     pub fn to_u16(self) -> u16 {
-        self.0
+        if constant::MONO_DISPLAY {
+            self.to_mono().0
+        } else {
+            self.0
+        }
     }
 
     pub fn hi_byte(self) -> u8 {
@@ -1095,6 +1269,14 @@ impl Color {
     pub fn negate(self) -> Self {
         Self(!self.0)
     }
+
+    /// Mix `fg` over `bg` with 4-bit `alpha`, 0 giving `bg` and 15 giving
+    /// `fg`. Used to draw the edges of anti-aliased glyphs. The channels are
+    /// interpolated and truncated exactly as by `Color::lerp`, so that the
+    /// rendered glyphs stay the same.
+    pub fn blend(bg: Self, fg: Self, alpha: u8) -> Self {
+        Self::lerp(bg, fg, alpha.min(15) as f32 / 15_f32)
+    }
 }
 
 impl Lerp for Color {
@@ -1117,3 +1299,77 @@ impl From<Color> for u16 {
         val.to_u16()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_blend() {
+        let bg = Color::rgb(0x10, 0x20, 0x40);
+        let fg = Color::white();
+        assert!(Color::blend(bg, fg, 0) == bg);
+        assert!(Color::blend(bg, fg, 15) == fg);
+        assert!(Color::blend(bg, fg, 20) == fg);
+
+        let half = Color::blend(Color::black(), fg, 8);
+        assert_eq!((half.r(), half.g(), half.b()), (0x80, 0x84, 0x80));
+    }
+
+    #[test]
+    fn dither_levels() {
+        let block = || (0..4).flat_map(|y| (0..4).map(move |x| Point::new(x, y)));
+        for level in 0..16u8 {
+            // the share of lit pixels is the level rounded to sixteenths
+            let lit = block().filter(|&p| dither_lit(level, p)).count();
+            assert_eq!(lit, (level as usize * 16 + 7) / 15, "level {}", level);
+            // the pattern repeats every four pixels
+            for p in block() {
+                assert_eq!(
+                    dither_lit(level, p),
+                    dither_lit(level, p + Offset::new(4, 8))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn grayscale_dithered() {
+        let fg = Color::white();
+        let bg = Color::black();
+        let colortable = get_color_table(fg, bg);
+        let p = Point::new(1, 2);
+        assert_eq!(grayscale_pixel(&colortable, 0, p), bg);
+        assert_eq!(grayscale_pixel(&colortable, 15, p), fg);
+        // level 8 is lit at threshold 1 but not at 9
+        let (lit, unlit) = (Point::new(2, 2), Point::new(3, 2));
+        if constant::MONO_DISPLAY {
+            assert_eq!(grayscale_pixel(&colortable, 8, lit), fg);
+            assert_eq!(grayscale_pixel(&colortable, 8, unlit), bg);
+        } else {
+            assert_eq!(grayscale_pixel(&colortable, 8, lit), colortable[8]);
+            assert_eq!(grayscale_pixel(&colortable, 8, unlit), colortable[8]);
+        }
+    }
+
+    #[test]
+    fn font_bpp() {
+        // The depth comes from the font data, which is generated for the model.
+        for font in [Font::NORMAL, Font::BOLD, Font::MONO, Font::DEMIBOLD] {
+            assert_eq!(font.bpp(), constant::FONT_BPP);
+            assert_eq!(font.anti_aliased(), constant::FONT_BPP > 1);
+        }
+    }
+
+    #[test]
+    fn line_partial() {
+        let start = Point::new(10, 20);
+        let end = Point::new(16, 14);
+        assert_eq!(line_length(start, end), 6);
+        assert!(line_point(start, end, 0) == start);
+        assert!(line_point(start, end, 3) == Point::new(13, 17));
+        assert!(line_point(start, end, 6) == end);
+        assert!(line_point(start, end, 100) == end);
+        assert!(line_point(start, start, 5) == start);
+    }
+}
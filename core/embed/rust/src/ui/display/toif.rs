@@ -5,8 +5,11 @@ use crate::{
     },
     ui::{
         constant,
-        display::{get_color_table, get_offset, pixeldata, pixeldata_dirty, set_window},
+        display::{
+            get_color_table, get_offset, grayscale_pixel, pixeldata, pixeldata_dirty, set_window,
+        },
         geometry::{Alignment2D, Offset, Point, Rect},
+        pool,
     },
 };
 
@@ -24,8 +27,8 @@ pub fn icon(icon: &Icon, center: Point, fg_color: Color, bg_color: Color) {
 
     let mut dest = [0_u8; 1];
 
-    let mut window = [0; UZLIB_WINDOW_SIZE];
-    let mut ctx = icon.toif.decompression_context(Some(&mut window));
+    let mut window = unwrap!(pool::Block::alloc(), "UI pool exhausted");
+    let mut ctx = icon.toif.decompression_context(Some(&mut *window));
 
     for py in area.y0..area.y1 {
         for px in area.x0..area.x1 {
@@ -35,9 +38,9 @@ pub fn icon(icon: &Icon, center: Point, fg_color: Color, bg_color: Color) {
             if clamped.contains(p) {
                 if x % 2 == 0 {
                     unwrap!(ctx.uncompress(&mut dest), "Decompression failed");
-                    pixeldata(colortable[(dest[0] & 0xF) as usize]);
+                    pixeldata(grayscale_pixel(&colortable, dest[0] & 0xF, p));
                 } else {
-                    pixeldata(colortable[(dest[0] >> 4) as usize]);
+                    pixeldata(grayscale_pixel(&colortable, dest[0] >> 4, p));
                 }
             } else if x % 2 == 0 {
                 //continue unzipping but dont write to display
@@ -0,0 +1,85 @@
+use num_traits::FromPrimitive;
+
+/// Stable short codes of fatal errors, displayed prominently on the error
+/// screens so that support can triage a crash without the full message.
+///
+/// NOTE: Codes are referenced from support materials. Never renumber or reuse
+/// them, only append new ones.
+///
+/// The discriminants are the `error_kind_t` values passed from C.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, FromPrimitive)]
+#[repr(u8)]
+pub enum ErrorCode {
+    /// Fatal error which does not fall into any other category, e.g. `ensure!`.
+    FatalError = 0,
+    /// Rust panic.
+    RustPanic = 1,
+    /// `unwrap!` on `None` or `Err`.
+    UnwrapFailed = 2,
+    /// Failed C `assert`.
+    AssertFailed = 3,
+    /// Fault detected by hardware checks, e.g. board capabilities.
+    FaultDetected = 4,
+    /// Uncaught MicroPython exception.
+    UncaughtException = 5,
+    /// Clock security system failure.
+    ClockSecurity = 6,
+    HardFault = 7,
+    MemManage = 8,
+    StackOverflow = 9,
+    BusFault = 10,
+    UsageFault = 11,
+    /// Stack protector detected stack smashing.
+    StackSmashing = 12,
+}
+
+impl ErrorCode {
+    /// Error code of the `error_kind_t` passed from C, unknown kinds are shown
+    /// as a generic fatal error.
+    pub fn from_c(kind: cty::c_int) -> Self {
+        Self::from_i32(kind).unwrap_or(Self::FatalError)
+    }
+
+    /// Short code shown to the user.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::FatalError => "E01",
+            Self::RustPanic => "E02",
+            Self::UnwrapFailed => "E03",
+            Self::AssertFailed => "E04",
+            Self::FaultDetected => "E05",
+            Self::UncaughtException => "E10",
+            Self::ClockSecurity => "E11",
+            Self::HardFault => "E12",
+            Self::MemManage => "E13",
+            Self::StackOverflow => "E14",
+            Self::BusFault => "E15",
+            Self::UsageFault => "E16",
+            Self::StackSmashing => "E17",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trezorhal::common::{
+        ERROR_KIND_FATAL, ERROR_KIND_RUST_PANIC, ERROR_KIND_UNWRAP_FAILED,
+    };
+
+    #[test]
+    fn from_c() {
+        assert_eq!(ErrorCode::from_c(ERROR_KIND_FATAL), ErrorCode::FatalError);
+        assert_eq!(
+            ErrorCode::from_c(ERROR_KIND_RUST_PANIC),
+            ErrorCode::RustPanic
+        );
+        assert_eq!(
+            ErrorCode::from_c(ERROR_KIND_UNWRAP_FAILED),
+            ErrorCode::UnwrapFailed
+        );
+        assert_eq!(ErrorCode::from_c(12), ErrorCode::StackSmashing);
+        assert_eq!(ErrorCode::from_c(13), ErrorCode::FatalError);
+        assert_eq!(ErrorCode::from_c(-1), ErrorCode::FatalError);
+    }
+}
@@ -0,0 +1,283 @@
+//! Recognition of gestures in the stream of touch events. Components feed the
+//! raw `TouchEvent`s to a `GestureRecognizer` and react to the `Gesture`s it
+//! emits, instead of tracking the touch positions on their own.
+
+use crate::{
+    time::{Duration, Instant},
+    ui::geometry::{Offset, Point},
+};
+
+use super::TouchEvent;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum SwipeDirection {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Gesture {
+    /// Touch moved at least `GestureThresholds::swipe_distance` in the given
+    /// direction before being released.
+    Swipe(SwipeDirection),
+    /// Touch was released without moving, reported at the release point.
+    Tap(Point),
+    /// Second tap followed the previous one close enough in time and space.
+    /// The first tap is reported as `Tap` too.
+    DoubleTap(Point),
+    /// Touch was held in place for `GestureThresholds::long_press`. Reported
+    /// as soon as the time elapses, nothing is reported on the release.
+    LongPress(Point),
+}
+
+#[derive(Copy, Clone)]
+pub struct GestureThresholds {
+    /// Distance along the main axis which makes a touch a swipe.
+    pub swipe_distance: i16,
+    /// Distance within which a touch is still considered not moving.
+    pub tap_slop: i16,
+    /// Time for which a touch has to be held to become a long press.
+    pub long_press: Duration,
+    /// Maximal time between the releases of two taps forming a double tap.
+    pub double_tap: Duration,
+}
+
+impl GestureThresholds {
+    pub const DEFAULT: Self = Self {
+        swipe_distance: 36,
+        tap_slop: 10,
+        long_press: Duration::from_millis(1000),
+        double_tap: Duration::from_millis(300),
+    };
+}
+
+#[derive(Copy, Clone)]
+enum State {
+    Idle,
+    Pressed {
+        origin: Point,
+        start: Instant,
+        moved: bool,
+    },
+    /// Long press was reported, waiting for the release.
+    LongPressed,
+}
+
+pub struct GestureRecognizer {
+    thresholds: GestureThresholds,
+    state: State,
+    last_tap: Option<(Point, Instant)>,
+}
+
+impl GestureRecognizer {
+    pub const fn new(thresholds: GestureThresholds) -> Self {
+        Self {
+            thresholds,
+            state: State::Idle,
+            last_tap: None,
+        }
+    }
+
+    pub fn thresholds(&self) -> &GestureThresholds {
+        &self.thresholds
+    }
+
+    /// Whether a touch is in progress. A component recognizing long presses
+    /// should request a timer for `GestureThresholds::long_press` when it
+    /// starts and call `timer` once it fires.
+    pub fn is_pressed(&self) -> bool {
+        matches!(self.state, State::Pressed { .. })
+    }
+
+    pub fn touch(&mut self, event: TouchEvent, now: Instant) -> Option<Gesture> {
+        match (event, self.state) {
+            (TouchEvent::TouchStart(pos), _) => {
+                self.state = State::Pressed {
+                    origin: pos,
+                    start: now,
+                    moved: false,
+                };
+                None
+            }
+            (
+                TouchEvent::TouchMove(pos),
+                State::Pressed {
+                    origin,
+                    start,
+                    moved,
+                },
+            ) => {
+                self.state = State::Pressed {
+                    origin,
+                    start,
+                    moved: moved || self.is_moved(pos - origin),
+                };
+                self.timer(now)
+            }
+            (TouchEvent::TouchEnd(pos), State::Pressed { origin, start, .. }) => {
+                self.state = State::Idle;
+                self.released(pos, pos - origin, now.saturating_duration_since(start), now)
+            }
+            (TouchEvent::TouchEnd(_), _) => {
+                self.state = State::Idle;
+                None
+            }
+            (TouchEvent::TouchCancel, _) => {
+                self.state = State::Idle;
+                self.last_tap = None;
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Report a long press if the touch has been held for long enough.
+    pub fn timer(&mut self, now: Instant) -> Option<Gesture> {
+        match self.state {
+            State::Pressed {
+                origin,
+                start,
+                moved: false,
+            } if now.saturating_duration_since(start) >= self.thresholds.long_press => {
+                self.state = State::LongPressed;
+                self.last_tap = None;
+                Some(Gesture::LongPress(origin))
+            }
+            _ => None,
+        }
+    }
+
+    fn is_moved(&self, ofs: Offset) -> bool {
+        let abs = ofs.abs();
+        abs.x.max(abs.y) > self.thresholds.tap_slop
+    }
+
+    fn released(
+        &mut self,
+        pos: Point,
+        ofs: Offset,
+        held: Duration,
+        now: Instant,
+    ) -> Option<Gesture> {
+        let abs = ofs.abs();
+        if abs.x.max(abs.y) >= self.thresholds.swipe_distance {
+            self.last_tap = None;
+            let direction = match (abs.x > abs.y, ofs.x < 0, ofs.y < 0) {
+                (true, true, _) => SwipeDirection::Left,
+                (true, false, _) => SwipeDirection::Right,
+                (false, _, true) => SwipeDirection::Up,
+                (false, _, false) => SwipeDirection::Down,
+            };
+            return Some(Gesture::Swipe(direction));
+        }
+        if self.is_moved(ofs) {
+            // Neither a swipe nor a tap.
+            self.last_tap = None;
+            return None;
+        }
+        if held >= self.thresholds.long_press {
+            // The timer did not fire in time, report the long press now.
+            self.last_tap = None;
+            return Some(Gesture::LongPress(pos - ofs));
+        }
+        let double = self.last_tap.map_or(false, |(last_pos, last_time)| {
+            now.saturating_duration_since(last_time) <= self.thresholds.double_tap
+                && !self.is_moved(pos - last_pos)
+        });
+        if double {
+            self.last_tap = None;
+            Some(Gesture::DoubleTap(pos))
+        } else {
+            self.last_tap = Some((pos, now));
+            Some(Gesture::Tap(pos))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::at;
+
+    fn feed(events: &[(TouchEvent, u32)]) -> Vec<Gesture> {
+        let start = Instant::now();
+        let mut recognizer = GestureRecognizer::new(GestureThresholds::DEFAULT);
+        events
+            .iter()
+            .filter_map(|&(event, millis)| recognizer.touch(event, at(start, millis)))
+            .collect()
+    }
+
+    #[test]
+    fn gesture_swipe() {
+        let gestures = feed(&[
+            (TouchEvent::TouchStart(Point::new(100, 100)), 0),
+            (TouchEvent::TouchMove(Point::new(80, 105)), 50),
+            (TouchEvent::TouchEnd(Point::new(40, 110)), 100),
+        ]);
+        assert_eq!(gestures, [Gesture::Swipe(SwipeDirection::Left)]);
+
+        let gestures = feed(&[
+            (TouchEvent::TouchStart(Point::new(100, 100)), 0),
+            (TouchEvent::TouchEnd(Point::new(110, 180)), 100),
+        ]);
+        assert_eq!(gestures, [Gesture::Swipe(SwipeDirection::Down)]);
+    }
+
+    #[test]
+    fn gesture_taps() {
+        let gestures = feed(&[
+            (TouchEvent::TouchStart(Point::new(50, 50)), 0),
+            (TouchEvent::TouchEnd(Point::new(52, 49)), 80),
+            (TouchEvent::TouchStart(Point::new(51, 50)), 200),
+            (TouchEvent::TouchEnd(Point::new(51, 50)), 250),
+            (TouchEvent::TouchStart(Point::new(51, 50)), 1000),
+            (TouchEvent::TouchEnd(Point::new(51, 50)), 1050),
+        ]);
+        assert_eq!(
+            gestures,
+            [
+                Gesture::Tap(Point::new(52, 49)),
+                Gesture::DoubleTap(Point::new(51, 50)),
+                Gesture::Tap(Point::new(51, 50)),
+            ]
+        );
+
+        // moved too much to be a tap, too little to be a swipe
+        let gestures = feed(&[
+            (TouchEvent::TouchStart(Point::new(50, 50)), 0),
+            (TouchEvent::TouchEnd(Point::new(70, 50)), 80),
+        ]);
+        assert!(gestures.is_empty());
+    }
+
+    #[test]
+    fn gesture_long_press() {
+        let start = Instant::now();
+        let mut recognizer = GestureRecognizer::new(GestureThresholds::DEFAULT);
+        let origin = Point::new(120, 120);
+        assert_eq!(
+            recognizer.touch(TouchEvent::TouchStart(origin), start),
+            None
+        );
+        assert!(recognizer.is_pressed());
+        assert_eq!(recognizer.timer(at(start, 500)), None);
+        assert_eq!(
+            recognizer.timer(at(start, 1000)),
+            Some(Gesture::LongPress(origin))
+        );
+        assert_eq!(
+            recognizer.touch(TouchEvent::TouchEnd(origin), at(start, 1500)),
+            None
+        );
+
+        // cancelled touch is not reported at all
+        let gestures = feed(&[
+            (TouchEvent::TouchStart(origin), 0),
+            (TouchEvent::TouchCancel, 50),
+        ]);
+        assert!(gestures.is_empty());
+    }
+}
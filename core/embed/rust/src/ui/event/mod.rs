@@ -0,0 +1,165 @@
+mod gesture;
+
+use crate::{error, ui::geometry::Point};
+use core::convert::TryInto;
+
+pub use gesture::{Gesture, GestureRecognizer, GestureThresholds, SwipeDirection};
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum PhysicalButton {
+    Left,
+    Right,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum ButtonEvent {
+    ButtonPressed(PhysicalButton),
+    ButtonReleased(PhysicalButton),
+}
+
+impl ButtonEvent {
+    pub fn new(event: u32, button: u32) -> Result<Self, error::Error> {
+        let button = match button {
+            0 => PhysicalButton::Left,
+            1 => PhysicalButton::Right,
+            _ => return Err(error::Error::OutOfRange),
+        };
+        let result = match event {
+            1 => Self::ButtonPressed(button),
+            2 => Self::ButtonReleased(button),
+            _ => return Err(error::Error::OutOfRange),
+        };
+        Ok(result)
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum TouchEvent {
+    /// A person has started touching the screen at given absolute coordinates.
+    /// `TouchMove` will usually follow, and `TouchEnd` should finish the
+    /// interaction.
+    TouchStart(Point),
+    /// Touch has moved into a different point on the screen.
+    TouchMove(Point),
+    /// Touch has ended at a point on the screen.
+    TouchEnd(Point),
+    /// Touch was interrupted, e.g. by a second finger landing on the screen.
+    /// Components should abandon the interaction in progress without acting
+    /// on it. Secondary touch points are not reported until all fingers are
+    /// lifted.
+    TouchCancel,
+}
+
+impl TouchEvent {
+    pub fn new(event: u32, x: u32, y: u32) -> Result<Self, error::Error> {
+        let point = Point::new(x.try_into()?, y.try_into()?);
+        let result = match event {
+            1 => Self::TouchStart(point),
+            2 => Self::TouchMove(point),
+            4 => Self::TouchEnd(point),
+            8 => Self::TouchCancel,
+            _ => return Err(error::Error::OutOfRange),
+        };
+        Ok(result)
+    }
+
+    /// Replace the event by `TouchCancel` if the contact is too large to be a
+    /// fingertip, e.g. a palm or cheek resting on the screen. `area` is the
+    /// contact size reported by the touch controller, if it provides any.
+    #[cfg(feature = "touch")]
+    pub fn reject_large_contact(self, area: Option<u8>) -> Self {
+        match (area, crate::ui::constant::TOUCH_MAX_CONTACT_AREA) {
+            (Some(area), Some(max_area)) if area > max_area => Self::TouchCancel,
+            _ => self,
+        }
+    }
+
+    /// Correct the position reported by the touch controller with the active
+    /// touch calibration.
+    #[cfg(feature = "touch")]
+    pub fn calibrated(self) -> Self {
+        use crate::ui::touch_calibration::transform;
+        match self {
+            Self::TouchStart(point) => Self::TouchStart(transform(point)),
+            Self::TouchMove(point) => Self::TouchMove(transform(point)),
+            Self::TouchEnd(point) => Self::TouchEnd(transform(point)),
+            Self::TouchCancel => Self::TouchCancel,
+        }
+    }
+}
+
+/// Key pressed on a development keyboard, e.g. in the emulator window. Only
+/// used to synthesize the touch or button events of the current model, see
+/// `ui::keyboard`.
+#[cfg(feature = "keyboard")]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum KeyEvent {
+    Left,
+    Right,
+    Up,
+    Down,
+    Enter,
+    Back,
+}
+
+#[cfg(feature = "keyboard")]
+impl KeyEvent {
+    pub fn new(key: u32) -> Result<Self, error::Error> {
+        let result = match key {
+            1 => Self::Left,
+            2 => Self::Right,
+            3 => Self::Up,
+            4 => Self::Down,
+            5 => Self::Enter,
+            6 => Self::Back,
+            _ => return Err(error::Error::OutOfRange),
+        };
+        Ok(result)
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum USBEvent {
+    /// USB host has connected/disconnected.
+    Connected(bool),
+}
+
+/// Change of the device state shown in the status bar.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum StatusEvent {
+    /// Device has been locked/unlocked.
+    Locked(bool),
+    /// Charge of the battery in percent.
+    Battery(u8),
+    /// Battery has started/stopped charging.
+    Charging(bool),
+}
+
+impl StatusEvent {
+    pub fn new(kind: u32, value: u32) -> Result<Self, error::Error> {
+        let result = match kind {
+            1 => Self::Locked(value != 0),
+            2 => Self::Battery(value.min(100) as u8),
+            3 => Self::Charging(value != 0),
+            _ => return Err(error::Error::OutOfRange),
+        };
+        Ok(result)
+    }
+}
+
+/// Share of a Shamir backup has been entered during recovery.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct RecoveryEvent {
+    pub group: u8,
+    /// Shares of the group entered so far.
+    pub entered: u8,
+}
+
+impl RecoveryEvent {
+    pub fn new(group: u32, entered: u32) -> Result<Self, error::Error> {
+        Ok(Self {
+            group: group.try_into()?,
+            entered: entered.try_into()?,
+        })
+    }
+}
@@ -79,12 +79,12 @@ impl Offset {
     /// the rectangle such that it is aligned relative to the `point`.
     pub const fn snap(self, point: Point, alignment: Alignment2D) -> Point {
         let x_off = match alignment.0 {
-            Alignment::Start => 0,
+            Alignment::Start | Alignment::Justified => 0,
             Alignment::Center => self.x / 2,
             Alignment::End => self.x,
         };
         let y_off = match alignment.1 {
-            Alignment::Start => 0,
+            Alignment::Start | Alignment::Justified => 0,
             Alignment::Center => self.y / 2,
             Alignment::End => self.y,
         };
@@ -128,6 +128,12 @@ impl Sub<Offset> for Offset {
     }
 }
 
+impl Lerp for Offset {
+    fn lerp(a: Self, b: Self, t: f32) -> Self {
+        Offset::new(i16::lerp(a.x, b.x, t), i16::lerp(a.y, b.y, t))
+    }
+}
+
 impl From<Point> for Offset {
     fn from(val: Point) -> Self {
         Offset::new(val.x, val.y)
@@ -136,7 +142,7 @@ impl From<Point> for Offset {
 
 /// A point in 2D space defined by the the `x` and `y` coordinate. Relative
 /// coordinates, vectors, and offsets are represented by the `Offset` type.
-#[derive(Copy, Clone, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Point {
     pub x: i16,
     pub y: i16,
@@ -308,6 +314,14 @@ impl Rect {
         point.x >= self.x0 && point.x < self.x1 && point.y >= self.y0 && point.y < self.y1
     }
 
+    pub const fn is_empty(&self) -> bool {
+        self.x0 >= self.x1 || self.y0 >= self.y1
+    }
+
+    pub const fn intersects(&self, other: Self) -> bool {
+        !self.clamp(other).is_empty()
+    }
+
     pub const fn union(&self, other: Self) -> Self {
         Self {
             x0: min(self.x0, other.x0),
@@ -407,6 +421,22 @@ impl Rect {
     }
 }
 
+/// Trace the bounding box of the areas passed to the sink by `bounds`, e.g. by
+/// `Component::bounds`, as a `Bounds` element.
+#[cfg(feature = "ui_debug")]
+pub fn trace_bounds(t: &mut dyn crate::trace::Tracer, bounds: impl FnOnce(&mut dyn FnMut(Rect))) {
+    let mut union: Option<Rect> = None;
+    bounds(&mut |r| union = Some(union.map_or(r, |u| u.union(r))));
+    t.open("Bounds");
+    if let Some(r) = union {
+        t.field("x0", &r.x0);
+        t.field("y0", &r.y0);
+        t.field("x1", &r.x1);
+        t.field("y1", &r.y1);
+    }
+    t.close();
+}
+
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub struct Insets {
     pub top: i16,
@@ -455,6 +485,10 @@ pub enum Alignment {
     Start,
     Center,
     End,
+    /// Text lines stretched to the full width by widening the spaces between
+    /// words, except for the last line of a paragraph. Same as `Start` for
+    /// anything else than text.
+    Justified,
 }
 
 pub type Alignment2D = (Alignment, Alignment);
@@ -677,7 +711,7 @@ impl LinearPlacement {
         };
 
         let initial_cursor = match self.align {
-            Alignment::Start => 0,
+            Alignment::Start | Alignment::Justified => 0,
             Alignment::Center => available_space / 2 - total_size / 2,
             Alignment::End => available_space - total_size,
         };
@@ -691,3 +725,25 @@ pub trait Dimensions {
     fn fit(&mut self, bounds: Rect);
     fn area(&self) -> Rect;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds_traced_as_union() {
+        let mut t = Vec::new();
+        trace_bounds(&mut t, |sink| {
+            sink(Rect::new(Point::new(10, 20), Point::new(30, 40)));
+            sink(Rect::new(Point::new(0, 30), Point::new(20, 50)));
+        });
+        assert_eq!(
+            String::from_utf8(t).unwrap(),
+            "<Bounds x0:0 y0:20 x1:30 y1:50 >"
+        );
+
+        let mut t = Vec::new();
+        trace_bounds(&mut t, |_| {});
+        assert_eq!(String::from_utf8(t).unwrap(), "<Bounds >");
+    }
+}
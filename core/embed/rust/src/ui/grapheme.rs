@@ -0,0 +1,117 @@
+//! Grapheme clusters, i.e. what the user perceives as a single character: a
+//! base character together with the combining marks and variation selectors
+//! following it, or an emoji sequence joined by zero width joiners. Text is
+//! truncated and edited by whole clusters so that accents are never separated
+//! from the letters they belong to.
+
+const ZWJ: char = '\u{200D}';
+
+/// Characters which extend the preceding cluster instead of starting a new one.
+fn is_extending(ch: char) -> bool {
+    matches!(ch,
+        '\u{0300}'..='\u{036F}' // combining diacritical marks
+        | '\u{1AB0}'..='\u{1AFF}' // combining diacritical marks extended
+        | '\u{1DC0}'..='\u{1DFF}' // combining diacritical marks supplement
+        | '\u{20D0}'..='\u{20FF}' // combining marks for symbols
+        | '\u{FE00}'..='\u{FE0F}' // variation selectors
+        | '\u{FE20}'..='\u{FE2F}' // combining half marks
+        | ZWJ
+    )
+}
+
+/// Whether there is a cluster boundary between characters `prev` and `next`.
+pub fn is_boundary(prev: char, next: char) -> bool {
+    prev != ZWJ && !is_extending(next)
+}
+
+/// Iterator over grapheme clusters of a string, from either end.
+pub struct Graphemes<'a> {
+    text: &'a str,
+}
+
+pub fn graphemes(text: &str) -> Graphemes {
+    Graphemes { text }
+}
+
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chars = self.text.char_indices();
+        let (_, mut prev) = chars.next()?;
+        let end = chars
+            .find(|&(_, ch)| {
+                let boundary = is_boundary(prev, ch);
+                prev = ch;
+                boundary
+            })
+            .map_or(self.text.len(), |(i, _)| i);
+        let (cluster, rest) = self.text.split_at(end);
+        self.text = rest;
+        Some(cluster)
+    }
+}
+
+impl DoubleEndedIterator for Graphemes<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let mut chars = self.text.char_indices().rev();
+        let (mut start, mut next) = chars.next()?;
+        for (i, ch) in chars {
+            if is_boundary(ch, next) {
+                break;
+            }
+            start = i;
+            next = ch;
+        }
+        let (rest, cluster) = self.text.split_at(start);
+        self.text = rest;
+        Some(cluster)
+    }
+}
+
+/// Longest prefix of `text` consisting of whole grapheme clusters which is at
+/// most `max_len` bytes long.
+pub fn truncate(text: &str, max_len: usize) -> &str {
+    let mut len = 0;
+    for cluster in graphemes(text) {
+        if len + cluster.len() > max_len {
+            break;
+        }
+        len += cluster.len();
+    }
+    &text[..len]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn graphemes_forward_and_back() {
+        // "ahoj" with acute accent and caron composed from combining marks
+        let text = "a\u{301}ho\u{30C}j";
+        let expected = ["a\u{301}", "h", "o\u{30C}", "j"];
+        assert!(graphemes(text).eq(expected));
+        assert!(graphemes(text).rev().eq(expected.into_iter().rev()));
+        assert!(graphemes("").next().is_none());
+
+        // family emoji joined by ZWJ, followed by heart with variation selector
+        let text = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{2764}\u{FE0F}";
+        let expected = [
+            "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}",
+            "\u{2764}\u{FE0F}",
+        ];
+        assert!(graphemes(text).eq(expected));
+        assert!(graphemes(text).rev().eq(expected.into_iter().rev()));
+    }
+
+    #[test]
+    fn graphemes_truncate() {
+        let text = "xe\u{301}y";
+        assert_eq!(truncate(text, 0), "");
+        assert_eq!(truncate(text, 2), "x");
+        assert_eq!(truncate(text, 3), "x");
+        assert_eq!(truncate(text, 4), "xe\u{301}");
+        assert_eq!(truncate(text, 10), text);
+    }
+}
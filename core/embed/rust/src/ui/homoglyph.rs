@@ -0,0 +1,165 @@
+//! Detection of identifiers which can be mistaken for different ones, e.g. a
+//! domain name spelled with Cyrillic letters which look the same as the Latin
+//! ones. Flows rendering such identifiers use it to call out the spoofed names
+//! instead of showing them as if they were genuine.
+
+/// Scripts containing letters that look like Latin ones. Letters of the other
+/// scripts cannot be confused with Latin and may be freely mixed with it.
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Script {
+    Latin,
+    Greek,
+    Cyrillic,
+    Armenian,
+    Cherokee,
+}
+
+impl Script {
+    fn of(ch: char) -> Option<Self> {
+        if !ch.is_alphabetic() {
+            return None;
+        }
+        match ch {
+            'A'..='Z'
+            | 'a'..='z'
+            | '\u{00C0}'..='\u{024F}'
+            | '\u{0250}'..='\u{02AF}'
+            | '\u{1E00}'..='\u{1EFF}'
+            | '\u{2C60}'..='\u{2C7F}'
+            | '\u{A720}'..='\u{A7FF}'
+            | '\u{FF21}'..='\u{FF3A}'
+            | '\u{FF41}'..='\u{FF5A}' => Some(Self::Latin),
+            '\u{0370}'..='\u{03FF}' | '\u{1F00}'..='\u{1FFF}' => Some(Self::Greek),
+            '\u{0400}'..='\u{052F}'
+            | '\u{1C80}'..='\u{1C8F}'
+            | '\u{2DE0}'..='\u{2DFF}'
+            | '\u{A640}'..='\u{A69F}' => Some(Self::Cyrillic),
+            '\u{0530}'..='\u{058F}' => Some(Self::Armenian),
+            '\u{13A0}'..='\u{13FF}' | '\u{AB70}'..='\u{ABBF}' => Some(Self::Cherokee),
+            _ => None,
+        }
+    }
+}
+
+/// Non-ASCII letters which are rendered (almost) the same as an ASCII letter.
+/// Sorted, so that it can be binary searched.
+#[rustfmt::skip]
+const CONFUSABLES: [char; 70] = [
+    '\u{0131}', // dotless i
+    '\u{0251}', // latin alpha
+    '\u{0261}', // script g
+    '\u{0391}', '\u{0392}', '\u{0395}', '\u{0396}', '\u{0397}', '\u{0399}', // greek A B E Z H I
+    '\u{039A}', '\u{039C}', '\u{039D}', '\u{039F}', '\u{03A1}', '\u{03A4}', // greek K M N O P T
+    '\u{03A5}', '\u{03A7}', // greek Y X
+    '\u{03B1}', '\u{03B9}', '\u{03BA}', '\u{03BD}', '\u{03BF}', '\u{03C1}', // greek a i k v o p
+    '\u{03C5}', // greek u
+    '\u{0405}', '\u{0406}', '\u{0408}', '\u{0410}', '\u{0412}', '\u{0415}', // cyrillic S I J A B E
+    '\u{041A}', '\u{041C}', '\u{041D}', '\u{041E}', '\u{0420}', '\u{0421}', // cyrillic K M H O P C
+    '\u{0422}', '\u{0425}', // cyrillic T X
+    '\u{0430}', '\u{0435}', '\u{043E}', '\u{0440}', '\u{0441}', '\u{0443}', // cyrillic a e o p c y
+    '\u{0445}', '\u{0455}', '\u{0456}', '\u{0458}', '\u{04BB}', '\u{04CF}', // cyrillic x s i j h l
+    '\u{0501}', '\u{051B}', '\u{051D}', // cyrillic d q w
+    '\u{0570}', '\u{0578}', '\u{057D}', '\u{0585}', // armenian h n u o
+    '\u{13A0}', '\u{13A1}', '\u{13A2}', '\u{13AA}', '\u{13AB}', '\u{13B3}', // cherokee D R T A J W
+    '\u{13B7}', '\u{13BB}', '\u{13C0}', '\u{13C3}', '\u{13DA}', '\u{13DE}', // cherokee M H G Z S L
+    '\u{13E2}', // cherokee P
+];
+
+pub fn is_confusable(ch: char) -> bool {
+    CONFUSABLES.binary_search(&ch).is_ok()
+}
+
+/// Fullwidth and mathematical variants of ASCII characters. These have no
+/// place in identifiers other than to imitate the regular characters.
+fn is_compatibility_form(ch: char) -> bool {
+    matches!(ch, '\u{FF01}'..='\u{FF5E}' | '\u{1D400}'..='\u{1D7FF}')
+}
+
+/// Whether `text` may be an imitation of a different identifier. Every label
+/// of a domain name is checked separately, so that e.g. a Greek name under a
+/// Latin top level domain is fine. A label is suspicious if it:
+/// - mixes letters of scripts which look alike, e.g. Latin and Cyrillic,
+/// - contains Latin letters that look like different ASCII ones, e.g. the
+///   dotless i,
+/// - consists only of non-Latin letters which look like Latin ones, e.g.
+///   "аррӏе" in Cyrillic,
+/// - contains fullwidth or mathematical variants of ASCII characters.
+pub fn is_lookalike(text: &str) -> bool {
+    text.split('.').any(is_lookalike_label)
+}
+
+fn is_lookalike_label(label: &str) -> bool {
+    let mut script = None;
+    let mut all_confusable = true;
+    for ch in label.chars() {
+        if is_compatibility_form(ch) {
+            return true;
+        }
+        let ch_script = match Script::of(ch) {
+            Some(ch_script) => ch_script,
+            None => continue,
+        };
+        if script.map_or(false, |script| script != ch_script) {
+            return true;
+        }
+        script = Some(ch_script);
+        if is_confusable(ch) {
+            if ch_script == Script::Latin {
+                return true;
+            }
+        } else {
+            all_confusable = false;
+        }
+    }
+    script.map_or(false, |script| script != Script::Latin) && all_confusable
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn homoglyph_confusables_sorted() {
+        assert!(CONFUSABLES.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn homoglyph_genuine() {
+        assert!(!is_lookalike(""));
+        assert!(!is_lookalike("webauthn.io"));
+        assert!(!is_lookalike("vitalik.eth"));
+        assert!(!is_lookalike("123-456.example.com"));
+        assert!(!is_lookalike("příliš-žluťoučký.cz"));
+        assert!(!is_lookalike("пример.рф"));
+        assert!(!is_lookalike("παράδειγμα.gr"));
+        // scripts which do not resemble Latin can be mixed with it
+        assert!(!is_lookalike("中文.example"));
+    }
+
+    #[test]
+    fn homoglyph_spoofed() {
+        // cyrillic a in an otherwise latin name
+        assert!(is_lookalike("p\u{0430}ypal.com"));
+        // greek omicron
+        assert!(is_lookalike("g\u{03BF}\u{03BF}gle.com"));
+        // dotless i
+        assert!(is_lookalike("b\u{0131}nance.com"));
+        // whole name in cyrillic lookalikes
+        assert!(is_lookalike("\u{0430}\u{0440}\u{0440}\u{04CF}\u{0435}"));
+        // fullwidth letters
+        assert!(is_lookalike("\u{FF45}xample.com"));
+    }
+
+    #[test]
+    fn homoglyph_ens() {
+        assert!(!is_lookalike("vitalik.eth"));
+        assert!(!is_lookalike("sub.nick.eth"));
+        // cyrillic a and i
+        assert!(is_lookalike("vit\u{0430}lik.eth"));
+        assert!(is_lookalike("v\u{0456}talik.eth"));
+        // only the spoofed subdomain is a lookalike
+        assert!(is_lookalike("p\u{0430}y.nick.eth"));
+        // cyrillic lookalikes of the whole name
+        assert!(is_lookalike("\u{0430}\u{0440}\u{0440}\u{04CF}\u{0435}.eth"));
+    }
+}
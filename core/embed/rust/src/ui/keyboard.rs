@@ -0,0 +1,4 @@
+//! Reexporting the `keyboard` module of the model. Only the Model T emulator
+//! forwards the keyboard, on Model R the arrow keys are the buttons already.
+
+pub use super::model_tt::keyboard::*;
@@ -0,0 +1,164 @@
+//! Pulse of the backlight when a confirmation shows up while the user has
+//! neither touched the device nor got a new layout for a while, e.g. when the
+//! host asks after a long computation, so that it is not missed. Driven by the
+//! layout executor, which reports the user input and passes the events through.
+//!
+//! Displays without a backlight are left as they are, there is no way to invert
+//! them without repainting the layout.
+
+use crate::{
+    time::{Duration, Instant},
+    ui::{
+        component::{BacklightFade, Event, EventCtx, TimerToken},
+        display,
+    },
+};
+
+/// Time without input after which a new layout pulses the backlight.
+const IDLE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Delay of the pulse after the layout is attached, so that it starts after
+/// the backlight fade of the first paint.
+const PULSE_DELAY: Duration = Duration::from_millis(300);
+
+/// Peak of the pulse.
+const PULSE_LEVEL: i32 = 255;
+
+/// Time of the last sign of the user being around, i.e. the last input or the
+/// last layout shown to them.
+pub struct Activity {
+    last: Option<Instant>,
+}
+
+impl Activity {
+    pub const fn new() -> Self {
+        Self { last: None }
+    }
+
+    pub fn note(&mut self, now: Instant) {
+        self.last = Some(now);
+    }
+
+    /// Whether there was no activity for `IDLE_TIMEOUT` before `now`. False
+    /// before the first layout, the user is looking at the boot screen.
+    pub fn is_idle(&self, now: Instant) -> bool {
+        match self.last {
+            Some(last) => now.saturating_duration_since(last) >= IDLE_TIMEOUT,
+            None => false,
+        }
+    }
+}
+
+#[cfg(not(test))]
+static mut ACTIVITY: Activity = Activity::new();
+
+// Layout tests run in parallel threads and each of them has its own input.
+#[cfg(test)]
+std::thread_local! {
+    static ACTIVITY: core::cell::UnsafeCell<Activity> =
+        core::cell::UnsafeCell::new(Activity::new());
+}
+
+/// Returns the activity of the user.
+///
+/// # Safety
+///
+/// The caller has to guarantee that no other reference returned by this
+/// function is alive while the result is used.
+unsafe fn activity() -> &'static mut Activity {
+    #[cfg(not(test))]
+    let ptr = core::ptr::addr_of_mut!(ACTIVITY);
+    #[cfg(test)]
+    let ptr = ACTIVITY.with(|activity| activity.get());
+    unsafe { &mut *ptr }
+}
+
+/// Record a touch or a button press at `now`.
+pub fn note_input(now: Instant) {
+    // SAFETY: Called by the layout executor for input events in the UI task,
+    // the reference is only used for the assignment.
+    unsafe { activity() }.note(now);
+}
+
+enum Phase {
+    Waiting(TimerToken),
+    /// Fading to `PULSE_LEVEL`, the level before the pulse is kept to return
+    /// to it.
+    Rising(i32),
+    Falling,
+}
+
+/// Pulse of the backlight of a single layout. Only layouts which `request` it,
+/// i.e. confirmations the host is waiting for, pulse.
+pub struct Attention {
+    requested: bool,
+    phase: Option<Phase>,
+    fade: BacklightFade,
+}
+
+impl Attention {
+    pub const fn new() -> Self {
+        Self {
+            requested: false,
+            phase: None,
+            fade: BacklightFade::new(),
+        }
+    }
+
+    pub fn request(&mut self) {
+        self.requested = true;
+    }
+
+    pub fn event(&mut self, ctx: &mut EventCtx, event: Event) {
+        if let Event::Attach = event {
+            let now = Instant::now();
+            // SAFETY: Attached by the layout executor in the UI task, the
+            // reference does not outlive this block.
+            let idle = unsafe {
+                let activity = activity();
+                let idle = activity.is_idle(now);
+                activity.note(now);
+                idle
+            };
+            if self.requested && idle && self.phase.is_none() {
+                self.phase = Some(Phase::Waiting(ctx.request_timer(PULSE_DELAY)));
+            }
+        }
+
+        if let (Some(Phase::Waiting(token)), Event::Timer(t)) = (&self.phase, event) {
+            if *token == t {
+                let level = display::backlight();
+                self.fade.start(ctx, PULSE_LEVEL.max(level));
+                self.phase = Some(Phase::Rising(level));
+            }
+        }
+
+        self.fade.event(ctx, event);
+        if let Some(Phase::Rising(level)) = self.phase {
+            if !self.fade.is_running() {
+                self.fade.start(ctx, level);
+                self.phase = Some(Phase::Falling);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attention_idle() {
+        let mut activity = Activity::new();
+        let now = Instant::now();
+        let later = now.checked_add(IDLE_TIMEOUT).unwrap();
+        // Nothing was shown yet.
+        assert!(!activity.is_idle(later));
+
+        activity.note(now);
+        assert!(!activity.is_idle(now));
+        assert!(activity.is_idle(later));
+        activity.note(later);
+        assert!(!activity.is_idle(later));
+    }
+}
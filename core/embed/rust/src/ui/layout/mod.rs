@@ -1,3 +1,4 @@
+pub mod attention;
 pub mod obj;
 pub mod result;
 pub mod util;
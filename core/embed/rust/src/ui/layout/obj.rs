@@ -14,7 +14,7 @@ use crate::{
         typ::Type,
         util,
     },
-    time::Duration,
+    time::{Duration, Instant},
     ui::{
         component::{Child, Component, Event, EventCtx, Never, TimerToken},
         constant,
@@ -23,11 +23,15 @@ use crate::{
     },
 };
 
+use super::attention::{self, Attention};
+
 #[cfg(feature = "buttons")]
 use crate::ui::event::ButtonEvent;
+use crate::ui::event::{RecoveryEvent, StatusEvent, USBEvent};
+#[cfg(feature = "keyboard")]
+use crate::ui::{event::KeyEvent, keyboard};
 #[cfg(feature = "touch")]
-use crate::ui::event::TouchEvent;
-use crate::ui::event::USBEvent;
+use crate::{trezorhal::touch, ui::event::TouchEvent};
 
 /// Conversion trait implemented by components that know how to convert their
 /// message values into MicroPython `Obj`s.
@@ -113,6 +117,8 @@ struct LayoutObjInner {
     event_ctx: EventCtx,
     timer_fn: Obj,
     page_count: u16,
+    sensitive: bool,
+    attention: Attention,
 }
 
 impl LayoutObj {
@@ -132,6 +138,8 @@ impl LayoutObj {
                 event_ctx: EventCtx::new(),
                 timer_fn: Obj::const_none(),
                 page_count: 1,
+                sensitive: false,
+                attention: Attention::new(),
             }),
         })
     }
@@ -143,6 +151,18 @@ impl LayoutObj {
         unsafe { Gc::as_mut(&mut inner.root) }.obj_skip_paint();
     }
 
+    /// Mark the layout as showing sensitive data (PIN, passphrase, seed words).
+    /// Debug screenshot facilities refuse to capture such layouts.
+    pub fn mark_sensitive(&self) {
+        self.inner.borrow_mut().sensitive = true;
+    }
+
+    /// Pulse the backlight when the layout gets attached after the user was
+    /// away for a while, see `attention`.
+    pub fn request_attention(&self) {
+        self.inner.borrow_mut().attention.request();
+    }
+
     /// Timer callback is expected to be a callable object of the following
     /// form: `def timer(token: int, deadline_in_ms: int)`.
     fn obj_set_timer_fn(&self, timer_fn: Obj) {
@@ -172,6 +192,11 @@ impl LayoutObj {
         // All concerning `Child` wrappers should have already marked themselves for
         // painting by now, and we're prepared for a paint pass.
 
+        if is_input(event) {
+            attention::note_input(Instant::now());
+        }
+        inner.attention.event(&mut inner.event_ctx, event);
+
         // Drain any pending timers into the callback.
         while let Some((token, deadline)) = inner.event_ctx.pop_timer() {
             let token = token.try_into();
@@ -187,6 +212,9 @@ impl LayoutObj {
             inner.page_count = count as u16;
         }
 
+        #[cfg(feature = "ambient_light")]
+        crate::ui::display::brightness::update(inner.event_ctx.anim_frame_requested());
+
         Ok(msg)
     }
 
@@ -212,50 +240,7 @@ impl LayoutObj {
     /// raises an exception.
     #[cfg(feature = "ui_debug")]
     fn obj_trace(&self, callback: Obj) {
-        use crate::trace::{Trace, Tracer};
-
-        struct CallbackTracer(Obj);
-
-        impl Tracer for CallbackTracer {
-            fn int(&mut self, i: i64) {
-                self.0.call_with_n_args(&[i.try_into().unwrap()]).unwrap();
-            }
-
-            fn bytes(&mut self, b: &[u8]) {
-                self.0.call_with_n_args(&[b.try_into().unwrap()]).unwrap();
-            }
-
-            fn string(&mut self, s: &str) {
-                self.0.call_with_n_args(&[s.try_into().unwrap()]).unwrap();
-            }
-
-            fn symbol(&mut self, name: &str) {
-                self.0
-                    .call_with_n_args(&[
-                        "<".try_into().unwrap(),
-                        name.try_into().unwrap(),
-                        ">".try_into().unwrap(),
-                    ])
-                    .unwrap();
-            }
-
-            fn open(&mut self, name: &str) {
-                self.0
-                    .call_with_n_args(&["<".try_into().unwrap(), name.try_into().unwrap()])
-                    .unwrap();
-            }
-
-            fn field(&mut self, name: &str, value: &dyn Trace) {
-                self.0
-                    .call_with_n_args(&[name.try_into().unwrap(), ": ".try_into().unwrap()])
-                    .unwrap();
-                value.trace(self);
-            }
-
-            fn close(&mut self) {
-                self.0.call_with_n_args(&[">".try_into().unwrap()]).unwrap();
-            }
-        }
+        use crate::trace::Trace;
 
         self.inner
             .borrow()
@@ -263,10 +248,25 @@ impl LayoutObj {
             .trace(&mut CallbackTracer(callback));
     }
 
+    /// Trace the bounding box of the components, see `trace_bounds`.
+    #[cfg(feature = "ui_debug")]
+    fn obj_trace_bounds(&self, callback: Obj) {
+        use crate::ui::geometry::trace_bounds;
+
+        let inner = self.inner.borrow();
+        trace_bounds(&mut CallbackTracer(callback), |sink| {
+            inner.root.obj_bounds(sink)
+        });
+    }
+
     fn obj_page_count(&self) -> Obj {
         self.inner.borrow().page_count.into()
     }
 
+    fn obj_is_sensitive(&self) -> Obj {
+        self.inner.borrow().sensitive.into()
+    }
+
     #[cfg(feature = "ui_debug")]
     fn obj_bounds(&self) {
         use crate::ui::display;
@@ -293,18 +293,71 @@ impl LayoutObj {
                 Qstr::MP_QSTR_button_event => obj_fn_var!(3, 3, ui_layout_button_event).as_obj(),
                 Qstr::MP_QSTR_progress_event => obj_fn_var!(3, 3, ui_layout_progress_event).as_obj(),
                 Qstr::MP_QSTR_usb_event => obj_fn_var!(2, 2, ui_layout_usb_event).as_obj(),
+                Qstr::MP_QSTR_status_event => obj_fn_var!(3, 3, ui_layout_status_event).as_obj(),
+                Qstr::MP_QSTR_recovery_event => obj_fn_var!(3, 3, ui_layout_recovery_event).as_obj(),
+                Qstr::MP_QSTR_key_event => obj_fn_var!(2, 2, ui_layout_key_event).as_obj(),
                 Qstr::MP_QSTR_timer => obj_fn_2!(ui_layout_timer).as_obj(),
                 Qstr::MP_QSTR_paint => obj_fn_1!(ui_layout_paint).as_obj(),
                 Qstr::MP_QSTR_request_complete_repaint => obj_fn_1!(ui_layout_request_complete_repaint).as_obj(),
                 Qstr::MP_QSTR_trace => obj_fn_2!(ui_layout_trace).as_obj(),
+                Qstr::MP_QSTR_trace_bounds => obj_fn_2!(ui_layout_trace_bounds).as_obj(),
                 Qstr::MP_QSTR_bounds => obj_fn_1!(ui_layout_bounds).as_obj(),
                 Qstr::MP_QSTR_page_count => obj_fn_1!(ui_layout_page_count).as_obj(),
+                Qstr::MP_QSTR_is_sensitive => obj_fn_1!(ui_layout_is_sensitive).as_obj(),
+                Qstr::MP_QSTR_request_attention => obj_fn_1!(ui_layout_request_attention).as_obj(),
             }),
         };
         &TYPE
     }
 }
 
+/// Tracer passing every piece of the tracing information to a MicroPython
+/// callable. Panics in case the callable raises an exception.
+#[cfg(feature = "ui_debug")]
+pub struct CallbackTracer(pub Obj);
+
+#[cfg(feature = "ui_debug")]
+impl crate::trace::Tracer for CallbackTracer {
+    fn int(&mut self, i: i64) {
+        self.0.call_with_n_args(&[i.try_into().unwrap()]).unwrap();
+    }
+
+    fn bytes(&mut self, b: &[u8]) {
+        self.0.call_with_n_args(&[b.try_into().unwrap()]).unwrap();
+    }
+
+    fn string(&mut self, s: &str) {
+        self.0.call_with_n_args(&[s.try_into().unwrap()]).unwrap();
+    }
+
+    fn symbol(&mut self, name: &str) {
+        self.0
+            .call_with_n_args(&[
+                "<".try_into().unwrap(),
+                name.try_into().unwrap(),
+                ">".try_into().unwrap(),
+            ])
+            .unwrap();
+    }
+
+    fn open(&mut self, name: &str) {
+        self.0
+            .call_with_n_args(&["<".try_into().unwrap(), name.try_into().unwrap()])
+            .unwrap();
+    }
+
+    fn field(&mut self, name: &str, value: &dyn crate::trace::Trace) {
+        self.0
+            .call_with_n_args(&[name.try_into().unwrap(), ": ".try_into().unwrap()])
+            .unwrap();
+        value.trace(self);
+    }
+
+    fn close(&mut self) {
+        self.0.call_with_n_args(&[">".try_into().unwrap()]).unwrap();
+    }
+}
+
 impl From<Gc<LayoutObj>> for Obj {
     fn from(val: Gc<LayoutObj>) -> Self {
         // SAFETY:
@@ -365,6 +418,18 @@ impl TryFrom<Never> for Obj {
     }
 }
 
+/// Whether `event` comes from the user, as opposed to timers, USB and the
+/// like.
+fn is_input(event: Event) -> bool {
+    match event {
+        #[cfg(feature = "touch")]
+        Event::Touch(_) => true,
+        #[cfg(feature = "buttons")]
+        Event::Button(_) => true,
+        _ => false,
+    }
+}
+
 extern "C" fn ui_layout_attach_timer_fn(this: Obj, timer_fn: Obj) -> Obj {
     let block = || {
         let this: Gc<LayoutObj> = this.try_into()?;
@@ -387,7 +452,9 @@ extern "C" fn ui_layout_touch_event(n_args: usize, args: *const Obj) -> Obj {
             args[1].try_into()?,
             args[2].try_into()?,
             args[3].try_into()?,
-        )?;
+        )?
+        .calibrated()
+        .reject_large_contact(touch::contact().map(|c| c.area));
         let msg = this.obj_event(Event::Touch(event))?;
         Ok(msg)
     };
@@ -445,6 +512,57 @@ extern "C" fn ui_layout_usb_event(n_args: usize, args: *const Obj) -> Obj {
     unsafe { util::try_with_args_and_kwargs(n_args, args, &Map::EMPTY, block) }
 }
 
+extern "C" fn ui_layout_status_event(n_args: usize, args: *const Obj) -> Obj {
+    let block = |args: &[Obj], _kwargs: &Map| {
+        if args.len() != 3 {
+            return Err(Error::TypeError);
+        }
+        let this: Gc<LayoutObj> = args[0].try_into()?;
+        let event = StatusEvent::new(args[1].try_into()?, args[2].try_into()?)?;
+        let msg = this.obj_event(Event::Status(event))?;
+        Ok(msg)
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, &Map::EMPTY, block) }
+}
+
+extern "C" fn ui_layout_recovery_event(n_args: usize, args: *const Obj) -> Obj {
+    let block = |args: &[Obj], _kwargs: &Map| {
+        if args.len() != 3 {
+            return Err(Error::TypeError);
+        }
+        let this: Gc<LayoutObj> = args[0].try_into()?;
+        let event = RecoveryEvent::new(args[1].try_into()?, args[2].try_into()?)?;
+        let msg = this.obj_event(Event::Recovery(event))?;
+        Ok(msg)
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, &Map::EMPTY, block) }
+}
+
+#[cfg(feature = "keyboard")]
+extern "C" fn ui_layout_key_event(n_args: usize, args: *const Obj) -> Obj {
+    let block = |args: &[Obj], _kwargs: &Map| {
+        if args.len() != 2 {
+            return Err(Error::TypeError);
+        }
+        let this: Gc<LayoutObj> = args[0].try_into()?;
+        let key = KeyEvent::new(args[1].try_into()?)?;
+        // Feed the synthesized events one by one, stop at the first message.
+        for event in keyboard::key_events(key) {
+            let msg = this.obj_event(event)?;
+            if msg != Obj::const_none() {
+                return Ok(msg);
+            }
+        }
+        Ok(Obj::const_none())
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, &Map::EMPTY, block) }
+}
+
+#[cfg(not(feature = "keyboard"))]
+extern "C" fn ui_layout_key_event(_n_args: usize, _args: *const Obj) -> Obj {
+    Obj::const_none()
+}
+
 extern "C" fn ui_layout_timer(this: Obj, token: Obj) -> Obj {
     let block = || {
         let this: Gc<LayoutObj> = this.try_into()?;
@@ -488,6 +606,23 @@ extern "C" fn ui_layout_page_count(this: Obj) -> Obj {
     unsafe { util::try_or_raise(block) }
 }
 
+extern "C" fn ui_layout_is_sensitive(this: Obj) -> Obj {
+    let block = || {
+        let this: Gc<LayoutObj> = this.try_into()?;
+        Ok(this.obj_is_sensitive())
+    };
+    unsafe { util::try_or_raise(block) }
+}
+
+extern "C" fn ui_layout_request_attention(this: Obj) -> Obj {
+    let block = || {
+        let this: Gc<LayoutObj> = this.try_into()?;
+        this.request_attention();
+        Ok(Obj::const_none())
+    };
+    unsafe { util::try_or_raise(block) }
+}
+
 #[cfg(feature = "ui_debug")]
 #[no_mangle]
 pub extern "C" fn ui_debug_layout_type() -> &'static Type {
@@ -509,6 +644,21 @@ extern "C" fn ui_layout_trace(_this: Obj, _callback: Obj) -> Obj {
     Obj::const_none()
 }
 
+#[cfg(feature = "ui_debug")]
+extern "C" fn ui_layout_trace_bounds(this: Obj, callback: Obj) -> Obj {
+    let block = || {
+        let this: Gc<LayoutObj> = this.try_into()?;
+        this.obj_trace_bounds(callback);
+        Ok(Obj::const_none())
+    };
+    unsafe { util::try_or_raise(block) }
+}
+
+#[cfg(not(feature = "ui_debug"))]
+extern "C" fn ui_layout_trace_bounds(_this: Obj, _callback: Obj) -> Obj {
+    Obj::const_none()
+}
+
 #[cfg(feature = "ui_debug")]
 extern "C" fn ui_layout_bounds(this: Obj) -> Obj {
     let block = || {
@@ -9,16 +9,23 @@ use crate::{
         util::try_or_raise,
     },
     ui::{
+        animation::set_reduced_motion,
         component::text::{
             paragraphs::{Paragraph, ParagraphSource, ParagraphStrType},
+            props::PropSource,
+            tokens::set_bold_text,
             TextStyle,
         },
+        homoglyph::is_lookalike,
         util::set_animation_disabled,
     },
 };
 use cstr_core::cstr;
 use heapless::Vec;
 
+#[cfg(feature = "ui_debug")]
+use crate::time::{advance_clock, set_manual_clock, Duration};
+
 #[cfg(feature = "jpeg")]
 use crate::{
     micropython::{
@@ -114,137 +121,144 @@ impl ParagraphSource for ConfirmBlob {
     }
 }
 
-pub struct PropsList {
+/// Properties of `confirm_properties`, a list of `(key, value, value_is_mono)`
+/// tuples. Keys and values may be `None`, byte values are shown in hex.
+pub struct ObjProps {
     items: Gc<List>,
-    key_font: &'static TextStyle,
-    value_font: &'static TextStyle,
-    value_mono_font: &'static TextStyle,
 }
 
-impl PropsList {
-    pub fn new(
-        obj: Obj,
-        key_font: &'static TextStyle,
-        value_font: &'static TextStyle,
-        value_mono_font: &'static TextStyle,
-    ) -> Result<Self, Error> {
+impl ObjProps {
+    pub fn new(obj: Obj) -> Result<Self, Error> {
         Ok(Self {
             items: obj.try_into()?,
-            key_font,
-            value_font,
-            value_mono_font,
         })
     }
+
+    fn entry(&self, index: usize) -> Result<[Obj; 3], Error> {
+        iter_into_objs(self.items.get(index)?)
+    }
+
+    fn text(obj: Obj, offset: usize) -> Result<Option<StrBuffer>, Error> {
+        if obj == Obj::const_none() {
+            Ok(None)
+        } else if obj.is_str() {
+            let content: StrBuffer = obj.try_into()?;
+            Ok(Some(content.skip_prefix(offset)))
+        } else if obj.is_bytes() {
+            Ok(Some(hexlify_bytes(obj, offset, MAX_HEX_CHARS_ON_SCREEN)?))
+        } else {
+            Err(Error::TypeError)
+        }
+    }
+
+    fn error() -> StrBuffer {
+        StrBuffer::from("ERROR")
+    }
 }
 
-impl ParagraphSource for PropsList {
+impl PropSource for ObjProps {
     type StrType = StrBuffer;
 
-    fn at(&self, index: usize, offset: usize) -> Paragraph<Self::StrType> {
-        let block = move || {
-            let entry = self.items.get(index / 2)?;
-            let [key, value, value_is_mono]: [Obj; 3] = iter_into_objs(entry)?;
-            let value_is_mono: bool = bool::try_from(value_is_mono)?;
-            let obj: Obj;
-            let style: &TextStyle;
-
-            if index % 2 == 0 {
-                if !key.is_str() && key != Obj::const_none() {
-                    return Err(Error::TypeError);
-                }
-                style = self.key_font;
-                obj = key;
-            } else {
-                if value_is_mono {
-                    style = self.value_mono_font;
-                } else {
-                    if value.is_bytes() {
-                        return Err(Error::TypeError);
-                    }
-                    style = self.value_font;
-                }
-                obj = value;
-            };
-
-            if obj == Obj::const_none() {
-                return Ok(Paragraph::new(style, StrBuffer::empty()));
-            }
+    fn size(&self) -> usize {
+        self.items.len()
+    }
 
-            let para = if obj.is_str() {
-                let content: StrBuffer = obj.try_into()?;
-                Paragraph::new(style, content.skip_prefix(offset))
-            } else if obj.is_bytes() {
-                let content = hexlify_bytes(obj, offset, MAX_HEX_CHARS_ON_SCREEN)?;
-                Paragraph::new(style, content)
-            } else {
+    fn key(&self, index: usize, offset: usize) -> Option<StrBuffer> {
+        let block = || {
+            let [key, _, _] = self.entry(index)?;
+            if key.is_bytes() {
                 return Err(Error::TypeError);
-            };
+            }
+            Self::text(key, offset)
+        };
+        block().unwrap_or_else(|_| Some(Self::error()))
+    }
 
-            if obj == key && value != Obj::const_none() {
-                Ok(para.no_break())
-            } else {
-                Ok(para)
+    fn value(&self, index: usize, offset: usize) -> Option<(StrBuffer, bool)> {
+        let block = || {
+            let [_, value, value_is_mono] = self.entry(index)?;
+            let value_is_mono = bool::try_from(value_is_mono)?;
+            if !value_is_mono && value.is_bytes() {
+                return Err(Error::TypeError);
             }
+            Ok(Self::text(value, offset)?.map(|text| (text, value_is_mono)))
         };
-        match block() {
-            Ok(para) => para,
-            Err(_) => Paragraph::new(self.value_font, StrBuffer::from("ERROR")),
-        }
+        block().unwrap_or_else(|_| Some((Self::error(), false)))
     }
 
-    fn size(&self) -> usize {
-        2 * self.items.len()
+    fn has_value(&self, index: usize) -> bool {
+        matches!(self.entry(index), Ok([_, value, _]) if value != Obj::const_none())
     }
 }
 
-impl<T: ParagraphStrType, const N: usize> ParagraphSource for Vec<Paragraph<T>, N> {
-    type StrType = T;
-
-    fn at(&self, index: usize, offset: usize) -> Paragraph<Self::StrType> {
-        let para = &self[index];
-        para.map(|content| content.skip_prefix(offset))
+impl ParagraphStrType for StrBuffer {
+    fn skip_prefix(&self, chars: usize) -> Self {
+        self.offset(chars)
     }
 
-    fn size(&self) -> usize {
-        self.len()
+    fn take_prefix(&self, bytes: usize) -> Self {
+        self.truncate(bytes)
     }
 }
 
-impl<T: ParagraphStrType, const N: usize> ParagraphSource for [Paragraph<T>; N] {
-    type StrType = T;
-
-    fn at(&self, index: usize, offset: usize) -> Paragraph<Self::StrType> {
-        let para = &self[index];
-        para.map(|content| content.skip_prefix(offset))
-    }
+pub extern "C" fn upy_disable_animation(disable: Obj) -> Obj {
+    let block = || {
+        set_animation_disabled(disable.try_into()?);
+        Ok(Obj::const_none())
+    };
+    unsafe { try_or_raise(block) }
+}
 
-    fn size(&self) -> usize {
-        self.len()
-    }
+pub extern "C" fn upy_set_reduced_motion(enable: Obj) -> Obj {
+    let block = || {
+        set_reduced_motion(enable.try_into()?);
+        Ok(Obj::const_none())
+    };
+    unsafe { try_or_raise(block) }
 }
 
-impl<T: ParagraphStrType> ParagraphSource for Paragraph<T> {
-    type StrType = T;
+pub extern "C" fn upy_set_bold_text(enable: Obj) -> Obj {
+    let block = || {
+        set_bold_text(enable.try_into()?);
+        Ok(Obj::const_none())
+    };
+    unsafe { try_or_raise(block) }
+}
 
-    fn at(&self, index: usize, offset: usize) -> Paragraph<Self::StrType> {
-        assert_eq!(index, 0);
-        self.map(|content| content.skip_prefix(offset))
-    }
+pub extern "C" fn upy_enable_auto_brightness(min: Obj, max: Obj) -> Obj {
+    let block = || {
+        let min: i32 = min.try_into()?;
+        let max: i32 = max.try_into()?;
+        #[cfg(feature = "ambient_light")]
+        crate::ui::display::brightness::enable(min, max);
+        #[cfg(not(feature = "ambient_light"))]
+        let _ = (min, max);
+        Ok(Obj::const_none())
+    };
+    unsafe { try_or_raise(block) }
+}
 
-    fn size(&self) -> usize {
-        1
-    }
+pub extern "C" fn upy_is_lookalike(text: Obj) -> Obj {
+    let block = || {
+        let text: StrBuffer = text.try_into()?;
+        Ok(is_lookalike(text.as_ref()).into())
+    };
+    unsafe { try_or_raise(block) }
 }
 
-impl ParagraphStrType for StrBuffer {
-    fn skip_prefix(&self, chars: usize) -> Self {
-        self.offset(chars)
-    }
+#[cfg(feature = "ui_debug")]
+pub extern "C" fn upy_set_manual_clock(enable: Obj) -> Obj {
+    let block = || {
+        set_manual_clock(enable.try_into()?);
+        Ok(Obj::const_none())
+    };
+    unsafe { try_or_raise(block) }
 }
 
-pub extern "C" fn upy_disable_animation(disable: Obj) -> Obj {
+#[cfg(feature = "ui_debug")]
+pub extern "C" fn upy_advance_clock(millis: Obj) -> Obj {
     let block = || {
-        set_animation_disabled(disable.try_into()?);
+        advance_clock(Duration::from_millis(millis.try_into()?));
         Ok(Obj::const_none())
     };
     unsafe { try_or_raise(block) }
@@ -0,0 +1,89 @@
+use core::fmt::{self, Write};
+
+/// Severity of a `ui_log!` message, from the most to the least important.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+}
+
+impl Level {
+    fn tag(self) -> &'static str {
+        match self {
+            Level::Error => "E",
+            Level::Warn => "W",
+            Level::Info => "I",
+            Level::Debug => "D",
+        }
+    }
+}
+
+/// Least important level still printed. Raise it to `Debug` locally to see
+/// the layout diagnostics, which are printed on every page change.
+const MAX_LEVEL: Level = Level::Warn;
+
+/// Longest message printed, the rest is cut off.
+const MAX_LINE_LENGTH: usize = 160;
+
+/// Whether messages of `level` reach the debug console. Constant, so that the
+/// `ui_log!` calls compile to nothing in builds without the console.
+pub const fn enabled(level: Level) -> bool {
+    cfg!(all(feature = "debug", feature = "micropython")) && level as u8 <= MAX_LEVEL as u8
+}
+
+/// Print the message of `ui_log!` to the debug console, prefixed by its level
+/// and the module it comes from. Use the macro instead of calling this
+/// directly.
+pub fn log(level: Level, target: &str, args: fmt::Arguments) {
+    #[cfg(all(feature = "debug", feature = "micropython"))]
+    {
+        let mut line = heapless::String::<MAX_LINE_LENGTH>::new();
+        // Overflow only truncates the message.
+        let _ = write_line(&mut line, level, target, args);
+        crate::micropython::print::print(&line);
+        crate::micropython::print::print("\n");
+    }
+    #[cfg(not(all(feature = "debug", feature = "micropython")))]
+    let _ = (level, target, args);
+}
+
+fn write_line(w: &mut dyn Write, level: Level, target: &str, args: fmt::Arguments) -> fmt::Result {
+    let target = target.strip_prefix("trezor_lib::").unwrap_or(target);
+    write!(w, "[{}] {}: ", level.tag(), target)?;
+    w.write_fmt(args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn log_line_format() {
+        let mut line = heapless::String::<MAX_LINE_LENGTH>::new();
+        write_line(
+            &mut line,
+            Level::Warn,
+            "trezor_lib::ui::component::text",
+            format_args!("{} lines", 3),
+        )
+        .unwrap();
+        assert_eq!(line.as_str(), "[W] ui::component::text: 3 lines");
+    }
+
+    #[test]
+    fn log_levels() {
+        assert!(Level::Error < Level::Debug);
+        assert_eq!(
+            enabled(Level::Error),
+            cfg!(all(feature = "debug", feature = "micropython"))
+        );
+        assert_eq!(
+            enabled(Level::Warn),
+            cfg!(all(feature = "debug", feature = "micropython"))
+        );
+        assert!(!enabled(Level::Info));
+        assert!(!enabled(Level::Debug));
+    }
+}
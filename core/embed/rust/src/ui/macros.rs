@@ -4,3 +4,29 @@ macro_rules! include_res {
         include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/ui/", $filename))
     };
 }
+
+/// Print a message to the debug console, e.g. `ui_log!(Debug, "{} pages",
+/// count)`. The level is one of `Error`, `Warn`, `Info` or `Debug`, see
+/// `ui::log::Level`. Compiles to nothing without the `debug` feature, the
+/// arguments are type-checked nevertheless.
+#[allow(unused_macros)]
+macro_rules! ui_log {
+    ($level:ident, $($arg:tt)+) => {
+        if crate::ui::log::enabled(crate::ui::log::Level::$level) {
+            crate::ui::log::log(
+                crate::ui::log::Level::$level,
+                module_path!(),
+                format_args!($($arg)+),
+            );
+        }
+    };
+}
+
+/// Fail the build if the constant expression `$cond` does not hold, e.g. when
+/// layout constants do not fit the screen of the model being built.
+#[allow(unused_macros)]
+macro_rules! const_assert {
+    ($cond:expr, $msg:expr) => {
+        const _: () = assert!($cond, $msg);
+    };
+}
@@ -5,9 +5,21 @@ pub mod animation;
 pub mod component;
 pub mod constant;
 pub mod display;
+pub mod error_code;
 pub mod event;
 pub mod geometry;
+pub mod grapheme;
+pub mod homoglyph;
+#[cfg(feature = "keyboard")]
+pub mod keyboard;
 pub mod lerp;
+pub mod log;
+pub mod pool;
+pub mod screens;
+pub mod text_buffer;
+pub mod theme;
+#[cfg(feature = "touch")]
+pub mod touch_calibration;
 mod util;
 
 #[cfg(feature = "micropython")]
@@ -1,13 +1,17 @@
-use crate::ui::{
-    component::{Component, Event, EventCtx},
-    display::{self, Color, Font},
-    event::{ButtonEvent, PhysicalButton},
-    geometry::{Offset, Point, Rect},
+use crate::{
+    time::Duration,
+    ui::{
+        component::{Component, Event, EventCtx, ResultButton, TimerToken},
+        display::{self, Color, Font},
+        event::{ButtonEvent, PhysicalButton},
+        geometry::{Offset, Point, Rect},
+    },
 };
 
 use super::theme;
 
 pub enum ButtonMsg {
+    /// Button was released, or is being held with auto-repeat enabled.
     Clicked,
 }
 
@@ -33,6 +37,9 @@ pub struct Button<T> {
     content: ButtonContent<T>,
     styles: ButtonStyleSheet,
     state: State,
+    auto_repeat: bool,
+    repeat_timer: Option<TimerToken>,
+    repeated: bool,
 }
 
 impl<T: AsRef<str>> Button<T> {
@@ -44,9 +51,23 @@ impl<T: AsRef<str>> Button<T> {
             baseline: Point::zero(),
             area: Rect::zero(),
             state: State::Released,
+            auto_repeat: false,
+            repeat_timer: None,
+            repeated: false,
         }
     }
 
+    /// Keep emitting `Clicked` while the button is held, first after
+    /// `REPEAT_DELAY` and then every `REPEAT_INTERVAL`. Releasing the button
+    /// after a repeat does not emit another `Clicked`.
+    pub fn with_auto_repeat(mut self) -> Self {
+        self.auto_repeat = true;
+        self
+    }
+
+    pub const REPEAT_DELAY: Duration = Duration::from_millis(500);
+    pub const REPEAT_INTERVAL: Duration = Duration::from_millis(150);
+
     pub fn with_text(pos: ButtonPos, text: T, styles: ButtonStyleSheet) -> Self {
         Self::new(pos, ButtonContent::Text(text), styles)
     }
@@ -113,12 +134,27 @@ where
         match event {
             Event::Button(ButtonEvent::ButtonPressed(which)) if self.pos.hit(&which) => {
                 self.set(ctx, State::Pressed);
+                self.repeated = false;
+                if self.auto_repeat {
+                    self.repeat_timer = Some(ctx.request_timer(Self::REPEAT_DELAY));
+                }
             }
             Event::Button(ButtonEvent::ButtonReleased(which)) if self.pos.hit(&which) => {
+                self.repeat_timer = None;
                 if matches!(self.state, State::Pressed) {
                     self.set(ctx, State::Released);
+                    if !self.repeated {
+                        return Some(ButtonMsg::Clicked);
+                    }
+                }
+            }
+            Event::Timer(token) if self.repeat_timer == Some(token) => {
+                if matches!(self.state, State::Pressed) {
+                    self.repeated = true;
+                    self.repeat_timer = Some(ctx.request_timer(Self::REPEAT_INTERVAL));
                     return Some(ButtonMsg::Clicked);
                 }
+                self.repeat_timer = None;
             }
             _ => {}
         };
@@ -152,6 +188,15 @@ where
     }
 }
 
+impl<T> ResultButton for Button<T>
+where
+    T: AsRef<str>,
+{
+    fn is_clicked(msg: &ButtonMsg) -> bool {
+        matches!(msg, ButtonMsg::Clicked)
+    }
+}
+
 #[cfg(feature = "ui_debug")]
 impl<T> crate::trace::Trace for Button<T>
 where
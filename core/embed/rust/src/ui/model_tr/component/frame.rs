@@ -1,13 +1,15 @@
-use super::theme;
+use super::{theme, ScrollingLabel};
 use crate::ui::{
     component::{Child, Component, Event, EventCtx},
     display::{self, Font},
-    geometry::{Insets, Offset, Rect},
+    geometry::{Insets, Point, Rect},
 };
 
+/// Content below a bold title, which scrolls when it is too wide for the
+/// screen, e.g. with a long coin name.
 pub struct Frame<T, U> {
     area: Rect,
-    title: U,
+    title: Child<ScrollingLabel<U>>,
     content: Child<T>,
 }
 
@@ -18,7 +20,7 @@ where
 {
     pub fn new(title: U, content: T) -> Self {
         Self {
-            title,
+            title: Child::new(ScrollingLabel::bold(title)),
             area: Rect::zero(),
             content: Child::new(content),
         }
@@ -42,23 +44,24 @@ where
         let (title_area, content_area) = bounds.split_top(Font::BOLD.line_height());
         let content_area = content_area.inset(Insets::top(TITLE_SPACE));
 
+        // Title text sits on a baseline slightly above the dotted line.
+        let baseline = title_area.y1 - 2;
+        self.title.place(Rect::new(
+            Point::new(title_area.x0, baseline - Font::BOLD.ascent()),
+            Point::new(title_area.x1, baseline + Font::BOLD.descent()),
+        ));
         self.area = title_area;
         self.content.place(content_area);
         bounds
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        self.title.event(ctx, event);
         self.content.event(ctx, event)
     }
 
     fn paint(&mut self) {
-        display::text(
-            self.area.bottom_left() - Offset::y(2),
-            self.title.as_ref(),
-            Font::BOLD,
-            theme::FG,
-            theme::BG,
-        );
+        self.title.paint();
         display::dotted_line(self.area.bottom_left(), self.area.width(), theme::FG);
         self.content.paint();
     }
@@ -72,7 +75,7 @@ where
 {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.open("Frame");
-        t.field("title", &self.title);
+        t.field("title", self.title.inner().text());
         t.field("content", &self.content);
         t.close();
     }
@@ -1,7 +1,7 @@
 use crate::{
     time::{Duration, Instant},
     ui::{
-        animation::Animation,
+        animation::Timeline,
         component::{Component, Event, EventCtx},
         display::{self, Color, Font},
         geometry::{Offset, Rect},
@@ -15,8 +15,8 @@ pub enum LoaderMsg {
 
 enum State {
     Initial,
-    Growing(Animation<u16>),
-    Shrinking(Animation<u16>),
+    Growing(Timeline<u16>),
+    Shrinking(Timeline<u16>),
     Grown,
 }
 
@@ -46,12 +46,13 @@ impl Loader {
     }
 
     pub fn start_growing(&mut self, ctx: &mut EventCtx, now: Instant) {
-        let mut anim = Animation::new(
+        let mut anim = Timeline::new(
             display::LOADER_MIN,
             display::LOADER_MAX,
             self.growing_duration,
             now,
-        );
+        )
+        .functional();
         if let State::Shrinking(shrinking) = &self.state {
             anim.seek_to_value(shrinking.value(now));
         }
@@ -66,12 +67,13 @@ impl Loader {
     }
 
     pub fn start_shrinking(&mut self, ctx: &mut EventCtx, now: Instant) {
-        let mut anim = Animation::new(
+        let mut anim = Timeline::new(
             display::LOADER_MAX,
             display::LOADER_MIN,
             self.shrinking_duration,
             now,
-        );
+        )
+        .functional();
         if let State::Growing(growing) = &self.state {
             anim.seek_to_value(display::LOADER_MAX - growing.value(now));
         }
@@ -87,7 +89,7 @@ impl Loader {
         self.state = State::Initial;
     }
 
-    pub fn animation(&self) -> Option<&Animation<u16>> {
+    pub fn animation(&self) -> Option<&Timeline<u16>> {
         match &self.state {
             State::Initial => None,
             State::Grown => None,
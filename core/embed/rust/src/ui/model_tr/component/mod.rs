@@ -5,7 +5,7 @@ mod frame;
 mod loader;
 mod page;
 mod result_anim;
-mod result_popup;
+mod scrolling_label;
 
 use super::theme;
 
@@ -16,4 +16,10 @@ pub use frame::Frame;
 pub use loader::{Loader, LoaderMsg, LoaderStyle, LoaderStyleSheet};
 pub use page::ButtonPage;
 pub use result_anim::{ResultAnim, ResultAnimMsg};
-pub use result_popup::{ResultPopup, ResultPopupMsg};
+pub use scrolling_label::ScrollingLabel;
+
+pub use crate::ui::component::ResultPopupMsg;
+
+/// Result notification with the growing icon and the buttons of the model.
+pub type ResultPopup<T> =
+    crate::ui::component::result_popup::ResultPopup<T, ResultAnim, Button<&'static str>>;
@@ -26,8 +26,10 @@ where
             content,
             scrollbar: ScrollBar::vertical(),
             pad: Pad::with_background(background),
-            prev: Button::with_text(ButtonPos::Left, "BACK", theme::button_cancel()),
-            next: Button::with_text(ButtonPos::Right, "NEXT", theme::button_default()),
+            prev: Button::with_text(ButtonPos::Left, "BACK", theme::button_cancel())
+                .with_auto_repeat(),
+            next: Button::with_text(ButtonPos::Right, "NEXT", theme::button_default())
+                .with_auto_repeat(),
             cancel: Button::with_text(ButtonPos::Left, "CANCEL", theme::button_cancel()),
             confirm: Button::with_text(ButtonPos::Right, "CONFIRM", theme::button_default()),
         }
@@ -57,7 +59,8 @@ where
         self.pad.place(bounds);
         self.content.place(content_area);
         let page_count = self.content.page_count();
-        self.scrollbar.set_count_and_active_page(page_count, 0);
+        let active_page = self.scrollbar.set_count_keep_active_page(page_count);
+        self.content.change_page(active_page);
         self.scrollbar.place(scrollbar_area);
         self.prev.place(button_area);
         self.next.place(button_area);
@@ -151,6 +154,14 @@ impl ScrollBar {
         self.active_page = active_page;
     }
 
+    /// Update the page count after the content has been laid out again,
+    /// staying on the active page if it still exists. Returns the active page.
+    pub fn set_count_keep_active_page(&mut self, page_count: usize) -> usize {
+        self.page_count = page_count;
+        self.active_page = self.active_page.min(page_count.saturating_sub(1));
+        self.active_page
+    }
+
     pub fn has_next_page(&self) -> bool {
         self.active_page < self.page_count - 1
     }
@@ -101,6 +101,11 @@ impl Component for ResultAnim {
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
         let now = Instant::now();
 
+        // Grow as soon as the screen is shown.
+        if let (Event::Attach, State::Initial) = (event, &self.state) {
+            self.start_growing(ctx, now);
+        }
+
         if let Event::Timer(EventCtx::ANIM_FRAME_TIMER) = event {
             if let State::Growing(_) = self.state {
                 // We have something to paint, so request to be painted in the next pass.
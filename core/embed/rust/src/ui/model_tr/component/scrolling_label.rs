@@ -0,0 +1,107 @@
+use super::theme;
+use crate::{
+    time::Instant,
+    ui::{
+        component::{Component, Event, EventCtx, Marquee, Never},
+        display::Font,
+        geometry::Rect,
+    },
+};
+
+/// Single line of text, e.g. a coin name or an URL, which scrolls back and
+/// forth when it is too wide for the screen. Text which fits stays still.
+pub struct ScrollingLabel<T> {
+    marquee: Marquee<T>,
+}
+
+impl<T> ScrollingLabel<T>
+where
+    T: AsRef<str>,
+{
+    pub fn new(text: T, font: Font) -> Self {
+        Self {
+            marquee: Marquee::new(text, font, theme::FG, theme::BG)
+                .with_speed(theme::MARQUEE_MILLIS_PER_LETTER)
+                .with_pause(theme::MARQUEE_PAUSE),
+        }
+    }
+
+    pub fn normal(text: T) -> Self {
+        Self::new(text, Font::NORMAL)
+    }
+
+    pub fn bold(text: T) -> Self {
+        Self::new(text, Font::BOLD)
+    }
+
+    pub fn mono(text: T) -> Self {
+        Self::new(text, Font::MONO)
+    }
+
+    pub fn text(&self) -> &T {
+        self.marquee.text()
+    }
+}
+
+impl<T> Component for ScrollingLabel<T>
+where
+    T: AsRef<str>,
+{
+    type Msg = Never;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        // Scrolling distance depends on the area, start over on the next attach.
+        self.marquee.reset();
+        self.marquee.place(bounds)
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        let now = Instant::now();
+        if matches!(event, Event::Attach) && !self.marquee.fits() {
+            self.marquee.start(ctx, now);
+        }
+        self.marquee.event_at(ctx, event, now);
+        None
+    }
+
+    fn paint(&mut self) {
+        self.marquee.paint();
+    }
+
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        self.marquee.bounds(sink)
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl<T> crate::trace::Trace for ScrollingLabel<T>
+where
+    T: AsRef<str>,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.open("ScrollingLabel");
+        t.field("content", &self.marquee);
+        t.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::geometry::Point;
+
+    fn attached(text: &'static str) -> ScrollingLabel<&'static str> {
+        let mut label = ScrollingLabel::normal(text);
+        label.place(Rect::new(Point::zero(), Point::new(60, 10)));
+        label.event(&mut EventCtx::new(), Event::Attach);
+        label
+    }
+
+    #[test]
+    fn scrolling_label_starts_when_too_wide() {
+        assert!(!attached("BTC").marquee.is_animating());
+        assert!(attached("Bitcoin Cash (Testnet) on a narrow screen")
+            .marquee
+            .is_animating());
+    }
+}
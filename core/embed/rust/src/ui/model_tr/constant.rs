@@ -3,10 +3,12 @@ use crate::ui::geometry::{Offset, Point, Rect};
 pub const WIDTH: i16 = 128;
 pub const HEIGHT: i16 = 128;
 pub const LINE_SPACE: i16 = 1;
+/// Display is monochromatic, see `Color::to_mono`.
+pub const MONO_DISPLAY: bool = true;
 pub const FONT_BPP: i16 = 1;
+/// No touch screen, contacts are never rejected.
+pub const TOUCH_MAX_CONTACT_AREA: Option<u8> = None;
 
-pub const LOADER_OUTER: f32 = 32_f32;
-pub const LOADER_INNER: f32 = 18_f32;
 pub const LOADER_ICON_MAX_SIZE: i16 = 8;
 
 pub const fn size() -> Offset {
@@ -7,17 +7,26 @@ use crate::{
         component::{
             base::Component,
             paginated::{PageMsg, Paginate},
-            text::paragraphs::{Paragraph, Paragraphs},
+            text::{
+                paragraphs::{Paragraph, Paragraphs},
+                StyleToken,
+            },
             FormattedText,
         },
         layout::{
             obj::{ComponentMsgObj, LayoutObj},
             result::{CANCELLED, CONFIRMED},
-            util::upy_disable_animation,
+            util::{
+                upy_disable_animation, upy_enable_auto_brightness, upy_is_lookalike,
+                upy_set_bold_text, upy_set_reduced_motion,
+            },
         },
     },
 };
 
+#[cfg(feature = "ui_debug")]
+use crate::ui::layout::util::{upy_advance_clock, upy_set_manual_clock};
+
 use super::{
     component::{Button, ButtonPage, ButtonPos, Frame},
     theme,
@@ -74,7 +83,7 @@ extern "C" fn new_confirm_action(n_args: usize, args: *const Obj, kwargs: *mut M
         let obj = LayoutObj::new(Frame::new(
             title,
             ButtonPage::new(
-                FormattedText::new(theme::TEXT_NORMAL, theme::FORMATTED, format)
+                FormattedText::new(*theme::text(StyleToken::Body), theme::FORMATTED, format)
                     .with("action", action.unwrap_or_default())
                     .with("description", description.unwrap_or_default()),
                 theme::BG,
@@ -96,8 +105,11 @@ extern "C" fn new_confirm_text(n_args: usize, args: *const Obj, kwargs: *mut Map
             title,
             ButtonPage::new(
                 Paragraphs::new([
-                    Paragraph::new(&theme::TEXT_NORMAL, description.unwrap_or_default()),
-                    Paragraph::new(&theme::TEXT_BOLD, data),
+                    Paragraph::new(
+                        theme::text(StyleToken::Body),
+                        description.unwrap_or_default(),
+                    ),
+                    Paragraph::new(theme::text(StyleToken::Emphasis), data),
                 ]),
                 theme::BG,
             ),
@@ -121,6 +133,35 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     """Disable animations, debug builds only."""
     Qstr::MP_QSTR_disable_animation => obj_fn_1!(upy_disable_animation).as_obj(),
 
+    /// def set_reduced_motion(enable: bool) -> None:
+    ///     """Skip animations and transitions to their end states."""
+    Qstr::MP_QSTR_set_reduced_motion => obj_fn_1!(upy_set_reduced_motion).as_obj(),
+
+    /// def set_bold_text(enable: bool) -> None:
+    ///     """Use heavier fonts in the layouts created from now on."""
+    Qstr::MP_QSTR_set_bold_text => obj_fn_1!(upy_set_bold_text).as_obj(),
+
+    /// def enable_auto_brightness(min: int, max: int) -> None:
+    ///     """Adjust the backlight between `min` and `max` according to the ambient
+    ///     light, if the device has a sensor."""
+    Qstr::MP_QSTR_enable_auto_brightness => obj_fn_2!(upy_enable_auto_brightness).as_obj(),
+
+    /// def is_lookalike(text: str) -> bool:
+    ///     """Whether the name could be mistaken for a different one, e.g. when
+    ///     spelled with Cyrillic letters resembling the Latin ones."""
+    Qstr::MP_QSTR_is_lookalike => obj_fn_1!(upy_is_lookalike).as_obj(),
+
+    /// def set_manual_clock(enable: bool) -> None:
+    ///     """Stop the animation clock, to be stepped by `advance_clock`. Debug
+    ///     builds only."""
+    #[cfg(feature = "ui_debug")]
+    Qstr::MP_QSTR_set_manual_clock => obj_fn_1!(upy_set_manual_clock).as_obj(),
+
+    /// def advance_clock(millis: int) -> None:
+    ///     """Move the stopped animation clock forward. Debug builds only."""
+    #[cfg(feature = "ui_debug")]
+    Qstr::MP_QSTR_advance_clock => obj_fn_1!(upy_advance_clock).as_obj(),
+
     /// def confirm_action(
     ///     *,
     ///     title: str,
@@ -184,7 +225,7 @@ mod tests {
     fn trace_example_layout() {
         let mut layout = Dialog::new(
             FormattedText::new(
-                theme::TEXT_NORMAL,
+                *theme::text(StyleToken::Body),
                 theme::FORMATTED,
                 "Testing text layout, with some text, and some more text. And {param}",
             )
@@ -216,7 +257,7 @@ arameters! > left:<Button text:Left > right:<Button text:Right > >"#
             "Please confirm",
             Dialog::new(
                 FormattedText::new(
-                    theme::TEXT_NORMAL,
+                    *theme::text(StyleToken::Body),
                     theme::FORMATTED,
                     "Testing text layout, with some text, and some more text. And {param}",
                 )
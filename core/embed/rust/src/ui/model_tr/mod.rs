@@ -1,5 +1,6 @@
 pub mod component;
 pub mod constant;
+pub mod screens;
 pub mod theme;
 
 #[cfg(feature = "micropython")]
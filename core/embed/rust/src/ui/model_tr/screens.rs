@@ -0,0 +1,61 @@
+use crate::ui::{
+    component::text::{layout::TextLayout, StyleToken},
+    constant::screen,
+    display::{self, Font},
+    error_code::ErrorCode,
+    geometry::{Alignment, Insets, Point},
+};
+
+use super::theme;
+
+const TITLE_BASELINE: i16 = 10;
+const CODE_BASELINE: i16 = 22;
+const MESSAGE_HEIGHT: i16 = 20;
+const QR_SIZE: u32 = 64;
+const QR_CENTER: Point = Point::new(screen().center().x, 80);
+const FOOTER_BASELINE: i16 = 126;
+
+/// Full-screen error notice with a prominent error code. If given, `qr_data`
+/// is encoded in a QR code so that the details can be easily shared with
+/// support.
+pub fn screen_error(title: &str, msg: &str, code: ErrorCode, qr_data: Option<&str>, footer: &str) {
+    let area = screen();
+    display::rect_fill(area, theme::BG);
+
+    display::text_center(
+        Point::new(area.center().x, TITLE_BASELINE),
+        title,
+        Font::BOLD,
+        theme::FG,
+        theme::BG,
+    );
+    display::text_center(
+        Point::new(area.center().x, CODE_BASELINE),
+        code.as_str(),
+        Font::BOLD,
+        theme::FG,
+        theme::BG,
+    );
+
+    let message_area = area
+        .inset(Insets::top(CODE_BASELINE + 2))
+        .split_top(MESSAGE_HEIGHT)
+        .0;
+    TextLayout::new(*theme::text(StyleToken::Body))
+        .with_bounds(message_area)
+        .with_align(Alignment::Center)
+        .render_text(msg);
+
+    if let Some(data) = qr_data {
+        // Nothing sensible can be done if the data do not fit into a QR code.
+        display::qrcode(QR_CENTER, data, QR_SIZE, true).unwrap_or(());
+    }
+
+    display::text_center(
+        Point::new(area.center().x, FOOTER_BASELINE),
+        footer,
+        Font::NORMAL,
+        theme::FG,
+        theme::BG,
+    );
+}
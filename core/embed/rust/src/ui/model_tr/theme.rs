@@ -1,14 +1,22 @@
-use crate::ui::{
-    component::text::{formatted::FormattedFonts, TextStyle},
-    display::{Color, Font},
-    model_tr::component::{LoaderStyle, LoaderStyleSheet},
+use crate::{
+    time::Duration,
+    ui::{
+        component::text::{
+            formatted::FormattedFonts,
+            tokens::{bold_text, TextTheme},
+            StyleToken, TextStyle,
+        },
+        display::{Color, Font, LoaderGeometry},
+        geometry::Insets,
+        model_tr::component::{LoaderStyle, LoaderStyleSheet},
+    },
 };
 
 use super::component::{ButtonStyle, ButtonStyleSheet};
 
 // Color palette.
-pub const WHITE: Color = Color::rgb(255, 255, 255);
-pub const BLACK: Color = Color::rgb(0, 0, 0);
+pub const WHITE: Color = Color::white();
+pub const BLACK: Color = Color::black();
 pub const GREY_LIGHT: Color = WHITE; // Word/page break characters.
 pub const FG: Color = WHITE; // Default foreground (text & icon) color.
 pub const BG: Color = BLACK; // Default background color.
@@ -46,6 +54,15 @@ pub fn button_cancel() -> ButtonStyleSheet {
     }
 }
 
+// Scrolling of labels too wide for the screen, see `ScrollingLabel`.
+pub const MARQUEE_MILLIS_PER_LETTER: u32 = 200;
+pub const MARQUEE_PAUSE: Duration = Duration::from_millis(1500);
+
+pub const LOADER: LoaderGeometry = LoaderGeometry::new(32, 14);
+/// Loader of the install and wipe progress screens, making room for the text
+/// below it.
+pub const PROGRESS_LOADER: LoaderGeometry = LOADER.with_y_offset(-20);
+
 pub fn loader_default() -> LoaderStyleSheet {
     LoaderStyleSheet {
         normal: &LoaderStyle {
@@ -56,10 +73,49 @@ pub fn loader_default() -> LoaderStyleSheet {
     }
 }
 
-pub const TEXT_NORMAL: TextStyle = TextStyle::new(Font::NORMAL, FG, BG, FG, FG);
-pub const TEXT_DEMIBOLD: TextStyle = TextStyle::new(Font::DEMIBOLD, FG, BG, FG, FG);
-pub const TEXT_BOLD: TextStyle = TextStyle::new(Font::BOLD, FG, BG, FG, FG);
-pub const TEXT_MONO: TextStyle = TextStyle::new(Font::MONO, FG, BG, FG, FG);
+/// Two dots are enough to see the text goes on, and leave more of the narrow
+/// display to the text itself.
+pub const TEXT_NORMAL: TextStyle = TextStyle::new(Font::NORMAL, FG, BG, FG, FG).with_ellipsis("..");
+pub const TEXT_DEMIBOLD: TextStyle = TEXT_NORMAL.with_font(Font::DEMIBOLD);
+pub const TEXT_BOLD: TextStyle = TEXT_NORMAL.with_font(Font::BOLD);
+pub const TEXT_MONO: TextStyle = TEXT_NORMAL
+    .with_font(Font::MONO)
+    .with_escape_invisible(true);
+
+/// The display has a single color, warnings are distinguished by the font.
+pub const TEXT_THEME: TextTheme = TextTheme {
+    title: &TEXT_BOLD,
+    subtitle: &TEXT_DEMIBOLD,
+    body: &TEXT_NORMAL,
+    secondary: &TEXT_NORMAL,
+    emphasis: &TEXT_BOLD,
+    mono: &TEXT_MONO,
+    warning: &TEXT_BOLD,
+};
+
+pub const TEXT_THEME_BOLD: TextTheme = TextTheme {
+    title: &TEXT_BOLD,
+    subtitle: &TEXT_BOLD,
+    body: &TEXT_DEMIBOLD,
+    secondary: &TEXT_DEMIBOLD,
+    emphasis: &TEXT_BOLD,
+    mono: &TEXT_MONO,
+    warning: &TEXT_BOLD,
+};
+
+/// Text theme selected in the settings.
+pub fn text_theme() -> &'static TextTheme {
+    if bold_text() {
+        &TEXT_THEME_BOLD
+    } else {
+        &TEXT_THEME
+    }
+}
+
+/// Style of the text with the given meaning.
+pub fn text(token: StyleToken) -> &'static TextStyle {
+    text_theme().style(token)
+}
 
 pub const FORMATTED: FormattedFonts = FormattedFonts {
     normal: Font::NORMAL,
@@ -67,3 +123,12 @@ pub const FORMATTED: FormattedFonts = FormattedFonts {
     bold: Font::BOLD,
     mono: Font::MONO,
 };
+
+pub const BUTTON_HEIGHT: i16 = 13;
+pub const CHECKLIST_SPACING: i16 = 2;
+pub const RESULT_ICON_HEIGHT: i16 = 24;
+
+/// The screen is too small to leave a border around the content.
+pub const fn borders() -> Insets {
+    Insets::zero()
+}
@@ -0,0 +1,119 @@
+use crate::ui::{
+    component::{text::StyleToken, Component, Event, EventCtx, Label, Pad},
+    constant::screen,
+    display,
+    event::TouchEvent,
+    geometry::{Offset, Point, Rect},
+    model_tt::theme,
+    touch_calibration::Calibration,
+};
+
+use super::result::{CalibrationResult, ReturnToC};
+
+#[derive(Copy, Clone)]
+pub enum CalibrationMsg {
+    Done,
+}
+
+impl ReturnToC for CalibrationMsg {
+    fn return_to_c(self) -> u32 {
+        match self {
+            Self::Done => CalibrationResult::Done,
+        }
+        .return_to_c()
+    }
+}
+
+/// Asks the user to tap two crosshairs near opposite corners of the screen and
+/// computes the touch calibration from the reported positions. Expects touch
+/// events without any calibration applied.
+pub struct TouchCalibration {
+    label: Label<&'static str>,
+    pad: Pad,
+    targets: [Point; 2],
+    measured: [Point; 2],
+    step: usize,
+    result: Option<Calibration>,
+}
+
+impl TouchCalibration {
+    pub fn new() -> Self {
+        let area = screen();
+        Self {
+            label: Label::centered(
+                "Tap the center of the cross",
+                *theme::text(StyleToken::Body),
+            ),
+            pad: Pad::with_background(theme::BG),
+            targets: [
+                area.top_left() + Offset::uniform(Self::TARGET_INSET),
+                area.bottom_right() - Offset::uniform(Self::TARGET_INSET + 1),
+            ],
+            measured: [Point::zero(); 2],
+            step: 0,
+            result: None,
+        }
+    }
+
+    /// Calibration computed from the taps, `None` if the measurement was
+    /// implausible.
+    pub fn result(&self) -> Option<Calibration> {
+        self.result
+    }
+
+    const TARGET_INSET: i16 = 30;
+    const TARGET_SIZE: i16 = 21;
+}
+
+impl Component for TouchCalibration {
+    type Msg = CalibrationMsg;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.pad.place(bounds);
+        let label_size = Offset::new(bounds.width(), self.label.font().line_height());
+        self.label
+            .place(Rect::from_center_and_size(bounds.center(), label_size));
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        if let Event::Touch(TouchEvent::TouchEnd(point)) = event {
+            self.measured[self.step] = point;
+            self.step += 1;
+            if self.step == self.targets.len() {
+                self.result = Calibration::from_samples(self.targets, self.measured);
+                return Some(CalibrationMsg::Done);
+            }
+            self.pad.clear();
+            ctx.request_paint();
+        }
+        None
+    }
+
+    fn paint(&mut self) {
+        self.pad.paint();
+        self.label.paint();
+        let target = self.targets[self.step];
+        display::rect_fill(
+            Rect::from_center_and_size(target, Offset::new(Self::TARGET_SIZE, 1)),
+            theme::FG,
+        );
+        display::rect_fill(
+            Rect::from_center_and_size(target, Offset::new(1, Self::TARGET_SIZE)),
+            theme::FG,
+        );
+    }
+
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        sink(self.pad.area);
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl crate::trace::Trace for TouchCalibration {
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.open("TouchCalibration");
+        t.field("step", &self.step);
+        t.close();
+    }
+}
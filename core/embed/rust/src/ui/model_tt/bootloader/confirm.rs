@@ -0,0 +1,239 @@
+use heapless::Vec;
+
+use crate::ui::{
+    component::{
+        text::{
+            markup::{self, MarkupStyles},
+            paragraphs::{widen, Paragraph, ParagraphVec, Paragraphs, VecExt},
+            props::{PropsList, PropsStyle},
+            StyleToken,
+        },
+        Child, Component, ComponentExt, Event, EventCtx, Map, ModalStack, ModalStackMsg, Never,
+        Split,
+    },
+    display::Icon,
+    geometry::{Insets, Rect},
+    model_tt::{
+        component::{Button, ButtonMsg, HorizontalSwipePage},
+        theme,
+    },
+};
+
+use super::{
+    result::{ConfirmResult, ReturnToC},
+    text::BootStr,
+    title::{TitleBar, CORNER_BUTTON_AREA, TITLE_AREA_HEIGHT},
+};
+
+#[derive(Copy, Clone)]
+pub enum ConfirmMsg {
+    Cancel,
+    Confirm,
+}
+
+impl ReturnToC for ConfirmMsg {
+    fn return_to_c(self) -> u32 {
+        match self {
+            Self::Cancel => ConfirmResult::Cancel,
+            Self::Confirm => ConfirmResult::Confirm,
+        }
+        .return_to_c()
+    }
+}
+
+/// Bootloader confirmation screen: title, message and cancel/confirm buttons.
+/// The title is preceded by a warning icon if there is an alert. Content which
+/// does not fit on the screen, e.g. a long vendor string, is split into pages
+/// switched by swiping. The message is parsed as `markup`.
+/// Optionally an info button in the title bar opens an overlay with further
+/// details as key-value properties, closing it brings back the original
+/// content.
+pub struct Confirm<'a> {
+    title: Child<TitleBar<&'a str>>,
+    info_button: Option<Child<Button<&'static str>>>,
+    stack: ModalStack<Content<'a>, Option<Info<'a>>>,
+}
+
+impl<'a> Confirm<'a> {
+    pub fn new(
+        title: &'a str,
+        message: &'a str,
+        vendor: Option<&'a str>,
+        alert: Option<&'a str>,
+        info: InfoProps<'a>,
+        confirm: &'static str,
+    ) -> Self {
+        let info_button = (!info.is_empty())
+            .then(|| Child::new(Button::with_text("INFO").styled(theme::button_info())));
+        let title_bar = TitleBar::new(title);
+        let title_bar = if alert.is_some() {
+            title_bar.with_icon(Icon::new(theme::ICON_WARN))
+        } else {
+            title_bar
+        };
+        Self {
+            title: Child::new(title_bar),
+            info_button,
+            stack: ModalStack::new(
+                content(message, vendor, alert, confirm),
+                (!info.is_empty()).then(|| info_overlay(info)),
+                theme::BG,
+            ),
+        }
+    }
+}
+
+impl Component for Confirm<'_> {
+    type Msg = ConfirmMsg;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        let bounds = bounds.inset(theme::borders());
+        let (title_area, content_area) = bounds.split_top(TITLE_AREA_HEIGHT);
+        let title_area = if let Some(info_button) = self.info_button.as_mut() {
+            let (title_area, info_area) = title_area.split_right(CORNER_BUTTON_AREA.width());
+            info_button.place(info_area);
+            title_area
+        } else {
+            title_area
+        };
+        self.title.place(title_area);
+        self.stack
+            .place(content_area.inset(Insets::top(theme::CONTENT_BORDER)));
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        if let Some(ButtonMsg::Clicked) = self.info_button.event(ctx, event) {
+            if self.stack.is_modal_shown() {
+                self.stack.close_modal(ctx);
+            } else {
+                self.stack.show_modal(ctx);
+            }
+            return None;
+        }
+        match self.stack.event(ctx, event) {
+            Some(ModalStackMsg::Modal(InfoMsg::Close)) => {
+                self.stack.close_modal(ctx);
+                None
+            }
+            Some(ModalStackMsg::Content(msg)) => Some(msg),
+            None => None,
+        }
+    }
+
+    fn paint(&mut self) {
+        self.title.paint();
+        self.info_button.paint();
+        self.stack.paint();
+    }
+
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        self.title.bounds(sink);
+        self.info_button.bounds(sink);
+        self.stack.bounds(sink);
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl crate::trace::Trace for Confirm<'_> {
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.open("Confirm");
+        t.field("title", &self.title);
+        t.field("content", &self.stack);
+        t.close();
+    }
+}
+
+fn never<M>(msg: Never) -> Option<M> {
+    match msg {}
+}
+
+type ConfirmButton<M> = Map<Child<Button<&'static str>>, fn(ButtonMsg) -> Option<M>>;
+
+type Content<'a> = Split<
+    Map<
+        Child<
+            HorizontalSwipePage<Paragraphs<ParagraphVec<BootStr<'a>, { MESSAGE_PARAGRAPHS + 2 }>>>,
+        >,
+        fn(Never) -> Option<ConfirmMsg>,
+    >,
+    Split<ConfirmButton<ConfirmMsg>, ConfirmButton<ConfirmMsg>>,
+>;
+
+/// Paragraphs of the message over this number are dropped.
+const MESSAGE_PARAGRAPHS: usize = 6;
+
+/// Bold words of the message are emphasized within its body text.
+fn message_styles() -> MarkupStyles {
+    MarkupStyles {
+        normal: theme::text(StyleToken::Body),
+        bold: theme::text(StyleToken::Emphasis),
+    }
+}
+
+fn content<'a>(
+    message: &'a str,
+    vendor: Option<&'a str>,
+    alert: Option<&'a str>,
+    confirm: &'static str,
+) -> Content<'a> {
+    // Leave space for the vendor and the alert.
+    let mut paragraphs: ParagraphVec<BootStr<'a>, { MESSAGE_PARAGRAPHS + 2 }> = widen(
+        markup::parse::<_, MESSAGE_PARAGRAPHS>(&BootStr::from(message), message_styles()),
+    );
+    if let Some(vendor) = vendor {
+        paragraphs.add(Paragraph::new(
+            theme::text(StyleToken::Subtitle),
+            vendor.into(),
+        ));
+    }
+    if let Some(alert) = alert {
+        paragraphs.add(Paragraph::new(theme::text(StyleToken::Warning), alert.into()).centered());
+    }
+    let cancel = Button::with_text("CANCEL")
+        .styled(theme::button_cancel())
+        .into_child()
+        .map(
+            (|msg: ButtonMsg| matches!(msg, ButtonMsg::Clicked).then_some(ConfirmMsg::Cancel))
+                as fn(_) -> _,
+        );
+    let confirm = Button::with_text(confirm)
+        .styled(theme::button_confirm())
+        .into_child()
+        .map(
+            (|msg: ButtonMsg| matches!(msg, ButtonMsg::Clicked).then_some(ConfirmMsg::Confirm))
+                as fn(_) -> _,
+        );
+    Split::bottom(
+        theme::BUTTON_HEIGHT,
+        HorizontalSwipePage::new(Paragraphs::new(paragraphs), theme::BG)
+            .into_child()
+            .map(never::<ConfirmMsg> as fn(_) -> _),
+        Split::columns(1, 3, cancel, confirm).with_spacing(theme::BUTTON_SPACING),
+    )
+}
+
+enum InfoMsg {
+    Close,
+}
+
+/// `(key, value, value_is_mono)` properties shown in the info overlay.
+pub type InfoProps<'a> = Vec<(BootStr<'a>, BootStr<'a>, bool), 4>;
+
+type Info<'a> = Split<
+    Map<Child<Paragraphs<PropsList<InfoProps<'a>>>>, fn(Never) -> Option<InfoMsg>>,
+    ConfirmButton<InfoMsg>,
+>;
+
+fn info_overlay(props: InfoProps<'_>) -> Info<'_> {
+    Split::bottom(
+        theme::BUTTON_HEIGHT,
+        Paragraphs::new(PropsList::new(props, PropsStyle::default()))
+            .into_child()
+            .map(never::<InfoMsg> as fn(_) -> _),
+        Button::with_text("CLOSE").into_child().map(
+            (|msg: ButtonMsg| matches!(msg, ButtonMsg::Clicked).then_some(InfoMsg::Close))
+                as fn(_) -> _,
+        ),
+    )
+}
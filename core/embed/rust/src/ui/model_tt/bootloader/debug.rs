@@ -0,0 +1,138 @@
+//! Bootloader screens painted with sample arguments by the debuglink of the
+//! firmware, so that they can be inspected without running the bootloader.
+
+use crate::{
+    trace::{Trace, Tracer},
+    ui::{
+        component::Component,
+        geometry::{trace_bounds, Rect},
+    },
+};
+
+use super::{
+    add_fingerprint, install_confirm, install_fail, install_success, screen, show, wipe_confirm,
+    wipe_fail, wipe_success, with_install_confirm_version, with_install_low_battery, BatteryCharge,
+    BatteryLevel, FirmwareState, InfoProps, Intro, Menu, TouchCalibration, WIPED_PIN, WIPED_SEED,
+    WIPED_SETTINGS,
+};
+
+/// Names of the screens known to `render`.
+pub const SCREENS: [&str; 17] = [
+    "intro",
+    "intro_missing",
+    "intro_corrupted",
+    "menu",
+    "install_confirm",
+    "install_confirm_upgrade",
+    "install_confirm_downgrade",
+    "wipe_confirm",
+    "touch_calibration",
+    "install_success",
+    "install_fail",
+    "wipe_success",
+    "wipe_fail",
+    "battery_charge",
+    "install_low_battery",
+    "install_progress",
+    "connect",
+];
+
+const VENDOR: &str = "SatoshiLabs";
+const FINGERPRINT: &str = "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+const VERSION_2_5_3: u32 = 0x0003_0502;
+const VERSION_2_6_0: u32 = 0x0000_0602;
+
+/// Paint the screen `name` and pass its trace, followed by its bounds, to `t`,
+/// the caller refreshes the display. Returns false if there is no such screen.
+pub fn render(name: &str, t: &mut dyn Tracer) -> bool {
+    let mut paint = |frame: &mut dyn Screen| {
+        frame.show();
+        frame.trace(t);
+        trace_bounds(t, |sink| frame.bounds(sink));
+    };
+    match name {
+        "intro" => paint(&mut Intro::new(
+            "BOOTLOADER 2.1.0",
+            "Firmware 2.6.0 by SatoshiLabs",
+            FirmwareState::Valid,
+        )),
+        "intro_missing" => paint(&mut Intro::new(
+            "BOOTLOADER 2.1.0",
+            "No firmware",
+            FirmwareState::Missing,
+        )),
+        "intro_corrupted" => paint(&mut Intro::new(
+            "WELCOME",
+            "Go to trezor.io/start",
+            FirmwareState::Corrupted,
+        )),
+        "menu" => paint(&mut Menu::new(
+            "2.1.0",
+            [(0x10, "Calibrate touch", true), (0x11, "Reboot", true)],
+        )),
+        "install_confirm" => {
+            let mut info = InfoProps::new();
+            unwrap!(add_fingerprint(&mut info, FINGERPRINT));
+            paint(&mut install_confirm(
+                "INSTALL FIRMWARE",
+                "Install firmware by SatoshiLabs?",
+                VENDOR,
+                "",
+                info,
+            ))
+        }
+        "install_confirm_upgrade" => unwrap!(with_install_confirm_version(
+            VERSION_2_5_3,
+            VERSION_2_6_0,
+            VENDOR,
+            "",
+            FINGERPRINT,
+            |frame| paint(frame),
+        )),
+        "install_confirm_downgrade" => unwrap!(with_install_confirm_version(
+            VERSION_2_6_0,
+            VERSION_2_5_3,
+            VENDOR,
+            "Seed will be erased!",
+            FINGERPRINT,
+            |frame| paint(frame),
+        )),
+        "wipe_confirm" => paint(&mut wipe_confirm()),
+        "touch_calibration" => paint(&mut TouchCalibration::new()),
+        "install_success" => paint(&mut install_success("Restarting in 5 seconds.")),
+        "install_fail" => paint(&mut install_fail()),
+        "wipe_success" => paint(&mut wipe_success(WIPED_SEED | WIPED_PIN | WIPED_SETTINGS)),
+        "wipe_fail" => paint(&mut wipe_fail()),
+        "battery_charge" => paint(&mut BatteryCharge::new(BatteryLevel::new(42, true))),
+        "install_low_battery" => with_install_low_battery(10, |frame| paint(frame)),
+        // Painted directly rather than by a component.
+        "install_progress" => {
+            super::screen_install_progress(500, true);
+            t.symbol("install_progress");
+            trace_bounds(t, |sink| sink(screen()));
+        }
+        "connect" => {
+            super::screen_connect();
+            t.symbol("connect");
+            trace_bounds(t, |sink| sink(screen()));
+        }
+        _ => return false,
+    }
+    true
+}
+
+/// Component painted by `render`.
+trait Screen: Trace {
+    fn show(&mut self);
+    fn bounds(&self, sink: &mut dyn FnMut(Rect));
+}
+
+impl<F: Component + Trace> Screen for F {
+    fn show(&mut self) {
+        show(self, true);
+    }
+
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        Component::bounds(self, sink);
+    }
+}
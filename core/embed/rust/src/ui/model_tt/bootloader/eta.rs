@@ -0,0 +1,191 @@
+use core::fmt::Write;
+
+use heapless::String;
+
+use crate::time::{Duration, Instant};
+
+/// Progress of a finished operation, the same scale as the loader.
+const PROGRESS_MAX: u16 = 1000;
+
+/// The estimate is not shown until the progress has been advancing for this
+/// long, the first chunks are not a good measure of the throughput.
+const ESTIMATE_AFTER: Duration = Duration::from_secs(2);
+
+/// No progress for this long is reported as a stall, e.g. of the USB transfer.
+pub const STALL_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub const STATUS_LEN: usize = 24;
+
+/// Estimate of the remaining time of an operation reporting its progress,
+/// from the average throughput since the first report.
+pub struct ProgressEta {
+    first: Option<(Instant, u16)>,
+    last: Option<(Instant, u16)>,
+}
+
+impl ProgressEta {
+    pub const fn new() -> Self {
+        Self {
+            first: None,
+            last: None,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Record `progress` reported at `now`. Repeated reports of the same
+    /// progress are not counted as advancing.
+    pub fn update(&mut self, now: Instant, progress: u16) {
+        if self.first.is_none() {
+            self.first = Some((now, progress));
+        }
+        match self.last {
+            Some((_, last)) if last == progress => {}
+            _ => self.last = Some((now, progress)),
+        }
+    }
+
+    /// Time until the progress reaches the end, `None` if there is not enough
+    /// data to tell.
+    pub fn remaining(&self, now: Instant) -> Option<Duration> {
+        let (first_time, first_progress) = self.first?;
+        let (last_time, last_progress) = self.last?;
+        let elapsed = last_time.saturating_duration_since(first_time);
+        let done = last_progress.saturating_sub(first_progress);
+        if done == 0 || elapsed < ESTIMATE_AFTER {
+            return None;
+        }
+        let left = PROGRESS_MAX.saturating_sub(last_progress) as u64;
+        let estimate = left * elapsed.to_millis() as u64 / done as u64;
+        let since_last = now.saturating_duration_since(last_time).to_millis() as u64;
+        Some(Duration::from_millis(
+            estimate.saturating_sub(since_last) as u32
+        ))
+    }
+
+    /// Whether the progress has not advanced for `STALL_TIMEOUT`.
+    pub fn is_stalled(&self, now: Instant) -> bool {
+        match self.last {
+            Some((last_time, progress)) if progress < PROGRESS_MAX => {
+                now.saturating_duration_since(last_time) >= STALL_TIMEOUT
+            }
+            _ => false,
+        }
+    }
+
+    /// Line shown under the progress: the warning when stalled, otherwise the
+    /// rounded estimate, empty while there is none.
+    pub fn status(&self, now: Instant) -> String<STATUS_LEN> {
+        let mut text = String::new();
+        if self.is_stalled(now) {
+            unwrap!(text.push_str("Stalled, check cable"));
+        } else if let Some(remaining) = self.remaining(now) {
+            let secs = (remaining.to_millis() + 999) / 1000;
+            if secs < 60 {
+                unwrap!(write!(text, "About {} s left", secs.max(1)));
+            } else {
+                unwrap!(write!(text, "About {} min left", (secs + 30) / 60));
+            }
+        }
+        text
+    }
+}
+
+/// Status line under the progress, remembering the text on the screen so that
+/// it is only repainted when it changes.
+pub struct ProgressStatus {
+    eta: ProgressEta,
+    shown: String<STATUS_LEN>,
+}
+
+impl ProgressStatus {
+    pub const fn new() -> Self {
+        Self {
+            eta: ProgressEta::new(),
+            shown: String::new(),
+        }
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    pub fn update(&mut self, now: Instant, progress: u16) {
+        self.eta.update(now, progress);
+    }
+
+    /// Text to paint at `now` and whether it is the stall warning, `None` if
+    /// the text on the screen is the same, unless `force`.
+    pub fn changed(&mut self, now: Instant, force: bool) -> Option<(String<STATUS_LEN>, bool)> {
+        let status = self.eta.status(now);
+        if !force && status == self.shown {
+            return None;
+        }
+        self.shown = status.clone();
+        Some((status, self.eta.is_stalled(now)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::at;
+
+    #[test]
+    fn eta_estimate() {
+        let start = Instant::now();
+        let mut eta = ProgressEta::new();
+        eta.update(start, 0);
+        eta.update(at(start, 1000), 50);
+        // Too early to tell.
+        assert!(eta.remaining(at(start, 1000)).is_none());
+        assert_eq!(eta.status(at(start, 1000)).as_str(), "");
+
+        eta.update(at(start, 4000), 200);
+        let remaining = eta.remaining(at(start, 4000)).unwrap();
+        assert_eq!(remaining.to_millis(), 16000);
+        assert_eq!(eta.status(at(start, 4000)).as_str(), "About 16 s left");
+        assert_eq!(eta.status(at(start, 5000)).as_str(), "About 15 s left");
+
+        eta.update(at(start, 40000), 300);
+        assert_eq!(eta.status(at(start, 40000)).as_str(), "About 2 min left");
+    }
+
+    #[test]
+    fn eta_stall() {
+        let start = Instant::now();
+        let mut eta = ProgressEta::new();
+        eta.update(start, 100);
+        eta.update(at(start, 1000), 100);
+        assert!(!eta.is_stalled(at(start, 4000)));
+        assert!(eta.is_stalled(at(start, 5000)));
+        assert_eq!(eta.status(at(start, 5000)).as_str(), "Stalled, check cable");
+
+        eta.update(at(start, 6000), 150);
+        assert!(!eta.is_stalled(at(start, 6000)));
+        eta.update(at(start, 7000), PROGRESS_MAX);
+        assert!(!eta.is_stalled(at(start, 20000)));
+    }
+
+    #[test]
+    fn status_changed() {
+        let start = Instant::now();
+        let mut status = ProgressStatus::new();
+        status.update(start, 100);
+        // Nothing to show yet, painted only when forced.
+        assert!(status.changed(start, false).is_none());
+        let (text, stalled) = status.changed(start, true).unwrap();
+        assert_eq!((text.as_str(), stalled), ("", false));
+
+        assert!(status.changed(at(start, 4000), false).is_none());
+        let (text, stalled) = status.changed(at(start, 5000), false).unwrap();
+        assert_eq!((text.as_str(), stalled), ("Stalled, check cable", true));
+        assert!(status.changed(at(start, 6000), false).is_none());
+
+        status.update(at(start, 7000), 150);
+        let (text, stalled) = status.changed(at(start, 7000), false).unwrap();
+        assert_eq!((text.as_str(), stalled), ("", false));
+    }
+}
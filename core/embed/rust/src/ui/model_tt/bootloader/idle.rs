@@ -0,0 +1,195 @@
+use crate::{
+    time::{Duration, Instant},
+    ui::{
+        constant::screen,
+        display::{self, toif::Icon, Color},
+        event::TouchEvent,
+        geometry::CENTER,
+        model_tt::theme,
+    },
+};
+
+/// Inactivity after which the backlight is dimmed.
+const DIM_TIMEOUT: Duration = Duration::from_secs(60);
+/// Inactivity after which the screen is turned off, leaving only a dimmed
+/// logo.
+const SCREEN_OFF_TIMEOUT: Duration = Duration::from_secs(180);
+/// Color of the logo shown with the screen turned off.
+const LOGO_COLOR: Color = theme::GREY_DARK;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum State {
+    Active,
+    Dimmed,
+    Off,
+    /// Screen was turned on by a touch, the rest of which is ignored.
+    Waking,
+}
+
+/// What the event loop should do with a touch event.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum TouchAction {
+    /// Pass the event to the screen.
+    Deliver,
+    /// Paint the whole screen again and turn the backlight on, the event is
+    /// not passed to the screen.
+    Wake,
+    /// Drop the event.
+    Ignore,
+}
+
+/// Inactivity watchdog of the bootloader event loop, dimming and then turning
+/// off the screen when left without touch input.
+pub struct Idle {
+    last_touch: Instant,
+    state: State,
+}
+
+impl Idle {
+    pub fn new(now: Instant) -> Self {
+        Self {
+            last_touch: now,
+            state: State::Active,
+        }
+    }
+
+    /// Whether the screen of the event loop is visible, i.e. worth painting.
+    pub fn is_screen_on(&self) -> bool {
+        self.state != State::Off
+    }
+
+    /// Register touch input at `now`.
+    pub fn touch(&mut self, event: TouchEvent, now: Instant) -> TouchAction {
+        let dimmed = self.state == State::Dimmed;
+        let action = self.update_touch(event, now);
+        if dimmed {
+            display::set_backlight(theme::BACKLIGHT_NORMAL);
+        }
+        action
+    }
+
+    /// Dim or turn off the screen if there was no touch input for long enough
+    /// at `now`.
+    pub fn check(&mut self, now: Instant) {
+        match self.update_idle(now) {
+            Some(State::Dimmed) => display::fade_backlight(theme::BACKLIGHT_DIM),
+            Some(State::Off) => {
+                display::fade_backlight(theme::BACKLIGHT_NONE);
+                display::sync();
+                display::rect_fill(screen(), theme::BG);
+                Icon::new(theme::IMAGE_LOGO).draw(screen().center(), CENTER, LOGO_COLOR, theme::BG);
+                display::refresh();
+            }
+            _ => {}
+        }
+    }
+
+    /// State transition of `touch`, without touching the display.
+    fn update_touch(&mut self, event: TouchEvent, now: Instant) -> TouchAction {
+        self.last_touch = now;
+        match self.state {
+            State::Active => TouchAction::Deliver,
+            State::Dimmed => {
+                self.state = State::Active;
+                TouchAction::Deliver
+            }
+            State::Off => {
+                self.state = match event {
+                    TouchEvent::TouchEnd(_) => State::Active,
+                    _ => State::Waking,
+                };
+                TouchAction::Wake
+            }
+            State::Waking => {
+                if let TouchEvent::TouchEnd(_) = event {
+                    self.state = State::Active;
+                }
+                TouchAction::Ignore
+            }
+        }
+    }
+
+    /// State transition of `check`, returns the newly entered state if any.
+    fn update_idle(&mut self, now: Instant) -> Option<State> {
+        let idle = now.saturating_duration_since(self.last_touch);
+        let next = match self.state {
+            State::Active if idle >= DIM_TIMEOUT => State::Dimmed,
+            State::Dimmed if idle >= SCREEN_OFF_TIMEOUT => State::Off,
+            _ => return None,
+        };
+        self.state = next;
+        Some(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{time::at, ui::geometry::Point};
+
+    #[test]
+    fn idle_timeouts() {
+        let start = Instant::now();
+        let mut idle = Idle::new(start);
+
+        assert_eq!(idle.update_idle(at(start, 59_000)), None);
+        assert_eq!(idle.update_idle(at(start, 60_000)), Some(State::Dimmed));
+        assert_eq!(idle.update_idle(at(start, 61_000)), None);
+        assert!(idle.is_screen_on());
+
+        // both timeouts count from the last touch
+        let touch = TouchEvent::TouchStart(Point::new(10, 10));
+        assert_eq!(
+            idle.update_touch(touch, at(start, 100_000)),
+            TouchAction::Deliver
+        );
+        assert_eq!(idle.state, State::Active);
+        assert_eq!(idle.update_idle(at(start, 159_000)), None);
+        assert_eq!(idle.update_idle(at(start, 160_000)), Some(State::Dimmed));
+        assert_eq!(idle.update_idle(at(start, 279_000)), None);
+        assert_eq!(idle.update_idle(at(start, 280_000)), Some(State::Off));
+        assert!(!idle.is_screen_on());
+        assert_eq!(idle.update_idle(at(start, 1000_000)), None);
+    }
+
+    #[test]
+    fn idle_wake() {
+        let start = Instant::now();
+        let point = Point::new(10, 10);
+        let mut idle = Idle::new(start);
+        idle.update_idle(at(start, 60_000));
+        idle.update_idle(at(start, 180_000));
+        assert_eq!(idle.state, State::Off);
+
+        // the rest of the waking touch does not reach the screen
+        let now = at(start, 200_000);
+        assert_eq!(
+            idle.update_touch(TouchEvent::TouchStart(point), now),
+            TouchAction::Wake
+        );
+        assert!(idle.is_screen_on());
+        assert_eq!(
+            idle.update_touch(TouchEvent::TouchMove(point), now),
+            TouchAction::Ignore
+        );
+        assert_eq!(
+            idle.update_touch(TouchEvent::TouchEnd(point), now),
+            TouchAction::Ignore
+        );
+        assert_eq!(
+            idle.update_touch(TouchEvent::TouchStart(point), now),
+            TouchAction::Deliver
+        );
+
+        // a lone touch end wakes the screen and finishes the touch
+        idle.update_idle(at(start, 260_000));
+        idle.update_idle(at(start, 380_000));
+        assert_eq!(idle.state, State::Off);
+        let now = at(start, 400_000);
+        assert_eq!(
+            idle.update_touch(TouchEvent::TouchEnd(point), now),
+            TouchAction::Wake
+        );
+        assert_eq!(idle.state, State::Active);
+    }
+}
@@ -0,0 +1,190 @@
+use crate::ui::{
+    component::{
+        text::{
+            paragraphs::{Paragraph, Paragraphs},
+            StyleToken,
+        },
+        Child, Component, Event, EventCtx, Label, Pad,
+    },
+    display::Font,
+    geometry::{Alignment, Insets, Rect},
+    model_tt::{
+        component::{Button, ButtonMsg},
+        theme,
+    },
+};
+
+use super::{
+    result::{IntroResult, ReturnToC},
+    text::BootStr,
+    title::{TitleBar, BUTTON_AREA_START, TITLE_AREA_HEIGHT},
+};
+
+#[derive(Copy, Clone)]
+pub enum IntroMsg {
+    Menu,
+    Host,
+}
+
+impl ReturnToC for IntroMsg {
+    fn return_to_c(self) -> u32 {
+        match self {
+            Self::Menu => IntroResult::Menu,
+            Self::Host => IntroResult::Host,
+        }
+        .return_to_c()
+    }
+}
+
+/// Values of the `fw_state` argument of `screen_intro`.
+pub const FW_VALID: u32 = 0;
+pub const FW_MISSING: u32 = 1;
+pub const FW_CORRUPTED: u32 = 2;
+
+/// State of the installed firmware as found by the bootloader.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum FirmwareState {
+    Valid,
+    Missing,
+    /// The firmware header or its code failed verification.
+    Corrupted,
+}
+
+impl FirmwareState {
+    /// Unknown values are treated as a failed verification, so that the
+    /// warning is rather shown than not.
+    pub fn from_c(value: u32) -> Self {
+        match value {
+            FW_VALID => Self::Valid,
+            FW_MISSING => Self::Missing,
+            _ => Self::Corrupted,
+        }
+    }
+
+    fn warning(self) -> Option<&'static str> {
+        match self {
+            Self::Valid => None,
+            Self::Missing => Some("NO FIRMWARE INSTALLED"),
+            Self::Corrupted => Some("FIRMWARE IS CORRUPTED"),
+        }
+    }
+
+    fn action(self) -> &'static str {
+        match self {
+            Self::Corrupted => "REPAIR FIRMWARE",
+            Self::Valid | Self::Missing => "INSTALL FIRMWARE",
+        }
+    }
+}
+
+/// Bootloader intro screen: title, message and menu/host buttons. If the
+/// firmware is missing or corrupted, a warning banner is shown below the
+/// title.
+pub struct Intro<'a> {
+    title: Child<TitleBar<&'a str>>,
+    banner: Option<(Pad, Child<Label<&'static str>>)>,
+    message: Child<Paragraphs<Paragraph<BootStr<'a>>>>,
+    menu: Child<Button<&'static str>>,
+    host: Child<Button<&'static str>>,
+}
+
+impl<'a> Intro<'a> {
+    pub fn new(title: &'a str, message: &'a str, firmware: FirmwareState) -> Self {
+        let banner = firmware.warning().map(|warning| {
+            (
+                Pad::with_background(theme::palette().error),
+                Child::new(Label::new(
+                    warning,
+                    Alignment::Center,
+                    theme::label_warning_banner(),
+                )),
+            )
+        });
+        Self {
+            title: Child::new(TitleBar::new(title)),
+            banner,
+            message: Child::new(Paragraphs::new(Paragraph::new(
+                theme::text(StyleToken::Body),
+                message.into(),
+            ))),
+            menu: Child::new(Button::with_text("MENU").styled(theme::button_default())),
+            host: Child::new(Button::with_text(firmware.action()).styled(theme::button_confirm())),
+        }
+    }
+
+    const BANNER_HEIGHT: i16 = 30;
+}
+
+impl Component for Intro<'_> {
+    type Msg = IntroMsg;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        let bounds = bounds.inset(theme::borders());
+        let (title_area, content_area) = bounds.split_top(TITLE_AREA_HEIGHT);
+        let (content_area, buttons_area) =
+            content_area.split_top(BUTTON_AREA_START - content_area.y0);
+        let content_area = if let Some((pad, label)) = self.banner.as_mut() {
+            let (banner_area, content_area) = content_area.split_top(Self::BANNER_HEIGHT);
+            pad.place(banner_area);
+            pad.clear();
+            let label_height = Font::BOLD.text_height();
+            label.place(banner_area.inset(Insets::top((Self::BANNER_HEIGHT - label_height) / 2)));
+            content_area
+        } else {
+            content_area
+        };
+        let (menu_area, host_area) = buttons_area.split_left(buttons_area.width() / 3);
+        self.title.place(title_area);
+        self.message
+            .place(content_area.inset(Insets::top(theme::CONTENT_BORDER)));
+        self.menu.place(menu_area);
+        self.host
+            .place(host_area.inset(Insets::left(theme::BUTTON_SPACING)));
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        if let Some(ButtonMsg::Clicked) = self.menu.event(ctx, event) {
+            return Some(IntroMsg::Menu);
+        }
+        if let Some(ButtonMsg::Clicked) = self.host.event(ctx, event) {
+            return Some(IntroMsg::Host);
+        }
+        None
+    }
+
+    fn paint(&mut self) {
+        self.title.paint();
+        if let Some((pad, label)) = self.banner.as_mut() {
+            pad.paint();
+            label.paint();
+        }
+        self.message.paint();
+        self.menu.paint();
+        self.host.paint();
+    }
+
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        self.title.bounds(sink);
+        if let Some((pad, _)) = &self.banner {
+            sink(pad.area);
+        }
+        self.message.bounds(sink);
+        self.menu.bounds(sink);
+        self.host.bounds(sink);
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl crate::trace::Trace for Intro<'_> {
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.open("Intro");
+        t.field("title", &self.title);
+        if let Some((_, label)) = &self.banner {
+            t.field("warning", label);
+        }
+        t.field("content", &self.message);
+        t.field("host", &self.host);
+        t.close();
+    }
+}
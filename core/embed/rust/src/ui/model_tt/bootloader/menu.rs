@@ -0,0 +1,221 @@
+use cty::c_char;
+use heapless::Vec;
+
+use crate::ui::{
+    component::{BacklightFade, Child, Component, ComponentExt, Event, EventCtx, Pad},
+    constant,
+    geometry::{Insets, Rect},
+    model_tt::{
+        component::{
+            Button, ButtonMsg, ListItem, ListItemMsg, ScrollBar, ScrollBarMsg, Swipe,
+            SwipeDirection,
+        },
+        theme,
+    },
+};
+
+use super::{
+    result::{ReturnToC, MENU_CLOSED},
+    title::{TitleBar, CORNER_BUTTON_AREA, TITLE_AREA_HEIGHT},
+};
+
+pub const MAX_ITEMS: usize = 8;
+
+// At least one item has to fit below the title.
+const_assert!(
+    constant::fits_height(
+        TITLE_AREA_HEIGHT + theme::CONTENT_BORDER + ListItem::<&str>::HEIGHT_SINGLE
+    ),
+    "bootloader menu item does not fit the screen"
+);
+
+#[derive(Copy, Clone)]
+pub enum MenuMsg {
+    Close,
+    /// Item with the given id was selected.
+    Item(u32),
+}
+
+impl ReturnToC for MenuMsg {
+    fn return_to_c(self) -> u32 {
+        match self {
+            Self::Close => MENU_CLOSED,
+            Self::Item(id) => id,
+        }
+    }
+}
+
+/// Entry of the menu table passed from C. Ids need to be neither
+/// `MENU_CLOSED`, which is returned when the menu gets closed, nor
+/// `SCREEN_INVALID_INPUT`.
+#[repr(C)]
+pub struct MenuItem {
+    pub id: u32,
+    pub label: *const c_char,
+    pub enabled: bool,
+}
+
+/// Bootloader menu: title bar with the bootloader version and a close button
+/// and a column of rows, one per item of the table. Disabled items are shown
+/// dimmed but cannot be selected. Items which don't fit on the screen are on
+/// further pages, reached by swiping up, with a scrollbar at the right edge.
+/// Items over `MAX_ITEMS` are ignored.
+pub struct Menu<'a> {
+    title: Child<TitleBar<&'a str>>,
+    close: Child<Button<&'static str>>,
+    items: Vec<(u32, Child<ListItem<&'a str>>), MAX_ITEMS>,
+    pad: Pad,
+    swipe: Swipe,
+    scrollbar: ScrollBar,
+    fade: BacklightFade,
+    /// Number of items on a page.
+    page_size: usize,
+}
+
+impl<'a> Menu<'a> {
+    pub fn new(version: &'a str, items: impl IntoIterator<Item = (u32, &'a str, bool)>) -> Self {
+        let items = items
+            .into_iter()
+            .take(MAX_ITEMS)
+            .map(|(id, label, enabled)| {
+                let item = ListItem::new(label)
+                    .with_chevron()
+                    .initially_enabled(enabled);
+                (id, Child::new(item))
+            })
+            .collect();
+        Self {
+            title: Child::new(TitleBar::new("BOOTLOADER").with_aux(version)),
+            close: Child::new(Button::with_text("CLOSE").styled(theme::button_info())),
+            items,
+            pad: Pad::with_background(theme::BG),
+            swipe: Swipe::new(),
+            scrollbar: ScrollBar::vertical().with_thumb(),
+            fade: BacklightFade::new(),
+            page_size: MAX_ITEMS,
+        }
+    }
+
+    const ROW_HEIGHT: i16 = ListItem::<&str>::HEIGHT_SINGLE;
+
+    /// Indices of the items on the current page.
+    fn visible(&self) -> core::ops::Range<usize> {
+        let start = self.scrollbar.active_page * self.page_size;
+        start..(start + self.page_size).min(self.items.len())
+    }
+
+    fn setup_swipe(&mut self) {
+        self.swipe.allow_up = self.scrollbar.has_next_page();
+        self.swipe.allow_down = self.scrollbar.has_previous_page();
+    }
+
+    fn on_page_change(&mut self, ctx: &mut EventCtx) {
+        self.setup_swipe();
+        self.pad.clear();
+        for i in self.visible() {
+            self.items[i].1.request_complete_repaint(ctx);
+        }
+        // Swipe has dimmed the screen.
+        self.fade.start(ctx, theme::BACKLIGHT_NORMAL);
+    }
+}
+
+impl Component for Menu<'_> {
+    type Msg = MenuMsg;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        let bounds = bounds.inset(theme::borders());
+        let (title_area, content_area) = bounds.split_top(TITLE_AREA_HEIGHT);
+        let (title_area, close_area) = title_area.split_right(CORNER_BUTTON_AREA.width());
+        self.title.place(title_area);
+        self.close.place(close_area);
+
+        let content_area = content_area.inset(Insets::top(theme::CONTENT_BORDER));
+        self.pad.place(content_area);
+        self.swipe.place(content_area);
+
+        let row = Self::ROW_HEIGHT + theme::BUTTON_SPACING;
+        self.page_size = (((content_area.height() + theme::BUTTON_SPACING) / row) as usize).max(1);
+        let page_count = ((self.items.len() + self.page_size - 1) / self.page_size).max(1);
+        let (rows_area, scrollbar_area) = if page_count > 1 {
+            ScrollBar::split_right(content_area)
+        } else {
+            (content_area, Rect::zero())
+        };
+        for (i, (_, item)) in self.items.iter_mut().enumerate() {
+            let slot = (i % self.page_size) as i16;
+            let (_, item_area) = rows_area.split_top(slot * row);
+            let (item_area, _) = item_area.split_top(Self::ROW_HEIGHT);
+            item.place(item_area);
+        }
+        self.scrollbar.place(scrollbar_area);
+        self.scrollbar.set_count_keep_active_page(page_count);
+        self.setup_swipe();
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        self.fade.event(ctx, event);
+        if let Some(ButtonMsg::Clicked) = self.close.event(ctx, event) {
+            return Some(MenuMsg::Close);
+        }
+        let was_dragging = self.scrollbar.is_dragging();
+        if let Some(ScrollBarMsg::JumpTo(page)) = self.scrollbar.event(ctx, event) {
+            self.scrollbar.go_to(page);
+            self.on_page_change(ctx);
+            return None;
+        }
+        if was_dragging || self.scrollbar.is_dragging() {
+            return None;
+        }
+        if let Some(swipe) = self.swipe.event(ctx, event) {
+            match swipe {
+                SwipeDirection::Up => self.scrollbar.go_to_next_page(),
+                SwipeDirection::Down => self.scrollbar.go_to_previous_page(),
+                _ => return None,
+            }
+            self.on_page_change(ctx);
+            return None;
+        }
+        for i in self.visible() {
+            let (id, item) = &mut self.items[i];
+            if let Some(ListItemMsg::Clicked) = item.event(ctx, event) {
+                return Some(MenuMsg::Item(*id));
+            }
+        }
+        None
+    }
+
+    fn paint(&mut self) {
+        self.title.paint();
+        self.close.paint();
+        self.pad.paint();
+        for i in self.visible() {
+            self.items[i].1.paint();
+        }
+        if self.scrollbar.has_pages() {
+            self.scrollbar.paint();
+        }
+    }
+
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        self.title.bounds(sink);
+        self.close.bounds(sink);
+        for i in self.visible() {
+            self.items[i].1.bounds(sink);
+        }
+        self.scrollbar.bounds(sink);
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl crate::trace::Trace for Menu<'_> {
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.open("Menu");
+        t.field("title", &self.title);
+        for (_, item) in self.items.iter() {
+            t.field("item", item);
+        }
+        t.close();
+    }
+}
@@ -0,0 +1,740 @@
+use core::fmt::Write;
+
+use cty::c_char;
+use heapless::String;
+
+use crate::{
+    time::{Duration, Instant},
+    trezorhal::{time, touch},
+    ui::{
+        component::{Component, Event, EventCtx},
+        constant::{self, screen},
+        display::{self, toif::Icon, Font, LoaderPercent},
+        event::TouchEvent,
+        geometry::{Offset, Rect},
+        model_tt::{
+            component::{
+                BatteryCharge, BatteryLevel, IndeterminateLoader, ResultIcon, ResultPopup,
+            },
+            theme,
+        },
+        pool::BlockString,
+        touch_calibration::{self, Calibration},
+        util::try_from_c_str,
+    },
+};
+
+mod calibration;
+mod confirm;
+#[cfg(feature = "ui_debug")]
+pub mod debug;
+mod eta;
+mod idle;
+mod intro;
+mod menu;
+mod result;
+mod text;
+mod timers;
+mod title;
+mod version;
+
+use calibration::TouchCalibration;
+use confirm::{Confirm, InfoProps};
+use eta::ProgressStatus;
+use idle::{Idle, TouchAction};
+use intro::{FirmwareState, Intro};
+use menu::{Menu, MenuItem, MAX_ITEMS};
+use result::{ReturnToC, SCREEN_INVALID_INPUT};
+use text::BootStr;
+use timers::Timers;
+use version::{Version, VersionChange};
+
+/// Distance of the progress text baseline from the bottom of the screen.
+const PROGRESS_TEXT_BOTTOM: i16 = 24;
+/// Distance of the baseline of the line with the estimated remaining time.
+const PROGRESS_STATUS_BOTTOM: i16 = 3;
+
+const_assert!(
+    constant::HEIGHT / 2 + theme::PROGRESS_LOADER.y_offset - theme::PROGRESS_LOADER.radius >= 0
+        && constant::HEIGHT / 2 + theme::PROGRESS_LOADER.y_offset + theme::PROGRESS_LOADER.radius
+            <= constant::HEIGHT - PROGRESS_TEXT_BOTTOM,
+    "progress loader does not fit above the text"
+);
+/// Delay between the frames of animations played by `show`.
+const ANIM_FRAME_DELAY: Duration = Duration::from_millis(16);
+
+#[cfg(not(test))]
+static mut PROGRESS_PERCENT: LoaderPercent = LoaderPercent::new(Font::BOLD);
+
+// Tests painting the progress in parallel must not share the digits.
+#[cfg(test)]
+std::thread_local! {
+    static PROGRESS_PERCENT: core::cell::UnsafeCell<LoaderPercent> =
+        core::cell::UnsafeCell::new(LoaderPercent::new(Font::BOLD));
+}
+
+/// Returns the percentage of the progress screen, kept between the calls from
+/// C so that only the changed digits are repainted.
+///
+/// # Safety
+///
+/// The caller has to guarantee that no other reference returned by this
+/// function is alive while the result is used.
+unsafe fn progress_percent() -> &'static mut LoaderPercent {
+    #[cfg(not(test))]
+    let ptr = core::ptr::addr_of_mut!(PROGRESS_PERCENT);
+    #[cfg(test)]
+    let ptr = PROGRESS_PERCENT.with(|percent| percent.get());
+    unsafe { &mut *ptr }
+}
+
+#[cfg(not(test))]
+static mut CONNECT_LOADER: IndeterminateLoader = IndeterminateLoader::new();
+
+// Tests running in parallel must not animate each other's loader.
+#[cfg(test)]
+std::thread_local! {
+    static CONNECT_LOADER: core::cell::UnsafeCell<IndeterminateLoader> =
+        core::cell::UnsafeCell::new(IndeterminateLoader::new());
+}
+
+/// Returns the loader of the connect screen, kept between the calls from C.
+///
+/// # Safety
+///
+/// The caller has to guarantee that no other reference returned by this
+/// function is alive while the result is used.
+unsafe fn connect_loader() -> &'static mut IndeterminateLoader {
+    #[cfg(not(test))]
+    let ptr = core::ptr::addr_of_mut!(CONNECT_LOADER);
+    #[cfg(test)]
+    let ptr = CONNECT_LOADER.with(|loader| loader.get());
+    unsafe { &mut *ptr }
+}
+
+static mut PROGRESS_STATUS: ProgressStatus = ProgressStatus::new();
+
+/// Returns the status line of the progress screen, kept between the calls from
+/// C.
+///
+/// # Safety
+///
+/// The caller has to guarantee that no other reference returned by this
+/// function is alive while the result is used.
+unsafe fn progress_status() -> &'static mut ProgressStatus {
+    unsafe { &mut *core::ptr::addr_of_mut!(PROGRESS_STATUS) }
+}
+
+fn touch_eval() -> Option<TouchEvent> {
+    let (event, x, y) = touch::read()?;
+    let event = TouchEvent::new(event, x, y).ok()?.calibrated();
+    Some(event.reject_large_contact(touch::contact().map(|c| c.area)))
+}
+
+/// Convert the C strings of `ptrs`, `None` if any of them is not valid UTF-8.
+///
+/// # Safety
+///
+/// Each of `ptrs` must be either null or point to a 0-terminated string that
+/// stays valid for the lifetime of the returned references.
+unsafe fn c_strs<'a, const N: usize>(ptrs: [*const c_char; N]) -> Option<[&'a str; N]> {
+    let mut strs = [""; N];
+    for (s, ptr) in strs.iter_mut().zip(ptrs) {
+        // SAFETY: Upheld by the caller.
+        *s = unsafe { try_from_c_str(ptr) }?;
+    }
+    Some(strs)
+}
+
+/// Generic error screen shown instead of a screen with invalid arguments.
+/// Returns `SCREEN_INVALID_INPUT`.
+fn show_invalid_input() -> u32 {
+    show_result(Outcome::Failure, "INVALID INPUT", RECONNECT_MSG, [], true);
+    SCREEN_INVALID_INPUT
+}
+
+/// Part of the screen to paint again after the events processed by `run`.
+#[derive(Copy, Clone)]
+enum Repaint {
+    Nothing,
+    Area(Rect),
+    Everything,
+}
+
+impl Repaint {
+    /// Add the paint requested in the last event pass of `ctx`.
+    fn add(self, ctx: &EventCtx) -> Self {
+        if !ctx.paint_requested() {
+            return self;
+        }
+        match (self, ctx.damage()) {
+            (Self::Nothing, Some(area)) => Self::Area(area),
+            (Self::Area(prev), Some(area)) => Self::Area(prev.union(area)),
+            _ => Self::Everything,
+        }
+    }
+}
+
+/// Deliver `event` to the component, taking the timers it requested into
+/// `timers`. Returns the message of the component and the part of the screen
+/// to paint, added to `repaint`. `ctx` lives as long as the screen, so that the
+/// tokens of the timers requested in different passes do not collide.
+fn dispatch<F>(
+    frame: &mut F,
+    ctx: &mut EventCtx,
+    timers: &mut Timers,
+    event: Event,
+    repaint: &mut Repaint,
+) -> Option<F::Msg>
+where
+    F: Component,
+{
+    ctx.clear();
+    let msg = frame.event(ctx, event);
+    timers.schedule(ctx, Instant::now());
+    *repaint = repaint.add(ctx);
+    msg
+}
+
+/// Paint the component and process touch and timer events until it emits a
+/// message. The screen is dimmed and then turned off when left without touch
+/// input, see `Idle`.
+fn run<F>(frame: &mut F) -> u32
+where
+    F: Component,
+    F::Msg: ReturnToC,
+{
+    let mut ctx = EventCtx::new();
+    let mut timers = Timers::new();
+    frame.place(screen());
+    if let Some(msg) = dispatch(
+        frame,
+        &mut ctx,
+        &mut timers,
+        Event::Attach,
+        &mut Repaint::Nothing,
+    ) {
+        return msg.return_to_c();
+    }
+    display::sync();
+    display::rect_fill(screen(), theme::BG);
+    frame.paint();
+    display::refresh();
+    // The C code fades the previous screen out before showing a new one.
+    display::fade_backlight(theme::BACKLIGHT_NORMAL);
+
+    let mut idle = Idle::new(Instant::now());
+    loop {
+        // Process all events that arrived since the last pass before painting, so
+        // that e.g. a fast drag results in a single paint and flush. Only the area
+        // changed by the events is painted, nothing if the screen stays the same.
+        let mut repaint = Repaint::Nothing;
+        while let Some(event) = touch_eval() {
+            match idle.touch(event, Instant::now()) {
+                TouchAction::Deliver => {
+                    let event = Event::Touch(event);
+                    if let Some(msg) = dispatch(frame, &mut ctx, &mut timers, event, &mut repaint) {
+                        return msg.return_to_c();
+                    }
+                }
+                TouchAction::Wake => {
+                    let event = Event::RequestPaint;
+                    dispatch(frame, &mut ctx, &mut timers, event, &mut Repaint::Nothing);
+                    display::sync();
+                    display::rect_fill(screen(), theme::BG);
+                    frame.paint();
+                    display::refresh();
+                    display::set_backlight(theme::BACKLIGHT_NORMAL);
+                }
+                TouchAction::Ignore => {}
+            }
+        }
+        while let Some(token) = timers.pop_expired(Instant::now()) {
+            let event = Event::Timer(token);
+            if let Some(msg) = dispatch(frame, &mut ctx, &mut timers, event, &mut repaint) {
+                return msg.return_to_c();
+            }
+        }
+        idle.check(Instant::now());
+        if !idle.is_screen_on() {
+            continue;
+        }
+        match repaint {
+            Repaint::Nothing => {}
+            Repaint::Area(area) => {
+                display::sync();
+                display::clip::with_clip(area, || frame.paint());
+                display::refresh();
+            }
+            Repaint::Everything => {
+                display::sync();
+                frame.paint();
+                display::refresh();
+            }
+        }
+    }
+}
+
+/// Paint the component once, after playing any animation it starts when
+/// attached, e.g. the drawing of the result icon.
+fn show<F>(frame: &mut F, clear: bool)
+where
+    F: Component,
+{
+    frame.place(screen());
+    let mut ctx = EventCtx::new();
+    frame.event(&mut ctx, Event::Attach);
+    display::sync();
+    if clear {
+        display::rect_fill(screen(), theme::BG);
+    }
+    frame.paint();
+
+    while ctx.anim_frame_requested() {
+        display::refresh();
+        time::sleep(ANIM_FRAME_DELAY);
+        ctx.clear();
+        frame.event(&mut ctx, Event::Timer(EventCtx::ANIM_FRAME_TIMER));
+        display::sync();
+        frame.paint();
+    }
+}
+
+fn screen_progress(text: &str, progress: u16, initialize: bool) {
+    // SAFETY: The bootloader is single-threaded and the reference is dropped
+    // before returning.
+    let percent = unsafe { progress_percent() };
+    let geometry = theme::PROGRESS_LOADER.with_center_kept();
+
+    display::sync();
+    if initialize {
+        display::rect_fill(screen(), theme::BG);
+        display::text_center(
+            screen().bottom_center() - Offset::y(PROGRESS_TEXT_BOTTOM),
+            text,
+            Font::NORMAL,
+            theme::FG,
+            theme::BG,
+        );
+        percent.invalidate();
+    }
+    display::loader(progress, geometry, theme::FG, theme::BG, None);
+    percent.paint(progress, geometry, theme::FG, theme::BG);
+
+    // SAFETY: The bootloader is single-threaded and the reference is dropped
+    // before `paint_progress_status` takes another one.
+    let status = unsafe { progress_status() };
+    if initialize {
+        status.reset();
+    }
+    status.update(Instant::now(), progress);
+    paint_progress_status(initialize);
+}
+
+/// Paint the estimated remaining time under the progress, or a warning if the
+/// progress has stalled. Only painted if the text changed, unless `force`.
+fn paint_progress_status(force: bool) {
+    // SAFETY: The bootloader is single-threaded and the reference is dropped
+    // before returning.
+    let status = unsafe { progress_status() };
+    let (text, stalled) = match status.changed(Instant::now(), force) {
+        Some(changed) => changed,
+        None => return,
+    };
+    let font = Font::NORMAL;
+    let baseline = screen().bottom_center() - Offset::y(PROGRESS_STATUS_BOTTOM);
+    let (_, line) = screen().split_bottom(PROGRESS_STATUS_BOTTOM + font.ascent());
+    let color = if stalled {
+        theme::RED
+    } else {
+        theme::GREY_LIGHT
+    };
+    display::sync();
+    display::rect_fill(line, theme::BG);
+    display::text_center(baseline, &text, font, color, theme::BG);
+}
+
+/// Screen shown while waiting for the host to connect, with a loader spinning
+/// as long as `screen_connect_animate` gets called.
+#[no_mangle]
+pub extern "C" fn screen_connect() {
+    // SAFETY: The bootloader is single-threaded and the reference is dropped
+    // before returning.
+    let loader = unsafe { connect_loader() };
+    let (loader_area, _) = screen().split_bottom(PROGRESS_TEXT_BOTTOM + Font::NORMAL.line_height());
+    loader.place(loader_area);
+    loader.event(&mut EventCtx::new(), Event::Attach);
+
+    display::sync();
+    display::rect_fill(screen(), theme::BG);
+    display::text_center(
+        screen().bottom_center() - Offset::y(PROGRESS_TEXT_BOTTOM),
+        "Waiting for host...",
+        Font::NORMAL,
+        theme::FG,
+        theme::BG,
+    );
+    loader.paint();
+}
+
+/// Advance the loader of `screen_connect` to the current time, to be called
+/// periodically, e.g. while polling USB.
+#[no_mangle]
+pub extern "C" fn screen_connect_animate() {
+    // SAFETY: See `screen_connect`.
+    let loader = unsafe { connect_loader() };
+    let mut ctx = EventCtx::new();
+    loader.event(&mut ctx, Event::Timer(EventCtx::ANIM_FRAME_TIMER));
+    if ctx.paint_requested() {
+        display::sync();
+        loader.paint();
+    }
+}
+
+/// Progress of the firmware installation, from 0 to 1000. The whole screen is
+/// painted if `initialize` is set, otherwise only the loader ring and the
+/// changed digits of the percentage.
+#[no_mangle]
+pub extern "C" fn screen_install_progress(progress: u16, initialize: bool) {
+    screen_progress("Installing firmware", progress, initialize);
+}
+
+/// Report a stall of the progress if there was none for a few seconds, to be
+/// called periodically while waiting for the data, e.g. on USB read timeouts.
+/// The warning is replaced by the estimate once the progress advances.
+#[no_mangle]
+pub extern "C" fn screen_progress_check_stall() {
+    paint_progress_status(false);
+}
+
+/// Progress of wiping the device, see `screen_install_progress`.
+#[no_mangle]
+pub extern "C" fn screen_wipe_progress(progress: u16, initialize: bool) {
+    screen_progress("Wiping device", progress, initialize);
+}
+
+#[no_mangle]
+pub extern "C" fn screen_install_confirm(
+    title: *const c_char,
+    message: *const c_char,
+    vendor: *const c_char,
+    alert: *const c_char,
+    fw_info: *const c_char,
+) -> u32 {
+    // SAFETY: The strings are only borrowed until the user makes a decision.
+    match unsafe { c_strs([title, message, vendor, alert, fw_info]) } {
+        Some([title, message, vendor, alert, fw_info]) => {
+            let mut info = InfoProps::new();
+            add_fingerprint(&mut info, fw_info)
+                .map(|_| run(&mut install_confirm(title, message, vendor, alert, info)))
+                .unwrap_or_else(show_invalid_input)
+        }
+        None => show_invalid_input(),
+    }
+}
+
+/// Like `screen_install_confirm`, with the title and message describing the
+/// change between the versions in the image header format. `current_version`
+/// is 0 if there is no firmware installed.
+#[no_mangle]
+pub extern "C" fn screen_install_confirm_version(
+    current_version: u32,
+    new_version: u32,
+    vendor: *const c_char,
+    alert: *const c_char,
+    fw_info: *const c_char,
+) -> u32 {
+    // SAFETY: The strings are only borrowed until the user makes a decision.
+    match unsafe { c_strs([vendor, alert, fw_info]) } {
+        Some([vendor, alert, fw_info]) => with_install_confirm_version(
+            current_version,
+            new_version,
+            vendor,
+            alert,
+            fw_info,
+            |frame| run(frame),
+        )
+        .unwrap_or_else(show_invalid_input),
+        None => show_invalid_input(),
+    }
+}
+
+/// Pass the confirmation of `screen_install_confirm_version` to `f`, `None` if
+/// the texts do not fit.
+fn with_install_confirm_version<R>(
+    current_version: u32,
+    new_version: u32,
+    vendor: &str,
+    alert: &str,
+    fw_info: &str,
+    f: impl FnOnce(&mut Confirm) -> R,
+) -> Option<R> {
+    let new = Version::from_u32(new_version);
+    let mut new_str: String<32> = String::new();
+    let mut current_str: String<32> = String::new();
+    let mut message = BlockString::alloc()?;
+    write!(new_str, "{}", new).ok()?;
+    write!(message, "Install firmware version {}?", new).ok()?;
+    let title = if current_version == 0 {
+        "INSTALL FIRMWARE"
+    } else {
+        let current = Version::from_u32(current_version);
+        write!(current_str, "{}", current).ok()?;
+        write!(message, "\nCurrently installed: {}.", current).ok()?;
+        match current.change_to(new) {
+            VersionChange::Upgrade => "UPDATE FIRMWARE",
+            VersionChange::Same => "REINSTALL FIRMWARE",
+            VersionChange::Downgrade => "DOWNGRADE FIRMWARE",
+        }
+    };
+    let mut info = InfoProps::new();
+    info.push(("Version".into(), new_str.as_str().into(), false))
+        .ok()?;
+    if !current_str.is_empty() {
+        info.push(("Installed".into(), current_str.as_str().into(), false))
+            .ok()?;
+    }
+    add_fingerprint(&mut info, fw_info)?;
+    Some(f(&mut install_confirm(
+        title, &message, vendor, alert, info,
+    )))
+}
+
+/// `fw_info` is the fingerprint of the image, omitted if empty. `None` if
+/// there is no room for it.
+fn add_fingerprint<'a>(info: &mut InfoProps<'a>, fw_info: &'a str) -> Option<()> {
+    if !fw_info.is_empty() {
+        info.push(("Fingerprint".into(), fw_info.into(), true))
+            .ok()?;
+    }
+    Some(())
+}
+
+fn install_confirm<'a>(
+    title: &'a str,
+    message: &'a str,
+    vendor: &'a str,
+    alert: &'a str,
+    info: InfoProps<'a>,
+) -> Confirm<'a> {
+    let vendor = (!vendor.is_empty()).then_some(vendor);
+    let alert = (!alert.is_empty()).then_some(alert);
+    Confirm::new(title, message, vendor, alert, info, "INSTALL")
+}
+
+fn wipe_confirm() -> Confirm<'static> {
+    Confirm::new(
+        "WIPE DEVICE",
+        "Do you really want to wipe the device?",
+        None,
+        Some("Seed and firmware will be erased!"),
+        InfoProps::new(),
+        "WIPE",
+    )
+}
+
+/// Confirmation of the wipe requested by the host.
+#[no_mangle]
+pub extern "C" fn screen_wipe_confirm() -> u32 {
+    run(&mut wipe_confirm())
+}
+
+/// Intro screen of the bootloader. `fw_state` is one of the `FW_*` values of
+/// the header, a warning is shown unless the firmware is valid.
+#[no_mangle]
+pub extern "C" fn screen_intro(title: *const c_char, message: *const c_char, fw_state: u32) -> u32 {
+    // SAFETY: The strings are only borrowed until the user makes a decision.
+    match unsafe { c_strs([title, message]) } {
+        Some([title, message]) => {
+            let mut frame = Intro::new(title, message, FirmwareState::from_c(fw_state));
+            run(&mut frame)
+        }
+        None => show_invalid_input(),
+    }
+}
+
+/// Bootloader menu built from a table of `count` items. Returns the id of the
+/// selected item or `MENU_CLOSED` if the menu was closed. Items over
+/// `MAX_ITEMS` are ignored.
+#[no_mangle]
+pub extern "C" fn screen_menu(
+    bld_version: *const c_char,
+    items: *const MenuItem,
+    count: u32,
+) -> u32 {
+    let items = if items.is_null() {
+        &[]
+    } else {
+        // SAFETY: The table of `count` items is only borrowed until the user makes a
+        // decision. Items which are ignored anyway are not part of the slice.
+        unsafe { core::slice::from_raw_parts(items, (count as usize).min(MAX_ITEMS)) }
+    };
+    let mut labels = [""; MAX_ITEMS];
+    for (label, item) in labels.iter_mut().zip(items) {
+        // SAFETY: The labels are only borrowed until the user makes a decision.
+        match unsafe { try_from_c_str(item.label) } {
+            Some(s) => *label = s,
+            None => return show_invalid_input(),
+        }
+    }
+    // SAFETY: The string is only borrowed until the user makes a decision.
+    match unsafe { c_strs([bld_version]) } {
+        Some([bld_version]) => {
+            let mut frame = Menu::new(
+                bld_version,
+                items
+                    .iter()
+                    .zip(labels)
+                    .map(|(item, label)| (item.id, label, item.enabled)),
+            );
+            run(&mut frame)
+        }
+        None => show_invalid_input(),
+    }
+}
+
+/// Run the touch calibration and store the result. Returns true on success,
+/// otherwise the previous calibration is kept.
+#[no_mangle]
+pub extern "C" fn screen_touch_calibration() -> bool {
+    let previous = touch_calibration::active();
+    // Targets need to be measured in raw controller coordinates.
+    touch_calibration::set_active(Calibration::IDENTITY);
+    let mut frame = TouchCalibration::new();
+    run(&mut frame);
+    match frame.result() {
+        Some(calibration) if calibration.store().is_ok() => true,
+        _ => {
+            touch_calibration::set_active(previous);
+            false
+        }
+    }
+}
+
+/// Outcome of a result screen, selecting its icon and colors.
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Outcome {
+    Success,
+    Failure,
+}
+
+const RECONNECT_MSG: &str = "Please reconnect the device.";
+
+/// Result screen of a finished operation, the single place defining how
+/// install and wipe outcomes look like.
+fn result_frame<'a>(
+    outcome: Outcome,
+    title: &'a str,
+    message: &'a str,
+    checklist: impl IntoIterator<Item = &'a str>,
+) -> ResultPopup<BootStr<'a>> {
+    let title = BootStr::from(title);
+    let icon = match outcome {
+        Outcome::Success => ResultIcon::success(),
+        Outcome::Failure => ResultIcon::failure(),
+    };
+    ResultPopup::new(icon, title)
+        .with_message(message.into())
+        .with_checklist(
+            Icon::new(theme::ICON_LIST_CURRENT),
+            Icon::new(theme::ICON_LIST_CHECK),
+            checklist.into_iter().map(BootStr::from),
+        )
+}
+
+fn install_success(reboot_msg: &str) -> ResultPopup<BootStr<'_>> {
+    result_frame(Outcome::Success, "FIRMWARE INSTALLED", reboot_msg, [])
+}
+
+#[no_mangle]
+pub extern "C" fn screen_install_success(reboot_msg: *const c_char, complete_draw: bool) {
+    // SAFETY: The message is only borrowed while the screen is being drawn.
+    match unsafe { try_from_c_str(reboot_msg) } {
+        Some(reboot_msg) => show(&mut install_success(reboot_msg), complete_draw),
+        None => {
+            show_invalid_input();
+        }
+    }
+}
+
+fn install_fail() -> ResultPopup<BootStr<'static>> {
+    result_frame(Outcome::Failure, "INSTALLATION FAILED", RECONNECT_MSG, [])
+}
+
+#[no_mangle]
+pub extern "C" fn screen_install_fail() {
+    show(&mut install_fail(), true);
+}
+
+/// Flags of `screen_wipe_success` telling what was erased.
+pub const WIPED_SEED: u32 = 1 << 0;
+pub const WIPED_PIN: u32 = 1 << 1;
+pub const WIPED_SETTINGS: u32 = 1 << 2;
+
+fn wipe_success(erased: u32) -> ResultPopup<BootStr<'static>> {
+    let items = [
+        (WIPED_SEED, "Recovery seed"),
+        (WIPED_PIN, "PIN"),
+        (WIPED_SETTINGS, "Settings"),
+    ];
+    result_frame(
+        Outcome::Success,
+        "DEVICE WIPED",
+        RECONNECT_MSG,
+        items
+            .into_iter()
+            .filter(|(flag, _)| erased & flag != 0)
+            .map(|(_, item)| item),
+    )
+}
+
+/// Result of a completed wipe, with the erased parts listed as indicated by
+/// `erased`, a combination of the `WIPED_*` flags.
+#[no_mangle]
+pub extern "C" fn screen_wipe_success(erased: u32) {
+    show(&mut wipe_success(erased), true);
+}
+
+fn wipe_fail() -> ResultPopup<BootStr<'static>> {
+    result_frame(Outcome::Failure, "WIPE FAILED", RECONNECT_MSG, [])
+}
+
+#[no_mangle]
+pub extern "C" fn screen_wipe_fail() {
+    show(&mut wipe_fail(), true);
+}
+
+/// Charge of the battery in percent below which the firmware is not
+/// installed, so that the device does not run out of power halfway through.
+pub const BATTERY_MIN_INSTALL: u8 = 20;
+
+/// Charge of the battery, e.g. while the device is powered by a charger only.
+#[no_mangle]
+pub extern "C" fn screen_battery_charge(soc: u8, charging: bool) {
+    let mut frame = BatteryCharge::new(BatteryLevel::new(soc, charging));
+    show(&mut frame, true);
+}
+
+/// Pass the refusal of `screen_install_low_battery` to `f`.
+fn with_install_low_battery<R>(soc: u8, f: impl FnOnce(&mut ResultPopup<BootStr>) -> R) -> R {
+    let mut message: String<64> = String::new();
+    unwrap!(write!(
+        message,
+        "Battery at {}%, charge it to at least {}% and try again.",
+        soc.min(100),
+        BATTERY_MIN_INSTALL
+    ));
+    f(&mut result_frame(
+        Outcome::Failure,
+        "BATTERY LOW",
+        &message,
+        [],
+    ))
+}
+
+/// Refusal to install the firmware with the battery charged to `soc` percent,
+/// less than `BATTERY_MIN_INSTALL`.
+#[no_mangle]
+pub extern "C" fn screen_install_low_battery(soc: u8) {
+    with_install_low_battery(soc, |frame| show(frame, true));
+}
@@ -0,0 +1,103 @@
+//! Values returned to the C code by the bootloader screens. They are exported
+//! to `rust_ui_bootloader.h`, so both sides use the same names for the same
+//! numbers. The numbers are part of the interface and must not change.
+
+/// Value returned by the screens which could not be shown because of invalid
+/// arguments, after showing a generic error screen.
+pub const SCREEN_INVALID_INPUT: u32 = u32::MAX;
+
+/// Value returned by `screen_menu` when the menu gets closed. The ids of the
+/// items must be neither this nor `SCREEN_INVALID_INPUT`.
+pub const MENU_CLOSED: u32 = 0;
+
+/// Decision made on the confirmation screen, returned by
+/// `screen_install_confirm`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(u32)]
+pub enum ConfirmResult {
+    Cancel = 1,
+    Confirm = 2,
+}
+
+/// Choice made on the intro screen, returned by `screen_intro`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(u32)]
+pub enum IntroResult {
+    Menu = 1,
+    Host = 2,
+}
+
+/// Returned by `screen_touch_calibration` once the calibration is finished.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(u32)]
+pub enum CalibrationResult {
+    Done = 1,
+}
+
+/// Conversion of a component message into the value returned to the C
+/// bootloader code.
+pub trait ReturnToC {
+    fn return_to_c(self) -> u32;
+}
+
+impl ReturnToC for ConfirmResult {
+    fn return_to_c(self) -> u32 {
+        self as u32
+    }
+}
+
+impl ReturnToC for IntroResult {
+    fn return_to_c(self) -> u32 {
+        self as u32
+    }
+}
+
+impl ReturnToC for CalibrationResult {
+    fn return_to_c(self) -> u32 {
+        self as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The matches are exhaustive on purpose, a new variant has to be given its
+    // number here as well.
+
+    #[test]
+    fn confirm_result_values() {
+        for result in [ConfirmResult::Cancel, ConfirmResult::Confirm] {
+            let expected = match result {
+                ConfirmResult::Cancel => 1,
+                ConfirmResult::Confirm => 2,
+            };
+            assert_eq!(result.return_to_c(), expected);
+        }
+    }
+
+    #[test]
+    fn intro_result_values() {
+        for result in [IntroResult::Menu, IntroResult::Host] {
+            let expected = match result {
+                IntroResult::Menu => 1,
+                IntroResult::Host => 2,
+            };
+            assert_eq!(result.return_to_c(), expected);
+        }
+    }
+
+    #[test]
+    fn calibration_result_values() {
+        let expected = match CalibrationResult::Done {
+            CalibrationResult::Done => 1,
+        };
+        assert_eq!(CalibrationResult::Done.return_to_c(), expected);
+    }
+
+    #[test]
+    fn screen_special_values() {
+        assert_eq!(SCREEN_INVALID_INPUT, 0xFFFF_FFFF);
+        assert_eq!(MENU_CLOSED, 0);
+    }
+}
@@ -0,0 +1,30 @@
+use crate::ui::component::text::paragraphs::ParagraphStrType;
+
+/// String passed from C, borrowed for the lifetime of a screen. Text components
+/// take their strings through `ParagraphStrType`, which the firmware implements
+/// only for `StrBuffer`, so plain `&str` is wrapped to keep the firmware from
+/// instantiating them twice.
+#[derive(Copy, Clone)]
+pub struct BootStr<'a>(&'a str);
+
+impl<'a> From<&'a str> for BootStr<'a> {
+    fn from(s: &'a str) -> Self {
+        Self(s)
+    }
+}
+
+impl AsRef<str> for BootStr<'_> {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
+
+impl ParagraphStrType for BootStr<'_> {
+    fn skip_prefix(&self, bytes: usize) -> Self {
+        Self(&self.0[bytes..])
+    }
+
+    fn take_prefix(&self, bytes: usize) -> Self {
+        Self(&self.0[..bytes])
+    }
+}
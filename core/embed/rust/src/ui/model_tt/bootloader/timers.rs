@@ -0,0 +1,124 @@
+use heapless::Vec;
+
+use crate::{
+    time::Instant,
+    ui::component::{EventCtx, TimerToken},
+};
+
+/// Maximum number of timers pending at the same time.
+const MAX_TIMERS: usize = 8;
+
+/// Timers requested by the components of a bootloader screen, waiting to be
+/// delivered as `Event::Timer` by the event loop.
+pub struct Timers {
+    pending: Vec<(TimerToken, Instant), MAX_TIMERS>,
+}
+
+impl Timers {
+    pub const fn new() -> Self {
+        Self {
+            pending: Vec::new(),
+        }
+    }
+
+    /// Take the timers requested during the event pass of `ctx`. A timer
+    /// requested again is rescheduled, timers over the capacity are dropped.
+    pub fn schedule(&mut self, ctx: &mut EventCtx, now: Instant) {
+        while let Some((token, duration)) = ctx.pop_timer() {
+            let deadline = now.checked_add(duration).unwrap_or(now);
+            if let Some(timer) = self.pending.iter_mut().find(|(t, _)| *t == token) {
+                timer.1 = deadline;
+            } else {
+                // Dropping the timer is the best we can do when full.
+                let _ = self.pending.push((token, deadline));
+            }
+        }
+    }
+
+    /// Remove and return a timer which is due at `now`.
+    pub fn pop_expired(&mut self, now: Instant) -> Option<TimerToken> {
+        let index = self
+            .pending
+            .iter()
+            .position(|(_, deadline)| *deadline <= now)?;
+        Some(self.pending.swap_remove(index).0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        time::{at, Duration},
+        ui::{
+            component::{Component, Event},
+            geometry::Rect,
+        },
+    };
+
+    /// Requests a timer when attached and reports when it fires.
+    struct Blink {
+        delay: Duration,
+        token: Option<TimerToken>,
+    }
+
+    impl Blink {
+        fn new(millis: u32) -> Self {
+            Self {
+                delay: Duration::from_millis(millis),
+                token: None,
+            }
+        }
+    }
+
+    impl Component for Blink {
+        type Msg = ();
+
+        fn place(&mut self, bounds: Rect) -> Rect {
+            bounds
+        }
+
+        fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+            match event {
+                Event::Attach => {
+                    self.token = Some(ctx.request_timer(self.delay));
+                    None
+                }
+                Event::Timer(token) if Some(token) == self.token => Some(()),
+                _ => None,
+            }
+        }
+
+        fn paint(&mut self) {}
+    }
+
+    #[test]
+    fn timers_two_components() {
+        let start = Instant::now();
+        let mut ctx = EventCtx::new();
+        let mut timers = Timers::new();
+        let mut first = Blink::new(1000);
+        let mut second = Blink::new(3000);
+
+        // Each component gets its own event pass, the context is shared like in
+        // the event loop of the bootloader.
+        ctx.clear();
+        first.event(&mut ctx, Event::Attach);
+        timers.schedule(&mut ctx, start);
+        ctx.clear();
+        second.event(&mut ctx, Event::Attach);
+        timers.schedule(&mut ctx, start);
+        assert!(first.token != second.token);
+
+        assert!(timers.pop_expired(at(start, 500)).is_none());
+        let token = unwrap!(timers.pop_expired(at(start, 1000)));
+        assert_eq!(first.event(&mut ctx, Event::Timer(token)), Some(()));
+        assert_eq!(second.event(&mut ctx, Event::Timer(token)), None);
+        assert!(timers.pop_expired(at(start, 2000)).is_none());
+
+        let token = unwrap!(timers.pop_expired(at(start, 3000)));
+        assert_eq!(first.event(&mut ctx, Event::Timer(token)), None);
+        assert_eq!(second.event(&mut ctx, Event::Timer(token)), Some(()));
+        assert!(timers.pop_expired(at(start, 10000)).is_none());
+    }
+}
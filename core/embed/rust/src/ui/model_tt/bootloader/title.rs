@@ -0,0 +1,136 @@
+use crate::ui::{
+    component::{Child, Component, Event, EventCtx, Label, Never},
+    constant,
+    display::Icon,
+    geometry::{Point, Rect, TOP_LEFT},
+    model_tt::theme,
+};
+
+/// Height of the area screens reserve for the title bar.
+pub const TITLE_AREA_HEIGHT: i16 = 32;
+
+/// Button in the top right corner of a screen, next to the title, e.g. closing
+/// the menu or showing the details of a confirmation.
+pub const CORNER_BUTTON_AREA: Rect = Rect::new(
+    Point::new(
+        constant::WIDTH - theme::borders().right - 64,
+        theme::borders().top,
+    ),
+    Point::new(
+        constant::WIDTH - theme::borders().right,
+        theme::borders().top + TITLE_AREA_HEIGHT,
+    ),
+);
+
+/// Top of the row of buttons at the bottom of a screen.
+pub const BUTTON_AREA_START: i16 =
+    constant::HEIGHT - theme::borders().bottom - theme::BUTTON_HEIGHT;
+
+const_assert!(
+    constant::fits_screen(CORNER_BUTTON_AREA),
+    "corner button does not fit the screen"
+);
+const_assert!(
+    BUTTON_AREA_START >= theme::borders().top + TITLE_AREA_HEIGHT + theme::CONTENT_BORDER,
+    "bottom buttons overlap the title"
+);
+
+/// Title bar of the bootloader screens: title with an optional leading icon
+/// and an optional right-aligned auxiliary text, e.g. the bootloader version.
+pub struct TitleBar<T> {
+    area: Rect,
+    icon: Option<Icon>,
+    title: Child<Label<T>>,
+    aux: Option<Child<Label<T>>>,
+}
+
+impl<T> TitleBar<T>
+where
+    T: AsRef<str>,
+{
+    pub fn new(title: T) -> Self {
+        Self {
+            area: Rect::zero(),
+            icon: None,
+            title: Child::new(Label::left_aligned(title, theme::label_title())),
+            aux: None,
+        }
+    }
+
+    pub fn with_icon(mut self, icon: Icon) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn with_aux(mut self, text: T) -> Self {
+        self.aux = Some(Child::new(Label::right_aligned(
+            text,
+            theme::label_title_aux(),
+        )));
+        self
+    }
+
+    const ICON_SPACING: i16 = 8;
+    const AUX_SPACING: i16 = 8;
+}
+
+impl<T> Component for TitleBar<T>
+where
+    T: AsRef<str>,
+{
+    type Msg = Never;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.area = bounds;
+        let mut title_area = bounds;
+        if let Some(icon) = self.icon {
+            let icon_width = icon.toif.width() + Self::ICON_SPACING;
+            title_area = title_area.split_left(icon_width).1;
+        }
+        if let Some(aux) = self.aux.as_mut() {
+            let aux_width = aux.inner().max_size().x;
+            let (rest, aux_area) = title_area.split_right(aux_width);
+            aux.place(aux_area);
+            title_area = rest.split_right(Self::AUX_SPACING).0;
+        }
+        self.title.place(title_area);
+        bounds
+    }
+
+    fn event(&mut self, _ctx: &mut EventCtx, _event: Event) -> Option<Self::Msg> {
+        None
+    }
+
+    fn paint(&mut self) {
+        if let Some(icon) = self.icon {
+            let style = theme::label_title();
+            icon.draw(
+                self.area.top_left(),
+                TOP_LEFT,
+                style.text_color,
+                style.background_color,
+            );
+        }
+        self.title.paint();
+        self.aux.paint();
+    }
+
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        sink(self.area)
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl<T> crate::trace::Trace for TitleBar<T>
+where
+    T: AsRef<str>,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.open("TitleBar");
+        t.field("title", &self.title);
+        if let Some(aux) = &self.aux {
+            t.field("aux", aux);
+        }
+        t.close();
+    }
+}
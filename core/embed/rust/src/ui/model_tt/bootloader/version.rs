@@ -0,0 +1,103 @@
+use core::fmt;
+
+/// Firmware version as stored in the image header, compared the same way the
+/// bootloader compares it when deciding about the installation.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct Version {
+    pub major: u8,
+    pub minor: u8,
+    pub patch: u8,
+    pub build: u8,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum VersionChange {
+    Downgrade,
+    Same,
+    Upgrade,
+}
+
+impl Version {
+    pub const fn new(major: u8, minor: u8, patch: u8, build: u8) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+            build,
+        }
+    }
+
+    /// Decode the `major | minor << 8 | patch << 16 | build << 24` format of
+    /// the image header.
+    pub const fn from_u32(version: u32) -> Self {
+        let [major, minor, patch, build] = version.to_le_bytes();
+        Self::new(major, minor, patch, build)
+    }
+
+    /// Kind of the installation of `new` over this version.
+    pub fn change_to(self, new: Self) -> VersionChange {
+        match new.cmp(&self) {
+            core::cmp::Ordering::Less => VersionChange::Downgrade,
+            core::cmp::Ordering::Equal => VersionChange::Same,
+            core::cmp::Ordering::Greater => VersionChange::Upgrade,
+        }
+    }
+}
+
+/// Formats as "2.6.0", or "2.6.0 (build 7)" if the build number is set.
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        if self.build != 0 {
+            write!(f, " (build {})", self.build)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::fmt::Write;
+
+    use heapless::String;
+
+    use super::*;
+
+    fn format(version: Version) -> String<32> {
+        let mut s = String::new();
+        unwrap!(write!(s, "{}", version));
+        s
+    }
+
+    #[test]
+    fn version_format() {
+        assert_eq!(format(Version::from_u32(0x0000_0602)), "2.6.0");
+        assert_eq!(format(Version::from_u32(0x0703_0602)), "2.6.3 (build 7)");
+        assert_eq!(format(Version::new(255, 0, 12, 0)), "255.0.12");
+    }
+
+    #[test]
+    fn version_change() {
+        let current = Version::new(2, 6, 0, 0);
+        assert_eq!(
+            current.change_to(Version::new(2, 6, 0, 0)),
+            VersionChange::Same
+        );
+        assert_eq!(
+            current.change_to(Version::new(2, 6, 0, 1)),
+            VersionChange::Upgrade
+        );
+        assert_eq!(
+            current.change_to(Version::new(2, 10, 0, 0)),
+            VersionChange::Upgrade
+        );
+        assert_eq!(
+            current.change_to(Version::new(2, 5, 9, 9)),
+            VersionChange::Downgrade
+        );
+        assert_eq!(
+            current.change_to(Version::new(1, 12, 1, 0)),
+            VersionChange::Downgrade
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use core::fmt::Write;
+
+use heapless::String;
+
+use crate::ui::{
+    component::{Component, Event, EventCtx, Pad},
+    display::{self, Color, Font},
+    event::{StatusEvent, TouchEvent},
+    geometry::{Insets, Offset, Point, Rect},
+};
+
+use super::{theme, HomescreenMsg};
+
+/// Charge at and below which the battery is shown red.
+pub const BATTERY_LOW: u8 = 15;
+
+/// Charge of the battery as reported by the fuel gauge.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct BatteryLevel {
+    /// State of charge in percent.
+    pub soc: u8,
+    pub charging: bool,
+}
+
+impl BatteryLevel {
+    pub const fn new(soc: u8, charging: bool) -> Self {
+        Self {
+            soc: if soc > 100 { 100 } else { soc },
+            charging,
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        if self.charging {
+            theme::GREEN
+        } else if self.soc <= BATTERY_LOW {
+            theme::RED
+        } else {
+            theme::FG
+        }
+    }
+}
+
+/// Paint a battery outline with `tip` attached to the right side of `body`,
+/// filled proportionally to the charge. The background is left as it is.
+pub fn paint_battery(body: Rect, tip: Offset, level: BatteryLevel, border: i16) {
+    let color = level.color();
+    for i in 0..border {
+        display::rect_stroke(body.inset(Insets::uniform(i)), color);
+    }
+    display::rect_fill(
+        Rect::from_top_left_and_size(Point::new(body.x1, body.center().y - tip.y / 2), tip),
+        color,
+    );
+    let inner = body.inset(Insets::uniform(2 * border));
+    let (charge, _) = inner.split_left(battery_fill(level.soc, inner.width()));
+    display::rect_fill(charge, color);
+}
+
+/// Width of the filled part of a battery `width` wide.
+fn battery_fill(soc: u8, width: i16) -> i16 {
+    let soc = soc.min(100) as i32;
+    let fill = (width as i32 * soc + 50) / 100;
+    // Anything but an empty battery shows at least a sliver.
+    if soc > 0 {
+        fill.max(1) as i16
+    } else {
+        0
+    }
+}
+
+/// Percentage and state shown under the battery, e.g. "CHARGING 42%".
+fn charge_text(level: BatteryLevel) -> String<16> {
+    let mut text = String::new();
+    let state = if level.charging { "CHARGING " } else { "" };
+    unwrap!(write!(text, "{}{}%", state, level.soc));
+    text
+}
+
+/// Screen visualizing the charge of the battery, e.g. while the device is
+/// connected only to a charger. Updated by the battery and charging status
+/// events, dismissed by a touch.
+pub struct BatteryCharge {
+    pad: Pad,
+    level: BatteryLevel,
+}
+
+impl BatteryCharge {
+    const BODY_SIZE: Offset = Offset::new(120, 60);
+    const TIP_SIZE: Offset = Offset::new(8, 24);
+    const BORDER: i16 = 3;
+    const TEXT_OFFSET: i16 = 40;
+
+    pub fn new(level: BatteryLevel) -> Self {
+        Self {
+            pad: Pad::with_background(theme::BG),
+            level,
+        }
+    }
+
+    pub fn set_level(&mut self, ctx: &mut EventCtx, level: BatteryLevel) {
+        if self.level != level {
+            self.level = level;
+            self.pad.clear();
+            ctx.request_paint();
+        }
+    }
+}
+
+impl Component for BatteryCharge {
+    type Msg = HomescreenMsg;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.pad.place(bounds);
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        let level = match event {
+            Event::Touch(TouchEvent::TouchEnd(_)) => return Some(HomescreenMsg::Dismissed),
+            Event::Status(StatusEvent::Battery(soc)) => BatteryLevel::new(soc, self.level.charging),
+            Event::Status(StatusEvent::Charging(charging)) => {
+                BatteryLevel::new(self.level.soc, charging)
+            }
+            _ => return None,
+        };
+        self.set_level(ctx, level);
+        None
+    }
+
+    fn paint(&mut self) {
+        self.pad.paint();
+        let center = self.pad.area.center();
+        let body =
+            Rect::from_center_and_size(center - Offset::x(Self::TIP_SIZE.x / 2), Self::BODY_SIZE);
+        paint_battery(body, Self::TIP_SIZE, self.level, Self::BORDER);
+        display::text_center(
+            Point::new(center.x, body.y1 + Self::TEXT_OFFSET),
+            &charge_text(self.level),
+            Font::BOLD,
+            self.level.color(),
+            theme::BG,
+        );
+    }
+
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        sink(self.pad.area)
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl crate::trace::Trace for BatteryCharge {
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.open("BatteryCharge");
+        t.field("soc", &(self.level.soc as usize));
+        t.field("charging", &(self.level.charging as usize));
+        t.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn battery_fill_width() {
+        assert_eq!(battery_fill(0, 18), 0);
+        assert_eq!(battery_fill(1, 18), 1);
+        assert_eq!(battery_fill(50, 18), 9);
+        assert_eq!(battery_fill(100, 18), 18);
+        assert_eq!(battery_fill(200, 18), 18);
+    }
+
+    #[test]
+    fn battery_charge_text() {
+        assert_eq!(
+            charge_text(BatteryLevel::new(42, true)).as_str(),
+            "CHARGING 42%"
+        );
+        assert_eq!(charge_text(BatteryLevel::new(150, false)).as_str(), "100%");
+    }
+
+    #[test]
+    fn battery_charge_status_events() {
+        let mut screen = BatteryCharge::new(BatteryLevel::new(40, false));
+        let mut ctx = EventCtx::new();
+
+        screen.event(&mut ctx, Event::Status(StatusEvent::Battery(40)));
+        assert!(!ctx.paint_requested());
+
+        screen.event(&mut ctx, Event::Status(StatusEvent::Charging(true)));
+        assert!(ctx.paint_requested());
+        assert!(screen.level == BatteryLevel::new(40, true));
+
+        ctx.clear();
+        screen.event(&mut ctx, Event::Status(StatusEvent::Battery(41)));
+        assert!(ctx.paint_requested());
+        assert!(screen.level == BatteryLevel::new(41, true));
+
+        ctx.clear();
+        screen.event(&mut ctx, Event::Status(StatusEvent::Locked(true)));
+        assert!(!ctx.paint_requested());
+    }
+}
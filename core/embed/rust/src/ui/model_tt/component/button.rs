@@ -2,7 +2,8 @@ use crate::{
     time::Duration,
     ui::{
         component::{
-            Component, ComponentExt, Event, EventCtx, FixedHeightBar, GridPlaced, Map, TimerToken,
+            Component, ComponentExt, Event, EventCtx, FixedHeightBar, GridPlaced, Map,
+            ResultButton, TimerToken,
         },
         display::{self, toif::Icon, Color, Font},
         event::TouchEvent,
@@ -274,6 +275,23 @@ where
                     }
                 }
             }
+            Event::Touch(TouchEvent::TouchCancel) => {
+                match self.state {
+                    State::Pressed => {
+                        // Interaction was aborted, do not treat it as a click.
+                        self.set(ctx, State::Initial);
+                        self.long_timer = None;
+                        return Some(ButtonMsg::Released);
+                    }
+                    State::Released => {
+                        self.set(ctx, State::Initial);
+                        self.long_timer = None;
+                    }
+                    _ => {
+                        // Do nothing.
+                    }
+                }
+            }
             Event::Timer(token) => {
                 if self.long_timer == Some(token) {
                     self.long_timer = None;
@@ -299,6 +317,15 @@ where
     }
 }
 
+impl<T> ResultButton for Button<T>
+where
+    T: AsRef<str>,
+{
+    fn is_clicked(msg: &ButtonMsg) -> bool {
+        matches!(msg, ButtonMsg::Clicked)
+    }
+}
+
 #[cfg(feature = "ui_debug")]
 impl<T> crate::trace::Trace for Button<T>
 where
@@ -1,16 +1,18 @@
 use crate::ui::{
     component::{
-        image::BlendedImage,
-        text::paragraphs::{
-            Paragraph, ParagraphSource, ParagraphStrType, ParagraphVecShort, Paragraphs, VecExt,
+        text::{
+            paragraphs::{
+                paragraph_vec, Paragraph, ParagraphSource, ParagraphStrType, ParagraphVec,
+                Paragraphs, VecExt,
+            },
+            StyleToken,
         },
         Child, Component, Event, EventCtx, Never,
     },
-    display::toif::Icon,
     geometry::{Insets, LinearPlacement, Rect},
 };
 
-use super::theme;
+use super::{theme, ResultIcon};
 
 pub enum DialogMsg<T, U> {
     Content(T),
@@ -88,9 +90,12 @@ where
     }
 }
 
+/// Title with description, or the four lines of `IconDialog::new_shares`.
+const ICON_DIALOG_PARAGRAPHS: usize = 4;
+
 pub struct IconDialog<T, U> {
-    image: Child<BlendedImage>,
-    paragraphs: Paragraphs<ParagraphVecShort<T>>,
+    image: Child<ResultIcon>,
+    paragraphs: Paragraphs<ParagraphVec<T, ICON_DIALOG_PARAGRAPHS>>,
     controls: Child<U>,
 }
 
@@ -99,11 +104,11 @@ where
     T: ParagraphStrType,
     U: Component,
 {
-    pub fn new(icon: BlendedImage, title: T, controls: U) -> Self {
+    pub fn new(icon: ResultIcon, title: T, controls: U) -> Self {
         Self {
             image: Child::new(icon),
-            paragraphs: Paragraphs::new(ParagraphVecShort::from_iter([Paragraph::new(
-                &theme::TEXT_DEMIBOLD,
+            paragraphs: Paragraphs::new(paragraph_vec([Paragraph::new(
+                theme::text(StyleToken::Subtitle),
                 title,
             )
             .centered()]))
@@ -120,7 +125,7 @@ where
         if !description.as_ref().is_empty() {
             self.paragraphs
                 .inner_mut()
-                .add(Paragraph::new(&theme::TEXT_NORMAL_OFF_WHITE, description).centered());
+                .add(Paragraph::new(theme::text(StyleToken::Secondary), description).centered());
         }
         self
     }
@@ -128,18 +133,12 @@ where
     pub fn new_shares(lines: [T; 4], controls: U) -> Self {
         let [l0, l1, l2, l3] = lines;
         Self {
-            image: Child::new(BlendedImage::new(
-                Icon::new(theme::IMAGE_BG_CIRCLE),
-                Icon::new(theme::IMAGE_FG_SUCCESS),
-                theme::SUCCESS_COLOR,
-                theme::FG,
-                theme::BG,
-            )),
-            paragraphs: ParagraphVecShort::from_iter([
-                Paragraph::new(&theme::TEXT_NORMAL_OFF_WHITE, l0).centered(),
-                Paragraph::new(&theme::TEXT_DEMIBOLD, l1).centered(),
-                Paragraph::new(&theme::TEXT_NORMAL_OFF_WHITE, l2).centered(),
-                Paragraph::new(&theme::TEXT_DEMIBOLD, l3).centered(),
+            image: Child::new(ResultIcon::success()),
+            paragraphs: paragraph_vec([
+                Paragraph::new(theme::text(StyleToken::Secondary), l0).centered(),
+                Paragraph::new(theme::text(StyleToken::Subtitle), l1).centered(),
+                Paragraph::new(theme::text(StyleToken::Secondary), l2).centered(),
+                Paragraph::new(theme::text(StyleToken::Subtitle), l3).centered(),
             ])
             .into_paragraphs()
             .with_placement(LinearPlacement::vertical().align_at_center()),
@@ -148,7 +147,6 @@ where
     }
 
     pub const ICON_AREA_PADDING: i16 = 2;
-    pub const ICON_AREA_HEIGHT: i16 = 60;
     pub const VALUE_SPACE: i16 = 5;
 }
 
@@ -167,7 +165,7 @@ where
         let controls_area = self.controls.place(bounds);
         let content_area = bounds.inset(Insets::bottom(controls_area.height()));
 
-        let (image_area, content_area) = content_area.split_top(Self::ICON_AREA_HEIGHT);
+        let (image_area, content_area) = content_area.split_top(theme::RESULT_ICON_HEIGHT);
 
         self.image.place(image_area);
         self.paragraphs.place(content_area);
@@ -175,6 +173,7 @@ where
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        self.image.event(ctx, event);
         self.paragraphs.event(ctx, event);
         self.controls.event(ctx, event).map(Self::Msg::Controls)
     }
@@ -1,6 +1,8 @@
 use crate::ui::{
-    component::{image::Image, Child, Component, Event, EventCtx, Label},
-    display,
+    component::{
+        image::Image, text::StyleToken, BacklightFade, Child, Component, Event, EventCtx, Label,
+    },
+    constant, display,
     geometry::{Alignment, Insets, Rect},
     model_tt::component::{
         fido_icons::get_fido_icon_data,
@@ -17,6 +19,13 @@ const SCROLLBAR_HEIGHT: i16 = 10;
 const APP_NAME_PADDING: i16 = 12;
 const APP_NAME_HEIGHT: i16 = 30;
 
+const_assert!(
+    constant::fits_height(
+        ICON_HEIGHT + SCROLLBAR_INSET_TOP + SCROLLBAR_HEIGHT + APP_NAME_PADDING + APP_NAME_HEIGHT
+    ),
+    "FIDO confirmation does not fit the screen"
+);
+
 pub enum FidoMsg {
     Confirmed(usize),
     Cancelled,
@@ -30,7 +39,7 @@ pub struct FidoConfirm<F: Fn(usize) -> T, T, U> {
     /// Function/closure that will return appropriate page on demand.
     get_account: F,
     scrollbar: ScrollBar,
-    fade: bool,
+    fade: BacklightFade,
     controls: U,
 }
 
@@ -60,13 +69,19 @@ where
         page_swipe.allow_left = scrollbar.has_next_page();
 
         Self {
-            app_name: Label::new(app_name, Alignment::Center, theme::TEXT_BOLD),
-            account_name: Label::new("".into(), Alignment::Center, theme::TEXT_BOLD),
+            app_name: Label::new(app_name, Alignment::Center, *theme::text(StyleToken::Title))
+                .with_lookalike_style(theme::label_lookalike()),
+            account_name: Label::new(
+                "".into(),
+                Alignment::Center,
+                *theme::text(StyleToken::Title),
+            )
+            .with_lookalike_style(theme::label_lookalike()),
             page_swipe,
             icon: Child::new(Image::new(icon_data)),
             get_account,
             scrollbar,
-            fade: false,
+            fade: BacklightFade::new(),
             controls,
         }
     }
@@ -90,8 +105,8 @@ where
         // Redraw the page.
         ctx.request_paint();
 
-        // Reset backlight to normal level on next paint.
-        self.fade = true;
+        // Reset backlight to normal level after the next paint.
+        self.fade.start(ctx, theme::BACKLIGHT_NORMAL);
     }
 
     fn active_page(&self) -> usize {
@@ -143,6 +158,7 @@ where
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        self.fade.event(ctx, event);
         if let Some(swipe) = self.page_swipe.event(ctx, event) {
             // Swipe encountered, update the page.
             self.on_page_swipe(ctx, swipe);
@@ -183,12 +199,6 @@ where
             self.account_name.set_text(current_account);
             self.account_name.paint();
         }
-
-        if self.fade {
-            self.fade = false;
-            // Note that this is blocking and takes some time.
-            display::fade_backlight(theme::BACKLIGHT_NORMAL);
-        }
     }
 
     fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
@@ -202,9 +212,13 @@ where
 impl<F, T, U> crate::trace::Trace for FidoConfirm<F, T, U>
 where
     F: Fn(usize) -> T,
+    T: AsRef<str>,
 {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.open("FidoPaginatedPage");
+        if self.app_name.is_lookalike() {
+            t.symbol("lookalike");
+        }
         t.close();
     }
 }
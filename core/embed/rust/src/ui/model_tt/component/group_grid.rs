@@ -0,0 +1,220 @@
+use heapless::Vec;
+
+use crate::ui::{
+    component::{Component, Event, EventCtx, Never, Pad},
+    display::{self, toif::Icon, Color, Font},
+    geometry::{Offset, Point, Rect, CENTER},
+    util,
+};
+
+use super::theme;
+
+/// Most groups a SLIP-39 backup can have.
+pub const MAX_GROUPS: usize = 16;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct GroupShares {
+    pub entered: u8,
+    /// Zero if no share of the group was entered yet, the threshold is only
+    /// known from the shares themselves.
+    pub threshold: u8,
+}
+
+impl GroupShares {
+    pub const fn is_complete(self) -> bool {
+        self.threshold > 0 && self.entered >= self.threshold
+    }
+}
+
+/// Structure of a SLIP-39 advanced backup: a row for each group with a dot
+/// for each share needed to recover the group, filled for the shares already
+/// entered. Complete groups are green, the pending ones are greyed out once
+/// enough groups are complete.
+pub struct GroupGrid {
+    area: Rect,
+    pad: Pad,
+    group_threshold: u8,
+    groups: Vec<GroupShares, MAX_GROUPS>,
+}
+
+impl GroupGrid {
+    const LABEL: &'static str = "GROUP ";
+    const LABEL_WIDTH: i16 = 80;
+    const ROW_HEIGHT_MAX: i16 = 30;
+    const DOT_INTERVAL_MAX: i16 = 18;
+
+    pub fn new(group_threshold: u8) -> Self {
+        Self {
+            area: Rect::zero(),
+            pad: Pad::with_background(theme::BG),
+            group_threshold,
+            groups: Vec::new(),
+        }
+    }
+
+    /// Append a group with `entered` of the `threshold` shares needed to
+    /// recover it, in the order of the `(entered, threshold)` pairs passed from
+    /// MicroPython. Groups over `MAX_GROUPS` are dropped.
+    pub fn with_group(mut self, entered: u8, threshold: u8) -> Self {
+        let _ = self.groups.push(GroupShares { entered, threshold });
+        self
+    }
+
+    pub fn groups(&self) -> &[GroupShares] {
+        &self.groups
+    }
+
+    pub fn group_threshold(&self) -> u8 {
+        self.group_threshold
+    }
+
+    pub fn set_entered(&mut self, ctx: &mut EventCtx, group: usize, entered: u8) {
+        if let Some(shares) = self.groups.get_mut(group) {
+            shares.entered = entered;
+            self.pad.clear();
+            ctx.request_paint();
+        }
+    }
+
+    pub fn completed_groups(&self) -> usize {
+        self.groups.iter().filter(|g| g.is_complete()).count()
+    }
+
+    /// Number of groups which still have to be completed.
+    pub fn remaining_groups(&self) -> usize {
+        (self.group_threshold as usize).saturating_sub(self.completed_groups())
+    }
+
+    /// Whether enough groups are complete to recover the secret.
+    pub fn is_done(&self) -> bool {
+        self.completed_groups() >= self.group_threshold as usize
+    }
+
+    fn row_height(&self) -> i16 {
+        let rows = self.groups.len().max(1) as i16;
+        (self.area.height() / rows).min(Self::ROW_HEIGHT_MAX)
+    }
+
+    fn color(&self, group: GroupShares) -> Color {
+        if group.is_complete() {
+            theme::GREEN
+        } else if self.is_done() {
+            theme::GREY_MEDIUM
+        } else {
+            theme::FG
+        }
+    }
+
+    fn paint_row(&self, row: Rect, index: usize, group: GroupShares) {
+        let color = self.color(group);
+        let (label_area, dots_area) = row.split_left(Self::LABEL_WIDTH);
+
+        // Leave out the label if the rows are too dense for the text.
+        let font = Font::NORMAL;
+        if row.height() >= font.text_height() {
+            let baseline = Point::new(
+                label_area.x0,
+                label_area.center().y + font.text_height() / 2,
+            );
+            display::text(baseline, Self::LABEL, font, color, theme::BG);
+            let mut buf = [0u8; 4];
+            if let Some(number) = util::u32_to_str(index as u32 + 1, &mut buf) {
+                let baseline = baseline + Offset::x(font.text_width(Self::LABEL));
+                display::text(baseline, number, font, color, theme::BG);
+            }
+        }
+
+        let interval = dot_interval(dots_area.width(), group.threshold);
+        let mut center = Point::new(dots_area.x0 + interval / 2, dots_area.center().y);
+        for share in 0..group.threshold {
+            let dot = if share < group.entered {
+                theme::DOT_ACTIVE
+            } else {
+                theme::DOT_INACTIVE
+            };
+            Icon::new(dot).draw(center, CENTER, color, theme::BG);
+            center = center + Offset::x(interval);
+        }
+    }
+}
+
+/// Distance between the centers of the dots of `count` shares in `width`.
+fn dot_interval(width: i16, count: u8) -> i16 {
+    (width / (count.max(1) as i16)).min(GroupGrid::DOT_INTERVAL_MAX)
+}
+
+impl Component for GroupGrid {
+    type Msg = Never;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.area = bounds;
+        self.pad.place(bounds);
+        bounds
+    }
+
+    fn event(&mut self, _ctx: &mut EventCtx, _event: Event) -> Option<Self::Msg> {
+        None
+    }
+
+    fn paint(&mut self) {
+        self.pad.paint();
+        let row_height = self.row_height();
+        let mut rest = self.area;
+        for (index, group) in self.groups.iter().enumerate() {
+            let (row, next) = rest.split_top(row_height);
+            self.paint_row(row, index, *group);
+            rest = next;
+        }
+    }
+
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        sink(self.area)
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl crate::trace::Trace for GroupGrid {
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.open("GroupGrid");
+        t.field("group_threshold", &(self.group_threshold as usize));
+        t.field("completed_groups", &self.completed_groups());
+        t.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn group_grid_progress() {
+        let mut grid = GroupGrid::new(2)
+            .with_group(2, 2)
+            .with_group(1, 3)
+            .with_group(0, 1)
+            .with_group(0, 0);
+        assert_eq!(grid.groups()[1].threshold, 3);
+        assert_eq!(grid.completed_groups(), 1);
+        assert!(!grid.is_done());
+
+        grid.set_entered(&mut EventCtx::new(), 2, 1);
+        assert_eq!(grid.completed_groups(), 2);
+        assert!(grid.is_done());
+        assert_eq!(
+            grid.groups()[2],
+            GroupShares {
+                entered: 1,
+                threshold: 1
+            }
+        );
+        // The threshold of a group without shares is not known.
+        assert!(!grid.groups()[3].is_complete());
+    }
+
+    #[test]
+    fn group_grid_dot_interval() {
+        assert_eq!(dot_interval(160, 3), GroupGrid::DOT_INTERVAL_MAX);
+        assert_eq!(dot_interval(160, 16), 10);
+        assert_eq!(dot_interval(160, 0), GroupGrid::DOT_INTERVAL_MAX);
+    }
+}
@@ -1,5 +1,7 @@
 mod render;
 
+use core::mem;
+
 use crate::{
     micropython::gc::Gc,
     storage::{get_avatar, get_avatar_len},
@@ -18,7 +20,7 @@ use render::{
     homescreen, homescreen_blurred, HomescreenNotification, HomescreenText, HOMESCREEN_IMAGE_SIZE,
 };
 
-use super::{theme, Loader, LoaderMsg};
+use super::{theme, Loader, LoaderMsg, StatusBar};
 
 const AREA: Rect = constant::screen();
 const TOP_CENTER: Point = AREA.top_center();
@@ -30,13 +32,42 @@ const LOADER_OFFSET: Offset = Offset::y(-10);
 const LOADER_DELAY: Duration = Duration::from_millis(500);
 const LOADER_DURATION: Duration = Duration::from_millis(2000);
 
+/// What the next `paint()` has to repaint.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Repaint {
+    Nothing,
+    /// Only an indicator of the status bar has changed.
+    StatusOnly,
+    Full,
+}
+
+impl Repaint {
+    /// Update after an event. `full` is whether anything besides the status
+    /// bar requested a paint, `status_changed` whether an indicator changed.
+    fn update(&mut self, event: Event, full: bool, status_changed: bool) {
+        if full || matches!(event, Event::Attach | Event::RequestPaint) {
+            *self = Self::Full;
+        } else if status_changed && *self == Self::Nothing {
+            *self = Self::StatusOnly;
+        }
+    }
+
+    /// Take the pending repaint, returns whether only the status bar is to be
+    /// painted.
+    fn take_status_only(&mut self) -> bool {
+        mem::replace(self, Self::Nothing) == Self::StatusOnly
+    }
+}
+
 pub struct Homescreen<T> {
     label: T,
     notification: Option<(T, u8)>,
     hold_to_lock: bool,
     loader: Loader,
     pad: Pad,
+    status: StatusBar,
     paint_notification_only: bool,
+    repaint: Repaint,
     delay: Option<TimerToken>,
 }
 
@@ -55,7 +86,9 @@ where
             hold_to_lock,
             loader: Loader::new().with_durations(LOADER_DURATION, LOADER_DURATION / 3),
             pad: Pad::with_background(theme::BG),
+            status: StatusBar::new(false, usb_configured()),
             paint_notification_only: false,
+            repaint: Repaint::Full,
             delay: None,
         }
     }
@@ -119,6 +152,13 @@ where
                     self.delay = Some(ctx.request_timer(LOADER_DELAY));
                 }
             }
+            Event::Touch(TouchEvent::TouchCancel) => {
+                // Aborted hold never confirms, even if the loader is fully grown.
+                self.delay = None;
+                if self.loader.is_animating() {
+                    self.loader.start_shrinking(ctx, Instant::now());
+                }
+            }
             Event::Touch(TouchEvent::TouchEnd(_)) => {
                 self.delay = None;
                 let now = Instant::now();
@@ -164,21 +204,31 @@ where
     fn place(&mut self, bounds: Rect) -> Rect {
         self.pad.place(AREA);
         self.loader.place(AREA.translate(LOADER_OFFSET));
+        self.status.place(AREA);
         bounds
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
         Self::event_usb(self, ctx, event);
-        if self.hold_to_lock {
-            Self::event_hold(self, ctx, event).then_some(HomescreenMsg::Dismissed)
-        } else {
-            None
-        }
+        let dismissed = self.hold_to_lock && Self::event_hold(self, ctx, event);
+        let full = ctx.paint_requested();
+        let status_changed = self.status.event(ctx, event).is_some();
+        self.repaint.update(event, full, status_changed);
+        dismissed.then_some(HomescreenMsg::Dismissed)
     }
 
     fn paint(&mut self) {
+        let loader = self.loader.is_animating() || self.loader.is_completely_grown(Instant::now());
+        if self.repaint.take_status_only() && !loader {
+            // The status bar is hidden by the notification.
+            if self.get_notification().is_none() {
+                self.status.paint();
+            }
+            return;
+        }
+
         self.pad.paint();
-        if self.loader.is_animating() || self.loader.is_completely_grown(Instant::now()) {
+        if loader {
             self.paint_loader();
         } else {
             let mut label_style = theme::TEXT_BOLD;
@@ -192,6 +242,7 @@ where
             };
 
             let notification = self.get_notification();
+            let show_status = notification.is_none();
 
             let res = get_image();
             if let Ok(data) = res {
@@ -209,6 +260,11 @@ where
                     self.paint_notification_only,
                 );
             }
+
+            if show_status {
+                self.status.invalidate();
+                self.status.paint();
+            }
         }
     }
 
@@ -223,6 +279,7 @@ impl<T: AsRef<str>> crate::trace::Trace for Homescreen<T> {
     fn trace(&self, d: &mut dyn crate::trace::Tracer) {
         d.open("Homescreen");
         d.field("label", &self.label.as_ref());
+        d.field("status", &self.status);
         d.close();
     }
 }
@@ -230,11 +287,18 @@ impl<T: AsRef<str>> crate::trace::Trace for Homescreen<T> {
 pub struct Lockscreen<T> {
     label: T,
     bootscreen: bool,
+    status: StatusBar,
+    repaint: Repaint,
 }
 
 impl<T> Lockscreen<T> {
     pub fn new(label: T, bootscreen: bool) -> Self {
-        Lockscreen { label, bootscreen }
+        Lockscreen {
+            label,
+            bootscreen,
+            status: StatusBar::new(!bootscreen, usb_configured()),
+            repaint: Repaint::Full,
+        }
     }
 }
 
@@ -245,17 +309,25 @@ where
     type Msg = HomescreenMsg;
 
     fn place(&mut self, bounds: Rect) -> Rect {
+        self.status.place(AREA);
         bounds
     }
 
-    fn event(&mut self, _ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
         if let Event::Touch(TouchEvent::TouchEnd(_)) = event {
             return Some(HomescreenMsg::Dismissed);
         }
+        let status_changed = self.status.event(ctx, event).is_some();
+        self.repaint.update(event, false, status_changed);
         None
     }
 
     fn paint(&mut self) {
+        if self.repaint.take_status_only() {
+            self.status.paint();
+            return;
+        }
+
         let (locked, tap) = if self.bootscreen {
             ("NOT CONNECTED", "Tap to connect")
         } else {
@@ -295,6 +367,8 @@ where
         } else {
             homescreen_blurred(IMAGE_HOMESCREEN, &texts);
         }
+        self.status.invalidate();
+        self.status.paint();
     }
 }
 
@@ -323,6 +397,39 @@ fn get_image() -> Result<Gc<[u8]>, ()> {
 impl<T> crate::trace::Trace for Lockscreen<T> {
     fn trace(&self, d: &mut dyn crate::trace::Tracer) {
         d.open("Lockscreen");
+        d.field("status", &self.status);
         d.close();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::event::StatusEvent;
+
+    #[test]
+    fn repaint_status_only_on_change() {
+        let status = Event::Status(StatusEvent::Battery(50));
+
+        // An unchanged indicator does not skip the next full repaint.
+        let mut repaint = Repaint::Nothing;
+        repaint.update(status, false, false);
+        repaint.update(Event::USB(USBEvent::Connected(false)), true, false);
+        assert!(!repaint.take_status_only());
+
+        let mut repaint = Repaint::Nothing;
+        repaint.update(status, false, true);
+        assert!(repaint.take_status_only());
+        assert!(!repaint.take_status_only());
+
+        // Any other paint request wins, whatever the order.
+        let mut repaint = Repaint::Nothing;
+        repaint.update(status, false, true);
+        repaint.update(Event::RequestPaint, false, false);
+        assert!(!repaint.take_status_only());
+
+        let mut repaint = Repaint::Full;
+        repaint.update(status, false, true);
+        assert!(!repaint.take_status_only());
+    }
+}
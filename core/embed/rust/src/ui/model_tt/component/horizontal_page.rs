@@ -0,0 +1,252 @@
+use crate::{
+    time::{Duration, Instant},
+    ui::{
+        animation::{Easing, Timeline},
+        component::{Component, Event, EventCtx, Pad, Paginate},
+        display::Color,
+        event::{Gesture, GestureRecognizer, GestureThresholds, SwipeDirection},
+        geometry::{Offset, Rect},
+        util::animation_disabled,
+    },
+};
+
+use super::{ScrollBar, ScrollBarMsg};
+
+/// Paginated content switching the pages with horizontal swipes. The new page
+/// slides in from the side, a row of dots below the content shows the active
+/// page.
+pub struct HorizontalSwipePage<T> {
+    content: T,
+    /// Area of the content when it is not sliding.
+    content_area: Rect,
+    pad: Pad,
+    scrollbar: ScrollBar,
+    gestures: GestureRecognizer,
+    /// Horizontal offset of the content while the page is sliding in.
+    slide: Option<Timeline<i16>>,
+}
+
+impl<T> HorizontalSwipePage<T>
+where
+    T: Paginate,
+    T: Component,
+{
+    const SCROLLBAR_HEIGHT: i16 = 18;
+    const SLIDE_DURATION: Duration = Duration::from_millis(200);
+
+    pub fn new(content: T, background: Color) -> Self {
+        Self {
+            content,
+            content_area: Rect::zero(),
+            pad: Pad::with_background(background),
+            scrollbar: ScrollBar::horizontal(),
+            gestures: GestureRecognizer::new(GestureThresholds::DEFAULT),
+            slide: None,
+        }
+    }
+
+    pub fn active_page(&self) -> usize {
+        self.scrollbar.active_page
+    }
+
+    pub fn page_count(&self) -> usize {
+        self.scrollbar.page_count
+    }
+
+    fn change_page(&mut self, ctx: &mut EventCtx, direction: SwipeDirection) {
+        let from = match direction {
+            SwipeDirection::Left if self.scrollbar.has_next_page() => {
+                self.scrollbar.go_to_next_page();
+                self.content_area.width()
+            }
+            SwipeDirection::Right if self.scrollbar.has_previous_page() => {
+                self.scrollbar.go_to_previous_page();
+                -self.content_area.width()
+            }
+            _ => return,
+        };
+        self.content.change_page(self.scrollbar.active_page);
+        if !animation_disabled() {
+            self.slide = Some(
+                Timeline::new(from, 0, Self::SLIDE_DURATION, Instant::now())
+                    .with_easing(Easing::EaseOut),
+            );
+            self.content
+                .place(self.content_area.translate(Offset::x(from)));
+            ctx.request_anim_frame();
+        }
+        self.pad.clear();
+        ctx.request_paint();
+    }
+
+    fn jump_to_page(&mut self, ctx: &mut EventCtx, page: usize) {
+        self.scrollbar.go_to(page);
+        self.content.change_page(page);
+        self.slide = None;
+        self.content.place(self.content_area);
+        self.pad.clear();
+        ctx.request_paint();
+    }
+
+    fn animate(&mut self, ctx: &mut EventCtx, event: Event) {
+        let offset = match self
+            .slide
+            .as_mut()
+            .and_then(|slide| slide.event(ctx, event))
+        {
+            Some(offset) => offset,
+            None => return,
+        };
+        if offset == 0 {
+            self.slide = None;
+        }
+        self.content
+            .place(self.content_area.translate(Offset::x(offset)));
+        self.pad.clear();
+        ctx.request_paint();
+    }
+}
+
+impl<T> Component for HorizontalSwipePage<T>
+where
+    T: Paginate,
+    T: Component,
+{
+    type Msg = T::Msg;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.pad.place(bounds);
+        self.slide = None;
+
+        // Try to fit the content on a single page first, and make space for the
+        // dots if it doesn't fit.
+        self.content_area = bounds;
+        self.content.place(bounds);
+        let mut page_count = self.content.page_count();
+        if page_count > 1 {
+            let (content_area, scrollbar_area) = bounds.split_bottom(Self::SCROLLBAR_HEIGHT);
+            self.content_area = content_area;
+            self.content.place(content_area);
+            page_count = self.content.page_count();
+            self.scrollbar.place(scrollbar_area);
+        }
+        let active_page = self.scrollbar.set_count_keep_active_page(page_count);
+        self.content.change_page(active_page);
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        ctx.set_page_count(self.scrollbar.page_count);
+        let was_dragging = self.scrollbar.is_dragging();
+        if let Some(ScrollBarMsg::JumpTo(page)) = self.scrollbar.event(ctx, event) {
+            self.jump_to_page(ctx, page);
+            return None;
+        }
+        if was_dragging || self.scrollbar.is_dragging() {
+            return None;
+        }
+        match event {
+            Event::Timer(EventCtx::ANIM_FRAME_TIMER) => self.animate(ctx, event),
+            Event::Touch(touch) if self.scrollbar.has_pages() => {
+                if let Some(Gesture::Swipe(direction)) = self.gestures.touch(touch, Instant::now())
+                {
+                    self.change_page(ctx, direction);
+                    return None;
+                }
+            }
+            _ => {}
+        }
+        self.content.event(ctx, event)
+    }
+
+    fn paint(&mut self) {
+        self.pad.paint();
+        self.content.paint();
+        if self.scrollbar.has_pages() {
+            self.scrollbar.paint();
+        }
+    }
+
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        sink(self.pad.area);
+        self.scrollbar.bounds(sink);
+        self.content.bounds(sink);
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl<T> crate::trace::Trace for HorizontalSwipePage<T>
+where
+    T: crate::trace::Trace,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.open("HorizontalSwipePage");
+        t.field("active_page", &self.scrollbar.active_page);
+        t.field("page_count", &self.scrollbar.page_count);
+        t.field("content", &self.content);
+        t.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ui::{
+        component::text::paragraphs::{Paragraph, Paragraphs},
+        event::TouchEvent,
+        geometry::Point,
+        model_tt::{constant, theme},
+    };
+
+    use super::*;
+
+    const SCREEN: Rect = constant::screen().inset(theme::borders());
+
+    fn swipe(component: &mut impl Component, from: (i16, i16), to: (i16, i16)) {
+        let mut ctx = EventCtx::new();
+        for event in [
+            TouchEvent::TouchStart(Point::new(from.0, from.1)),
+            TouchEvent::TouchEnd(Point::new(to.0, to.1)),
+        ] {
+            component.event(&mut ctx, Event::Touch(event));
+            ctx.clear();
+        }
+    }
+
+    #[test]
+    fn horizontal_swipe_page() {
+        let mut page = HorizontalSwipePage::new(
+            Paragraphs::new([
+                Paragraph::new(&theme::TEXT_NORMAL, "Short one.").break_after(),
+                Paragraph::new(&theme::TEXT_NORMAL, "Short two.").break_after(),
+                Paragraph::new(&theme::TEXT_NORMAL, "Short three."),
+            ]),
+            theme::BG,
+        );
+        page.place(SCREEN);
+        assert_eq!(page.page_count(), 3);
+        assert_eq!(page.active_page(), 0);
+
+        // previous page of the first one does not exist
+        swipe(&mut page, (20, 100), (180, 100));
+        assert_eq!(page.active_page(), 0);
+
+        swipe(&mut page, (180, 100), (20, 100));
+        assert_eq!(page.active_page(), 1);
+        swipe(&mut page, (180, 100), (20, 100));
+        assert_eq!(page.active_page(), 2);
+        swipe(&mut page, (180, 100), (20, 100));
+        assert_eq!(page.active_page(), 2);
+
+        // vertical swipes do not change the page
+        swipe(&mut page, (100, 20), (100, 180));
+        assert_eq!(page.active_page(), 2);
+
+        swipe(&mut page, (20, 100), (180, 100));
+        assert_eq!(page.active_page(), 1);
+
+        // laying out again, e.g. after an overlay was closed, stays on the page
+        page.place(SCREEN);
+        assert_eq!(page.active_page(), 1);
+        assert_eq!(page.content.current_page(), 1);
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{
+    time::{Duration, Instant},
+    ui::{
+        component::{Component, Event, EventCtx, Never},
+        display::{self, Color, LoaderGeometry, LOADER_MAX},
+        geometry::Rect,
+        model_tt::constant,
+        util::animation_disabled,
+    },
+};
+
+use super::theme;
+
+/// Loader of operations with unknown duration, the segments of
+/// `display::loader_indeterminate` going around once per `PERIOD` in `FRAMES`
+/// steps. Animated by the animation frames from the attach on, the position is
+/// derived from the time so that irregular frames only make it skip, and it is
+/// only repainted when it moves to the next step.
+pub struct IndeterminateLoader {
+    area: Rect,
+    geometry: LoaderGeometry,
+    fg_color: Color,
+    bg_color: Color,
+    start: Option<Instant>,
+    progress: u16,
+}
+
+impl IndeterminateLoader {
+    const PERIOD: Duration = Duration::from_millis(1500);
+    const FRAMES: u32 = 60;
+
+    pub const fn new() -> Self {
+        Self {
+            area: Rect::zero(),
+            geometry: theme::LOADER,
+            fg_color: theme::FG,
+            bg_color: theme::BG,
+            start: None,
+            progress: 0,
+        }
+    }
+
+    pub const fn with_colors(self, fg_color: Color, bg_color: Color) -> Self {
+        Self {
+            fg_color,
+            bg_color,
+            ..self
+        }
+    }
+
+    pub fn progress(&self) -> u16 {
+        self.progress
+    }
+}
+
+/// Position of the loader `elapsed` after the start, rounded down to one of
+/// `frames` steps of the period.
+fn progress_at(elapsed: Duration, period: Duration, frames: u32) -> u16 {
+    let period = period.to_millis().max(1);
+    let phase = elapsed.to_millis() % period;
+    let frame = phase * frames / period;
+    (frame * LOADER_MAX as u32 / frames) as u16
+}
+
+impl Component for IndeterminateLoader {
+    type Msg = Never;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.area = bounds;
+        self.geometry = self
+            .geometry
+            .with_y_offset(bounds.center().y - constant::screen().center().y);
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        match event {
+            Event::Attach => {
+                self.start = Some(Instant::now());
+                self.progress = 0;
+                if !animation_disabled() {
+                    ctx.request_anim_frame();
+                }
+                ctx.request_paint();
+            }
+            Event::Timer(EventCtx::ANIM_FRAME_TIMER) => {
+                if let Some(start) = self.start {
+                    let elapsed = Instant::now().saturating_duration_since(start);
+                    let progress = progress_at(elapsed, Self::PERIOD, Self::FRAMES);
+                    ctx.request_anim_frame();
+                    if progress != self.progress {
+                        self.progress = progress;
+                        ctx.request_paint();
+                    }
+                }
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn paint(&mut self) {
+        display::loader_indeterminate(
+            self.progress,
+            self.geometry,
+            self.fg_color,
+            self.bg_color,
+            None,
+        );
+    }
+
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        sink(self.area)
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl crate::trace::Trace for IndeterminateLoader {
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.open("IndeterminateLoader");
+        t.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn indeterminate_progress() {
+        let period = Duration::from_millis(1000);
+        let at = |millis| progress_at(Duration::from_millis(millis), period, 100);
+        assert_eq!(at(0), 0);
+        assert_eq!(at(250), 250);
+        assert_eq!(at(1250), 250);
+        assert_eq!(at(999), 990);
+    }
+
+    #[test]
+    fn indeterminate_progress_steps() {
+        let period = Duration::from_millis(1000);
+        let at = |millis| progress_at(Duration::from_millis(millis), period, 4);
+        // Positions within a step are the same, not painted again.
+        assert_eq!(at(0), 0);
+        assert_eq!(at(249), 0);
+        assert_eq!(at(250), 250);
+        assert_eq!(at(600), 500);
+        assert_eq!(at(999), 750);
+    }
+}
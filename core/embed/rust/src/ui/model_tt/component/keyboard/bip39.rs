@@ -149,6 +149,15 @@ impl Component for Bip39Input {
     }
 }
 
+#[cfg(feature = "ui_debug")]
+impl crate::trace::Trace for Bip39Input {
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.open("Bip39Input");
+        t.field("content", &self.textbox.content());
+        t.close();
+    }
+}
+
 impl Bip39Input {
     pub fn new() -> Self {
         Self {
@@ -6,6 +6,7 @@ use crate::{
         component::{Event, EventCtx, TimerToken},
         display::{self, Color, Font},
         geometry::{Offset, Point, Rect},
+        grapheme,
         util::ResultExt,
     },
 };
@@ -159,10 +160,18 @@ impl<const L: usize> TextBox<L> {
         self.text.len() == self.text.capacity()
     }
 
+    /// Length of the content without its last grapheme cluster, so that
+    /// combining marks are deleted together with their base character.
+    fn len_without_last(&self) -> usize {
+        let last = grapheme::graphemes(&self.text).next_back();
+        self.text.len() - last.map_or(0, str::len)
+    }
+
     /// Delete the last character of content, if any.
     pub fn delete_last(&mut self, ctx: &mut EventCtx) {
-        let changed = self.text.pop().is_some();
+        let changed = !self.text.is_empty();
         if changed {
+            self.text.truncate(self.len_without_last());
             ctx.request_paint();
         }
     }
@@ -170,11 +179,13 @@ impl<const L: usize> TextBox<L> {
     /// Replaces the last character of the content with `ch`. If the content is
     /// empty, `ch` is appended.
     pub fn replace_last(&mut self, ctx: &mut EventCtx, ch: char) {
-        let previous = self.text.pop();
+        let len = self.len_without_last();
+        let mut buf = [0; 4];
+        let changed = self.text[len..] != *ch.encode_utf8(&mut buf);
+        self.text.truncate(len);
         self.text
             .push(ch)
             .assert_if_debugging_ui("TextBox has zero capacity");
-        let changed = previous != Some(ch);
         if changed {
             ctx.request_paint();
         }
@@ -213,15 +224,23 @@ impl<const L: usize> TextBox<L> {
 
 /// Create a visible "underscoring" of the last letter of a text.
 pub fn paint_pending_marker(text_baseline: Point, text: &str, font: Font, color: Color) {
-    // Measure the width of the last character of input.
-    if let Some(last) = text.chars().last() {
-        let width = font.text_width(text);
-        let last_width = font.char_width(last);
-        // Draw the marker 2px under the start of the baseline of the last character.
-        let marker_origin = text_baseline + Offset::new(width - last_width, 2);
-        // Draw the marker 1px longer than the last character, and 3px thick.
-        let marker_rect =
-            Rect::from_top_left_and_size(marker_origin, Offset::new(last_width + 1, 3));
+    if let Some(marker_rect) = pending_marker(text_baseline, text, font) {
         display::rect_fill(marker_rect, color);
     }
 }
+
+/// Area of the marker painted by `paint_pending_marker`, `None` for empty
+/// `text`.
+pub fn pending_marker(text_baseline: Point, text: &str, font: Font) -> Option<Rect> {
+    // Measure the width of the last character of input.
+    let last = text.chars().last()?;
+    let width = font.text_width(text);
+    let last_width = font.char_width(last);
+    // Draw the marker 2px under the start of the baseline of the last character.
+    let marker_origin = text_baseline + Offset::new(width - last_width, 2);
+    // Draw the marker 1px longer than the last character, and 3px thick.
+    Some(Rect::from_top_left_and_size(
+        marker_origin,
+        Offset::new(last_width + 1, 3),
+    ))
+}
@@ -186,9 +186,14 @@ pub enum MnemonicInputMsg {
 }
 
 #[cfg(feature = "ui_debug")]
-impl<T, U> crate::trace::Trace for MnemonicKeyboard<T, U> {
+impl<T, U> crate::trace::Trace for MnemonicKeyboard<T, U>
+where
+    T: crate::trace::Trace,
+{
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.open("MnemonicKeyboard");
+        // The typed letters give away the seed word.
+        t.field("input", &crate::trace::Secret(self.input.inner().inner()));
         t.close();
     }
 }
@@ -1,12 +1,11 @@
 use crate::ui::{
-    component::{base::ComponentExt, Child, Component, Event, EventCtx, Never},
-    display,
-    display::toif::Icon,
+    component::{base::ComponentExt, BacklightFade, Child, Component, Event, EventCtx, Never},
+    display::{self, framebuffer, toif::Icon},
     geometry::{Grid, Insets, Offset, Rect},
     model_tt::component::{
         button::{Button, ButtonContent, ButtonMsg},
         keyboard::common::{
-            paint_pending_marker, MultiTapKeyboard, TextBox, HEADER_HEIGHT, HEADER_PADDING_BOTTOM,
+            pending_marker, MultiTapKeyboard, TextBox, HEADER_HEIGHT, HEADER_PADDING_BOTTOM,
             HEADER_PADDING_SIDE,
         },
         swipe::{Swipe, SwipeDirection},
@@ -26,7 +25,7 @@ pub struct PassphraseKeyboard {
     confirm: Child<Button<&'static str>>,
     keys: [Child<Button<&'static str>>; KEY_COUNT],
     scrollbar: ScrollBar,
-    fade: bool,
+    fade: BacklightFade,
 }
 
 const STARTING_PAGE: usize = 1;
@@ -62,7 +61,7 @@ impl PassphraseKeyboard {
             keys: KEYBOARD[STARTING_PAGE]
                 .map(|text| Child::new(Button::new(Self::key_content(text)))),
             scrollbar: ScrollBar::horizontal(),
-            fade: false,
+            fade: BacklightFade::new(),
         }
     }
 
@@ -96,8 +95,8 @@ impl PassphraseKeyboard {
             .mutate(ctx, |ctx, i| i.multi_tap.clear_pending_state(ctx));
         // Update buttons.
         self.replace_button_content(ctx, key_page);
-        // Reset backlight to normal level on next paint.
-        self.fade = true;
+        // Reset backlight to normal level after the next paint.
+        self.fade.start(ctx, theme::BACKLIGHT_NORMAL);
         // So that swipe does not visually enable the input buttons when max length
         // reached
         self.update_input_btns_state(ctx);
@@ -188,6 +187,7 @@ impl Component for PassphraseKeyboard {
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        self.fade.event(ctx, event);
         if self.input.inner().multi_tap.is_timeout_event(event) {
             self.input
                 .mutate(ctx, |ctx, i| i.multi_tap.clear_pending_state(ctx));
@@ -258,11 +258,6 @@ impl Component for PassphraseKeyboard {
         for btn in &mut self.keys {
             btn.paint();
         }
-        if self.fade {
-            self.fade = false;
-            // Note that this is blocking and takes some time.
-            display::fade_backlight(theme::BACKLIGHT_NORMAL);
-        }
     }
 
     fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
@@ -307,65 +302,76 @@ impl Component for Input {
     fn paint(&mut self) {
         let style = theme::label_keyboard();
 
-        let mut text_baseline = self.area.top_left() + Offset::y(style.text_font.text_height())
+        let text_baseline = self.area.top_left() + Offset::y(style.text_font.text_height())
             - Offset::y(style.text_font.text_baseline());
 
         let text = self.textbox.content();
 
-        // Preparing the new text to be displayed.
-        // Possible optimization is to redraw the background only when pending character
-        // is replaced, or only draw rectangle over the pending character and
-        // marker.
-        display::rect_fill(self.area, theme::BG);
-
         // Find out how much text can fit into the textbox.
         // Accounting for the pending marker, which draws itself one pixel longer than
         // the last character
         let available_area_width = self.area.width() - 1;
-        let text_to_display = if style.text_font.text_width(text) <= available_area_width {
-            text // whole text can fit
+        let ellipsis = "...";
+        let (prefix, text_to_display) = if style.text_font.text_width(text) <= available_area_width
+        {
+            ("", text) // whole text can fit
         } else {
             // Text is longer, showing its right end with ellipsis at the beginning.
-            let ellipsis = "...";
-            let ellipsis_width = style.text_font.text_width(ellipsis);
-
-            // Drawing the ellipsis and moving the baseline for the rest of the text.
-            display::text(
-                text_baseline,
-                ellipsis,
-                style.text_font,
-                style.text_color,
-                style.background_color,
-            );
-            text_baseline = text_baseline + Offset::x(ellipsis_width);
-
             // Finding out how many additional text characters will fit in,
             // starting from the right end.
-            let remaining_available_width = available_area_width - ellipsis_width;
-            let chars_from_right = style
+            let remaining_available_width =
+                available_area_width - style.text_font.text_width(ellipsis);
+            let suffix_len = style
                 .text_font
                 .longest_suffix(remaining_available_width, text);
-
-            &text[text.len() - chars_from_right..]
+            (ellipsis, &text[text.len() - suffix_len..])
         };
+        let text_baseline_rest = text_baseline + Offset::x(style.text_font.text_width(prefix));
+        let marker = if self.multi_tap.pending_key().is_some() {
+            pending_marker(text_baseline_rest, text_to_display, style.text_font)
+        } else {
+            None
+        };
+
+        // The new text is drawn off-screen, so that the line does not flicker
+        // on every key press.
+        let rendered = framebuffer::render(self.area, theme::BG, |fb| {
+            for (baseline, text) in [
+                (text_baseline, prefix),
+                (text_baseline_rest, text_to_display),
+            ] {
+                fb.text(
+                    baseline,
+                    text,
+                    style.text_font,
+                    style.text_color,
+                    style.background_color,
+                );
+            }
+            if let Some(marker) = marker {
+                fb.rect_fill(marker, style.text_color);
+            }
+        });
+        if rendered {
+            return;
+        }
 
-        display::text(
-            text_baseline,
-            text_to_display,
-            style.text_font,
-            style.text_color,
-            style.background_color,
-        );
-
-        // Paint the pending marker.
-        if self.multi_tap.pending_key().is_some() {
-            paint_pending_marker(
-                text_baseline,
-                text_to_display,
+        display::rect_fill(self.area, theme::BG);
+        for (baseline, text) in [
+            (text_baseline, prefix),
+            (text_baseline_rest, text_to_display),
+        ] {
+            display::text(
+                baseline,
+                text,
                 style.text_font,
                 style.text_color,
+                style.background_color,
             );
         }
+        if let Some(marker) = marker {
+            display::rect_fill(marker, style.text_color);
+        }
     }
 
     fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
@@ -377,6 +383,7 @@ impl Component for Input {
 impl crate::trace::Trace for PassphraseKeyboard {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.open("PassphraseKeyboard");
+        t.field("passphrase", &crate::trace::Secret(&self.passphrase()));
         t.close();
     }
 }
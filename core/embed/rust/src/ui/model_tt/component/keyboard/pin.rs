@@ -2,7 +2,7 @@ use core::mem;
 use heapless::String;
 
 use crate::{
-    time::Duration,
+    time::{Duration, Instant},
     trezorhal::random,
     ui::{
         component::{
@@ -29,6 +29,12 @@ const MAX_VISIBLE_DOTS: usize = 14;
 const MAX_VISIBLE_DIGITS: usize = 16;
 const DIGIT_COUNT: usize = 10; // 0..10
 
+/// How long the digit just typed is shown in place of its dot.
+const LAST_DIGIT_TIMEOUT: Duration = Duration::from_secs(1);
+/// Period of the frames the keypad feedback is painted on, counted from when
+/// the keyboard was attached.
+const FRAME_PERIOD: Duration = Duration::from_millis(40);
+
 const HEADER_HEIGHT: i16 = 25;
 const HEADER_PADDING_SIDE: i16 = 5;
 const HEADER_PADDING_BOTTOM: i16 = 12;
@@ -52,6 +58,10 @@ pub struct PinKeyboard<T> {
     confirm_btn: Child<Button<&'static str>>,
     digit_btns: [Child<Button<&'static str>>; DIGIT_COUNT],
     warning_timer: Option<TimerToken>,
+    frame_start: Option<Instant>,
+    frame_timer: Option<TimerToken>,
+    /// Changes are painted only once the next frame is due.
+    frame_due: bool,
 }
 
 impl<T> PinKeyboard<T>
@@ -102,6 +112,9 @@ where
                 .into_child(),
             digit_btns: Self::generate_digit_buttons(),
             warning_timer: None,
+            frame_start: None,
+            frame_timer: None,
+            frame_due: true,
         }
     }
 
@@ -144,9 +157,105 @@ where
             .mutate(ctx, |ctx, btn| btn.enable_if(ctx, !is_empty));
     }
 
+    /// Repaint every digit button when any of them is going to be painted,
+    /// e.g. to highlight a pressed key. The painted area and the duration of
+    /// the paint are then the same whichever key was touched, so that they do
+    /// not reveal its position through emanations of the display.
+    fn repaint_all_digits_if_any(&mut self, ctx: &mut EventCtx) {
+        if self.digit_btns.iter().any(|btn| btn.will_paint()) {
+            for btn in &mut self.digit_btns {
+                btn.request_complete_repaint(ctx);
+            }
+        }
+    }
+
+    fn will_paint(&self) -> bool {
+        self.textbox_pad.will_paint().is_some()
+            || self.textbox.will_paint()
+            || self.major_prompt.will_paint()
+            || self.minor_prompt.will_paint()
+            || self.major_warning.as_ref().map_or(false, Child::will_paint)
+            || self.erase_btn.will_paint()
+            || self.cancel_btn.will_paint()
+            || self.confirm_btn.will_paint()
+            || self.digit_btns.iter().any(Child::will_paint)
+    }
+
+    /// Postpone the paint of any change to the next frame of the schedule, so
+    /// that the time the feedback appears at depends neither on the key nor on
+    /// how long the handling of the touch took.
+    fn schedule_frame(&mut self, ctx: &mut EventCtx) {
+        if self.frame_due || self.frame_timer.is_some() || !self.will_paint() {
+            return;
+        }
+        let elapsed = self.frame_start.map_or(Duration::from_millis(0), |start| {
+            Instant::now().saturating_duration_since(start)
+        });
+        self.frame_timer = Some(ctx.request_timer(next_frame_delay(elapsed)));
+    }
+
     pub fn pin(&self) -> &str {
         self.textbox.inner().pin()
     }
+
+    fn handle_event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<PinKeyboardMsg> {
+        match event {
+            // Set up timer to switch off warning prompt.
+            Event::Attach if self.major_warning.is_some() => {
+                self.warning_timer = Some(ctx.request_timer(Duration::from_secs(2)));
+            }
+            // Hide warning, show major prompt.
+            Event::Timer(token) if Some(token) == self.warning_timer => {
+                self.major_warning = None;
+                self.textbox_pad.clear();
+                self.minor_prompt.request_complete_repaint(ctx);
+                ctx.request_paint();
+            }
+            _ => {}
+        }
+
+        self.textbox.event(ctx, event);
+        if let Some(Clicked) = self.confirm_btn.event(ctx, event) {
+            return Some(PinKeyboardMsg::Confirmed);
+        }
+        if let Some(Clicked) = self.cancel_btn.event(ctx, event) {
+            return Some(PinKeyboardMsg::Cancelled);
+        }
+        match self.erase_btn.event(ctx, event) {
+            Some(ButtonMsg::Clicked) => {
+                self.textbox.mutate(ctx, |ctx, t| t.pop(ctx));
+                self.pin_modified(ctx);
+                return None;
+            }
+            Some(ButtonMsg::LongPressed) => {
+                self.textbox.mutate(ctx, |ctx, t| t.clear(ctx));
+                self.pin_modified(ctx);
+                return None;
+            }
+            _ => {}
+        }
+        let mut pushed = None;
+        for btn in &mut self.digit_btns {
+            if let Some(Clicked) = btn.event(ctx, event) {
+                if let ButtonContent::Text(text) = btn.inner().content() {
+                    pushed = Some(*text);
+                    break;
+                }
+            }
+        }
+        if let Some(text) = pushed {
+            self.textbox.mutate(ctx, |ctx, t| t.push(ctx, text));
+            self.pin_modified(ctx);
+        }
+        self.repaint_all_digits_if_any(ctx);
+        None
+    }
+}
+
+/// Time from `elapsed` since the start of the frame schedule to the next frame.
+fn next_frame_delay(elapsed: Duration) -> Duration {
+    let period = FRAME_PERIOD.to_millis();
+    Duration::from_millis(period - elapsed.to_millis() % period)
 }
 
 impl<T> Component for PinKeyboard<T>
@@ -204,53 +313,29 @@ where
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
         match event {
-            // Set up timer to switch off warning prompt.
-            Event::Attach if self.major_warning.is_some() => {
-                self.warning_timer = Some(ctx.request_timer(Duration::from_secs(2)));
+            Event::Attach => {
+                self.frame_start = Some(Instant::now());
+                self.frame_due = true;
             }
-            // Hide warning, show major prompt.
-            Event::Timer(token) if Some(token) == self.warning_timer => {
-                self.major_warning = None;
-                self.textbox_pad.clear();
-                self.minor_prompt.request_complete_repaint(ctx);
+            Event::RequestPaint => self.frame_due = true,
+            Event::Timer(token) if Some(token) == self.frame_timer => {
+                self.frame_timer = None;
+                self.frame_due = true;
                 ctx.request_paint();
-            }
-            _ => {}
-        }
-
-        self.textbox.event(ctx, event);
-        if let Some(Clicked) = self.confirm_btn.event(ctx, event) {
-            return Some(PinKeyboardMsg::Confirmed);
-        }
-        if let Some(Clicked) = self.cancel_btn.event(ctx, event) {
-            return Some(PinKeyboardMsg::Cancelled);
-        }
-        match self.erase_btn.event(ctx, event) {
-            Some(ButtonMsg::Clicked) => {
-                self.textbox.mutate(ctx, |ctx, t| t.pop(ctx));
-                self.pin_modified(ctx);
-                return None;
-            }
-            Some(ButtonMsg::LongPressed) => {
-                self.textbox.mutate(ctx, |ctx, t| t.clear(ctx));
-                self.pin_modified(ctx);
                 return None;
             }
             _ => {}
         }
-        for btn in &mut self.digit_btns {
-            if let Some(Clicked) = btn.event(ctx, event) {
-                if let ButtonContent::Text(text) = btn.inner().content() {
-                    self.textbox.mutate(ctx, |ctx, t| t.push(ctx, text));
-                    self.pin_modified(ctx);
-                    return None;
-                }
-            }
-        }
-        None
+        let msg = self.handle_event(ctx, event);
+        self.schedule_frame(ctx);
+        msg
     }
 
     fn paint(&mut self) {
+        // Between the frames the changes are kept for the next one.
+        if !mem::replace(&mut self.frame_due, false) {
+            return;
+        }
         self.erase_btn.paint();
         self.textbox_pad.paint();
         if self.textbox.inner().is_empty() {
@@ -289,6 +374,9 @@ struct PinDots {
     style: TextStyle,
     digits: String<MAX_LENGTH>,
     display_digits: bool,
+    /// Show the last digit instead of its dot until `last_digit_timer` fires.
+    display_last_digit: bool,
+    last_digit_timer: Option<TimerToken>,
 }
 
 impl PinDots {
@@ -303,6 +391,8 @@ impl PinDots {
             style,
             digits: String::new(),
             display_digits: false,
+            display_last_digit: false,
+            last_digit_timer: None,
         }
     }
 
@@ -323,6 +413,7 @@ impl PinDots {
 
     fn clear(&mut self, ctx: &mut EventCtx) {
         self.digits.clear();
+        self.hide_last_digit();
         ctx.request_paint()
     }
 
@@ -331,15 +422,23 @@ impl PinDots {
             // `self.pin` is full and wasn't able to accept all of
             // `text`. Should not happen.
         };
+        self.display_last_digit = true;
+        self.last_digit_timer = Some(ctx.request_timer(LAST_DIGIT_TIMEOUT));
         ctx.request_paint()
     }
 
     fn pop(&mut self, ctx: &mut EventCtx) {
         if self.digits.pop().is_some() {
+            self.hide_last_digit();
             ctx.request_paint()
         }
     }
 
+    fn hide_last_digit(&mut self) {
+        self.display_last_digit = false;
+        self.last_digit_timer = None;
+    }
+
     fn pin(&self) -> &str {
         &self.digits
     }
@@ -401,14 +500,38 @@ impl PinDots {
             );
         }
 
-        // Draw a dot for each PIN digit.
-        for _ in 0..dots_visible {
-            Icon::new(theme::DOT_ACTIVE).draw(
-                cursor,
-                TOP_LEFT,
-                self.style.text_color,
-                self.style.background_color,
-            );
+        // Draw a dot for each PIN digit, the last one may be shown as the digit itself.
+        for i in 0..dots_visible {
+            let last_digit = match self.digits.get(digits - 1..) {
+                Some(digit) if self.display_last_digit && i == dots_visible - 1 => Some(digit),
+                _ => None,
+            };
+            if let Some(digit) = last_digit {
+                // Clear a cell of the same size for any digit, monospace digits have the
+                // same advance, so that the painted area does not depend on the value.
+                let baseline =
+                    cursor + Offset::new(Self::DOT / 2, (Self::DOT + Font::MONO.text_height()) / 2);
+                let cell = Offset::new(Font::MONO.text_width("0"), Font::MONO.text_max_height());
+                let top_left = baseline - Offset::new(cell.x / 2, Font::MONO.ascent());
+                display::rect_fill(
+                    Rect::from_top_left_and_size(top_left, cell),
+                    self.style.background_color,
+                );
+                display::text_center(
+                    baseline,
+                    digit,
+                    Font::MONO,
+                    self.style.text_color,
+                    self.style.background_color,
+                );
+            } else {
+                Icon::new(theme::DOT_ACTIVE).draw(
+                    cursor,
+                    TOP_LEFT,
+                    self.style.text_color,
+                    self.style.background_color,
+                );
+            }
             cursor.x += step;
         }
     }
@@ -433,13 +556,19 @@ impl Component for PinDots {
                 };
                 None
             }
-            Event::Touch(TouchEvent::TouchEnd(_)) => {
+            Event::Touch(TouchEvent::TouchEnd(_) | TouchEvent::TouchCancel) => {
                 if mem::replace(&mut self.display_digits, false) {
                     self.pad.clear();
                     ctx.request_paint();
                 };
                 None
             }
+            Event::Timer(token) if Some(token) == self.last_digit_timer => {
+                self.hide_last_digit();
+                self.pad.clear();
+                ctx.request_paint();
+                None
+            }
             _ => None,
         }
     }
@@ -467,6 +596,86 @@ where
 {
     fn trace(&self, t: &mut dyn crate::trace::Tracer) {
         t.open("PinKeyboard");
+        t.field("pin", &crate::trace::Secret(&self.pin()));
         t.close();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        time::{advance_clock, ManualClock},
+        ui::{constant::screen, event::TouchEvent},
+    };
+
+    use super::*;
+
+    #[test]
+    fn frame_schedule() {
+        let at = |millis| next_frame_delay(Duration::from_millis(millis));
+        assert_eq!(at(0), FRAME_PERIOD);
+        assert_eq!(at(15), Duration::from_millis(25));
+        assert_eq!(at(39), Duration::from_millis(1));
+        assert_eq!(at(40), FRAME_PERIOD);
+        assert_eq!(at(1055), Duration::from_millis(25));
+    }
+
+    #[test]
+    fn feedback_on_frame() {
+        let _clock = ManualClock::start();
+        let mut keyboard = PinKeyboard::new("Enter PIN", "", None, true);
+        keyboard.place(screen());
+        let mut ctx = EventCtx::new();
+        keyboard.event(&mut ctx, Event::Attach);
+        // The first frame is painted right away.
+        assert!(keyboard.frame_due);
+        keyboard.frame_due = false;
+
+        advance_clock(Duration::from_millis(15));
+        ctx.clear();
+        let key = keyboard.digit_btns[0].inner().area().center();
+        keyboard.event(&mut ctx, Event::Touch(TouchEvent::TouchStart(key)));
+        // The highlight of the key waits for the next frame.
+        assert!(!keyboard.frame_due);
+        let (token, delay) = unwrap!(ctx.pop_timer());
+        assert_eq!(delay, Duration::from_millis(25));
+
+        // Further touches before the frame do not move it.
+        keyboard.event(&mut ctx, Event::Touch(TouchEvent::TouchEnd(key)));
+        assert!(keyboard.frame_timer == Some(token));
+        assert_eq!(keyboard.pin().len(), 1);
+
+        ctx.clear();
+        keyboard.event(&mut ctx, Event::Timer(token));
+        assert!(keyboard.frame_due);
+        assert!(ctx.paint_requested());
+    }
+
+    #[test]
+    fn last_digit_reveal() {
+        let mut dots = PinDots::new(theme::label_default());
+        let mut ctx = EventCtx::new();
+        dots.push(&mut ctx, "1");
+        assert!(dots.display_last_digit);
+        let (first, delay) = unwrap!(ctx.pop_timer());
+        assert_eq!(delay, LAST_DIGIT_TIMEOUT);
+
+        // The timer of a digit typed before does not mask the next one.
+        dots.push(&mut ctx, "2");
+        let (second, _) = unwrap!(ctx.pop_timer());
+        dots.event(&mut ctx, Event::Timer(first));
+        assert!(dots.display_last_digit);
+
+        // Masked again when its own timer fires.
+        ctx.clear();
+        dots.event(&mut ctx, Event::Timer(second));
+        assert!(!dots.display_last_digit);
+        assert!(ctx.paint_requested());
+
+        // Erasing masks the digit right away.
+        dots.push(&mut ctx, "3");
+        dots.pop(&mut ctx);
+        assert!(!dots.display_last_digit);
+        assert_eq!(dots.pin(), "12");
+    }
+}
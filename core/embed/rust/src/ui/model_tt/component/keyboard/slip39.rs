@@ -183,6 +183,15 @@ impl Component for Slip39Input {
     }
 }
 
+#[cfg(feature = "ui_debug")]
+impl crate::trace::Trace for Slip39Input {
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.open("Slip39Input");
+        t.field("content", &self.textbox.content());
+        t.close();
+    }
+}
+
 impl Slip39Input {
     pub fn new() -> Self {
         Self {
@@ -0,0 +1,249 @@
+use crate::ui::{
+    component::{Component, Event, EventCtx},
+    display::{self, toif::Icon, Color, Font},
+    event::TouchEvent,
+    geometry::{Point, Rect, CENTER},
+};
+
+use super::theme;
+
+pub enum ListItemMsg {
+    Clicked,
+}
+
+/// Row of a list or menu: primary text with optional dimmed secondary text
+/// below it, optional leading icon and trailing chevron. Items with a chevron
+/// lead somewhere and are clickable, others only display information. Disabled
+/// items are dimmed and cannot be clicked.
+pub struct ListItem<T> {
+    area: Rect,
+    primary: T,
+    secondary: Option<T>,
+    icon: Option<Icon>,
+    chevron: bool,
+    enabled: bool,
+    pressed: bool,
+}
+
+impl<T> ListItem<T>
+where
+    T: AsRef<str>,
+{
+    /// Height of a row with secondary text, single-line rows can be placed
+    /// into `HEIGHT_SINGLE`.
+    pub const HEIGHT: i16 = 52;
+    pub const HEIGHT_SINGLE: i16 = 32;
+    const ICON_SPACING: i16 = 8;
+    const LINE_SPACING: i16 = 4;
+
+    pub fn new(primary: T) -> Self {
+        Self {
+            area: Rect::zero(),
+            primary,
+            secondary: None,
+            icon: None,
+            chevron: false,
+            enabled: true,
+            pressed: false,
+        }
+    }
+
+    pub fn with_secondary(mut self, secondary: T) -> Self {
+        self.secondary = Some(secondary);
+        self
+    }
+
+    pub fn with_icon(mut self, icon: &'static [u8]) -> Self {
+        self.icon = Some(Icon::new(icon));
+        self
+    }
+
+    pub fn with_chevron(mut self) -> Self {
+        self.chevron = true;
+        self
+    }
+
+    pub fn initially_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    fn is_clickable(&self) -> bool {
+        self.chevron && self.enabled
+    }
+
+    fn set_pressed(&mut self, ctx: &mut EventCtx, pressed: bool) {
+        if self.pressed != pressed {
+            self.pressed = pressed;
+            ctx.request_paint();
+        }
+    }
+
+    fn background(&self) -> Color {
+        if self.pressed {
+            theme::GREY_DARK
+        } else {
+            theme::BG
+        }
+    }
+
+    fn foreground(&self) -> Color {
+        if self.enabled {
+            theme::FG
+        } else {
+            theme::GREY_MEDIUM
+        }
+    }
+
+    fn paint_text(&self, x: i16) {
+        let bg = self.background();
+        let fg = self.foreground();
+        let primary_height = Font::NORMAL.text_height();
+        if let Some(secondary) = &self.secondary {
+            let secondary_height = Font::NORMAL.text_height();
+            let text_height = primary_height + Self::LINE_SPACING + secondary_height;
+            let top = self.area.center().y - text_height / 2;
+            display::text(
+                Point::new(x, top + primary_height),
+                self.primary.as_ref(),
+                Font::NORMAL,
+                fg,
+                bg,
+            );
+            display::text(
+                Point::new(x, top + text_height),
+                secondary.as_ref(),
+                Font::NORMAL,
+                theme::GREY_LIGHT,
+                bg,
+            );
+        } else {
+            display::text(
+                Point::new(x, self.area.center().y + primary_height / 2),
+                self.primary.as_ref(),
+                Font::NORMAL,
+                fg,
+                bg,
+            );
+        }
+    }
+}
+
+impl<T> Component for ListItem<T>
+where
+    T: AsRef<str>,
+{
+    type Msg = ListItemMsg;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.area = bounds;
+        self.area
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        if !self.is_clickable() {
+            return None;
+        }
+        match event {
+            Event::Touch(TouchEvent::TouchStart(pos)) if self.area.contains(pos) => {
+                self.set_pressed(ctx, true);
+            }
+            Event::Touch(TouchEvent::TouchMove(pos)) if !self.area.contains(pos) => {
+                self.set_pressed(ctx, false);
+            }
+            Event::Touch(TouchEvent::TouchEnd(pos)) if self.pressed => {
+                self.set_pressed(ctx, false);
+                if self.area.contains(pos) {
+                    return Some(ListItemMsg::Clicked);
+                }
+            }
+            Event::Touch(TouchEvent::TouchCancel) => {
+                self.set_pressed(ctx, false);
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn paint(&mut self) {
+        let bg = self.background();
+        display::rect_fill(self.area, bg);
+
+        let mut text_x = self.area.x0;
+        if let Some(icon) = &self.icon {
+            let center = Point::new(self.area.x0 + theme::ICON_SIZE / 2, self.area.center().y);
+            icon.draw(center, CENTER, self.foreground(), bg);
+            text_x += theme::ICON_SIZE + Self::ICON_SPACING;
+        }
+        self.paint_text(text_x);
+
+        if self.chevron {
+            let center = Point::new(self.area.x1 - theme::ICON_SIZE / 2, self.area.center().y);
+            let color = if self.enabled {
+                theme::GREY_LIGHT
+            } else {
+                theme::GREY_MEDIUM
+            };
+            Icon::new(theme::ICON_NEXT).draw(center, CENTER, color, bg);
+        }
+    }
+
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        sink(self.area)
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl<T> crate::trace::Trace for ListItem<T>
+where
+    T: AsRef<str>,
+{
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.open("ListItem");
+        t.field("primary", &self.primary.as_ref());
+        if let Some(secondary) = &self.secondary {
+            t.field("secondary", &secondary.as_ref());
+        }
+        t.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn click(item: &mut ListItem<&str>, start: Point, end: Point) -> Option<ListItemMsg> {
+        let mut ctx = EventCtx::new();
+        item.event(&mut ctx, Event::Touch(TouchEvent::TouchStart(start)));
+        item.event(&mut ctx, Event::Touch(TouchEvent::TouchEnd(end)))
+    }
+
+    #[test]
+    fn list_item_click() {
+        let area = Rect::new(Point::zero(), Point::new(200, ListItem::<&str>::HEIGHT));
+        let inside = Point::new(100, 20);
+        let outside = Point::new(100, 100);
+
+        let mut item = ListItem::new("Reboot").with_chevron();
+        item.place(area);
+        assert!(matches!(
+            click(&mut item, inside, inside),
+            Some(ListItemMsg::Clicked)
+        ));
+        // releasing outside of the row cancels the click
+        assert!(click(&mut item, inside, outside).is_none());
+        assert!(!item.pressed);
+        assert!(click(&mut item, outside, inside).is_none());
+
+        // rows without a chevron do not lead anywhere
+        let mut item = ListItem::new("Version").with_secondary("2.6.0");
+        item.place(area);
+        assert!(click(&mut item, inside, inside).is_none());
+
+        let mut item = ListItem::new("Wipe device")
+            .with_chevron()
+            .initially_enabled(false);
+        item.place(area);
+        assert!(click(&mut item, inside, inside).is_none());
+    }
+}
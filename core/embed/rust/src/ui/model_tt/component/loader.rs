@@ -1,9 +1,9 @@
 use crate::{
     time::{Duration, Instant},
     ui::{
-        animation::Animation,
+        animation::Timeline,
         component::{Component, Event, EventCtx},
-        display::{self, toif::Icon, Color},
+        display::{self, toif::Icon, Color, LoaderGeometry},
         geometry::{Offset, Rect},
         model_tt::constant,
         util::animation_disabled,
@@ -19,12 +19,12 @@ pub enum LoaderMsg {
 
 enum State {
     Initial,
-    Growing(Animation<u16>),
-    Shrinking(Animation<u16>),
+    Growing(Timeline<u16>),
+    Shrinking(Timeline<u16>),
 }
 
 pub struct Loader {
-    offset_y: i16,
+    geometry: LoaderGeometry,
     state: State,
     growing_duration: Duration,
     shrinking_duration: Duration,
@@ -32,11 +32,9 @@ pub struct Loader {
 }
 
 impl Loader {
-    pub const SIZE: Offset = Offset::new(120, 120);
-
     pub fn new() -> Self {
         Self {
-            offset_y: 0,
+            geometry: theme::LOADER,
             state: State::Initial,
             growing_duration: Duration::from_millis(1000),
             shrinking_duration: Duration::from_millis(500),
@@ -54,13 +52,19 @@ impl Loader {
         self
     }
 
+    pub fn with_geometry(mut self, geometry: LoaderGeometry) -> Self {
+        self.geometry = geometry;
+        self
+    }
+
     pub fn start_growing(&mut self, ctx: &mut EventCtx, now: Instant) {
-        let mut anim = Animation::new(
+        let mut anim = Timeline::new(
             display::LOADER_MIN,
             display::LOADER_MAX,
             self.growing_duration,
             now,
-        );
+        )
+        .functional();
         if let State::Shrinking(shrinking) = &self.state {
             anim.seek_to_value(shrinking.value(now));
         }
@@ -75,12 +79,13 @@ impl Loader {
     }
 
     pub fn start_shrinking(&mut self, ctx: &mut EventCtx, now: Instant) {
-        let mut anim = Animation::new(
+        let mut anim = Timeline::new(
             display::LOADER_MAX,
             display::LOADER_MIN,
             self.shrinking_duration,
             now,
-        );
+        )
+        .functional();
         if let State::Growing(growing) = &self.state {
             anim.seek_to_value(display::LOADER_MAX.saturating_sub(growing.value(now)));
         }
@@ -99,7 +104,7 @@ impl Loader {
         self.state = State::Initial;
     }
 
-    pub fn animation(&self) -> Option<&Animation<u16>> {
+    pub fn animation(&self) -> Option<&Timeline<u16>> {
         match &self.state {
             State::Initial => None,
             State::Growing(a) | State::Shrinking(a) => Some(a),
@@ -132,8 +137,12 @@ impl Component for Loader {
         // NOTE: SwipeHoldPage relies on Loader being X-centered regardless of bounds.
         // If this changes then SwipeHoldPage needs to be changed too.
         let screen_center = constant::screen().center();
-        self.offset_y = bounds.center().y - screen_center.y;
-        Rect::from_center_and_size(screen_center + Offset::y(self.offset_y), Self::SIZE)
+        let offset_y = bounds.center().y - screen_center.y;
+        self.geometry = self.geometry.with_y_offset(offset_y);
+        Rect::from_center_and_size(
+            screen_center + Offset::y(offset_y),
+            Offset::uniform(self.geometry.radius * 2),
+        )
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
@@ -176,7 +185,7 @@ impl Component for Loader {
 
             display::loader(
                 progress,
-                self.offset_y,
+                self.geometry,
                 style.loader_color,
                 style.background_color,
                 style.icon,
@@ -207,6 +216,7 @@ impl crate::trace::Trace for Loader {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ui::animation::set_reduced_motion;
 
     #[test]
     fn loader_yields_expected_progress() {
@@ -226,6 +236,21 @@ mod tests {
         assert_eq!(l.progress(t), Some(0));
     }
 
+    #[test]
+    fn loader_reduced_motion() {
+        set_reduced_motion(true);
+        let mut ctx = EventCtx::new();
+        let mut l = Loader::new();
+        let t = Instant::now();
+        l.start_growing(&mut ctx, t);
+        // A tap lasting a single animation frame must not confirm.
+        let t = add_millis(t, 16);
+        assert!(!l.is_completely_grown(t));
+        let t = add_millis(t, 1000);
+        assert!(l.is_completely_grown(t));
+        set_reduced_motion(false);
+    }
+
     fn add_millis(inst: Instant, millis: u32) -> Instant {
         inst.checked_add(Duration::from_millis(millis)).unwrap()
     }
@@ -1,19 +1,28 @@
+mod battery;
 mod button;
 mod dialog;
 mod fido;
 #[rustfmt::skip]
 mod fido_icons;
 mod frame;
+mod group_grid;
 mod hold_to_confirm;
 mod homescreen;
+mod horizontal_page;
+mod indeterminate_loader;
 mod keyboard;
+mod list_item;
 mod loader;
 mod number_input;
 mod page;
 mod progress;
+mod recovery_progress;
+mod result_icon;
 mod scroll;
+mod status_bar;
 mod swipe;
 
+pub use battery::{BatteryCharge, BatteryLevel};
 pub use button::{
     Button, ButtonContent, ButtonMsg, ButtonStyle, ButtonStyleSheet, CancelConfirmMsg,
     CancelInfoConfirmMsg, SelectWordMsg,
@@ -21,8 +30,11 @@ pub use button::{
 pub use dialog::{Dialog, DialogMsg, IconDialog};
 pub use fido::{FidoConfirm, FidoMsg};
 pub use frame::{Frame, NotificationFrame};
+pub use group_grid::GroupGrid;
 pub use hold_to_confirm::{HoldToConfirm, HoldToConfirmMsg};
 pub use homescreen::{Homescreen, HomescreenMsg, Lockscreen};
+pub use horizontal_page::HorizontalSwipePage;
+pub use indeterminate_loader::IndeterminateLoader;
 pub use keyboard::{
     bip39::Bip39Input,
     mnemonic::{MnemonicInput, MnemonicKeyboard, MnemonicKeyboardMsg},
@@ -31,11 +43,21 @@ pub use keyboard::{
     slip39::Slip39Input,
     word_count::{SelectWordCount, SelectWordCountMsg},
 };
+pub use list_item::{ListItem, ListItemMsg};
 pub use loader::{Loader, LoaderMsg, LoaderStyle, LoaderStyleSheet};
 pub use number_input::{NumberInputDialog, NumberInputDialogMsg};
 pub use page::{SwipeHoldPage, SwipePage};
 pub use progress::Progress;
-pub use scroll::ScrollBar;
+pub use recovery_progress::RecoveryProgress;
+pub use result_icon::ResultIcon;
+pub use scroll::{ScrollBar, ScrollBarMsg, ScrollBarStyle};
+pub use status_bar::StatusBar;
 pub use swipe::{Swipe, SwipeDirection};
 
+pub use crate::ui::component::ResultPopupMsg;
+
+/// Result notification with the icon and the buttons of the model.
+pub type ResultPopup<T> =
+    crate::ui::component::result_popup::ResultPopup<T, ResultIcon, Button<&'static str>>;
+
 use super::theme;
@@ -2,10 +2,14 @@ use crate::ui::{
     component::{
         base::ComponentExt,
         paginated::Paginate,
-        text::paragraphs::{Paragraph, ParagraphStrType, Paragraphs},
+        text::{
+            paragraphs::{Paragraph, ParagraphStrType, Paragraphs},
+            StyleToken,
+        },
         Child, Component, Event, EventCtx, Pad,
     },
-    display::{self, Font},
+    display::{self, toif::Icon, Font},
+    event::TouchEvent,
     geometry::{Grid, Insets, Offset, Rect},
     util,
 };
@@ -22,12 +26,15 @@ where
     F: Fn(u32) -> T,
 {
     area: Rect,
+    background: Pad,
     description_func: F,
     input: Child<NumberInput>,
     paragraphs: Child<Paragraphs<Paragraph<T>>>,
     paragraphs_pad: Pad,
     info_button: Child<Button<&'static str>>,
     confirm_button: Child<Button<&'static str>>,
+    keypad: Option<Child<NumberKeypad>>,
+    keypad_open: bool,
 }
 
 impl<T, F> NumberInputDialog<T, F>
@@ -39,17 +46,42 @@ where
         let text = description_func(init_value);
         Self {
             area: Rect::zero(),
+            background: Pad::with_background(theme::BG),
             description_func,
             input: NumberInput::new(min, max, init_value).into_child(),
-            paragraphs: Paragraphs::new(Paragraph::new(&theme::TEXT_NORMAL, text)).into_child(),
+            paragraphs: Paragraphs::new(Paragraph::new(theme::text(StyleToken::Body), text))
+                .into_child(),
             paragraphs_pad: Pad::with_background(theme::BG),
             info_button: Button::with_text("INFO").into_child(),
             confirm_button: Button::with_text("CONTINUE")
                 .styled(theme::button_confirm())
                 .into_child(),
+            keypad: None,
+            keypad_open: false,
         }
     }
 
+    /// Open a keypad for typing the value in when the value is tapped.
+    pub fn with_keypad(mut self) -> Self {
+        let input = self.input.inner();
+        self.keypad = Some(NumberKeypad::new(input.min, input.max).into_child());
+        self
+    }
+
+    fn open_keypad(&mut self, ctx: &mut EventCtx) {
+        if let Some(keypad) = &mut self.keypad {
+            keypad.mutate(ctx, |ctx, keypad| keypad.reset(ctx));
+            self.keypad_open = true;
+        }
+    }
+
+    fn close_keypad(&mut self, ctx: &mut EventCtx) {
+        self.keypad_open = false;
+        self.background.clear();
+        self.paragraphs_pad.clear();
+        self.request_complete_repaint(ctx);
+    }
+
     fn update_text(&mut self, ctx: &mut EventCtx, value: u32) {
         let text = (self.description_func)(value);
         self.paragraphs.mutate(ctx, move |ctx, para| {
@@ -76,6 +108,10 @@ where
 
     fn place(&mut self, bounds: Rect) -> Rect {
         self.area = bounds;
+        self.background.place(bounds);
+        if let Some(keypad) = &mut self.keypad {
+            keypad.place(bounds);
+        }
         let button_height = theme::BUTTON_HEIGHT;
         let content_area = self.area.inset(Insets::top(2 * theme::BUTTON_SPACING));
         let (input_area, content_area) = content_area.split_top(button_height);
@@ -98,8 +134,27 @@ where
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
-        if let Some(NumberInputMsg::Changed(i)) = self.input.event(ctx, event) {
-            self.update_text(ctx, i);
+        if self.keypad_open {
+            let msg = self.keypad.as_mut().and_then(|k| k.event(ctx, event));
+            match msg {
+                Some(NumberKeypadMsg::Confirmed(value)) => {
+                    self.close_keypad(ctx);
+                    self.input
+                        .mutate(ctx, |ctx, input| input.set_value(ctx, value));
+                    self.update_text(ctx, self.value());
+                }
+                Some(NumberKeypadMsg::Cancelled) => self.close_keypad(ctx),
+                None => {}
+            }
+            return None;
+        }
+        match self.input.event(ctx, event) {
+            Some(NumberInputMsg::Changed(i)) => self.update_text(ctx, i),
+            Some(NumberInputMsg::KeypadRequested) => {
+                self.open_keypad(ctx);
+                return None;
+            }
+            None => {}
         }
         self.paragraphs.event(ctx, event);
         if let Some(ButtonMsg::Clicked) = self.info_button.event(ctx, event) {
@@ -112,6 +167,13 @@ where
     }
 
     fn paint(&mut self) {
+        if self.keypad_open {
+            if let Some(keypad) = &mut self.keypad {
+                keypad.paint();
+            }
+            return;
+        }
+        self.background.paint();
         self.input.paint();
         self.paragraphs_pad.paint();
         self.paragraphs.paint();
@@ -121,6 +183,12 @@ where
 
     fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
         sink(self.area);
+        if self.keypad_open {
+            if let Some(keypad) = &self.keypad {
+                keypad.bounds(sink);
+            }
+            return;
+        }
         self.input.bounds(sink);
         self.paragraphs.bounds(sink);
         self.info_button.bounds(sink);
@@ -140,12 +208,27 @@ where
         t.field("paragraphs", &self.paragraphs);
         t.field("info_button", &self.info_button);
         t.field("confirm_button", &self.confirm_button);
+        if self.keypad_open {
+            t.field("keypad", &self.keypad);
+        }
         t.close();
     }
 }
 
 pub enum NumberInputMsg {
     Changed(u32),
+    /// The value was tapped without dragging, the caller may offer to type
+    /// it in.
+    KeypadRequested,
+}
+
+/// Horizontal distance of a drag over the value which changes it by one.
+const DRAG_STEP: i16 = 12;
+
+struct Drag {
+    origin: i16,
+    value: u32,
+    moved: bool,
 }
 
 pub struct NumberInput {
@@ -155,6 +238,7 @@ pub struct NumberInput {
     min: u32,
     max: u32,
     value: u32,
+    drag: Option<Drag>,
 }
 
 impl NumberInput {
@@ -173,8 +257,29 @@ impl NumberInput {
             min,
             max,
             value,
+            drag: None,
         }
     }
+
+    pub fn set_value(&mut self, ctx: &mut EventCtx, value: u32) {
+        self.value = value.clamp(self.min, self.max);
+        self.dec
+            .mutate(ctx, |ctx, btn| btn.enable_if(ctx, self.value > self.min));
+        self.inc
+            .mutate(ctx, |ctx, btn| btn.enable_if(ctx, self.value < self.max));
+        ctx.request_paint();
+    }
+}
+
+/// Value at horizontal position `x` of a drag which started at `drag.origin`.
+fn dragged_value(drag: &Drag, x: i16, min: u32, max: u32) -> u32 {
+    let steps = (x - drag.origin) / DRAG_STEP;
+    let value = if steps < 0 {
+        drag.value.saturating_sub(steps.unsigned_abs() as u32)
+    } else {
+        drag.value.saturating_add(steps as u32)
+    };
+    value.clamp(min, max)
 }
 
 impl Component for NumberInput {
@@ -189,22 +294,43 @@ impl Component for NumberInput {
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
-        let mut changed = false;
         if let Some(ButtonMsg::Clicked) = self.dec.event(ctx, event) {
-            self.value = self.min.max(self.value.saturating_sub(1));
-            changed = true;
+            self.set_value(ctx, self.value.saturating_sub(1));
+            return Some(NumberInputMsg::Changed(self.value));
         };
         if let Some(ButtonMsg::Clicked) = self.inc.event(ctx, event) {
-            self.value = self.max.min(self.value.saturating_add(1));
-            changed = true;
-        };
-        if changed {
-            self.dec
-                .mutate(ctx, |ctx, btn| btn.enable_if(ctx, self.value > self.min));
-            self.inc
-                .mutate(ctx, |ctx, btn| btn.enable_if(ctx, self.value < self.max));
-            ctx.request_paint();
+            self.set_value(ctx, self.value.saturating_add(1));
             return Some(NumberInputMsg::Changed(self.value));
+        };
+        match event {
+            Event::Touch(TouchEvent::TouchStart(pos)) if self.area.contains(pos) => {
+                self.drag = Some(Drag {
+                    origin: pos.x,
+                    value: self.value,
+                    moved: false,
+                });
+            }
+            Event::Touch(TouchEvent::TouchMove(pos)) => {
+                if let Some(drag) = &mut self.drag {
+                    let value = dragged_value(drag, pos.x, self.min, self.max);
+                    drag.moved |= value != drag.value;
+                    if value != self.value {
+                        self.set_value(ctx, value);
+                        return Some(NumberInputMsg::Changed(self.value));
+                    }
+                }
+            }
+            Event::Touch(TouchEvent::TouchEnd(pos)) => {
+                if let Some(drag) = self.drag.take() {
+                    if !drag.moved && self.area.contains(pos) {
+                        return Some(NumberInputMsg::KeypadRequested);
+                    }
+                }
+            }
+            Event::Touch(TouchEvent::TouchCancel) => {
+                self.drag = None;
+            }
+            _ => {}
         }
         None
     }
@@ -212,16 +338,7 @@ impl Component for NumberInput {
     fn paint(&mut self) {
         let mut buf = [0u8; 10];
         if let Some(text) = util::u32_to_str(self.value, &mut buf) {
-            let digit_font = Font::DEMIBOLD;
-            let y_offset = digit_font.text_height() / 2 + Button::<&str>::BASELINE_OFFSET;
-            display::rect_fill(self.area, theme::BG);
-            display::text_center(
-                self.area.center() + Offset::y(y_offset),
-                text,
-                digit_font,
-                theme::FG,
-                theme::BG,
-            );
+            paint_value(self.area, text);
         }
         self.dec.paint();
         self.inc.paint();
@@ -242,3 +359,212 @@ impl crate::trace::Trace for NumberInput {
         t.close();
     }
 }
+
+fn paint_value(area: Rect, text: &str) {
+    let digit_font = Font::DEMIBOLD;
+    let y_offset = digit_font.text_height() / 2 + Button::<&str>::BASELINE_OFFSET;
+    display::rect_fill(area, theme::BG);
+    display::text_center(
+        area.center() + Offset::y(y_offset),
+        text,
+        digit_font,
+        theme::FG,
+        theme::BG,
+    );
+}
+
+pub enum NumberKeypadMsg {
+    Confirmed(u32),
+    Cancelled,
+}
+
+const DIGIT_COUNT: usize = 10;
+
+/// Digit keypad for typing in a number directly, covering the whole dialog.
+/// Erasing with no digits typed cancels the entry.
+pub struct NumberKeypad {
+    area: Rect,
+    pad: Pad,
+    min: u32,
+    max: u32,
+    typed: Option<u32>,
+    erase_btn: Child<Button<&'static str>>,
+    confirm_btn: Child<Button<&'static str>>,
+    digit_btns: [Child<Button<&'static str>>; DIGIT_COUNT],
+}
+
+impl NumberKeypad {
+    const DIGITS: [&'static str; DIGIT_COUNT] = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+
+    pub fn new(min: u32, max: u32) -> Self {
+        Self {
+            area: Rect::zero(),
+            pad: Pad::with_background(theme::BG),
+            min,
+            max,
+            typed: None,
+            erase_btn: Button::with_icon(Icon::new(theme::ICON_BACK))
+                .styled(theme::button_reset())
+                .into_child(),
+            confirm_btn: Button::with_icon(Icon::new(theme::ICON_CONFIRM))
+                .styled(theme::button_confirm())
+                .initially_enabled(false)
+                .into_child(),
+            digit_btns: Self::DIGITS.map(|digit| {
+                Button::with_text(digit)
+                    .styled(theme::button_pin())
+                    .into_child()
+            }),
+        }
+    }
+
+    /// Start over with no digits typed, repainting the whole area.
+    pub fn reset(&mut self, ctx: &mut EventCtx) {
+        self.typed = None;
+        self.pad.clear();
+        self.confirm_btn.mutate(ctx, |ctx, btn| btn.disable(ctx));
+        self.request_complete_repaint(ctx);
+    }
+
+    fn set_typed(&mut self, ctx: &mut EventCtx, typed: Option<u32>) {
+        self.typed = typed;
+        let confirmable = is_confirmable(typed, self.min);
+        self.confirm_btn
+            .mutate(ctx, |ctx, btn| btn.enable_if(ctx, confirmable));
+        // Only the typed value has changed, the buttons report their own paint.
+        ctx.request_paint_area(self.area);
+    }
+}
+
+/// Value after typing `digit`, or `None` if it would exceed `max`.
+fn append_digit(typed: Option<u32>, digit: u32, max: u32) -> Option<u32> {
+    let value = typed
+        .unwrap_or(0)
+        .checked_mul(10)
+        .and_then(|value| value.checked_add(digit))?;
+    (value <= max).then_some(value)
+}
+
+/// Whether `typed` can be confirmed, values below `min` are not accepted.
+fn is_confirmable(typed: Option<u32>, min: u32) -> bool {
+    typed.map_or(false, |typed| typed >= min)
+}
+
+impl Component for NumberKeypad {
+    type Msg = NumberKeypadMsg;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        let (value_area, keypad_area) = bounds
+            .inset(Insets::top(2 * theme::BUTTON_SPACING))
+            .split_top(theme::BUTTON_HEIGHT);
+        let keypad_area = keypad_area.inset(Insets::top(theme::BUTTON_SPACING));
+        let grid = Grid::new(keypad_area, 4, 3).with_spacing(theme::KEYBOARD_SPACING);
+        // Phone layout: 1 2 3 / 4 5 6 / 7 8 9 / erase 0 confirm.
+        for (i, btn) in self.digit_btns.iter_mut().enumerate() {
+            let cell = if i == 0 { 10 } else { i - 1 };
+            btn.place(grid.cell(cell));
+        }
+        self.erase_btn.place(grid.cell(9));
+        self.confirm_btn.place(grid.cell(11));
+        self.area = value_area;
+        self.pad.place(bounds);
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        for (digit, btn) in self.digit_btns.iter_mut().enumerate() {
+            if let Some(ButtonMsg::Clicked) = btn.event(ctx, event) {
+                if let Some(typed) = append_digit(self.typed, digit as u32, self.max) {
+                    self.set_typed(ctx, Some(typed));
+                }
+                return None;
+            }
+        }
+        if let Some(ButtonMsg::Clicked) = self.erase_btn.event(ctx, event) {
+            match self.typed {
+                None => return Some(NumberKeypadMsg::Cancelled),
+                Some(typed) => self.set_typed(ctx, (typed >= 10).then_some(typed / 10)),
+            }
+        }
+        if let Some(ButtonMsg::Clicked) = self.confirm_btn.event(ctx, event) {
+            match self.typed {
+                Some(typed) if is_confirmable(self.typed, self.min) => {
+                    return Some(NumberKeypadMsg::Confirmed(typed));
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    fn paint(&mut self) {
+        self.pad.paint();
+        let mut buf = [0u8; 10];
+        let text = match self.typed {
+            Some(typed) => util::u32_to_str(typed, &mut buf),
+            None => Some(""),
+        };
+        if let Some(text) = text {
+            paint_value(self.area, text);
+        }
+        self.erase_btn.paint();
+        self.confirm_btn.paint();
+        for btn in &mut self.digit_btns {
+            btn.paint();
+        }
+    }
+
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        sink(self.area);
+        self.erase_btn.bounds(sink);
+        self.confirm_btn.bounds(sink);
+        for btn in &self.digit_btns {
+            btn.bounds(sink);
+        }
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl crate::trace::Trace for NumberKeypad {
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.open("NumberKeypad");
+        t.field("typed", &self.typed.map(|typed| typed as usize));
+        t.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keypad_append_digit() {
+        assert_eq!(append_digit(None, 0, 99), Some(0));
+        assert_eq!(append_digit(None, 7, 99), Some(7));
+        assert_eq!(append_digit(Some(7), 5, 99), Some(75));
+        assert_eq!(append_digit(Some(75), 1, 99), None);
+        assert_eq!(append_digit(Some(u32::MAX / 10), 9, u32::MAX), None);
+    }
+
+    #[test]
+    fn keypad_confirmable() {
+        assert!(!is_confirmable(None, 0));
+        assert!(!is_confirmable(Some(0), 1));
+        assert!(is_confirmable(Some(1), 1));
+        assert!(is_confirmable(Some(16), 1));
+    }
+
+    #[test]
+    fn drag_steps() {
+        let drag = Drag {
+            origin: 100,
+            value: 5,
+            moved: false,
+        };
+        assert_eq!(dragged_value(&drag, 100 + DRAG_STEP - 1, 1, 16), 5);
+        assert_eq!(dragged_value(&drag, 100 + 2 * DRAG_STEP, 1, 16), 7);
+        assert_eq!(dragged_value(&drag, 100 - DRAG_STEP, 1, 16), 4);
+        assert_eq!(dragged_value(&drag, 0, 1, 16), 1);
+        assert_eq!(dragged_value(&drag, 300, 1, 16), 16);
+    }
+}
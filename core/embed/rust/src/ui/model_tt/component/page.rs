@@ -1,16 +1,16 @@
 use crate::ui::{
     component::{
-        base::ComponentExt, paginated::PageMsg, Component, Event, EventCtx, FixedHeightBar, Label,
-        Pad, Paginate,
+        base::ComponentExt, paginated::PageMsg, BacklightFade, Component, Event, EventCtx,
+        FixedHeightBar, Label, Pad, Paginate,
     },
-    display::{self, toif::Icon, Color},
+    display::{toif::Icon, Color},
     geometry::{Insets, Rect},
     model_tt::component::{Button, ButtonMsg},
 };
 
 use super::{
     hold_to_confirm::{handle_hold_event, CancelHold, CancelHoldMsg},
-    theme, CancelConfirmMsg, Loader, ScrollBar, Swipe, SwipeDirection,
+    theme, CancelConfirmMsg, Loader, ScrollBar, ScrollBarMsg, Swipe, SwipeDirection,
 };
 
 pub struct SwipePage<T, U> {
@@ -21,7 +21,7 @@ pub struct SwipePage<T, U> {
     scrollbar: ScrollBar,
     hint: Label<&'static str>,
     button_back: Option<Button<&'static str>>,
-    fade: Option<i32>,
+    fade: BacklightFade,
 }
 
 impl<T, U> SwipePage<T, U>
@@ -39,7 +39,7 @@ where
             pad: Pad::with_background(background),
             hint: Label::centered("SWIPE TO CONTINUE", theme::label_page_hint()),
             button_back: None,
-            fade: None,
+            fade: BacklightFade::new(),
         }
     }
 
@@ -65,7 +65,7 @@ where
 
         // Swipe has dimmed the screen, so fade back to normal backlight after the next
         // paint.
-        self.fade = Some(theme::BACKLIGHT_NORMAL);
+        self.fade.start(ctx, theme::BACKLIGHT_NORMAL);
     }
 
     /// Like `place()` but returns area for loader (content + scrollbar) to be
@@ -101,8 +101,9 @@ where
         };
 
         // Now that we finally have the page count, we can setup the scrollbar and the
-        // swiper.
-        self.scrollbar.set_count_and_active_page(page_count, 0);
+        // swiper. Stay on the page shown before, if any.
+        let active_page = self.scrollbar.set_count_keep_active_page(page_count);
+        self.content.change_page(active_page);
         self.setup_swipe();
 
         layout.content_single_page.union(layout.scrollbar)
@@ -124,6 +125,16 @@ where
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
         ctx.set_page_count(self.scrollbar.page_count);
+        self.fade.event(ctx, event);
+        let was_dragging = self.scrollbar.is_dragging();
+        if let Some(ScrollBarMsg::JumpTo(page)) = self.scrollbar.event(ctx, event) {
+            self.scrollbar.go_to(page);
+            self.on_page_change(ctx);
+            return None;
+        }
+        if was_dragging || self.scrollbar.is_dragging() {
+            return None;
+        }
         if let Some(swipe) = self.swipe.event(ctx, event) {
             match swipe {
                 SwipeDirection::Up => {
@@ -171,10 +182,6 @@ where
         } else {
             self.buttons.paint();
         }
-        if let Some(val) = self.fade.take() {
-            // Note that this is blocking and takes some time.
-            display::fade_backlight(val);
-        }
     }
 
     fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
@@ -224,8 +231,6 @@ pub struct PageLayout {
 }
 
 impl PageLayout {
-    const SCROLLBAR_WIDTH: i16 = 15;
-    const SCROLLBAR_SPACE: i16 = 5;
     const HINT_OFF: i16 = 19;
 
     pub fn new(area: Rect) -> Self {
@@ -233,9 +238,7 @@ impl PageLayout {
         let (buttons, _space) = area.split_right(theme::CONTENT_BORDER);
         let (_space, content) = area.split_left(theme::CONTENT_BORDER);
         let (content_single_page, _space) = content.split_right(theme::CONTENT_BORDER);
-        let (content, scrollbar) =
-            content.split_right(Self::SCROLLBAR_SPACE + Self::SCROLLBAR_WIDTH);
-        let (_space, scrollbar) = scrollbar.split_left(Self::SCROLLBAR_SPACE);
+        let (content, scrollbar) = ScrollBar::split_right(content);
 
         let (_, one_row_buttons) = area.split_bottom(theme::button_rows(1));
         let (hint_button, hint_button_hint) = one_row_buttons.split_left(one_row_buttons.height());
@@ -357,10 +360,6 @@ where
         } else {
             self.inner.buttons.paint();
         }
-        if let Some(val) = self.inner.fade.take() {
-            // Note that this is blocking and takes some time.
-            display::fade_backlight(val);
-        }
     }
 
     fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
@@ -385,7 +384,7 @@ mod tests {
         trace::Trace,
         ui::{
             component::{
-                text::paragraphs::{Paragraph, ParagraphStrType, Paragraphs},
+                text::paragraphs::{Paragraph, Paragraphs},
                 Empty,
             },
             event::TouchEvent,
@@ -398,12 +397,6 @@ mod tests {
 
     const SCREEN: Rect = constant::screen().inset(theme::borders());
 
-    impl ParagraphStrType for &'static str {
-        fn skip_prefix(&self, chars: usize) -> Self {
-            &self[chars..]
-        }
-    }
-
     fn trace(val: &impl Trace) -> String {
         let mut t = Vec::new();
         val.trace(&mut t);
@@ -497,8 +490,8 @@ mod tests {
         );
         page.place(SCREEN);
 
-        let expected1 = "<SwipePage active_page:0 page_count:2 content:<Paragraphs This is somewhat long\nparagraph that goes on\nand on and on and on\nand on and will definitely\nnot fit on just a single\nscreen. You have to\nswipe a bit to see all the\ntext it contains I guess....\n> buttons:<FixedHeightBar inner:<Button text:NO > > >";
-        let expected2 = "<SwipePage active_page:1 page_count:2 content:<Paragraphs There's just so much\nletters in it.\n> buttons:<FixedHeightBar inner:<Button text:NO > > >";
+        let expected1 = "<SwipePage active_page:0 page_count:2 content:<Paragraphs --- page 1/2 ---\nThis is somewhat long\nparagraph that goes on\nand on and on and on\nand on and will definitely\nnot fit on just a single\nscreen. You have to\nswipe a bit to see all the\ntext it contains I guess....\n--- page break ---\n> buttons:<FixedHeightBar inner:<Button text:NO > > >";
+        let expected2 = "<SwipePage active_page:1 page_count:2 content:<Paragraphs --- page 2/2 ---\nThere's just so much\nletters in it.\n> buttons:<FixedHeightBar inner:<Button text:NO > > >";
 
         assert_eq!(trace(&page), expected1);
         swipe_down(&mut page);
@@ -533,9 +526,9 @@ mod tests {
         );
         page.place(SCREEN);
 
-        let expected1 = "<SwipePage active_page:0 page_count:3 content:<Paragraphs This paragraph is using a\nbold font. It doesn't\nneed to be all that long.\nAnd this one is\nusing MONO. Mono\nspace is nice fo\nr numbers, they\n> buttons:<FixedHeightBar inner:<Button text:IDK > > >";
-        let expected2 = "<SwipePage active_page:1 page_count:3 content:<Paragraphs have the same wi\ndth and can be s\ncanned quickly.\nEven if they spa\nn several pages\nor something.\nLet's add another one...\n> buttons:<FixedHeightBar inner:<Button text:IDK > > >";
-        let expected3 = "<SwipePage active_page:2 page_count:3 content:<Paragraphs for a good measure. This\none should overflow all\nthe way to the third\npage with a bit of luck.\n> buttons:<FixedHeightBar inner:<Button text:IDK > > >";
+        let expected1 = "<SwipePage active_page:0 page_count:3 content:<Paragraphs --- page 1/3 ---\nThis paragraph is using a\nbold font. It doesn't\nneed to be all that long.\nAnd this one is\nusing MONO. Mono\nspace is nice fo\nr numbers, they\n--- page break ---\n> buttons:<FixedHeightBar inner:<Button text:IDK > > >";
+        let expected2 = "<SwipePage active_page:1 page_count:3 content:<Paragraphs --- page 2/3 ---\nhave the same wi\ndth and can be s\ncanned quickly.\nEven if they spa\nn several pages\nor something.\nLet's add another one...\n--- page break ---\n> buttons:<FixedHeightBar inner:<Button text:IDK > > >";
+        let expected3 = "<SwipePage active_page:2 page_count:3 content:<Paragraphs --- page 3/3 ---\nfor a good measure. This\none should overflow all\nthe way to the third\npage with a bit of luck.\n> buttons:<FixedHeightBar inner:<Button text:IDK > > >";
 
         assert_eq!(trace(&page), expected1);
         swipe_down(&mut page);
@@ -567,9 +560,9 @@ mod tests {
         );
         page.place(SCREEN);
 
-        let expected1 = "<SwipePage active_page:0 page_count:3 content:<Paragraphs Short one.\n> buttons:<FixedHeightBar inner:<Empty > > >";
-        let expected2 = "<SwipePage active_page:1 page_count:3 content:<Paragraphs Short two.\n> buttons:<FixedHeightBar inner:<Empty > > >";
-        let expected3 = "<SwipePage active_page:2 page_count:3 content:<Paragraphs Short three.\n> buttons:<FixedHeightBar inner:<Empty > > >";
+        let expected1 = "<SwipePage active_page:0 page_count:3 content:<Paragraphs --- page 1/3 ---\nShort one.\n--- page break ---\n> buttons:<FixedHeightBar inner:<Empty > > >";
+        let expected2 = "<SwipePage active_page:1 page_count:3 content:<Paragraphs --- page 2/3 ---\nShort two.\n--- page break ---\n> buttons:<FixedHeightBar inner:<Empty > > >";
+        let expected3 = "<SwipePage active_page:2 page_count:3 content:<Paragraphs --- page 3/3 ---\nShort three.\n> buttons:<FixedHeightBar inner:<Empty > > >";
 
         assert_eq!(trace(&page), expected1);
         swipe_up(&mut page);
@@ -5,12 +5,11 @@ use crate::{
     ui::{
         component::{
             base::ComponentExt,
-            paginated::Paginate,
-            text::paragraphs::{Paragraph, ParagraphStrType, Paragraphs},
-            Child, Component, Event, EventCtx, Label, Never, Pad,
+            text::{paragraphs::ParagraphStrType, StyleToken},
+            Child, Component, Event, EventCtx, Label, Never,
         },
-        display::{self, Font},
-        geometry::{Insets, Rect},
+        display::{self, framebuffer, Font, LoaderGeometry},
+        geometry::{Insets, Offset, Point, Rect},
         model_tt::constant,
         util::animation_disabled,
     },
@@ -21,10 +20,13 @@ use super::theme;
 pub struct Progress<T> {
     title: Child<Label<T>>,
     value: u16,
-    loader_y_offset: i16,
+    loader: LoaderGeometry,
     indeterminate: bool,
-    description: Child<Paragraphs<Paragraph<T>>>,
-    description_pad: Pad,
+    /// Centered lines separated by `\n`, painted off-screen so that they do
+    /// not flicker when updated.
+    description: T,
+    description_area: Rect,
+    description_dirty: bool,
     update_description: fn(&str) -> Result<T, Error>,
 }
 
@@ -43,16 +45,56 @@ where
         Self {
             title: Label::centered(title, theme::label_progress()).into_child(),
             value: 0,
-            loader_y_offset: 0,
+            loader: theme::LOADER,
             indeterminate,
-            description: Paragraphs::new(
-                Paragraph::new(&theme::TEXT_NORMAL, description).centered(),
-            )
-            .into_child(),
-            description_pad: Pad::with_background(theme::BG),
+            description,
+            description_area: Rect::zero(),
+            description_dirty: true,
             update_description,
         }
     }
+
+    fn paint_description(&self) {
+        let style = theme::text(StyleToken::Body);
+        let font = style.text_font;
+        let area = self.description_area;
+        let lines = || {
+            self.description
+                .as_ref()
+                .split('\n')
+                .enumerate()
+                .map(move |(i, line)| {
+                    let baseline = Point::new(
+                        area.center().x - font.text_width(line) / 2,
+                        area.y0 + font.text_height() + font.line_height() * i as i16,
+                    );
+                    (baseline, line)
+                })
+        };
+        let rendered = framebuffer::render(area, style.background_color, |fb| {
+            for (baseline, line) in lines() {
+                fb.text(
+                    baseline,
+                    line,
+                    font,
+                    style.text_color,
+                    style.background_color,
+                );
+            }
+        });
+        if !rendered {
+            display::rect_fill(area, style.background_color);
+            for (baseline, line) in lines() {
+                display::text(
+                    baseline,
+                    line,
+                    font,
+                    style.text_color,
+                    style.background_color,
+                );
+            }
+        }
+    }
 }
 
 impl<T> Component for Progress<T>
@@ -64,9 +106,6 @@ where
     fn place(&mut self, _bounds: Rect) -> Rect {
         let description_lines = 1 + self
             .description
-            .inner()
-            .inner()
-            .content()
             .as_ref()
             .chars()
             .filter(|c| *c == '\n')
@@ -76,27 +115,27 @@ where
             rest.split_bottom(Font::NORMAL.line_height() * description_lines);
         let loader = loader.inset(Insets::top(theme::CONTENT_BORDER));
         self.title.place(title);
-        self.loader_y_offset = loader.center().y - constant::screen().center().y;
-        self.description.place(description);
-        self.description_pad.place(description);
+        self.loader = self
+            .loader
+            .with_y_offset(loader.center().y - constant::screen().center().y);
+        self.description_area = description;
         Self::AREA
     }
 
     fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        if let Event::Attach | Event::RequestPaint = event {
+            self.description_dirty = true;
+        }
         if let Event::Progress(new_value, new_description) = event {
             if mem::replace(&mut self.value, new_value) != new_value {
                 if !animation_disabled() {
                     ctx.request_paint();
                 }
-                self.description.mutate(ctx, |ctx, para| {
-                    if para.inner_mut().content().as_ref() != new_description {
-                        let new_description = unwrap!((self.update_description)(new_description));
-                        para.inner_mut().update(new_description);
-                        para.change_page(0); // Recompute bounding box.
-                        ctx.request_paint();
-                        self.description_pad.clear();
-                    }
-                });
+                if self.description.as_ref() != new_description {
+                    self.description = unwrap!((self.update_description)(new_description));
+                    self.description_dirty = true;
+                    ctx.request_paint();
+                }
             }
         }
         None
@@ -105,24 +144,20 @@ where
     fn paint(&mut self) {
         self.title.paint();
         if self.indeterminate {
-            display::loader_indeterminate(
-                self.value,
-                self.loader_y_offset,
-                theme::FG,
-                theme::BG,
-                None,
-            );
+            display::loader_indeterminate(self.value, self.loader, theme::FG, theme::BG, None);
         } else {
-            display::loader(self.value, self.loader_y_offset, theme::FG, theme::BG, None);
+            display::loader(self.value, self.loader, theme::FG, theme::BG, None);
+        }
+        if self.description_dirty {
+            self.description_dirty = false;
+            self.paint_description();
         }
-        self.description_pad.paint();
-        self.description.paint();
     }
 
     fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
         sink(Self::AREA);
         self.title.bounds(sink);
-        self.description.bounds(sink);
+        sink(self.description_area);
     }
 }
 
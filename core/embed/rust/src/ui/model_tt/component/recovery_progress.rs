@@ -0,0 +1,186 @@
+use core::fmt::Write;
+
+use heapless::String;
+
+use crate::ui::{
+    component::{
+        text::StyleToken, Child, Component, ComponentExt, Event, EventCtx, Label, Never, Pad,
+    },
+    event::RecoveryEvent,
+    geometry::Rect,
+};
+
+use super::{theme, GroupGrid};
+
+const STATUS_LEN: usize = 24;
+
+/// Progress of a Shamir recovery: words of the share being entered, a summary
+/// of what is still missing, and the shares entered so far in every group.
+/// Shares entered while the screen is shown, see `Event::Recovery`, repaint
+/// only the parts which changed.
+pub struct RecoveryProgress {
+    pad: Pad,
+    words: Child<Label<String<STATUS_LEN>>>,
+    summary: Child<Label<String<STATUS_LEN>>>,
+    grid: Child<GroupGrid>,
+}
+
+impl RecoveryProgress {
+    const STATUS_HEIGHT: i16 = 54;
+
+    /// Progress of recovering from shares of `grid`. Words are not shown
+    /// unless `word_count` is set.
+    pub fn new(grid: GroupGrid) -> Self {
+        Self {
+            pad: Pad::with_background(theme::BG),
+            words: Child::new(Label::left_aligned(
+                String::new(),
+                *theme::text(StyleToken::Body),
+            )),
+            summary: Child::new(Label::left_aligned(
+                summary_text(&grid),
+                *theme::text(StyleToken::Title),
+            )),
+            grid: Child::new(grid),
+        }
+    }
+
+    /// Show `entered` of the `word_count` words of the current share.
+    pub fn with_words(mut self, entered: u8, word_count: u8) -> Self {
+        self.words = Child::new(Label::left_aligned(
+            words_text(entered, word_count),
+            *theme::text(StyleToken::Body),
+        ));
+        self
+    }
+
+    pub fn grid(&self) -> &GroupGrid {
+        self.grid.inner()
+    }
+
+    /// Record `entered` shares of `group`, e.g. after a share was accepted.
+    pub fn set_shares_entered(&mut self, ctx: &mut EventCtx, group: usize, entered: u8) {
+        self.grid
+            .mutate(ctx, |ctx, grid| grid.set_entered(ctx, group, entered));
+        let text = summary_text(self.grid.inner());
+        if self.summary.inner().text() != &text {
+            self.pad.clear();
+            self.summary.mutate(ctx, |ctx, summary| {
+                summary.set_text(text);
+                ctx.request_paint();
+            });
+            self.words.request_complete_repaint(ctx);
+        }
+    }
+}
+
+fn words_text(entered: u8, word_count: u8) -> String<STATUS_LEN> {
+    let mut text = String::new();
+    if word_count > 0 {
+        unwrap!(write!(text, "WORDS {}/{}", entered, word_count));
+    }
+    text
+}
+
+fn summary_text(grid: &GroupGrid) -> String<STATUS_LEN> {
+    let plural = |n: usize| if n == 1 { "" } else { "S" };
+    let mut text = String::new();
+    match grid.groups() {
+        _ if grid.is_done() => unwrap!(text.push_str("RECOVERY COMPLETE")),
+        // Basic backups have a single group, count the shares instead.
+        [group] => {
+            let n = group.threshold.saturating_sub(group.entered) as usize;
+            unwrap!(write!(text, "{} MORE SHARE{} NEEDED", n, plural(n)));
+        }
+        _ => {
+            let n = grid.remaining_groups();
+            unwrap!(write!(text, "{} MORE GROUP{} NEEDED", n, plural(n)));
+        }
+    }
+    text
+}
+
+impl Component for RecoveryProgress {
+    type Msg = Never;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        let (status_area, grid_area) = bounds.split_top(Self::STATUS_HEIGHT);
+        let (words_area, summary_area) = status_area.split_top(Self::STATUS_HEIGHT / 2);
+        self.pad.place(status_area);
+        self.words.place(words_area);
+        self.summary.place(summary_area);
+        self.grid.place(grid_area);
+        bounds
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        if let Event::Recovery(RecoveryEvent { group, entered }) = event {
+            self.set_shares_entered(ctx, group as usize, entered);
+        }
+        self.grid.event(ctx, event);
+        None
+    }
+
+    fn paint(&mut self) {
+        self.pad.paint();
+        self.words.paint();
+        self.summary.paint();
+        self.grid.paint();
+    }
+
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        sink(self.pad.area);
+        self.grid.bounds(sink);
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl crate::trace::Trace for RecoveryProgress {
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.open("RecoveryProgress");
+        t.field("words", &self.words.inner().text().as_str());
+        t.field("summary", &self.summary.inner().text().as_str());
+        t.field("grid", self.grid.inner());
+        t.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recovery_progress_texts() {
+        assert_eq!(words_text(3, 20).as_str(), "WORDS 3/20");
+        assert_eq!(words_text(3, 0).as_str(), "");
+
+        let basic = GroupGrid::new(1).with_group(2, 3);
+        assert_eq!(summary_text(&basic).as_str(), "1 MORE SHARE NEEDED");
+
+        let mut progress = RecoveryProgress::new(
+            GroupGrid::new(2)
+                .with_group(0, 2)
+                .with_group(1, 2)
+                .with_group(0, 1),
+        );
+        assert_eq!(
+            progress.summary.inner().text().as_str(),
+            "2 MORE GROUPS NEEDED"
+        );
+        let mut ctx = EventCtx::new();
+        let share = RecoveryEvent {
+            group: 2,
+            entered: 1,
+        };
+        progress.event(&mut ctx, Event::Recovery(share));
+        assert_eq!(
+            progress.summary.inner().text().as_str(),
+            "1 MORE GROUP NEEDED"
+        );
+        progress.set_shares_entered(&mut ctx, 1, 2);
+        assert_eq!(
+            progress.summary.inner().text().as_str(),
+            "RECOVERY COMPLETE"
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use crate::{
+    time::{Duration, Instant},
+    ui::{
+        animation::{Easing, Timeline},
+        component::{Component, Event, EventCtx, Never},
+        display::{self, toif::Icon, Color},
+        geometry::{Offset, Point, Rect, CENTER},
+        util::animation_disabled,
+    },
+};
+
+use super::theme;
+
+/// Time it takes to draw the whole symbol.
+const DRAW_DURATION: Duration = Duration::from_millis(300);
+/// Width of the symbol strokes.
+const STROKE_THICKNESS: i16 = 4;
+
+/// Strokes of the check mark, relative to the center of the shape, drawn one
+/// after another.
+const CHECK: &[(Offset, Offset)] = &[
+    (Offset::new(-11, 1), Offset::new(-4, 8)),
+    (Offset::new(-4, 8), Offset::new(11, -7)),
+];
+
+/// Strokes of the cross, relative to the center of the shape.
+const CROSS: &[(Offset, Offset)] = &[
+    (Offset::new(-8, -8), Offset::new(8, 8)),
+    (Offset::new(8, -8), Offset::new(-8, 8)),
+];
+
+/// What is drawn on top of the shape.
+enum Symbol {
+    /// Drawn progressively, one stroke after another.
+    Strokes(&'static [(Offset, Offset)]),
+    /// Static icon blended over the shape.
+    Icon(Icon),
+}
+
+/// Colored shape with a check mark, a cross or a static icon on top of it.
+/// The check mark and the cross are drawn stroke by stroke once the component
+/// is attached, unless animations are disabled. When painted without being
+/// attached, e.g. by the bootloader, the symbol is complete right away.
+pub struct ResultIcon {
+    shape: Icon,
+    shape_color: Color,
+    symbol: Symbol,
+    center: Point,
+    /// Drawn length of the strokes, in points.
+    progress: i16,
+    timeline: Option<Timeline<i16>>,
+}
+
+impl ResultIcon {
+    fn new(shape: Icon, shape_color: Color, symbol: Symbol) -> Self {
+        let mut icon = Self {
+            shape,
+            shape_color,
+            symbol,
+            center: Point::zero(),
+            progress: 0,
+            timeline: None,
+        };
+        icon.progress = icon.total_length();
+        icon
+    }
+
+    /// Shape in `shape_color` with the static `icon` over it.
+    pub fn with_icon(shape: Icon, shape_color: Color, icon: Icon) -> Self {
+        Self::new(shape, shape_color, Symbol::Icon(icon))
+    }
+
+    pub fn success() -> Self {
+        Self::new(
+            Icon::new(theme::palette().success_shape),
+            theme::palette().success,
+            Symbol::Strokes(CHECK),
+        )
+    }
+
+    pub fn failure() -> Self {
+        Self::new(
+            Icon::new(theme::palette().error_shape),
+            theme::palette().error,
+            Symbol::Strokes(CROSS),
+        )
+    }
+
+    fn strokes(&self) -> &'static [(Offset, Offset)] {
+        match self.symbol {
+            Symbol::Strokes(strokes) => strokes,
+            Symbol::Icon(_) => &[],
+        }
+    }
+
+    /// Length of all the strokes together, in points.
+    fn total_length(&self) -> i16 {
+        total_length(self.strokes())
+    }
+
+    fn start(&mut self, ctx: &mut EventCtx) {
+        let total = self.total_length();
+        if total == 0 || animation_disabled() {
+            self.progress = total;
+            return;
+        }
+        self.progress = 0;
+        self.timeline = Some(
+            Timeline::new(0, total, DRAW_DURATION, Instant::now()).with_easing(Easing::EaseOut),
+        );
+        ctx.request_anim_frame();
+        ctx.request_paint();
+    }
+}
+
+impl Component for ResultIcon {
+    type Msg = Never;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.center = bounds.center();
+        Rect::snap(self.center, self.shape.toif.size(), CENTER)
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        if let Event::Attach = event {
+            self.start(ctx);
+        } else if let Some(progress) = self.timeline.as_mut().and_then(|t| t.event(ctx, event)) {
+            if progress != self.progress {
+                self.progress = progress;
+                ctx.request_paint();
+            }
+        }
+        None
+    }
+
+    fn paint(&mut self) {
+        if let Symbol::Icon(icon) = self.symbol {
+            let shape_top_left = self.shape.toif.size().snap(self.center, CENTER);
+            let icon_offset = icon.toif.size().snap(self.center, CENTER) - shape_top_left;
+            display::icon_over_icon(
+                None,
+                (self.shape, shape_top_left.into(), self.shape_color),
+                (icon, icon_offset, theme::FG),
+                theme::BG,
+            );
+            return;
+        }
+        self.shape
+            .draw(self.center, CENTER, self.shape_color, theme::BG);
+        let mut remaining = self.progress;
+        for &(from, to) in self.strokes() {
+            if remaining <= 0 {
+                break;
+            }
+            let start = self.center + from;
+            let end = self.center + to;
+            let drawn = display::line_point(start, end, remaining);
+            display::line(start, drawn, STROKE_THICKNESS, theme::FG);
+            remaining -= display::line_length(start, end);
+        }
+    }
+
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        sink(Rect::snap(self.center, self.shape.toif.size(), CENTER));
+    }
+}
+
+fn total_length(strokes: &[(Offset, Offset)]) -> i16 {
+    strokes
+        .iter()
+        .map(|&(from, to)| display::line_length(Point::zero() + from, Point::zero() + to))
+        .sum()
+}
+
+#[cfg(feature = "ui_debug")]
+impl crate::trace::Trace for ResultIcon {
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.open("ResultIcon");
+        t.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn result_icon_lengths() {
+        assert_eq!(total_length(CHECK), 22);
+        assert_eq!(total_length(CROSS), 32);
+        assert_eq!(total_length(&[]), 0);
+    }
+}
@@ -1,14 +1,31 @@
 use crate::ui::{
-    component::{Component, Event, EventCtx, Never},
-    display::toif::Icon,
-    geometry::{LinearPlacement, Offset, Rect, CENTER},
+    component::{Component, Event, EventCtx},
+    display::{self, toif::Icon},
+    event::TouchEvent,
+    geometry::{Axis, LinearPlacement, Offset, Point, Rect, CENTER},
 };
 
 use super::theme;
 
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum ScrollBarStyle {
+    /// One dot per page, the current one highlighted.
+    Dots,
+    /// Track with a thumb as long as the track divided by the page count.
+    Thumb,
+}
+
+pub enum ScrollBarMsg {
+    /// The scrollbar was tapped or dragged over the position of another page.
+    /// The pager is expected to go to the page and update `active_page`.
+    JumpTo(usize),
+}
+
 pub struct ScrollBar {
     area: Rect,
     layout: LinearPlacement,
+    style: ScrollBarStyle,
+    dragging: bool,
     pub page_count: usize,
     pub active_page: usize,
 }
@@ -20,16 +37,37 @@ impl ScrollBar {
     const MAX_DOTS: usize = 7;
     /// Center to center.
     const DOT_INTERVAL: i16 = 18;
+    /// Thickness of the track and the thumb.
+    const THUMB_WIDTH: i16 = 4;
+    /// Width of a vertical scrollbar at the right edge of the content.
+    pub const WIDTH: i16 = 15;
+    /// Space between the content and a vertical scrollbar.
+    pub const SPACE: i16 = 5;
 
     fn new(layout: LinearPlacement) -> Self {
         Self {
             area: Rect::zero(),
             layout: layout.align_at_center().with_spacing(Self::DOT_INTERVAL),
+            style: ScrollBarStyle::Dots,
+            dragging: false,
             page_count: 0,
             active_page: 0,
         }
     }
 
+    pub fn with_thumb(mut self) -> Self {
+        self.style = ScrollBarStyle::Thumb;
+        self
+    }
+
+    /// Split `area` into the content and a vertical scrollbar at its right
+    /// edge.
+    pub fn split_right(area: Rect) -> (Rect, Rect) {
+        let (content, scrollbar) = area.split_right(Self::SPACE + Self::WIDTH);
+        let (_space, scrollbar) = scrollbar.split_left(Self::SPACE);
+        (content, scrollbar)
+    }
+
     pub fn vertical() -> Self {
         Self::new(LinearPlacement::vertical())
     }
@@ -43,6 +81,15 @@ impl ScrollBar {
         self.active_page = active_page;
     }
 
+    /// Update the page count after the content has been laid out again,
+    /// staying on the active page if it still exists, so that e.g. closing an
+    /// overlay does not scroll back to the beginning. Returns the active page.
+    pub fn set_count_keep_active_page(&mut self, page_count: usize) -> usize {
+        self.page_count = page_count;
+        self.active_page = self.active_page.min(page_count.saturating_sub(1));
+        self.active_page
+    }
+
     pub fn has_pages(&self) -> bool {
         self.page_count > 1
     }
@@ -66,31 +113,133 @@ impl ScrollBar {
     pub fn go_to(&mut self, active_page: usize) {
         self.active_page = active_page;
     }
+
+    /// Whether a touch which started on the scrollbar is in progress. The
+    /// pager should not treat it as a swipe.
+    pub fn is_dragging(&self) -> bool {
+        self.dragging
+    }
 }
 
 impl Component for ScrollBar {
-    type Msg = Never;
+    type Msg = ScrollBarMsg;
 
-    fn event(&mut self, _ctx: &mut EventCtx, _event: Event) -> Option<Self::Msg> {
-        None
+    fn event(&mut self, _ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        if !self.has_pages() {
+            return None;
+        }
+        let pos = match event {
+            Event::Touch(TouchEvent::TouchStart(pos)) if self.area.contains(pos) => {
+                self.dragging = true;
+                pos
+            }
+            Event::Touch(TouchEvent::TouchMove(pos)) if self.dragging => pos,
+            Event::Touch(TouchEvent::TouchEnd(_) | TouchEvent::TouchCancel) => {
+                self.dragging = false;
+                return None;
+            }
+            _ => return None,
+        };
+        let page = self.page_at(pos);
+        (page != self.active_page).then_some(ScrollBarMsg::JumpTo(page))
     }
 
     fn paint(&mut self) {
-        fn dotsize(distance: usize, nhidden: usize) -> Icon {
-            match (nhidden.saturating_sub(distance)).min(2 - distance) {
-                0 => Icon::new(theme::DOT_INACTIVE),
-                1 => Icon::new(theme::DOT_INACTIVE_HALF),
-                _ => Icon::new(theme::DOT_INACTIVE_QUARTER),
+        match self.style {
+            ScrollBarStyle::Dots => self.paint_dots(),
+            ScrollBarStyle::Thumb => self.paint_thumb(),
+        }
+    }
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.area = bounds;
+        bounds
+    }
+
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        sink(self.area);
+    }
+}
+
+impl ScrollBar {
+    /// Page whose dot, or part of the track, is closest to `pos`.
+    fn page_at(&self, pos: Point) -> usize {
+        let axis = self.layout.axis;
+        let along = |p: Point| axis.main(p.x, p.y);
+        match self.style {
+            ScrollBarStyle::Thumb => {
+                let track = self.area.size().axis(axis).max(1);
+                let offset = (along(pos) - along(self.area.top_left())).clamp(0, track - 1);
+                offset as usize * self.page_count / track as usize
+            }
+            ScrollBarStyle::Dots => {
+                let (first_shown, num_shown) = self.shown_dots();
+                let first_dot = along(self.area.center())
+                    - Self::DOT_INTERVAL * (num_shown.saturating_sub(1) as i16) / 2;
+                let offset = (along(pos) - first_dot + Self::DOT_INTERVAL / 2).max(0);
+                let index = (offset / Self::DOT_INTERVAL) as usize;
+                first_shown + index.min(num_shown.saturating_sub(1))
             }
         }
+    }
 
-        // Number of visible dots.
+    /// Page index of the first dot, and the number of dots.
+    fn shown_dots(&self) -> (usize, usize) {
         let num_shown = self.page_count.min(Self::MAX_DOTS);
-        // Page indices corresponding to the first (and last) dot.
         let first_shown = self
             .active_page
             .saturating_sub(Self::MAX_DOTS / 2)
             .min(self.page_count.saturating_sub(Self::MAX_DOTS));
+        (first_shown, num_shown)
+    }
+
+    /// Start and length of the thumb within a track of length `track`.
+    fn thumb_span(&self, track: i16) -> (i16, i16) {
+        if self.page_count <= 1 {
+            return (0, track);
+        }
+        let count = self.page_count as i16;
+        let length = (track / count).max(2 * Self::THUMB_WIDTH).min(track);
+        let page = self.active_page.min(self.page_count - 1) as i16;
+        let start = (track - length) * page / (count - 1);
+        (start, length)
+    }
+
+    fn paint_thumb(&self) {
+        let axis = self.layout.axis;
+        let track_length = self.area.size().axis(axis);
+        let (start, length) = self.thumb_span(track_length);
+        let span = |start: i16, length: i16| {
+            let center = self.area.center();
+            let cross_start = axis.main(center.y, center.x) - Self::THUMB_WIDTH / 2;
+            let main_start = axis.main(self.area.x0, self.area.y0) + start;
+            let top_left = match axis {
+                Axis::Horizontal => Point::new(main_start, cross_start),
+                Axis::Vertical => Point::new(cross_start, main_start),
+            };
+            let size = match axis {
+                Axis::Horizontal => Offset::new(length, Self::THUMB_WIDTH),
+                Axis::Vertical => Offset::new(Self::THUMB_WIDTH, length),
+            };
+            Rect::from_top_left_and_size(top_left, size)
+        };
+        display::rect_fill(self.area, theme::BG);
+        display::rect_fill(span(0, track_length), theme::GREY_DARK);
+        display::rect_fill(span(start, length), theme::FG);
+    }
+
+    fn paint_dots(&self) {
+        fn dotsize(distance: usize, nhidden: usize) -> Icon {
+            match (nhidden.saturating_sub(distance)).min(2 - distance) {
+                0 => Icon::new(theme::DOT_INACTIVE),
+                1 => Icon::new(theme::DOT_INACTIVE_HALF),
+                _ => Icon::new(theme::DOT_INACTIVE_QUARTER),
+            }
+        }
+
+        // Page indices corresponding to the first (and last) dot, and the number of
+        // visible dots.
+        let (first_shown, num_shown) = self.shown_dots();
         let last_shown = first_shown + num_shown - 1;
 
         let mut cursor = self.area.center()
@@ -114,13 +263,56 @@ impl Component for ScrollBar {
             cursor = cursor + Offset::on_axis(self.layout.axis, Self::DOT_INTERVAL);
         }
     }
+}
 
-    fn place(&mut self, bounds: Rect) -> Rect {
-        self.area = bounds;
-        bounds
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scrollbar_thumb_span() {
+        let mut scrollbar = ScrollBar::vertical().with_thumb();
+        scrollbar.set_count_and_active_page(1, 0);
+        assert_eq!(scrollbar.thumb_span(100), (0, 100));
+
+        scrollbar.set_count_and_active_page(4, 0);
+        assert_eq!(scrollbar.thumb_span(100), (0, 25));
+        scrollbar.go_to(3);
+        assert_eq!(scrollbar.thumb_span(100), (75, 25));
+        scrollbar.go_to(1);
+        assert_eq!(scrollbar.thumb_span(100), (25, 25));
+
+        // The thumb does not get shorter than twice its width.
+        scrollbar.set_count_and_active_page(50, 49);
+        assert_eq!(scrollbar.thumb_span(100), (92, 8));
     }
 
-    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
-        sink(self.area);
+    #[test]
+    fn scrollbar_keep_active_page() {
+        let mut scrollbar = ScrollBar::vertical();
+        scrollbar.set_count_and_active_page(4, 2);
+        assert_eq!(scrollbar.set_count_keep_active_page(5), 2);
+        assert_eq!(scrollbar.set_count_keep_active_page(2), 1);
+        assert_eq!(scrollbar.set_count_keep_active_page(0), 0);
+    }
+
+    #[test]
+    fn scrollbar_page_at() {
+        let mut thumb = ScrollBar::vertical().with_thumb();
+        thumb.place(Rect::new(Point::new(0, 0), Point::new(10, 100)));
+        thumb.set_count_and_active_page(4, 0);
+        assert_eq!(thumb.page_at(Point::new(5, 0)), 0);
+        assert_eq!(thumb.page_at(Point::new(5, 30)), 1);
+        assert_eq!(thumb.page_at(Point::new(5, 99)), 3);
+        assert_eq!(thumb.page_at(Point::new(5, 200)), 3);
+
+        // Three dots centered at x = 82, 100 and 118.
+        let mut dots = ScrollBar::horizontal();
+        dots.place(Rect::new(Point::new(0, 0), Point::new(200, 10)));
+        dots.set_count_and_active_page(3, 0);
+        assert_eq!(dots.page_at(Point::new(0, 5)), 0);
+        assert_eq!(dots.page_at(Point::new(95, 5)), 1);
+        assert_eq!(dots.page_at(Point::new(115, 5)), 2);
+        assert_eq!(dots.page_at(Point::new(199, 5)), 2);
     }
 }
@@ -0,0 +1,221 @@
+use crate::ui::{
+    component::{Component, Event, EventCtx},
+    display::{self, toif::Icon, Font},
+    event::{StatusEvent, USBEvent},
+    geometry::{Offset, Point, Rect, CENTER},
+};
+
+use super::{
+    battery::{paint_battery, BatteryLevel},
+    theme,
+};
+
+const BATTERY_SIZE: Offset = Offset::new(22, 12);
+const BATTERY_TIP: Offset = Offset::new(2, 4);
+
+/// Value shown by one of the indicators, painted only when it changes.
+struct Indicator<T> {
+    value: T,
+    dirty: bool,
+}
+
+impl<T> Indicator<T> {
+    const fn new(value: T) -> Self {
+        Self { value, dirty: true }
+    }
+}
+
+impl<T: PartialEq> Indicator<T> {
+    /// Returns whether the value has changed.
+    fn set(&mut self, value: T) -> bool {
+        if self.value == value {
+            return false;
+        }
+        self.value = value;
+        self.dirty = true;
+        true
+    }
+}
+
+/// Strip at the top of the screen with the lock, USB and battery indicators,
+/// right-aligned in this order. Every indicator has a slot of its own, so a
+/// change of one of them repaints only its slot.
+pub struct StatusBar {
+    area: Rect,
+    locked: Indicator<bool>,
+    usb: Indicator<bool>,
+    /// `None` on devices without a battery.
+    battery: Indicator<Option<BatteryLevel>>,
+}
+
+impl StatusBar {
+    pub const HEIGHT: i16 = 20;
+    const LOCK_WIDTH: i16 = 20;
+    const USB_WIDTH: i16 = 32;
+    const BATTERY_WIDTH: i16 = 30;
+
+    pub const fn new(locked: bool, usb_connected: bool) -> Self {
+        Self {
+            area: Rect::zero(),
+            locked: Indicator::new(locked),
+            usb: Indicator::new(usb_connected),
+            battery: Indicator::new(None),
+        }
+    }
+
+    // Setters return whether the indicator has changed.
+
+    pub fn set_locked(&mut self, ctx: &mut EventCtx, locked: bool) -> bool {
+        let changed = self.locked.set(locked);
+        if changed {
+            ctx.request_paint();
+        }
+        changed
+    }
+
+    pub fn set_usb_connected(&mut self, ctx: &mut EventCtx, connected: bool) -> bool {
+        let changed = self.usb.set(connected);
+        if changed {
+            ctx.request_paint();
+        }
+        changed
+    }
+
+    pub fn set_battery(&mut self, ctx: &mut EventCtx, level: Option<BatteryLevel>) -> bool {
+        let changed = self.battery.set(level);
+        if changed {
+            ctx.request_paint();
+        }
+        changed
+    }
+
+    /// Paint all the indicators with the next `paint()`, e.g. after the
+    /// screen under the bar has been repainted.
+    pub fn invalidate(&mut self) {
+        self.locked.dirty = true;
+        self.usb.dirty = true;
+        self.battery.dirty = true;
+    }
+
+    /// Slots of the battery, USB and lock indicators.
+    fn slots(&self) -> (Rect, Rect, Rect) {
+        let (rest, battery) = self.area.split_right(Self::BATTERY_WIDTH);
+        let (rest, usb) = rest.split_right(Self::USB_WIDTH);
+        let (_, lock) = rest.split_right(Self::LOCK_WIDTH);
+        (battery, usb, lock)
+    }
+
+    fn paint_lock(slot: Rect, locked: bool) {
+        display::rect_fill(slot, theme::BG);
+        if locked {
+            Icon::new(theme::ICON_LOCK).draw(slot.center(), CENTER, theme::FG, theme::BG);
+        }
+    }
+
+    fn paint_usb(slot: Rect, connected: bool) {
+        display::rect_fill(slot, theme::BG);
+        let color = if connected {
+            theme::FG
+        } else {
+            theme::GREY_DARK
+        };
+        let font = Font::BOLD;
+        let baseline = Point::new(slot.center().x, slot.center().y + font.text_height() / 2);
+        display::text_center(baseline, "USB", font, color, theme::BG);
+    }
+
+    fn paint_battery(slot: Rect, level: Option<BatteryLevel>) {
+        display::rect_fill(slot, theme::BG);
+        if let Some(level) = level {
+            let body = Rect::from_center_and_size(
+                slot.center() - Offset::x(BATTERY_TIP.x / 2),
+                BATTERY_SIZE,
+            );
+            paint_battery(body, BATTERY_TIP, level, 1);
+        }
+    }
+}
+
+/// An indicator has changed and only the status bar needs a repaint.
+pub struct StatusChanged;
+
+impl Component for StatusBar {
+    type Msg = StatusChanged;
+
+    fn place(&mut self, bounds: Rect) -> Rect {
+        self.area = bounds.split_top(Self::HEIGHT).0;
+        self.invalidate();
+        self.area
+    }
+
+    fn event(&mut self, ctx: &mut EventCtx, event: Event) -> Option<Self::Msg> {
+        let changed = match event {
+            Event::USB(USBEvent::Connected(connected)) => self.set_usb_connected(ctx, connected),
+            Event::Status(StatusEvent::Locked(locked)) => self.set_locked(ctx, locked),
+            Event::Status(StatusEvent::Battery(soc)) => {
+                let charging = self.battery.value.map_or(false, |level| level.charging);
+                self.set_battery(ctx, Some(BatteryLevel::new(soc, charging)))
+            }
+            Event::Status(StatusEvent::Charging(charging)) => {
+                // Charging without a known charge is not shown.
+                match self.battery.value {
+                    Some(level) => {
+                        self.set_battery(ctx, Some(BatteryLevel::new(level.soc, charging)))
+                    }
+                    None => false,
+                }
+            }
+            Event::RequestPaint => {
+                self.invalidate();
+                false
+            }
+            _ => false,
+        };
+        changed.then_some(StatusChanged)
+    }
+
+    fn paint(&mut self) {
+        let (battery, usb, lock) = self.slots();
+        if core::mem::replace(&mut self.battery.dirty, false) {
+            Self::paint_battery(battery, self.battery.value);
+        }
+        if core::mem::replace(&mut self.usb.dirty, false) {
+            Self::paint_usb(usb, self.usb.value);
+        }
+        if core::mem::replace(&mut self.locked.dirty, false) {
+            Self::paint_lock(lock, self.locked.value);
+        }
+    }
+
+    fn bounds(&self, sink: &mut dyn FnMut(Rect)) {
+        sink(self.area)
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl crate::trace::Trace for StatusBar {
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        t.open("StatusBar");
+        t.field("locked", &(self.locked.value as usize));
+        t.field("usb", &(self.usb.value as usize));
+        if let Some(level) = self.battery.value {
+            t.field("battery", &(level.soc as usize));
+        }
+        t.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_indicator_changes() {
+        let mut indicator = Indicator::new(false);
+        indicator.dirty = false;
+        assert!(!indicator.set(false));
+        assert!(!indicator.dirty);
+        assert!(indicator.set(true));
+        assert!(indicator.dirty);
+    }
+}
@@ -120,6 +120,11 @@ impl Component for Swipe {
                     }
                 };
             }
+            (Event::Touch(TouchEvent::TouchCancel), Some(_)) => {
+                // Interaction was aborted, reset the position and the backlight.
+                self.origin.take();
+                self.backlight(0.0);
+            }
             (Event::Touch(TouchEvent::TouchEnd(pos)), Some(origin)) => {
                 // Touch interaction is over, reset the position.
                 self.origin.take();
@@ -3,10 +3,13 @@ use crate::ui::geometry::{Offset, Point, Rect};
 pub const WIDTH: i16 = 240;
 pub const HEIGHT: i16 = 240;
 pub const LINE_SPACE: i16 = 4;
+/// Display is monochromatic, see `Color::to_mono`.
+pub const MONO_DISPLAY: bool = false;
 pub const FONT_BPP: i16 = 4;
+/// Touches with a larger contact area (as reported by the FT6x06 controller)
+/// are rejected as a palm or cheek rather than a fingertip.
+pub const TOUCH_MAX_CONTACT_AREA: Option<u8> = Some(10);
 
-pub const LOADER_OUTER: f32 = 60_f32;
-pub const LOADER_INNER: f32 = 42_f32;
 pub const LOADER_ICON_MAX_SIZE: i16 = 64;
 
 pub const fn size() -> Offset {
@@ -0,0 +1,52 @@
+use heapless::Vec;
+
+use crate::ui::{
+    component::Event,
+    event::{KeyEvent, TouchEvent},
+    geometry::{Offset, Point},
+};
+
+use super::{constant, theme};
+
+/// Touch events emulating the key press. Arrows swipe in the opposite
+/// direction (i.e. scroll towards the arrow), `Enter` and `Back` tap the
+/// bottom right and bottom left button, respectively.
+pub fn key_events(key: KeyEvent) -> Vec<Event<'static>, 3> {
+    let mut events = Vec::new();
+    match key {
+        KeyEvent::Left => swipe(&mut events, Offset::x(SWIPE_DISTANCE)),
+        KeyEvent::Right => swipe(&mut events, Offset::x(-SWIPE_DISTANCE)),
+        KeyEvent::Up => swipe(&mut events, Offset::y(SWIPE_DISTANCE)),
+        KeyEvent::Down => swipe(&mut events, Offset::y(-SWIPE_DISTANCE)),
+        KeyEvent::Enter => tap(&mut events, button_center(true)),
+        KeyEvent::Back => tap(&mut events, button_center(false)),
+    }
+    events
+}
+
+/// Long enough to pass the threshold of `Swipe`.
+const SWIPE_DISTANCE: i16 = 60;
+
+fn swipe(events: &mut Vec<Event<'static>, 3>, offset: Offset) {
+    let origin = constant::screen().center();
+    let end = origin + offset;
+    let _ = events.push(Event::Touch(TouchEvent::TouchStart(origin)));
+    let _ = events.push(Event::Touch(TouchEvent::TouchMove(origin.center(end))));
+    let _ = events.push(Event::Touch(TouchEvent::TouchEnd(end)));
+}
+
+fn tap(events: &mut Vec<Event<'static>, 3>, point: Point) {
+    let _ = events.push(Event::Touch(TouchEvent::TouchStart(point)));
+    let _ = events.push(Event::Touch(TouchEvent::TouchEnd(point)));
+}
+
+/// Center of the area where the bottom buttons are usually placed.
+fn button_center(right: bool) -> Point {
+    let area = constant::screen().inset(theme::borders());
+    let x = if right {
+        area.x1 - area.width() / 3
+    } else {
+        area.x0 + area.width() / 6
+    };
+    Point::new(x, area.y1 - theme::BUTTON_HEIGHT / 2)
+}
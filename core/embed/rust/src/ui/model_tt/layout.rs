@@ -1,5 +1,6 @@
 use core::{cmp::Ordering, convert::TryInto};
 use cstr_core::cstr;
+use heapless::Vec;
 
 use crate::{
     error::Error,
@@ -14,47 +15,64 @@ use crate::{
         qstr::Qstr,
         util,
     },
+    time::Duration,
     ui::{
         component::{
+            animated_qr,
             base::ComponentExt,
-            image::BlendedImage,
             paginated::{PageMsg, Paginate},
             painter,
             placed::GridPlaced,
             text::{
                 paragraphs::{
-                    Checklist, Paragraph, ParagraphSource, ParagraphStrType, ParagraphVecLong,
-                    ParagraphVecShort, Paragraphs, VecExt,
+                    Checklist, Paragraph, ParagraphSource, ParagraphStrType, ParagraphVec,
+                    Paragraphs, VecExt,
                 },
-                TextStyle,
+                props::{PropsList, PropsStyle},
+                StyleToken, TextStyle,
             },
-            Border, Component, Empty, FormattedText, Timeout, TimeoutMsg,
+            AddressDisplay, AnimatedQr, Border, Component, Empty, FormattedText, Timeout,
+            TimeoutMsg,
         },
-        display::{tjpgd::jpeg_info, toif::Icon},
+        display::{identicon::Identicon, tjpgd::jpeg_info, toif::Icon},
         geometry,
         layout::{
             obj::{ComponentMsgObj, LayoutObj},
             result::{CANCELLED, CONFIRMED, INFO},
             util::{
-                iter_into_array, iter_into_objs, upy_disable_animation, upy_jpeg_info,
-                upy_jpeg_test, ConfirmBlob, PropsList,
+                iter_into_array, iter_into_objs, upy_disable_animation, upy_enable_auto_brightness,
+                upy_is_lookalike, upy_jpeg_info, upy_jpeg_test, upy_set_bold_text,
+                upy_set_reduced_motion, ConfirmBlob, ObjProps,
             },
         },
     },
 };
 
+#[cfg(feature = "ui_debug")]
+use crate::ui::layout::util::{upy_advance_clock, upy_set_manual_clock};
+
 use super::{
     component::{
-        Bip39Input, Button, ButtonMsg, ButtonStyleSheet, CancelConfirmMsg, CancelInfoConfirmMsg,
-        Dialog, DialogMsg, FidoConfirm, FidoMsg, Frame, HoldToConfirm, HoldToConfirmMsg,
-        Homescreen, HomescreenMsg, IconDialog, Lockscreen, MnemonicInput, MnemonicKeyboard,
-        MnemonicKeyboardMsg, NotificationFrame, NumberInputDialog, NumberInputDialogMsg,
-        PassphraseKeyboard, PassphraseKeyboardMsg, PinKeyboard, PinKeyboardMsg, Progress,
-        SelectWordCount, SelectWordCountMsg, SelectWordMsg, Slip39Input, SwipeHoldPage, SwipePage,
+        BatteryCharge, BatteryLevel, Bip39Input, Button, ButtonMsg, ButtonStyleSheet,
+        CancelConfirmMsg, CancelInfoConfirmMsg, Dialog, DialogMsg, FidoConfirm, FidoMsg, Frame,
+        GroupGrid, HoldToConfirm, HoldToConfirmMsg, Homescreen, HomescreenMsg, IconDialog,
+        Lockscreen, MnemonicInput, MnemonicKeyboard, MnemonicKeyboardMsg, NotificationFrame,
+        NumberInputDialog, NumberInputDialogMsg, PassphraseKeyboard, PassphraseKeyboardMsg,
+        PinKeyboard, PinKeyboardMsg, Progress, RecoveryProgress, ResultIcon, ResultPopup,
+        ResultPopupMsg, SelectWordCount, SelectWordCountMsg, SelectWordMsg, Slip39Input,
+        SwipeHoldPage, SwipePage,
     },
     theme,
 };
 
+/// Paragraphs of `confirm_action`, the action and its description.
+const ACTION_PARAGRAPHS: usize = 2;
+/// Items of `confirm_with_info`, which fit a single screen.
+const INFO_PARAGRAPHS: usize = 8;
+/// Paragraphs of the paginated screens built from a list passed from
+/// MicroPython, the rest is dropped.
+const LIST_PARAGRAPHS: usize = 32;
+
 impl TryFrom<CancelConfirmMsg> for Obj {
     type Error = Error;
 
@@ -140,6 +158,17 @@ where
     }
 }
 
+impl<T> ComponentMsgObj for ResultPopup<T>
+where
+    T: ParagraphStrType,
+{
+    fn msg_try_into_obj(&self, msg: Self::Msg) -> Result<Obj, Error> {
+        match msg {
+            ResultPopupMsg::Confirmed => Ok(CONFIRMED.as_obj()),
+        }
+    }
+}
+
 impl<T> ComponentMsgObj for HoldToConfirm<T>
 where
     T: ComponentMsgObj,
@@ -249,6 +278,32 @@ where
     }
 }
 
+impl ComponentMsgObj for BatteryCharge {
+    fn msg_try_into_obj(&self, msg: Self::Msg) -> Result<Obj, Error> {
+        match msg {
+            HomescreenMsg::Dismissed => Ok(CANCELLED.as_obj()),
+        }
+    }
+}
+
+impl ComponentMsgObj for AnimatedQr {
+    fn msg_try_into_obj(&self, _msg: Self::Msg) -> Result<Obj, Error> {
+        unreachable!()
+    }
+}
+
+impl ComponentMsgObj for GroupGrid {
+    fn msg_try_into_obj(&self, _msg: Self::Msg) -> Result<Obj, Error> {
+        unreachable!()
+    }
+}
+
+impl ComponentMsgObj for RecoveryProgress {
+    fn msg_try_into_obj(&self, _msg: Self::Msg) -> Result<Obj, Error> {
+        unreachable!()
+    }
+}
+
 impl<T> ComponentMsgObj for Paragraphs<T>
 where
     T: ParagraphSource,
@@ -331,6 +386,14 @@ where
     }
 }
 
+extern "C" fn upy_set_color_blind(enable: Obj) -> Obj {
+    let block = || {
+        theme::set_color_blind(enable.try_into()?);
+        Ok(Obj::const_none())
+    };
+    unsafe { util::try_or_raise(block) }
+}
+
 extern "C" fn new_confirm_action(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
         let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
@@ -349,15 +412,15 @@ extern "C" fn new_confirm_action(n_args: usize, args: *const Obj, kwargs: *mut M
         let paragraphs = {
             let action = action.unwrap_or_default();
             let description = description.unwrap_or_default();
-            let mut paragraphs = ParagraphVecShort::new();
+            let mut paragraphs = ParagraphVec::<_, ACTION_PARAGRAPHS>::new();
             if !reverse {
                 paragraphs
-                    .add(Paragraph::new(&theme::TEXT_BOLD, action))
-                    .add(Paragraph::new(&theme::TEXT_NORMAL, description));
+                    .add(Paragraph::new(theme::text(StyleToken::Title), action))
+                    .add(Paragraph::new(theme::text(StyleToken::Body), description));
             } else {
                 paragraphs
-                    .add(Paragraph::new(&theme::TEXT_NORMAL, description))
-                    .add(Paragraph::new(&theme::TEXT_BOLD, action));
+                    .add(Paragraph::new(theme::text(StyleToken::Body), description))
+                    .add(Paragraph::new(theme::text(StyleToken::Title), action));
             }
             paragraphs.into_paragraphs()
         };
@@ -395,9 +458,9 @@ fn confirm_blob(
         description: description.unwrap_or_else(StrBuffer::empty),
         extra: extra.unwrap_or_else(StrBuffer::empty),
         data: data.try_into()?,
-        description_font: &theme::TEXT_NORMAL,
-        extra_font: &theme::TEXT_BOLD,
-        data_font: &theme::TEXT_MONO,
+        description_font: theme::text(StyleToken::Body),
+        extra_font: theme::text(StyleToken::Title),
+        data_font: theme::text(StyleToken::Mono),
     }
     .into_paragraphs();
 
@@ -448,18 +511,42 @@ extern "C" fn new_confirm_blob(n_args: usize, args: *const Obj, kwargs: *mut Map
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
 
+extern "C" fn new_confirm_address(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
+        let address: StrBuffer = kwargs.get(Qstr::MP_QSTR_address)?.try_into()?;
+        let verb_cancel: Option<StrBuffer> = kwargs
+            .get(Qstr::MP_QSTR_verb_cancel)
+            .unwrap_or_else(|_| Obj::const_none())
+            .try_into_option()?;
+        let identicon: Option<StrBuffer> = kwargs
+            .get(Qstr::MP_QSTR_identicon)
+            .unwrap_or_else(|_| Obj::const_none())
+            .try_into_option()?;
+
+        let mut address =
+            AddressDisplay::new(address, *theme::text(StyleToken::Mono), theme::GREY_LIGHT);
+        if let Some(seed) = identicon {
+            address = address.with_identicon(Identicon::new(seed.as_ref()));
+        }
+        let buttons = Button::cancel_confirm_text(verb_cancel, "CONFIRM".into());
+        let obj = LayoutObj::new(Frame::left_aligned(
+            theme::label_title(),
+            title,
+            SwipePage::new(address, buttons, theme::BG),
+        ))?;
+        Ok(obj.into())
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
 extern "C" fn new_confirm_properties(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
         let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
         let hold: bool = kwargs.get_or(Qstr::MP_QSTR_hold, false)?;
         let items: Obj = kwargs.get(Qstr::MP_QSTR_items)?;
 
-        let paragraphs = PropsList::new(
-            items,
-            &theme::TEXT_BOLD,
-            &theme::TEXT_NORMAL,
-            &theme::TEXT_MONO,
-        )?;
+        let paragraphs = PropsList::new(ObjProps::new(items)?, PropsStyle::default());
         let obj = if hold {
             LayoutObj::new(Frame::left_aligned(
                 theme::label_title(),
@@ -516,11 +603,11 @@ extern "C" fn new_confirm_reset_device(n_args: usize, args: *const Obj, kwargs:
         let button: StrBuffer = kwargs.get(Qstr::MP_QSTR_button)?.try_into()?;
 
         let paragraphs = Paragraphs::new(Paragraph::new(
-            &theme::TEXT_NORMAL,
+            theme::text(StyleToken::Body),
             StrBuffer::from("By continuing you agree\nto Trezor Company's\nterms and conditions."),
         ));
         let url = FormattedText::new(
-            theme::TEXT_NORMAL,
+            *theme::text(StyleToken::Body),
             theme::FORMATTED,
             "More info at {demibold}trezor.io/tos",
         );
@@ -579,6 +666,46 @@ extern "C" fn new_show_qr(n_args: usize, args: *const Obj, kwargs: *mut Map) ->
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
 
+extern "C" fn new_show_animated_qr(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
+        let frames: Obj = kwargs.get(Qstr::MP_QSTR_frames)?;
+        let time_ms: u32 = kwargs.get_or(Qstr::MP_QSTR_time_ms, 0)?;
+        let button: StrBuffer = kwargs.get_or(Qstr::MP_QSTR_button, "DONE".into())?;
+
+        let mut texts: Vec<StrBuffer, { animated_qr::MAX_FRAMES }> = Vec::new();
+        let mut iter_buf = IterBuf::new();
+        let iter = Iter::try_from_obj_with_buf(frames, &mut iter_buf)?;
+        for frame in iter {
+            texts
+                .push(frame.try_into()?)
+                .map_err(|_| Error::OutOfRange)?;
+        }
+        let qr = AnimatedQr::new(texts)?;
+        let qr = if time_ms > 0 {
+            qr.with_interval(Duration::from_millis(time_ms))
+        } else {
+            qr
+        };
+
+        let obj = LayoutObj::new(
+            Frame::left_aligned(
+                theme::label_title(),
+                title,
+                Dialog::new(
+                    qr,
+                    theme::button_bar(Button::with_text(button).map(|msg| {
+                        (matches!(msg, ButtonMsg::Clicked)).then(|| CancelConfirmMsg::Confirmed)
+                    })),
+                ),
+            )
+            .with_border(theme::borders()),
+        )?;
+        Ok(obj.into())
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
 extern "C" fn new_confirm_value(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
         let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
@@ -603,10 +730,13 @@ extern "C" fn new_confirm_joint_total(n_args: usize, args: *const Obj, kwargs: *
         let total_amount: StrBuffer = kwargs.get(Qstr::MP_QSTR_total_amount)?.try_into()?;
 
         let paragraphs = Paragraphs::new([
-            Paragraph::new(&theme::TEXT_NORMAL, "You are contributing:".into()),
-            Paragraph::new(&theme::TEXT_MONO, spending_amount),
-            Paragraph::new(&theme::TEXT_NORMAL, "To the total amount:".into()),
-            Paragraph::new(&theme::TEXT_MONO, total_amount),
+            Paragraph::new(
+                theme::text(StyleToken::Body),
+                "You are contributing:".into(),
+            ),
+            Paragraph::new(theme::text(StyleToken::Mono), spending_amount),
+            Paragraph::new(theme::text(StyleToken::Body), "To the total amount:".into()),
+            Paragraph::new(theme::text(StyleToken::Mono), total_amount),
         ]);
 
         let obj = LayoutObj::new(Frame::left_aligned(
@@ -633,12 +763,12 @@ extern "C" fn new_confirm_modify_output(n_args: usize, args: *const Obj, kwargs:
         };
 
         let paragraphs = Paragraphs::new([
-            Paragraph::new(&theme::TEXT_NORMAL, "Address:".into()),
-            Paragraph::new(&theme::TEXT_MONO, address).break_after(),
-            Paragraph::new(&theme::TEXT_NORMAL, description.into()),
-            Paragraph::new(&theme::TEXT_MONO, amount_change),
-            Paragraph::new(&theme::TEXT_NORMAL, "New amount:".into()),
-            Paragraph::new(&theme::TEXT_MONO, amount_new),
+            Paragraph::new(theme::text(StyleToken::Body), "Address:".into()),
+            Paragraph::new(theme::text(StyleToken::Mono), address).break_after(),
+            Paragraph::new(theme::text(StyleToken::Body), description.into()),
+            Paragraph::new(theme::text(StyleToken::Mono), amount_change),
+            Paragraph::new(theme::text(StyleToken::Body), "New amount:".into()),
+            Paragraph::new(theme::text(StyleToken::Mono), amount_new),
         ]);
 
         let buttons = Button::cancel_confirm(
@@ -670,10 +800,10 @@ extern "C" fn new_confirm_modify_fee(n_args: usize, args: *const Obj, kwargs: *m
         };
 
         let paragraphs = Paragraphs::new([
-            Paragraph::new(&theme::TEXT_NORMAL, description.into()),
-            Paragraph::new(&theme::TEXT_MONO, change),
-            Paragraph::new(&theme::TEXT_NORMAL, "\nTransaction fee:".into()),
-            Paragraph::new(&theme::TEXT_MONO, total_fee_new),
+            Paragraph::new(theme::text(StyleToken::Body), description.into()),
+            Paragraph::new(theme::text(StyleToken::Mono), change),
+            Paragraph::new(theme::text(StyleToken::Body), "\nTransaction fee:".into()),
+            Paragraph::new(theme::text(StyleToken::Mono), total_fee_new),
         ]);
 
         let buttons = Button::cancel_confirm(
@@ -694,7 +824,7 @@ extern "C" fn new_confirm_modify_fee(n_args: usize, args: *const Obj, kwargs: *m
 
 fn new_show_modal(
     kwargs: &Map,
-    icon: BlendedImage,
+    icon: ResultIcon,
     button_style: ButtonStyleSheet,
 ) -> Result<Obj, Error> {
     let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
@@ -711,14 +841,9 @@ fn new_show_modal(
     } else if no_buttons && time_ms > 0 {
         // Timeout, no buttons.
         LayoutObj::new(
-            IconDialog::new(
-                icon,
-                title,
-                Timeout::new(time_ms).map(|msg| {
-                    (matches!(msg, TimeoutMsg::TimedOut)).then(|| CancelConfirmMsg::Confirmed)
-                }),
-            )
-            .with_description(description),
+            ResultPopup::new(icon, title)
+                .with_message(description)
+                .with_timeout(time_ms),
         )?
         .into()
     } else if allow_cancel {
@@ -756,13 +881,7 @@ fn new_show_modal(
 
 extern "C" fn new_show_error(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let icon = BlendedImage::new(
-            Icon::new(theme::IMAGE_BG_CIRCLE),
-            Icon::new(theme::IMAGE_FG_ERROR),
-            theme::ERROR_COLOR,
-            theme::FG,
-            theme::BG,
-        );
+        let icon = ResultIcon::failure();
         new_show_modal(kwargs, icon, theme::button_default())
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
@@ -803,12 +922,10 @@ extern "C" fn new_confirm_fido(n_args: usize, args: *const Obj, kwargs: *mut Map
 
 extern "C" fn new_show_warning(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let icon = BlendedImage::new(
+        let icon = ResultIcon::with_icon(
             Icon::new(theme::IMAGE_BG_TRIANGLE),
-            Icon::new(theme::IMAGE_FG_WARN),
             theme::WARN_COLOR,
-            theme::FG,
-            theme::BG,
+            Icon::new(theme::IMAGE_FG_WARN),
         );
         new_show_modal(kwargs, icon, theme::button_reset())
     };
@@ -817,13 +934,7 @@ extern "C" fn new_show_warning(n_args: usize, args: *const Obj, kwargs: *mut Map
 
 extern "C" fn new_show_success(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let icon = BlendedImage::new(
-            Icon::new(theme::IMAGE_BG_CIRCLE),
-            Icon::new(theme::IMAGE_FG_SUCCESS),
-            theme::SUCCESS_COLOR,
-            theme::FG,
-            theme::BG,
-        );
+        let icon = ResultIcon::success();
         new_show_modal(kwargs, icon, theme::button_confirm())
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
@@ -831,12 +942,10 @@ extern "C" fn new_show_success(n_args: usize, args: *const Obj, kwargs: *mut Map
 
 extern "C" fn new_show_info(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
-        let icon = BlendedImage::new(
+        let icon = ResultIcon::with_icon(
             Icon::new(theme::IMAGE_BG_CIRCLE),
-            Icon::new(theme::IMAGE_FG_INFO),
             theme::INFO_COLOR,
-            theme::FG,
-            theme::BG,
+            Icon::new(theme::IMAGE_FG_INFO),
         );
         new_show_modal(kwargs, icon, theme::button_info())
     };
@@ -855,7 +964,7 @@ extern "C" fn new_show_simple(n_args: usize, args: *const Obj, kwargs: *mut Map)
                 theme::label_title(),
                 t,
                 Dialog::new(
-                    Paragraphs::new([Paragraph::new(&theme::TEXT_NORMAL, description)]),
+                    Paragraphs::new([Paragraph::new(theme::text(StyleToken::Body), description)]),
                     theme::button_bar(Button::with_text(button).map(|msg| {
                         (matches!(msg, ButtonMsg::Clicked)).then(|| CancelConfirmMsg::Confirmed)
                     })),
@@ -866,7 +975,7 @@ extern "C" fn new_show_simple(n_args: usize, args: *const Obj, kwargs: *mut Map)
             LayoutObj::new(Border::new(
                 theme::borders(),
                 Dialog::new(
-                    Paragraphs::new([Paragraph::new(&theme::TEXT_NORMAL, description)]),
+                    Paragraphs::new([Paragraph::new(theme::text(StyleToken::Body), description)]),
                     theme::button_bar(Button::with_text(button).map(|msg| {
                         (matches!(msg, ButtonMsg::Clicked)).then(|| CancelConfirmMsg::Confirmed)
                     })),
@@ -887,7 +996,7 @@ extern "C" fn new_confirm_with_info(n_args: usize, args: *const Obj, kwargs: *mu
         let info_button: StrBuffer = kwargs.get(Qstr::MP_QSTR_info_button)?.try_into()?;
         let items: Obj = kwargs.get(Qstr::MP_QSTR_items)?;
 
-        let mut paragraphs = ParagraphVecShort::new();
+        let mut paragraphs = ParagraphVec::<_, INFO_PARAGRAPHS>::new();
 
         let mut iter_buf = IterBuf::new();
         let iter = Iter::try_from_obj_with_buf(items, &mut iter_buf)?;
@@ -922,7 +1031,7 @@ extern "C" fn new_confirm_more(n_args: usize, args: *const Obj, kwargs: *mut Map
         let button: StrBuffer = kwargs.get(Qstr::MP_QSTR_button)?.try_into()?;
         let items: Obj = kwargs.get(Qstr::MP_QSTR_items)?;
 
-        let mut paragraphs = ParagraphVecLong::new();
+        let mut paragraphs = ParagraphVec::<_, LIST_PARAGRAPHS>::new();
 
         let mut iter_buf = IterBuf::new();
         let iter = Iter::try_from_obj_with_buf(items, &mut iter_buf)?;
@@ -954,10 +1063,10 @@ extern "C" fn new_confirm_coinjoin(n_args: usize, args: *const Obj, kwargs: *mut
         let max_feerate: StrBuffer = kwargs.get(Qstr::MP_QSTR_max_feerate)?.try_into()?;
 
         let paragraphs = Paragraphs::new([
-            Paragraph::new(&theme::TEXT_NORMAL, "Maximum rounds:".into()),
-            Paragraph::new(&theme::TEXT_BOLD, max_rounds),
-            Paragraph::new(&theme::TEXT_NORMAL, "Maximum mining fee:".into()),
-            Paragraph::new(&theme::TEXT_BOLD, max_feerate),
+            Paragraph::new(theme::text(StyleToken::Body), "Maximum rounds:".into()),
+            Paragraph::new(theme::text(StyleToken::Emphasis), max_rounds),
+            Paragraph::new(theme::text(StyleToken::Body), "Maximum mining fee:".into()),
+            Paragraph::new(theme::text(StyleToken::Emphasis), max_feerate),
         ]);
 
         let obj = LayoutObj::new(Frame::left_aligned(
@@ -982,6 +1091,7 @@ extern "C" fn new_request_pin(n_args: usize, args: *const Obj, kwargs: *mut Map)
             None
         };
         let obj = LayoutObj::new(PinKeyboard::new(prompt, subprompt, warning, allow_cancel))?;
+        obj.mark_sensitive();
         Ok(obj.into())
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
@@ -992,6 +1102,7 @@ extern "C" fn new_request_passphrase(n_args: usize, args: *const Obj, kwargs: *m
         let _prompt: StrBuffer = kwargs.get(Qstr::MP_QSTR_prompt)?.try_into()?;
         let _max_len: u32 = kwargs.get(Qstr::MP_QSTR_max_len)?.try_into()?;
         let obj = LayoutObj::new(PassphraseKeyboard::new())?;
+        obj.mark_sensitive();
         Ok(obj.into())
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
@@ -1001,6 +1112,7 @@ extern "C" fn new_request_bip39(n_args: usize, args: *const Obj, kwargs: *mut Ma
     let block = move |_args: &[Obj], kwargs: &Map| {
         let prompt: StrBuffer = kwargs.get(Qstr::MP_QSTR_prompt)?.try_into()?;
         let obj = LayoutObj::new(MnemonicKeyboard::new(Bip39Input::new(), prompt))?;
+        obj.mark_sensitive();
         Ok(obj.into())
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
@@ -1010,6 +1122,7 @@ extern "C" fn new_request_slip39(n_args: usize, args: *const Obj, kwargs: *mut M
     let block = move |_args: &[Obj], kwargs: &Map| {
         let prompt: StrBuffer = kwargs.get(Qstr::MP_QSTR_prompt)?.try_into()?;
         let obj = LayoutObj::new(MnemonicKeyboard::new(Slip39Input::new(), prompt))?;
+        obj.mark_sensitive();
         Ok(obj.into())
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
@@ -1022,7 +1135,8 @@ extern "C" fn new_select_word(n_args: usize, args: *const Obj, kwargs: *mut Map)
         let words_iterable: Obj = kwargs.get(Qstr::MP_QSTR_words)?;
         let words: [StrBuffer; 3] = iter_into_array(words_iterable)?;
 
-        let paragraphs = Paragraphs::new([Paragraph::new(&theme::TEXT_NORMAL, description)]);
+        let paragraphs =
+            Paragraphs::new([Paragraph::new(theme::text(StyleToken::Body), description)]);
         let buttons = Button::select_word(words);
 
         let obj = LayoutObj::new(Frame::left_aligned(
@@ -1030,6 +1144,7 @@ extern "C" fn new_select_word(n_args: usize, args: *const Obj, kwargs: *mut Map)
             title,
             SwipePage::new(paragraphs, buttons, theme::BG),
         ))?;
+        obj.mark_sensitive();
         Ok(obj.into())
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
@@ -1040,12 +1155,12 @@ extern "C" fn new_show_share_words(n_args: usize, args: *const Obj, kwargs: *mut
         let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
         let pages: Obj = kwargs.get(Qstr::MP_QSTR_pages)?;
 
-        let mut paragraphs = ParagraphVecLong::new();
+        let mut paragraphs = ParagraphVec::<_, LIST_PARAGRAPHS>::new();
         let mut iter_buf = IterBuf::new();
         let iter = Iter::try_from_obj_with_buf(pages, &mut iter_buf)?;
         for page in iter {
             let text: StrBuffer = page.try_into()?;
-            paragraphs.add(Paragraph::new(&theme::TEXT_MONO, text).break_after());
+            paragraphs.add(Paragraph::new(theme::text(StyleToken::Mono), text).break_after());
         }
 
         let obj = LayoutObj::new(Frame::left_aligned(
@@ -1053,6 +1168,7 @@ extern "C" fn new_show_share_words(n_args: usize, args: *const Obj, kwargs: *mut
             title,
             SwipeHoldPage::without_cancel(paragraphs.into_paragraphs(), theme::BG),
         ))?;
+        obj.mark_sensitive();
         Ok(obj.into())
     };
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
@@ -1065,6 +1181,7 @@ extern "C" fn new_request_number(n_args: usize, args: *const Obj, kwargs: *mut M
         let max_count: u32 = kwargs.get(Qstr::MP_QSTR_max_count)?.try_into()?;
         let count: u32 = kwargs.get(Qstr::MP_QSTR_count)?.try_into()?;
         let description_callback: Obj = kwargs.get(Qstr::MP_QSTR_description)?;
+        let keypad: bool = kwargs.get_or(Qstr::MP_QSTR_keypad, false)?;
         assert!(description_callback != Obj::const_none());
 
         let callback = move |i: u32| {
@@ -1076,13 +1193,11 @@ extern "C" fn new_request_number(n_args: usize, args: *const Obj, kwargs: *mut M
             .unwrap()
         };
 
+        let dialog = NumberInputDialog::new(min_count, max_count, count, callback);
+        let dialog = if keypad { dialog.with_keypad() } else { dialog };
+
         let obj = LayoutObj::new(
-            Frame::left_aligned(
-                theme::label_title(),
-                title,
-                NumberInputDialog::new(min_count, max_count, count, callback),
-            )
-            .with_border(theme::borders()),
+            Frame::left_aligned(theme::label_title(), title, dialog).with_border(theme::borders()),
         )?;
         Ok(obj.into())
     };
@@ -1097,7 +1212,7 @@ extern "C" fn new_show_checklist(n_args: usize, args: *const Obj, kwargs: *mut M
         let items: Obj = kwargs.get(Qstr::MP_QSTR_items)?;
 
         let mut iter_buf = IterBuf::new();
-        let mut paragraphs = ParagraphVecLong::new();
+        let mut paragraphs = ParagraphVec::<_, LIST_PARAGRAPHS>::new();
         let iter = Iter::try_from_obj_with_buf(items, &mut iter_buf)?;
         for (i, item) in iter.enumerate() {
             let style = match i.cmp(&active) {
@@ -1155,8 +1270,8 @@ extern "C" fn new_confirm_recovery(n_args: usize, args: *const Obj, kwargs: *mut
         let info_button: bool = kwargs.get_or(Qstr::MP_QSTR_info_button, false).unwrap();
 
         let paragraphs = Paragraphs::new([
-            Paragraph::new(&theme::TEXT_BOLD, title).centered(),
-            Paragraph::new(&theme::TEXT_NORMAL_OFF_WHITE, description).centered(),
+            Paragraph::new(theme::text(StyleToken::Title), title).centered(),
+            Paragraph::new(theme::text(StyleToken::Secondary), description).centered(),
         ])
         .with_spacing(theme::RECOVERY_SPACING);
 
@@ -1198,7 +1313,11 @@ extern "C" fn new_select_word_count(n_args: usize, args: *const Obj, kwargs: *mu
         };
 
         let paragraphs = Paragraphs::new(
-            Paragraph::new(&theme::TEXT_BOLD, StrBuffer::from("Number of words?")).centered(),
+            Paragraph::new(
+                theme::text(StyleToken::Title),
+                StrBuffer::from("Number of words?"),
+            )
+            .centered(),
         );
 
         let obj = LayoutObj::new(
@@ -1238,14 +1357,14 @@ extern "C" fn new_show_remaining_shares(n_args: usize, args: *const Obj, kwargs:
     let block = move |_args: &[Obj], kwargs: &Map| {
         let pages_iterable: Obj = kwargs.get(Qstr::MP_QSTR_pages)?;
 
-        let mut paragraphs = ParagraphVecLong::new();
+        let mut paragraphs = ParagraphVec::<_, LIST_PARAGRAPHS>::new();
         let mut iter_buf = IterBuf::new();
         let iter = Iter::try_from_obj_with_buf(pages_iterable, &mut iter_buf)?;
         for page in iter {
             let [title, description]: [StrBuffer; 2] = iter_into_array(page)?;
             paragraphs
-                .add(Paragraph::new(&theme::TEXT_BOLD, title))
-                .add(Paragraph::new(&theme::TEXT_NORMAL, description).break_after());
+                .add(Paragraph::new(theme::text(StyleToken::Title), title))
+                .add(Paragraph::new(theme::text(StyleToken::Body), description).break_after());
         }
 
         let obj = LayoutObj::new(Frame::left_aligned(
@@ -1264,6 +1383,75 @@ extern "C" fn new_show_remaining_shares(n_args: usize, args: *const Obj, kwargs:
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
 
+/// Grid of `(entered, threshold)` pairs of the groups in `groups_iterable`.
+fn group_grid(group_threshold: u8, groups_iterable: Obj) -> Result<GroupGrid, Error> {
+    let mut grid = GroupGrid::new(group_threshold);
+    let mut iter_buf = IterBuf::new();
+    let iter = Iter::try_from_obj_with_buf(groups_iterable, &mut iter_buf)?;
+    for group in iter {
+        let [entered, threshold]: [Obj; 2] = iter_into_array(group)?;
+        grid = grid.with_group(entered.try_into()?, threshold.try_into()?);
+    }
+    Ok(grid)
+}
+
+extern "C" fn new_show_group_grid(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
+        let group_threshold: u8 = kwargs.get(Qstr::MP_QSTR_group_threshold)?.try_into()?;
+        let groups_iterable: Obj = kwargs.get(Qstr::MP_QSTR_groups)?;
+        let button: StrBuffer = kwargs.get_or(Qstr::MP_QSTR_button, "CONTINUE".into())?;
+
+        let grid = group_grid(group_threshold, groups_iterable)?;
+
+        let obj = LayoutObj::new(
+            Frame::left_aligned(
+                theme::label_title(),
+                title,
+                Dialog::new(
+                    grid,
+                    theme::button_bar(Button::with_text(button).map(|msg| {
+                        (matches!(msg, ButtonMsg::Clicked)).then(|| CancelConfirmMsg::Confirmed)
+                    })),
+                ),
+            )
+            .with_border(theme::borders()),
+        )?;
+        Ok(obj.into())
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
+extern "C" fn new_show_recovery_progress(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
+        let group_threshold: u8 = kwargs.get(Qstr::MP_QSTR_group_threshold)?.try_into()?;
+        let groups_iterable: Obj = kwargs.get(Qstr::MP_QSTR_groups)?;
+        let words_entered: u8 = kwargs.get_or(Qstr::MP_QSTR_words_entered, 0)?;
+        let word_count: u8 = kwargs.get_or(Qstr::MP_QSTR_word_count, 0)?;
+        let button: StrBuffer = kwargs.get_or(Qstr::MP_QSTR_button, "CONTINUE".into())?;
+
+        let progress = RecoveryProgress::new(group_grid(group_threshold, groups_iterable)?)
+            .with_words(words_entered, word_count);
+
+        let obj = LayoutObj::new(
+            Frame::left_aligned(
+                theme::label_title(),
+                title,
+                Dialog::new(
+                    progress,
+                    theme::button_bar(Button::with_text(button).map(|msg| {
+                        (matches!(msg, ButtonMsg::Clicked)).then(|| CancelConfirmMsg::Confirmed)
+                    })),
+                ),
+            )
+            .with_border(theme::borders()),
+        )?;
+        Ok(obj.into())
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
 extern "C" fn new_show_progress(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
         let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
@@ -1318,6 +1506,17 @@ extern "C" fn new_show_lockscreen(n_args: usize, args: *const Obj, kwargs: *mut
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
 
+extern "C" fn new_show_battery_charge(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let soc: u8 = kwargs.get(Qstr::MP_QSTR_soc)?.try_into()?;
+        let charging: bool = kwargs.get_or(Qstr::MP_QSTR_charging, false)?;
+
+        let obj = LayoutObj::new(BatteryCharge::new(BatteryLevel::new(soc, charging)))?;
+        Ok(obj.into())
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
 extern "C" fn new_show_busyscreen(n_args: usize, args: *const Obj, kwargs: *mut Map) -> Obj {
     let block = move |_args: &[Obj], kwargs: &Map| {
         let title: StrBuffer = kwargs.get(Qstr::MP_QSTR_title)?.try_into()?;
@@ -1329,7 +1528,9 @@ extern "C" fn new_show_busyscreen(n_args: usize, args: *const Obj, kwargs: *mut
             theme::label_title(),
             title,
             Dialog::new(
-                Paragraphs::new(Paragraph::new(&theme::TEXT_NORMAL, description).centered()),
+                Paragraphs::new(
+                    Paragraph::new(theme::text(StyleToken::Body), description).centered(),
+                ),
                 Timeout::new(time_ms).map(|msg| {
                     (matches!(msg, TimeoutMsg::TimedOut)).then(|| CancelConfirmMsg::Cancelled)
                 }),
@@ -1343,6 +1544,28 @@ extern "C" fn new_show_busyscreen(n_args: usize, args: *const Obj, kwargs: *mut
     unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
 }
 
+#[cfg(feature = "ui_debug")]
+extern "C" fn upy_bootloader_screens(_n_args: usize, _args: *const Obj) -> Obj {
+    let block = || Ok(List::from_iter(super::bootloader::debug::SCREENS.into_iter())?.into());
+    unsafe { util::try_or_raise(block) }
+}
+
+#[cfg(feature = "ui_debug")]
+extern "C" fn new_render_bootloader_screen(
+    n_args: usize,
+    args: *const Obj,
+    kwargs: *mut Map,
+) -> Obj {
+    let block = move |_args: &[Obj], kwargs: &Map| {
+        let screen: StrBuffer = kwargs.get(Qstr::MP_QSTR_screen)?.try_into()?;
+        let trace: Obj = kwargs.get(Qstr::MP_QSTR_trace)?;
+
+        let mut tracer = crate::ui::layout::obj::CallbackTracer(trace);
+        Ok(super::bootloader::debug::render(screen.as_ref(), &mut tracer).into())
+    };
+    unsafe { util::try_with_args_and_kwargs(n_args, args, kwargs, block) }
+}
+
 #[no_mangle]
 pub static mp_module_trezorui2: Module = obj_module! {
     Qstr::MP_QSTR___name__ => Qstr::MP_QSTR_trezorui2.to_obj(),
@@ -1360,6 +1583,39 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     """Disable animations, debug builds only."""
     Qstr::MP_QSTR_disable_animation => obj_fn_1!(upy_disable_animation).as_obj(),
 
+    /// def set_color_blind(enable: bool) -> None:
+    ///     """Use the color-blind friendly palette in the layouts created from now on."""
+    Qstr::MP_QSTR_set_color_blind => obj_fn_1!(upy_set_color_blind).as_obj(),
+
+    /// def set_reduced_motion(enable: bool) -> None:
+    ///     """Skip animations and transitions to their end states."""
+    Qstr::MP_QSTR_set_reduced_motion => obj_fn_1!(upy_set_reduced_motion).as_obj(),
+
+    /// def set_bold_text(enable: bool) -> None:
+    ///     """Use heavier fonts in the layouts created from now on."""
+    Qstr::MP_QSTR_set_bold_text => obj_fn_1!(upy_set_bold_text).as_obj(),
+
+    /// def enable_auto_brightness(min: int, max: int) -> None:
+    ///     """Adjust the backlight between `min` and `max` according to the ambient
+    ///     light, if the device has a sensor."""
+    Qstr::MP_QSTR_enable_auto_brightness => obj_fn_2!(upy_enable_auto_brightness).as_obj(),
+
+    /// def is_lookalike(text: str) -> bool:
+    ///     """Whether the name could be mistaken for a different one, e.g. when
+    ///     spelled with Cyrillic letters resembling the Latin ones."""
+    Qstr::MP_QSTR_is_lookalike => obj_fn_1!(upy_is_lookalike).as_obj(),
+
+    /// def set_manual_clock(enable: bool) -> None:
+    ///     """Stop the animation clock, to be stepped by `advance_clock`. Debug
+    ///     builds only."""
+    #[cfg(feature = "ui_debug")]
+    Qstr::MP_QSTR_set_manual_clock => obj_fn_1!(upy_set_manual_clock).as_obj(),
+
+    /// def advance_clock(millis: int) -> None:
+    ///     """Move the stopped animation clock forward. Debug builds only."""
+    #[cfg(feature = "ui_debug")]
+    Qstr::MP_QSTR_advance_clock => obj_fn_1!(upy_advance_clock).as_obj(),
+
     /// def jpeg_info(data: bytes) -> (width: int, height: int, mcu_height: int):
     ///     """Get JPEG image dimensions."""
     Qstr::MP_QSTR_jpeg_info => obj_fn_1!(upy_jpeg_info).as_obj(),
@@ -1403,6 +1659,17 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     """Confirm byte sequence data."""
     Qstr::MP_QSTR_confirm_blob => obj_fn_kw!(0, new_confirm_blob).as_obj(),
 
+    /// def confirm_address(
+    ///     *,
+    ///     title: str,
+    ///     address: str,
+    ///     verb_cancel: str | None = None,
+    ///     identicon: str | None = None,
+    /// ) -> object:
+    ///     """Confirm address, split into chunks of four characters. The identicon
+    ///     seeded with `identicon`, if given, is drawn next to it."""
+    Qstr::MP_QSTR_confirm_address => obj_fn_kw!(0, new_confirm_address).as_obj(),
+
     /// def confirm_properties(
     ///     *,
     ///     title: str,
@@ -1431,6 +1698,19 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     """Show QR code."""
     Qstr::MP_QSTR_show_qr => obj_fn_kw!(0, new_show_qr).as_obj(),
 
+    /// def show_animated_qr(
+    ///     *,
+    ///     title: str,
+    ///     frames: Iterable[str],
+    ///     time_ms: int = 0,
+    ///     button: str = "DONE",
+    /// ) -> object:
+    ///     """Show a sequence of QR codes, e.g. the parts of an UR or BBQr
+    ///     encoded payload, changing every `time_ms` milliseconds. At most 16
+    ///     frames are supported. Tapping the code pauses and resumes the
+    ///     sequence."""
+    Qstr::MP_QSTR_show_animated_qr => obj_fn_kw!(0, new_show_animated_qr).as_obj(),
+
     /// def confirm_value(
     ///     *,
     ///     title: str,
@@ -1621,8 +1901,10 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///     min_count: int,
     ///     max_count: int,
     ///     description: Callable[[int], str] | None = None,
+    ///     keypad: bool = False,
     /// ) -> object:
-    ///    """Number input with + and - buttons, description, and info button."""
+    ///    """Number input with + and - buttons, description, and info button. The
+    ///    value can be dragged, and typed in on a keypad if `keypad` is set."""
     Qstr::MP_QSTR_request_number => obj_fn_kw!(0, new_request_number).as_obj(),
 
     /// def show_checklist(
@@ -1668,6 +1950,32 @@ pub static mp_module_trezorui2: Module = obj_module! {
     ///    """Shows SLIP39 state after info button is pressed on `confirm_recovery`."""
     Qstr::MP_QSTR_show_remaining_shares => obj_fn_kw!(0, new_show_remaining_shares).as_obj(),
 
+    /// def show_group_grid(
+    ///     *,
+    ///     title: str,
+    ///     group_threshold: int,
+    ///     groups: Iterable[tuple[int, int]],
+    ///     button: str = "CONTINUE",
+    /// ) -> object:
+    ///    """Shows SLIP39 advanced backup progress, one row of shares per group.
+    ///    `groups` are pairs of entered shares and the threshold of the group,
+    ///    the threshold is zero for groups without any share entered."""
+    Qstr::MP_QSTR_show_group_grid => obj_fn_kw!(0, new_show_group_grid).as_obj(),
+
+    /// def show_recovery_progress(
+    ///     *,
+    ///     title: str,
+    ///     group_threshold: int,
+    ///     groups: Iterable[tuple[int, int]],
+    ///     words_entered: int = 0,
+    ///     word_count: int = 0,
+    ///     button: str = "CONTINUE",
+    /// ) -> object:
+    ///    """Shows SLIP39 recovery progress: words of the current share, groups still
+    ///    needed and the shares entered in every group. Words are not shown if
+    ///    `word_count` is zero."""
+    Qstr::MP_QSTR_show_recovery_progress => obj_fn_kw!(0, new_show_recovery_progress).as_obj(),
+
     /// def show_progress(
     ///     *,
     ///     title: str,
@@ -1708,6 +2016,31 @@ pub static mp_module_trezorui2: Module = obj_module! {
     /// ) -> CANCELLED:
     ///     """Homescreen used for indicating coinjoin in progress."""
     Qstr::MP_QSTR_show_busyscreen => obj_fn_kw!(0, new_show_busyscreen).as_obj(),
+
+    /// def show_battery_charge(
+    ///     *,
+    ///     soc: int,
+    ///     charging: bool = False,
+    /// ) -> CANCELLED:
+    ///     """Charge of the battery, updated by the `status_event` of the layout.
+    ///     Dismissed by a touch."""
+    Qstr::MP_QSTR_show_battery_charge => obj_fn_kw!(0, new_show_battery_charge).as_obj(),
+
+    /// def bootloader_screens() -> list[str]:
+    ///     """Names of the bootloader screens known to `render_bootloader_screen`.
+    ///     Debug builds only."""
+    #[cfg(feature = "ui_debug")]
+    Qstr::MP_QSTR_bootloader_screens => obj_fn_var!(0, 0, upy_bootloader_screens).as_obj(),
+
+    /// def render_bootloader_screen(
+    ///     *,
+    ///     screen: str,
+    ///     trace: Callable[[str], None],
+    /// ) -> bool:
+    ///     """Paint the bootloader screen with sample arguments and trace it into
+    ///     `trace`, False if there is no such screen. Debug builds only."""
+    #[cfg(feature = "ui_debug")]
+    Qstr::MP_QSTR_render_bootloader_screen => obj_fn_kw!(0, new_render_bootloader_screen).as_obj(),
 };
 
 #[cfg(test)]
@@ -1737,7 +2070,7 @@ mod tests {
             Button::cancel_confirm(Button::with_text("Left"), Button::with_text("Right"), 1);
         let mut layout = Dialog::new(
             FormattedText::new(
-                theme::TEXT_NORMAL,
+                *theme::text(StyleToken::Body),
                 theme::FORMATTED,
                 "Testing text layout, with some text, and some more text. And {param}",
             )
@@ -1,6 +1,13 @@
+// Also part of debug builds of the firmware, which render the bootloader
+// screens for the debuglink.
+#[cfg(any(feature = "bootloader", feature = "ui_debug"))]
+pub mod bootloader;
 pub mod component;
 pub mod constant;
 pub mod event;
+#[cfg(feature = "keyboard")]
+pub mod keyboard;
+pub mod screens;
 pub mod theme;
 
 #[cfg(feature = "micropython")]
@@ -0,0 +1,70 @@
+use crate::ui::{
+    component::text::{layout::TextLayout, TextStyle},
+    constant::screen,
+    display::{self, Color, Font},
+    error_code::ErrorCode,
+    geometry::{Alignment, Insets, Point},
+};
+
+use super::theme;
+
+const FATAL_ERROR_COLOR: Color = theme::RED_DARK;
+const TEXT_FATAL_ERROR: TextStyle = TextStyle::new(
+    Font::NORMAL,
+    theme::WHITE,
+    FATAL_ERROR_COLOR,
+    theme::WHITE,
+    theme::WHITE,
+);
+
+const TITLE_BASELINE: i16 = 28;
+const CODE_BASELINE: i16 = 54;
+const MESSAGE_HEIGHT: i16 = 50;
+const QR_SIZE: u32 = 100;
+const QR_CENTER: Point = Point::new(screen().center().x, 156);
+const FOOTER_BASELINE: i16 = 228;
+
+/// Full-screen error notice with a prominent error code. If given, `qr_data`
+/// is encoded in a QR code so that the details can be easily shared with
+/// support.
+pub fn screen_error(title: &str, msg: &str, code: ErrorCode, qr_data: Option<&str>, footer: &str) {
+    let area = screen();
+    display::rect_fill(area, FATAL_ERROR_COLOR);
+
+    display::text_center(
+        Point::new(area.center().x, TITLE_BASELINE),
+        title,
+        Font::BOLD,
+        theme::WHITE,
+        FATAL_ERROR_COLOR,
+    );
+    display::text_center(
+        Point::new(area.center().x, CODE_BASELINE),
+        code.as_str(),
+        Font::BOLD,
+        theme::WHITE,
+        FATAL_ERROR_COLOR,
+    );
+
+    let message_area = area
+        .inset(Insets::new(CODE_BASELINE + 4, 10, 0, 10))
+        .split_top(MESSAGE_HEIGHT)
+        .0;
+    TextLayout::new(TEXT_FATAL_ERROR)
+        .with_bounds(message_area)
+        .with_align(Alignment::Center)
+        .render_text(msg);
+
+    if let Some(data) = qr_data {
+        // Nothing sensible can be done if the data do not fit into a QR code.
+        display::qrcode(QR_CENTER, data, QR_SIZE, true).unwrap_or(());
+    }
+
+    display::text_center(
+        Point::new(area.center().x, FOOTER_BASELINE),
+        footer,
+        Font::NORMAL,
+        theme::WHITE,
+        FATAL_ERROR_COLOR,
+    );
+}
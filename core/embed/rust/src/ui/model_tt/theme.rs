@@ -1,11 +1,17 @@
+use core::sync::atomic::{AtomicBool, Ordering};
+
 use crate::{
     time::Duration,
     ui::{
         component::{
-            text::{formatted::FormattedFonts, LineBreaking, PageBreaking, TextStyle},
+            text::{
+                formatted::FormattedFonts,
+                tokens::{bold_text, TextTheme},
+                LineBreaking, PageBreaking, StyleToken, TextStyle,
+            },
             FixedHeightBar,
         },
-        display::{Color, Font},
+        display::{Color, Font, LoaderGeometry},
         geometry::Insets,
     },
 };
@@ -24,8 +30,8 @@ pub const BACKLIGHT_NONE: i32 = 2;
 pub const BACKLIGHT_MAX: i32 = 255;
 
 // Color palette.
-pub const WHITE: Color = Color::rgb(0xFF, 0xFF, 0xFF);
-pub const BLACK: Color = Color::rgb(0, 0, 0);
+pub const WHITE: Color = Color::white();
+pub const BLACK: Color = Color::black();
 pub const FG: Color = WHITE; // Default foreground (text & icon) color.
 pub const BG: Color = BLACK; // Default background color.
 pub const RED: Color = Color::rgb(0xCD, 0x49, 0x49); // dark-coral
@@ -41,6 +47,11 @@ pub const GREY_LIGHT: Color = Color::rgb(0xA8, 0xA8, 0xA8); // greyish
 pub const GREY_MEDIUM: Color = Color::rgb(0x64, 0x64, 0x64);
 pub const GREY_DARK: Color = Color::rgb(0x33, 0x33, 0x33); // greyer
 pub const VIOLET: Color = Color::rgb(0x9E, 0x27, 0xD6);
+// Color-blind safe replacements of green and red.
+pub const BLUE_SAFE: Color = Color::rgb(0x00, 0x72, 0xB2);
+pub const BLUE_SAFE_DARK: Color = Color::rgb(0x00, 0x5A, 0x8C);
+pub const ORANGE_SAFE: Color = Color::rgb(0xD5, 0x5E, 0x00);
+pub const ORANGE_SAFE_DARK: Color = Color::rgb(0xA8, 0x4A, 0x00);
 
 // Commonly used corner radius (i.e. for buttons).
 pub const RADIUS: u8 = 2;
@@ -67,8 +78,6 @@ pub const ICON_LOCK: &[u8] = include_res!("model_tt/res/lock.toif");
 // Large, three-color icons.
 pub const WARN_COLOR: Color = YELLOW;
 pub const INFO_COLOR: Color = BLUE;
-pub const SUCCESS_COLOR: Color = GREEN;
-pub const ERROR_COLOR: Color = RED;
 pub const IMAGE_FG_WARN: &[u8] = include_res!("model_tt/res/warn_fg.toif");
 pub const IMAGE_FG_SUCCESS: &[u8] = include_res!("model_tt/res/success_fg.toif");
 pub const IMAGE_FG_ERROR: &[u8] = include_res!("model_tt/res/error_fg.toif");
@@ -78,6 +87,90 @@ pub const IMAGE_BG_TRIANGLE: &[u8] = include_res!("model_tt/res/triangle.toif");
 pub const IMAGE_BG_BACK_BTN: &[u8] = include_res!("model_tt/res/back_btn.toif");
 pub const IMAGE_BG_BACK_BTN_TALL: &[u8] = include_res!("model_tt/res/back_btn_tall.toif");
 
+// Full-screen logo, shown by the bootloader with the screen turned off.
+pub const IMAGE_LOGO: &[u8] = include_res!("model_tt/res/logo.toif");
+
+/// Colors and shapes telling the positive and negative outcomes and actions
+/// apart.
+pub struct Palette {
+    pub success: Color,
+    pub error: Color,
+    /// Background shapes of the large success and error icons.
+    pub success_shape: &'static [u8],
+    pub error_shape: &'static [u8],
+    /// Normal, active and disabled style of the buttons.
+    pub button_confirm: [&'static ButtonStyle; 3],
+    pub button_cancel: [&'static ButtonStyle; 3],
+    /// Style of the hold-to-confirm loader while held.
+    pub loader_active: &'static LoaderStyle,
+}
+
+pub const PALETTE_DEFAULT: Palette = Palette {
+    success: GREEN,
+    error: RED,
+    success_shape: IMAGE_BG_CIRCLE,
+    error_shape: IMAGE_BG_CIRCLE,
+    button_confirm: [
+        &button_filled(FG, GREEN, BG),
+        &button_filled(FG, GREEN_DARK, FG),
+        &button_filled(FG, GREEN, BG),
+    ],
+    button_cancel: [
+        &button_filled(FG, RED, BG),
+        &button_filled(FG, RED_DARK, FG),
+        &button_filled(GREY_LIGHT, RED, BG),
+    ],
+    loader_active: &LoaderStyle {
+        icon: None,
+        loader_color: GREEN,
+        background_color: BG,
+    },
+};
+
+/// Blue and orange are told apart with all common kinds of color blindness,
+/// failures are additionally drawn in a triangle instead of a circle.
+pub const PALETTE_COLOR_BLIND: Palette = Palette {
+    success: BLUE_SAFE,
+    error: ORANGE_SAFE,
+    success_shape: IMAGE_BG_CIRCLE,
+    error_shape: IMAGE_BG_TRIANGLE,
+    button_confirm: [
+        &button_filled(FG, BLUE_SAFE, BG),
+        &button_filled(FG, BLUE_SAFE_DARK, FG),
+        &button_filled(FG, BLUE_SAFE, BG),
+    ],
+    button_cancel: [
+        &button_filled(FG, ORANGE_SAFE, BG),
+        &button_filled(FG, ORANGE_SAFE_DARK, FG),
+        &button_filled(GREY_LIGHT, ORANGE_SAFE, BG),
+    ],
+    loader_active: &LoaderStyle {
+        icon: None,
+        loader_color: BLUE_SAFE,
+        background_color: BG,
+    },
+};
+
+static COLOR_BLIND: AtomicBool = AtomicBool::new(false);
+
+/// Palette selected in the settings.
+pub fn palette() -> &'static Palette {
+    palette_for(COLOR_BLIND.load(Ordering::Relaxed))
+}
+
+const fn palette_for(color_blind: bool) -> &'static Palette {
+    if color_blind {
+        &PALETTE_COLOR_BLIND
+    } else {
+        &PALETTE_DEFAULT
+    }
+}
+
+/// Use `PALETTE_COLOR_BLIND` for the layouts created from now on.
+pub fn set_color_blind(enabled: bool) {
+    COLOR_BLIND.store(enabled, Ordering::Relaxed);
+}
+
 // Default homescreen
 pub const IMAGE_HOMESCREEN: &[u8] = include_res!("model_tt/res/bg.jpg");
 
@@ -88,8 +181,8 @@ pub const DOT_INACTIVE_HALF: &[u8] = include_res!("model_tt/res/scroll-inactive-
 pub const DOT_INACTIVE_QUARTER: &[u8] = include_res!("model_tt/res/scroll-inactive-quarter.toif");
 pub const DOT_SMALL: &[u8] = include_res!("model_tt/res/scroll-small.toif");
 
-pub const fn label_default() -> TextStyle {
-    TEXT_NORMAL
+pub fn label_default() -> TextStyle {
+    *text(StyleToken::Body)
 }
 
 pub const fn label_keyboard() -> TextStyle {
@@ -108,8 +201,17 @@ pub const fn label_page_hint() -> TextStyle {
     TextStyle::new(Font::BOLD, GREY_LIGHT, BG, GREY_LIGHT, GREY_LIGHT)
 }
 
-pub const fn label_warning() -> TextStyle {
-    TEXT_DEMIBOLD
+pub fn label_warning() -> TextStyle {
+    *text(StyleToken::Warning)
+}
+
+/// Identifiers which can be mistaken for different ones.
+pub const fn label_lookalike() -> TextStyle {
+    TextStyle::new(Font::BOLD, RED, BG, GREY_LIGHT, GREY_LIGHT)
+}
+
+pub fn label_warning_banner() -> TextStyle {
+    TextStyle::new(Font::BOLD, FG, palette().error, GREY_LIGHT, GREY_LIGHT)
 }
 
 pub const fn label_warning_value() -> TextStyle {
@@ -132,6 +234,10 @@ pub const fn label_title() -> TextStyle {
     TextStyle::new(Font::BOLD, GREY_LIGHT, BG, GREY_LIGHT, GREY_LIGHT)
 }
 
+pub const fn label_title_aux() -> TextStyle {
+    TextStyle::new(Font::NORMAL, GREY_MEDIUM, BG, GREY_LIGHT, GREY_LIGHT)
+}
+
 pub fn button_default() -> ButtonStyleSheet {
     ButtonStyleSheet {
         normal: &ButtonStyle {
@@ -165,66 +271,33 @@ pub fn button_default() -> ButtonStyleSheet {
 }
 
 pub fn button_confirm() -> ButtonStyleSheet {
+    let [normal, active, disabled] = palette().button_confirm;
     ButtonStyleSheet {
-        normal: &ButtonStyle {
-            font: Font::BOLD,
-            text_color: FG,
-            button_color: GREEN,
-            background_color: BG,
-            border_color: BG,
-            border_radius: RADIUS,
-            border_width: 0,
-        },
-        active: &ButtonStyle {
-            font: Font::BOLD,
-            text_color: FG,
-            button_color: GREEN_DARK,
-            background_color: BG,
-            border_color: FG,
-            border_radius: RADIUS,
-            border_width: 0,
-        },
-        disabled: &ButtonStyle {
-            font: Font::BOLD,
-            text_color: FG,
-            button_color: GREEN,
-            background_color: BG,
-            border_color: BG,
-            border_radius: RADIUS,
-            border_width: 0,
-        },
+        normal,
+        active,
+        disabled,
     }
 }
 
 pub fn button_cancel() -> ButtonStyleSheet {
+    let [normal, active, disabled] = palette().button_cancel;
     ButtonStyleSheet {
-        normal: &ButtonStyle {
-            font: Font::BOLD,
-            text_color: FG,
-            button_color: RED,
-            background_color: BG,
-            border_color: BG,
-            border_radius: RADIUS,
-            border_width: 0,
-        },
-        active: &ButtonStyle {
-            font: Font::BOLD,
-            text_color: FG,
-            button_color: RED_DARK,
-            background_color: BG,
-            border_color: FG,
-            border_radius: RADIUS,
-            border_width: 0,
-        },
-        disabled: &ButtonStyle {
-            font: Font::BOLD,
-            text_color: GREY_LIGHT,
-            button_color: RED,
-            background_color: BG,
-            border_color: BG,
-            border_radius: RADIUS,
-            border_width: 0,
-        },
+        normal,
+        active,
+        disabled,
+    }
+}
+
+/// Bold text on a filled button.
+const fn button_filled(text_color: Color, button_color: Color, border_color: Color) -> ButtonStyle {
+    ButtonStyle {
+        font: Font::BOLD,
+        text_color,
+        button_color,
+        background_color: BG,
+        border_color,
+        border_radius: RADIUS,
+        border_width: 0,
     }
 }
 
@@ -364,6 +437,11 @@ pub fn button_clear() -> ButtonStyleSheet {
     button_default()
 }
 
+pub const LOADER: LoaderGeometry = LoaderGeometry::new(60, 18);
+/// Loader of the install and wipe progress screens, making room for the text
+/// below it.
+pub const PROGRESS_LOADER: LoaderGeometry = LOADER.with_y_offset(-20);
+
 pub fn loader_default() -> LoaderStyleSheet {
     LoaderStyleSheet {
         normal: &LoaderStyle {
@@ -371,20 +449,18 @@ pub fn loader_default() -> LoaderStyleSheet {
             loader_color: FG,
             background_color: BG,
         },
-        active: &LoaderStyle {
-            icon: None,
-            loader_color: GREEN,
-            background_color: BG,
-        },
+        active: palette().loader_active,
     }
 }
 
 pub const TEXT_NORMAL: TextStyle = TextStyle::new(Font::NORMAL, FG, BG, GREY_LIGHT, GREY_LIGHT);
-pub const TEXT_DEMIBOLD: TextStyle = TextStyle::new(Font::DEMIBOLD, FG, BG, GREY_LIGHT, GREY_LIGHT);
-pub const TEXT_BOLD: TextStyle = TextStyle::new(Font::BOLD, FG, BG, GREY_LIGHT, GREY_LIGHT);
-pub const TEXT_MONO: TextStyle = TextStyle::new(Font::MONO, FG, BG, GREY_LIGHT, GREY_LIGHT)
+pub const TEXT_DEMIBOLD: TextStyle = TEXT_NORMAL.with_font(Font::DEMIBOLD);
+pub const TEXT_BOLD: TextStyle = TEXT_NORMAL.with_font(Font::BOLD);
+pub const TEXT_MONO: TextStyle = TEXT_NORMAL
+    .with_font(Font::MONO)
     .with_line_breaking(LineBreaking::BreakWordsNoHyphen)
-    .with_page_breaking(PageBreaking::Cut);
+    .with_page_breaking(PageBreaking::Cut)
+    .with_escape_invisible(true);
 
 /// Convert Python-side numeric id to a `TextStyle`.
 pub fn textstyle_number(num: i32) -> &'static TextStyle {
@@ -397,14 +473,50 @@ pub fn textstyle_number(num: i32) -> &'static TextStyle {
     }
 }
 
-pub const TEXT_NORMAL_OFF_WHITE: TextStyle =
-    TextStyle::new(Font::NORMAL, OFF_WHITE, BG, GREY_LIGHT, GREY_LIGHT);
-pub const TEXT_CHECKLIST_DEFAULT: TextStyle =
-    TextStyle::new(Font::NORMAL, GREY_LIGHT, BG, GREY_LIGHT, GREY_LIGHT);
-pub const TEXT_CHECKLIST_SELECTED: TextStyle =
-    TextStyle::new(Font::NORMAL, FG, BG, GREY_LIGHT, GREY_LIGHT);
-pub const TEXT_CHECKLIST_DONE: TextStyle =
-    TextStyle::new(Font::NORMAL, GREEN_DARK, BG, GREY_LIGHT, GREY_LIGHT);
+pub const TEXT_NORMAL_OFF_WHITE: TextStyle = TEXT_NORMAL.with_text_color(OFF_WHITE);
+pub const TEXT_DEMIBOLD_OFF_WHITE: TextStyle = TEXT_DEMIBOLD.with_text_color(OFF_WHITE);
+pub const TEXT_CHECKLIST_DEFAULT: TextStyle = TEXT_NORMAL.with_text_color(GREY_LIGHT);
+pub const TEXT_CHECKLIST_SELECTED: TextStyle = TEXT_NORMAL;
+pub const TEXT_CHECKLIST_DONE: TextStyle = TEXT_NORMAL.with_text_color(GREEN_DARK);
+
+pub const TEXT_THEME: TextTheme = TextTheme {
+    title: &TEXT_BOLD,
+    subtitle: &TEXT_DEMIBOLD,
+    body: &TEXT_NORMAL,
+    secondary: &TEXT_NORMAL_OFF_WHITE,
+    emphasis: &TEXT_BOLD,
+    mono: &TEXT_MONO,
+    warning: &TEXT_DEMIBOLD,
+};
+
+/// Every weight one step heavier, warnings stay bolder than the body.
+pub const TEXT_THEME_BOLD: TextTheme = TextTheme {
+    title: &TEXT_BOLD,
+    subtitle: &TEXT_BOLD,
+    body: &TEXT_DEMIBOLD,
+    secondary: &TEXT_DEMIBOLD_OFF_WHITE,
+    emphasis: &TEXT_BOLD,
+    mono: &TEXT_MONO,
+    warning: &TEXT_BOLD,
+};
+
+/// Text theme selected in the settings.
+pub fn text_theme() -> &'static TextTheme {
+    text_theme_for(bold_text())
+}
+
+const fn text_theme_for(bold: bool) -> &'static TextTheme {
+    if bold {
+        &TEXT_THEME_BOLD
+    } else {
+        &TEXT_THEME
+    }
+}
+
+/// Style of the text with the given meaning.
+pub fn text(token: StyleToken) -> &'static TextStyle {
+    text_theme().style(token)
+}
 
 pub const FORMATTED: FormattedFonts = FormattedFonts {
     normal: Font::NORMAL,
@@ -418,6 +530,7 @@ pub const KEYBOARD_SPACING: i16 = 8;
 pub const BUTTON_HEIGHT: i16 = 38;
 pub const BUTTON_SPACING: i16 = 6;
 pub const CHECKLIST_SPACING: i16 = 10;
+pub const RESULT_ICON_HEIGHT: i16 = 60;
 pub const RECOVERY_SPACING: i16 = 18;
 
 /// Standard button height in pixels.
@@ -452,3 +565,56 @@ pub const fn borders_scroll() -> Insets {
 pub const fn borders_notification() -> Insets {
     Insets::new(48, 10, 14, 10)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_blind_palette() {
+        assert!(core::ptr::eq(palette_for(false), &PALETTE_DEFAULT));
+        assert!(core::ptr::eq(palette_for(true), &PALETTE_COLOR_BLIND));
+
+        for palette in [&PALETTE_DEFAULT, &PALETTE_COLOR_BLIND] {
+            assert_eq!(palette.button_confirm[0].button_color, palette.success);
+            assert_eq!(palette.button_cancel[0].button_color, palette.error);
+            assert_eq!(palette.loader_active.loader_color, palette.success);
+        }
+
+        // Red and green are told apart only by the red-green axis, the
+        // color-blind palette has to differ in blue and in the shape as well.
+        let palette = &PALETTE_COLOR_BLIND;
+        assert!(palette.success.b().abs_diff(palette.error.b()) >= 0x80);
+        assert!(!core::ptr::eq(palette.success_shape, palette.error_shape));
+    }
+
+    #[test]
+    fn text_theme_bold() {
+        assert!(core::ptr::eq(text_theme_for(false), &TEXT_THEME));
+        assert!(core::ptr::eq(text_theme_for(true), &TEXT_THEME_BOLD));
+        assert!(text(StyleToken::Body).text_font == TEXT_NORMAL.text_font);
+
+        // Each token is at least as heavy in the bold theme, the body text
+        // gets heavier and warnings still stand out of it.
+        let weight = |style: &TextStyle| match style.text_font {
+            Font::NORMAL | Font::MONO => 0,
+            Font::DEMIBOLD => 1,
+            Font::BOLD => 2,
+        };
+        for token in [
+            StyleToken::Title,
+            StyleToken::Subtitle,
+            StyleToken::Body,
+            StyleToken::Secondary,
+            StyleToken::Emphasis,
+            StyleToken::Mono,
+            StyleToken::Warning,
+        ] {
+            let (normal, bold) = (TEXT_THEME.style(token), TEXT_THEME_BOLD.style(token));
+            assert!(weight(bold) >= weight(normal));
+            assert_eq!(bold.text_color, normal.text_color);
+        }
+        assert!(weight(TEXT_THEME_BOLD.body) > weight(TEXT_THEME.body));
+        assert!(weight(TEXT_THEME_BOLD.warning) > weight(TEXT_THEME_BOLD.body));
+    }
+}
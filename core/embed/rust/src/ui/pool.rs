@@ -0,0 +1,251 @@
+//! Fixed-block pool for transient buffers of the UI, e.g. decompression
+//! windows, QR code scratch space or shaped text, which would otherwise take
+//! kilobytes of stack. There is no heap in the bootloader and the MicroPython
+//! GC is not available during painting, so the blocks are allocated
+//! statically.
+
+use core::{
+    cell::{Cell, UnsafeCell},
+    fmt,
+    ops::{Deref, DerefMut},
+    str,
+};
+
+use crate::trezorhal::uzlib::UZLIB_WINDOW_SIZE;
+
+/// Size of a single block in bytes, enough for a decompression window which is
+/// the largest of the buffers.
+pub const BLOCK_SIZE: usize = UZLIB_WINDOW_SIZE;
+
+/// Number of blocks in the pool. Painting needs at most two at the same time,
+/// e.g. `icon_over_icon` decompressing both icons, the third one is left for
+/// text formatted by the screen being painted.
+const BLOCK_COUNT: usize = 3;
+
+/// Usage of a pool since it was created, see `Pool::stats`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct PoolStats {
+    pub in_use: usize,
+    pub high_water: usize,
+    pub failed: usize,
+    pub capacity: usize,
+}
+
+pub struct Pool {
+    blocks: UnsafeCell<[[u8; BLOCK_SIZE]; BLOCK_COUNT]>,
+    /// Bit mask of the allocated blocks.
+    used: Cell<u8>,
+    /// Most blocks allocated at the same time so far.
+    high_water: Cell<u8>,
+    /// Number of allocations which failed because the pool was full.
+    failed: Cell<u16>,
+}
+
+impl Pool {
+    pub const fn new() -> Self {
+        Self {
+            blocks: UnsafeCell::new([[0; BLOCK_SIZE]; BLOCK_COUNT]),
+            used: Cell::new(0),
+            high_water: Cell::new(0),
+            failed: Cell::new(0),
+        }
+    }
+
+    /// Take a free block, or `None` if all of them are in use.
+    pub fn alloc(&self) -> Option<Block<'_>> {
+        let used = self.used.get();
+        let index = (0..BLOCK_COUNT).find(|i| used & (1 << i) == 0);
+        if index.is_none() {
+            self.failed.set(self.failed.get().saturating_add(1));
+            ui_log!(Error, "pool exhausted");
+        }
+        let index = index?;
+        let used = used | (1 << index);
+        self.used.set(used);
+        let in_use = used.count_ones() as u8;
+        if in_use > self.high_water.get() {
+            self.high_water.set(in_use);
+            ui_log!(Info, "pool high water {}/{}", in_use, BLOCK_COUNT);
+        }
+        let mut block = Block {
+            pool: self,
+            index: index as u8,
+        };
+        block.fill(0);
+        Some(block)
+    }
+
+    pub fn stats(&self) -> PoolStats {
+        PoolStats {
+            in_use: self.used.get().count_ones() as usize,
+            high_water: self.high_water.get() as usize,
+            failed: self.failed.get() as usize,
+            capacity: BLOCK_COUNT,
+        }
+    }
+
+    fn release(&self, index: u8) {
+        self.used.set(self.used.get() & !(1 << index));
+    }
+
+    fn block_ptr(&self, index: u8) -> *mut [u8; BLOCK_SIZE] {
+        // Only the pointer to the single block is formed, other blocks may be
+        // borrowed at the same time.
+        let blocks = self.blocks.get();
+        // SAFETY: `blocks` points to the array owned by `self` and `index` is
+        // less than `BLOCK_COUNT`, no reference is created.
+        unsafe { core::ptr::addr_of_mut!((*blocks)[index as usize]) }
+    }
+}
+
+#[cfg(not(test))]
+static mut POOL: Pool = Pool::new();
+
+// Tests paint from several threads at once, each of them has its own pool.
+#[cfg(test)]
+std::thread_local! {
+    static POOL: Pool = Pool::new();
+}
+
+/// Returns the pool of the UI.
+///
+/// # Safety
+///
+/// `Pool` is not thread-safe, the caller has to guarantee that the pool is
+/// not used from another thread at the same time.
+unsafe fn pool() -> &'static Pool {
+    #[cfg(not(test))]
+    let ptr = core::ptr::addr_of!(POOL);
+    #[cfg(test)]
+    let ptr = POOL.with(|pool| pool as *const Pool);
+    unsafe { &*ptr }
+}
+
+/// Usage of the pool of the UI since boot.
+pub fn stats() -> PoolStats {
+    // SAFETY: Only reads the counters. The firmware reports the statistics
+    // from the UI task, which is the only one painting.
+    unsafe { pool() }.stats()
+}
+
+/// Zeroed block of `BLOCK_SIZE` bytes, returned to the pool when dropped.
+pub struct Block<'a> {
+    pool: &'a Pool,
+    index: u8,
+}
+
+impl Block<'static> {
+    /// Take a free block of the pool of the UI, or `None` if all of them are
+    /// in use.
+    pub fn alloc() -> Option<Self> {
+        // SAFETY: Blocks are allocated while painting and painting happens in
+        // the UI task only, test threads have a pool of their own.
+        unsafe { pool() }.alloc()
+    }
+}
+
+impl Deref for Block<'_> {
+    type Target = [u8; BLOCK_SIZE];
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: The block is marked as used in the pool until `self` is
+        // dropped, so no other `Block` refers to the same memory.
+        unsafe { &*self.pool.block_ptr(self.index) }
+    }
+}
+
+impl DerefMut for Block<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: See `deref`, the block is exclusively owned by `self`.
+        unsafe { &mut *self.pool.block_ptr(self.index) }
+    }
+}
+
+impl Drop for Block<'_> {
+    fn drop(&mut self) {
+        self.pool.release(self.index);
+    }
+}
+
+/// Text formatted into a pool block, for strings too long to be kept on the
+/// stack. A write which would not fit into `BLOCK_SIZE` fails and leaves the
+/// text as it was.
+pub struct BlockString<'a> {
+    block: Block<'a>,
+    len: usize,
+}
+
+impl<'a> BlockString<'a> {
+    pub fn new(block: Block<'a>) -> Self {
+        Self { block, len: 0 }
+    }
+
+    pub fn as_str(&self) -> &str {
+        // SAFETY: Only whole strings are written by `write_str`.
+        unsafe { str::from_utf8_unchecked(&self.block[..self.len]) }
+    }
+}
+
+impl BlockString<'static> {
+    /// Allocate a string from the pool of the UI.
+    pub fn alloc() -> Option<Self> {
+        Block::alloc().map(Self::new)
+    }
+}
+
+impl fmt::Write for BlockString<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        let dest = self.block.get_mut(self.len..end).ok_or(fmt::Error)?;
+        dest.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+impl Deref for BlockString<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::fmt::Write;
+
+    use super::*;
+
+    #[test]
+    fn pool_alloc_free() {
+        let pool = Pool::new();
+        let mut first = pool.alloc().unwrap();
+        first[0] = 0xAA;
+        let blocks: [Option<Block>; BLOCK_COUNT] = core::array::from_fn(|_| pool.alloc());
+        // `first` took one of the blocks.
+        assert!(blocks[BLOCK_COUNT - 1].is_none());
+        assert_eq!(pool.stats().in_use, BLOCK_COUNT);
+        assert_eq!(pool.stats().high_water, BLOCK_COUNT);
+        assert_eq!(pool.stats().failed, 1);
+
+        drop(first);
+        drop(blocks);
+        assert_eq!(pool.stats().in_use, 0);
+        let reused = pool.alloc().unwrap();
+        assert!(reused.iter().all(|b| *b == 0));
+    }
+
+    #[test]
+    fn pool_string() {
+        let pool = Pool::new();
+        let mut text = BlockString::new(pool.alloc().unwrap());
+        write!(text, "Install firmware version {}?", "2.6.0").unwrap();
+        assert_eq!(&*text, "Install firmware version 2.6.0?");
+
+        let long = "x".repeat(BLOCK_SIZE);
+        assert!(text.write_str(&long).is_err());
+        assert_eq!(&*text, "Install firmware version 2.6.0?");
+        assert_eq!(pool.stats().in_use, 1);
+    }
+}
@@ -0,0 +1,103 @@
+use cty::c_char;
+use heapless::String;
+
+use crate::ui::{
+    error_code::ErrorCode,
+    util::{error_details, from_c_str},
+};
+
+#[cfg(feature = "model_tr")]
+use crate::ui::model_tr::screens::screen_error;
+#[cfg(feature = "model_tt")]
+use crate::ui::model_tt::screens::screen_error;
+
+/// Length of the message shown on the error screens.
+const ERROR_MESSAGE_LEN: usize = 64;
+
+/// Screen shown on `__fatal_error`, i.e. failed assertions and `unwrap!`s.
+/// The failed expression `expr` is shown under the message.
+pub fn screen_fatal_error(expr: &str, msg: &str, file: &str, line: u32, code: ErrorCode) {
+    let details = error_details(code, expr, msg, file, line);
+    let text = join_lines::<ERROR_MESSAGE_LEN>(&[msg, expr], '\n');
+    screen_error(
+        "FATAL ERROR",
+        &text,
+        code,
+        Some(details.as_str()),
+        "CONTACT SUPPORT",
+    );
+}
+
+/// Screen shown on `error_shutdown`, i.e. fault handlers.
+pub fn screen_error_shutdown(title: &str, msg: &str, code: ErrorCode) {
+    screen_error(title, msg, code, None, "UNPLUG THE DEVICE");
+}
+
+#[no_mangle]
+extern "C" fn screen_fatal_error_rust(
+    kind: cty::c_int,
+    expr: *const c_char,
+    msg: *const c_char,
+    file: *const c_char,
+    line: i32,
+) {
+    // SAFETY: Strings from `__fatal_error` are 0-terminated and only borrowed
+    // while the screen is being drawn.
+    let (expr, msg, file) = unsafe { (from_c_str(expr), from_c_str(msg), from_c_str(file)) };
+    screen_fatal_error(expr, msg, file, line as u32, ErrorCode::from_c(kind));
+}
+
+#[no_mangle]
+extern "C" fn screen_error_shutdown_rust(
+    kind: cty::c_int,
+    line1: *const c_char,
+    line2: *const c_char,
+    line3: *const c_char,
+    line4: *const c_char,
+) {
+    // SAFETY: Strings from `error_shutdown` are 0-terminated or null and only
+    // borrowed while the screen is being drawn.
+    let (title, lines) = unsafe {
+        (
+            from_c_str(line1),
+            [from_c_str(line2), from_c_str(line3), from_c_str(line4)],
+        )
+    };
+    let msg = join_lines::<ERROR_MESSAGE_LEN>(&lines, ' ');
+    screen_error_shutdown(title, &msg, ErrorCode::from_c(kind));
+}
+
+/// Join the non-empty `lines` with `sep`, e.g. the lines below the title of
+/// `error_shutdown`, which are parts of a single sentence wrapped by hand, so
+/// that the screen can wrap them again.
+fn join_lines<const N: usize>(lines: &[&str], sep: char) -> String<N> {
+    let mut msg = String::new();
+    for line in lines.iter().filter(|line| !line.is_empty()) {
+        if !msg.is_empty() && msg.push(sep).is_err() {
+            break;
+        }
+        if msg.push_str(line).is_err() {
+            break;
+        }
+    }
+    msg
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shutdown_message() {
+        let join = |lines: &[&str], sep| join_lines::<ERROR_MESSAGE_LEN>(lines, sep);
+        assert_eq!(join(&["(HF)", "", ""], ' ').as_str(), "(HF)");
+        assert_eq!(
+            join(&["from USB.", "Try different", "USB cable."], ' ').as_str(),
+            "from USB. Try different USB cable."
+        );
+        assert_eq!(
+            join(&["assert failed", "x == 1"], '\n').as_str(),
+            "assert failed\nx == 1"
+        );
+    }
+}
@@ -0,0 +1,237 @@
+use core::{fmt, str};
+
+use crate::{
+    error::Error,
+    ui::{grapheme, util::u32_to_str},
+};
+
+/// Capacity of a single buffer in bytes. Longer text is truncated.
+pub const TEXT_BUFFER_LEN: usize = 64;
+
+/// Number of buffers in the ring, i.e. how many `TextBuffer`s can be alive at
+/// the same time before the oldest one gets overwritten.
+const TEXT_BUFFER_COUNT: usize = 8;
+
+struct Slot {
+    data: [u8; TEXT_BUFFER_LEN],
+    len: u8,
+    generation: u16,
+}
+
+impl Slot {
+    const fn new() -> Self {
+        Self {
+            data: [0; TEXT_BUFFER_LEN],
+            len: 0,
+            generation: 0,
+        }
+    }
+}
+
+struct Ring {
+    slots: [Slot; TEXT_BUFFER_COUNT],
+    next: usize,
+}
+
+impl Ring {
+    const fn new() -> Self {
+        Self {
+            slots: [
+                Slot::new(),
+                Slot::new(),
+                Slot::new(),
+                Slot::new(),
+                Slot::new(),
+                Slot::new(),
+                Slot::new(),
+                Slot::new(),
+            ],
+            next: 0,
+        }
+    }
+
+    fn build(&mut self, f: impl FnOnce(&mut TextWriter)) -> TextBuffer {
+        let index = self.next;
+        self.next = (index + 1) % TEXT_BUFFER_COUNT;
+
+        let slot = &mut self.slots[index];
+        slot.generation = slot.generation.wrapping_add(1);
+        let mut writer = TextWriter {
+            data: &mut slot.data,
+            len: 0,
+        };
+        f(&mut writer);
+        slot.len = writer.len as u8;
+
+        TextBuffer {
+            slot: index as u8,
+            generation: slot.generation,
+        }
+    }
+
+    fn get(&self, buffer: TextBuffer) -> Option<&str> {
+        let slot = &self.slots[buffer.slot as usize];
+        if slot.generation != buffer.generation {
+            return None;
+        }
+        // SAFETY: `TextWriter` only ever writes whole UTF-8 strings.
+        Some(unsafe { str::from_utf8_unchecked(&slot.data[..slot.len as usize]) })
+    }
+}
+
+#[cfg(not(test))]
+static mut RING: Ring = Ring::new();
+
+// Tests formatting text in parallel must not overwrite each other's buffers.
+#[cfg(test)]
+std::thread_local! {
+    static RING: core::cell::UnsafeCell<Ring> = core::cell::UnsafeCell::new(Ring::new());
+}
+
+/// Returns the ring shared by all `TextBuffer`s.
+///
+/// # Safety
+///
+/// The caller has to guarantee that no other reference obtained from this
+/// function is alive, i.e. that the result is not held across calls.
+unsafe fn ring() -> &'static mut Ring {
+    #[cfg(not(test))]
+    let ptr = core::ptr::addr_of_mut!(RING);
+    #[cfg(test)]
+    let ptr = RING.with(|ring| ring.get());
+    unsafe { &mut *ptr }
+}
+
+/// Dynamic text, e.g. a formatted version or counter, stored in a ring of
+/// statically allocated buffers owned by the UI. Unlike `heapless::String`
+/// the capacity does not leak into the component types, and unlike
+/// `StrBuffer` it does not need the MicroPython GC.
+///
+/// Creating a new buffer reuses the least recently allocated slot, so only
+/// `TEXT_BUFFER_COUNT` buffers can be displayed at once. Reading an
+/// overwritten buffer is an error.
+#[derive(Copy, Clone)]
+pub struct TextBuffer {
+    slot: u8,
+    generation: u16,
+}
+
+impl TextBuffer {
+    /// Allocate a buffer and fill it using `f`.
+    pub fn build(f: impl FnOnce(&mut TextWriter)) -> Self {
+        // SAFETY: The reference is dropped before returning. `f` only gets the
+        // writer for a single slot, not the ring, so a nested `build` from
+        // within `f` cannot reach the slot being written.
+        unsafe { ring() }.build(f)
+    }
+
+    /// False if the slot was reused by a newer buffer.
+    pub fn is_valid(&self) -> bool {
+        // SAFETY: The reference is dropped before returning.
+        unsafe { ring() }.get(*self).is_some()
+    }
+
+    /// Passes the text to `f`, or fails with `Error::OutOfRange` if the slot
+    /// was reused by a newer buffer in the meantime.
+    pub fn map<T>(&self, f: impl FnOnce(&str) -> T) -> Result<T, Error> {
+        let mut copy = [0; TEXT_BUFFER_LEN];
+        let len = {
+            // SAFETY: The reference is dropped at the end of this block,
+            // before `f` (which may build new buffers) gets called.
+            let text = unsafe { ring() }.get(*self).ok_or(Error::OutOfRange)?;
+            copy[..text.len()].copy_from_slice(text.as_bytes());
+            text.len()
+        };
+        // SAFETY: Copied from a valid UTF-8 string.
+        Ok(f(unsafe { str::from_utf8_unchecked(&copy[..len]) }))
+    }
+}
+
+impl From<&str> for TextBuffer {
+    fn from(val: &str) -> Self {
+        Self::build(|w| w.push_str(val))
+    }
+}
+
+#[cfg(feature = "ui_debug")]
+impl crate::trace::Trace for TextBuffer {
+    fn trace(&self, t: &mut dyn crate::trace::Tracer) {
+        unwrap!(self.map(|text| t.string(text)))
+    }
+}
+
+/// Appends text to a `TextBuffer` being built, silently truncating at a
+/// grapheme cluster boundary once the buffer is full.
+pub struct TextWriter<'a> {
+    data: &'a mut [u8; TEXT_BUFFER_LEN],
+    len: usize,
+}
+
+impl TextWriter<'_> {
+    pub fn push_str(&mut self, s: &str) {
+        let s = grapheme::truncate(s, TEXT_BUFFER_LEN - self.len);
+        self.data[self.len..self.len + s.len()].copy_from_slice(s.as_bytes());
+        self.len += s.len();
+    }
+
+    pub fn push_u32(&mut self, num: u32) {
+        let mut buf = [0; 10];
+        self.push_str(unwrap!(u32_to_str(num, &mut buf)));
+    }
+}
+
+impl fmt::Write for TextWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_buffer_build() {
+        let mut ring = Ring::new();
+        let version = ring.build(|w| {
+            w.push_str("v");
+            w.push_u32(2);
+            w.push_str(".");
+            w.push_u32(10);
+        });
+        assert_eq!(ring.get(version), Some("v2.10"));
+
+        let long = "ž".repeat(TEXT_BUFFER_LEN);
+        let truncated = ring.build(|w| w.push_str(&long));
+        let text = ring.get(truncated).unwrap();
+        assert_eq!(text.len(), TEXT_BUFFER_LEN);
+        assert!(long.starts_with(text));
+    }
+
+    #[test]
+    fn text_buffer_reuse() {
+        let mut ring = Ring::new();
+        let first = ring.build(|w| w.push_str("first"));
+        let later: Vec<TextBuffer> = (1..TEXT_BUFFER_COUNT)
+            .map(|_| ring.build(|w| w.push_str("later")))
+            .collect();
+        assert_eq!(ring.get(first), Some("first"));
+        assert!(later.iter().all(|b| ring.get(*b) == Some("later")));
+
+        let newest = ring.build(|w| w.push_str("newest"));
+        assert_eq!(ring.get(first), None);
+        assert_eq!(ring.get(newest), Some("newest"));
+    }
+
+    #[test]
+    fn text_buffer_overwritten() {
+        let buffer = TextBuffer::build(|w| w.push_str("stale"));
+        assert_eq!(buffer.map(|text| text.len()).ok(), Some(5));
+        for _ in 0..TEXT_BUFFER_COUNT {
+            TextBuffer::build(|w| w.push_str("newer"));
+        }
+        assert!(!buffer.is_valid());
+        assert!(matches!(buffer.map(|_| ()), Err(Error::OutOfRange)));
+    }
+}
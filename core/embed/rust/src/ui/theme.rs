@@ -0,0 +1,14 @@
+//! Reexporting the styles and spacing which the components shared by the models
+//! take from the theme of the current feature (Trezor model), so that they
+//! don't need a copy for each model just to pick fonts and colors.
+
+#[cfg(all(feature = "model_tr", not(feature = "model_tt")))]
+pub use super::model_tr::theme::{
+    borders, text, text_theme, BUTTON_HEIGHT, CHECKLIST_SPACING, FORMATTED, LOADER,
+    PROGRESS_LOADER, RESULT_ICON_HEIGHT,
+};
+#[cfg(feature = "model_tt")]
+pub use super::model_tt::theme::{
+    borders, text, text_theme, BUTTON_HEIGHT, CHECKLIST_SPACING, FORMATTED, LOADER,
+    PROGRESS_LOADER, RESULT_ICON_HEIGHT,
+};
@@ -0,0 +1,250 @@
+use crate::{
+    trezorhal::flash::{self, FlashError},
+    ui::{constant::screen, geometry::Point},
+};
+
+/// Fixed-point denominator of `AxisTransform::scale`.
+const SCALE_ONE: i32 = 1 << 12;
+
+/// Identifies a valid calibration record at the start of the settings sector.
+const MAGIC: u32 = 0x4C414354; // "TCAL"
+
+/// Size of the flash record in words: magic, four parameters and checksum.
+const RECORD_WORDS: usize = 6;
+
+/// Corrections larger than this are considered a failed measurement rather than
+/// a slightly offset panel.
+const MAX_SCALE_DEVIATION: i32 = SCALE_ONE / 4;
+const MAX_OFFSET: i32 = 40;
+
+/// Linear correction of one axis, `v' = v * scale / SCALE_ONE + offset`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct AxisTransform {
+    scale: i32,
+    offset: i32,
+}
+
+impl AxisTransform {
+    pub const IDENTITY: Self = Self {
+        scale: SCALE_ONE,
+        offset: 0,
+    };
+
+    /// Transform mapping `measured` coordinates onto `expected` ones, `None`
+    /// if the samples are degenerate or the correction is implausibly large.
+    fn from_samples(expected: (i16, i16), measured: (i16, i16)) -> Option<Self> {
+        let expected_span = (expected.1 - expected.0) as i32;
+        let measured_span = (measured.1 - measured.0) as i32;
+        if measured_span == 0 {
+            return None;
+        }
+        let scale = expected_span * SCALE_ONE / measured_span;
+        let offset = expected.0 as i32 - measured.0 as i32 * scale / SCALE_ONE;
+        let transform = Self { scale, offset };
+        transform.is_plausible().then_some(transform)
+    }
+
+    fn is_plausible(&self) -> bool {
+        (self.scale - SCALE_ONE).abs() <= MAX_SCALE_DEVIATION && self.offset.abs() <= MAX_OFFSET
+    }
+
+    fn apply(&self, v: i16) -> i16 {
+        (v as i32 * self.scale / SCALE_ONE + self.offset) as i16
+    }
+}
+
+/// Per-axis correction of coordinates reported by the touch controller.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Calibration {
+    x: AxisTransform,
+    y: AxisTransform,
+}
+
+impl Calibration {
+    pub const IDENTITY: Self = Self {
+        x: AxisTransform::IDENTITY,
+        y: AxisTransform::IDENTITY,
+    };
+
+    /// Compute the calibration from two targets at opposite corners of the
+    /// screen and the points where they were actually reported.
+    pub fn from_samples(expected: [Point; 2], measured: [Point; 2]) -> Option<Self> {
+        Some(Self {
+            x: AxisTransform::from_samples(
+                (expected[0].x, expected[1].x),
+                (measured[0].x, measured[1].x),
+            )?,
+            y: AxisTransform::from_samples(
+                (expected[0].y, expected[1].y),
+                (measured[0].y, measured[1].y),
+            )?,
+        })
+    }
+
+    pub fn apply(&self, p: Point) -> Point {
+        let area = screen();
+        Point::new(
+            self.x.apply(p.x).clamp(area.x0, area.x1 - 1),
+            self.y.apply(p.y).clamp(area.y0, area.y1 - 1),
+        )
+    }
+
+    fn to_words(self) -> [u32; RECORD_WORDS] {
+        let mut words = [
+            MAGIC,
+            self.x.scale as u32,
+            self.x.offset as u32,
+            self.y.scale as u32,
+            self.y.offset as u32,
+            0,
+        ];
+        words[RECORD_WORDS - 1] = checksum(&words[..RECORD_WORDS - 1]);
+        words
+    }
+
+    fn from_words(words: &[u32]) -> Option<Self> {
+        if words.len() != RECORD_WORDS
+            || words[0] != MAGIC
+            || words[RECORD_WORDS - 1] != checksum(&words[..RECORD_WORDS - 1])
+        {
+            return None;
+        }
+        let calibration = Self {
+            x: AxisTransform {
+                scale: words[1] as i32,
+                offset: words[2] as i32,
+            },
+            y: AxisTransform {
+                scale: words[3] as i32,
+                offset: words[4] as i32,
+            },
+        };
+        (calibration.x.is_plausible() && calibration.y.is_plausible()).then_some(calibration)
+    }
+
+    /// Read the calibration stored in the settings sector, identity if there
+    /// is none.
+    pub fn load() -> Self {
+        flash::read_words(flash::SECTOR_SETTINGS, 0, RECORD_WORDS)
+            .and_then(Self::from_words)
+            .unwrap_or(Self::IDENTITY)
+    }
+
+    pub fn store(&self) -> Result<(), FlashError> {
+        flash::erase_and_write_words(flash::SECTOR_SETTINGS, &self.to_words())?;
+        set_active(*self);
+        Ok(())
+    }
+}
+
+fn checksum(words: &[u32]) -> u32 {
+    words
+        .iter()
+        .fold(0x811C9DC5, |acc, w| (acc ^ w).wrapping_mul(0x01000193))
+}
+
+#[cfg(not(test))]
+static mut ACTIVE: Option<Calibration> = None;
+
+// Tests feeding touch events in parallel must not calibrate each other.
+#[cfg(test)]
+std::thread_local! {
+    static ACTIVE: core::cell::UnsafeCell<Option<Calibration>> =
+        core::cell::UnsafeCell::new(None);
+}
+
+/// Returns the calibration applied to touch events, `None` until loaded.
+///
+/// # Safety
+///
+/// The caller has to guarantee that no other reference returned by this
+/// function is alive while the result is used.
+unsafe fn state() -> &'static mut Option<Calibration> {
+    #[cfg(not(test))]
+    let ptr = core::ptr::addr_of_mut!(ACTIVE);
+    #[cfg(test)]
+    let ptr = ACTIVE.with(|active| active.get());
+    unsafe { &mut *ptr }
+}
+
+/// Calibration currently applied to touch events, loaded from flash on first
+/// use.
+pub fn active() -> Calibration {
+    // SAFETY: The reference does not leave this expression.
+    let calibration = unsafe { *state() };
+    calibration.unwrap_or_else(|| {
+        let loaded = Calibration::load();
+        set_active(loaded);
+        loaded
+    })
+}
+
+/// Replace the calibration applied to touch events without storing it.
+pub fn set_active(calibration: Calibration) {
+    // SAFETY: The reference does not leave this expression.
+    unsafe { *state() = Some(calibration) };
+}
+
+/// Apply the active calibration to a point reported by the touch controller.
+pub fn transform(p: Point) -> Point {
+    active().apply(p)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::event::TouchEvent;
+
+    #[test]
+    fn calibration_from_samples() {
+        let expected = [Point::new(20, 20), Point::new(220, 220)];
+
+        let measured = expected;
+        let calibration = Calibration::from_samples(expected, measured).unwrap();
+        assert_eq!(calibration, Calibration::IDENTITY);
+
+        let measured = [Point::new(26, 16), Point::new(226, 216)];
+        let calibration = Calibration::from_samples(expected, measured).unwrap();
+        assert_eq!(
+            calibration.apply(Point::new(126, 116)),
+            Point::new(120, 120)
+        );
+        assert_eq!(calibration.apply(Point::new(0, 239)), Point::new(0, 239));
+
+        let measured = [Point::new(20, 20), Point::new(20, 220)];
+        assert_eq!(Calibration::from_samples(expected, measured), None);
+
+        let measured = [Point::new(120, 20), Point::new(220, 220)];
+        assert_eq!(Calibration::from_samples(expected, measured), None);
+    }
+
+    #[test]
+    fn calibration_record() {
+        let expected = [Point::new(20, 20), Point::new(220, 220)];
+        let measured = [Point::new(18, 24), Point::new(222, 220)];
+        let calibration = Calibration::from_samples(expected, measured).unwrap();
+
+        let mut words = calibration.to_words();
+        assert_eq!(Calibration::from_words(&words), Some(calibration));
+
+        words[2] ^= 1;
+        assert_eq!(Calibration::from_words(&words), None);
+        assert_eq!(Calibration::from_words(&[0xFFFFFFFF; RECORD_WORDS]), None);
+    }
+
+    #[test]
+    fn calibration_touch_events() {
+        let expected = [Point::new(20, 20), Point::new(220, 220)];
+        let measured = [Point::new(26, 16), Point::new(226, 216)];
+        set_active(Calibration::from_samples(expected, measured).unwrap());
+
+        let event = TouchEvent::new(4, 126, 116).unwrap().calibrated();
+        assert_eq!(event, TouchEvent::TouchEnd(Point::new(120, 120)));
+        let event = TouchEvent::new(8, 0, 0).unwrap().calibrated();
+        assert_eq!(event, TouchEvent::TouchCancel);
+
+        set_active(Calibration::IDENTITY);
+        let event = TouchEvent::new(1, 126, 116).unwrap().calibrated();
+        assert_eq!(event, TouchEvent::TouchStart(Point::new(126, 116)));
+    }
+}
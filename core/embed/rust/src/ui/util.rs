@@ -1,10 +1,24 @@
+use core::str;
+use cstr_core::CStr;
+use cty::c_char;
+use heapless::String;
+
 use crate::ui::{
     component::text::TextStyle,
     display,
     display::toif::Icon,
+    error_code::ErrorCode,
     geometry::{Offset, Point, CENTER},
+    grapheme,
 };
 
+/// Firmware version as "major.minor.patch", taken from `version.h`.
+pub const FIRMWARE_VERSION: &str = env!("TREZOR_FIRMWARE_VERSION");
+
+/// Maximum length of error details, chosen so that they always fit into a
+/// binary QR code.
+pub const ERROR_DETAILS_LEN: usize = 200;
+
 pub trait ResultExt {
     fn assert_if_debugging_ui(self, message: &str);
 }
@@ -38,6 +52,160 @@ pub fn u32_to_str(num: u32, buffer: &mut [u8]) -> Option<&str> {
     }
 }
 
+/// How `format_amount` renders an amount in the smallest unit of a coin, e.g.
+/// satoshi or wei.
+#[derive(Copy, Clone)]
+pub struct AmountFormat {
+    /// Number of decimal places of the displayed unit, e.g. 8 for BTC.
+    pub decimals: u8,
+    /// Separator of thousands in the integer part, none by default.
+    pub group_separator: Option<char>,
+    pub decimal_point: char,
+    /// Suffix separated by a space, e.g. "BTC", omitted if empty.
+    pub unit: &'static str,
+}
+
+impl AmountFormat {
+    pub const fn new(decimals: u8, unit: &'static str) -> Self {
+        Self {
+            decimals,
+            group_separator: None,
+            decimal_point: '.',
+            unit,
+        }
+    }
+
+    pub const fn with_group_separator(mut self, separator: char) -> Self {
+        self.group_separator = Some(separator);
+        self
+    }
+
+    pub const fn with_decimal_point(mut self, decimal_point: char) -> Self {
+        self.decimal_point = decimal_point;
+        self
+    }
+}
+
+/// Render `amount` as a fixed-point number in the unit of `format`, e.g.
+/// 123456789 satoshi as "1.23456789 BTC". Trailing zeros of the fraction are
+/// dropped, the same as `strings.format_amount` in Python. `None` if the result
+/// does not fit into `N` bytes.
+pub fn format_amount<const N: usize>(amount: u128, format: &AmountFormat) -> Option<String<N>> {
+    // Digits from the least significant, u128 has at most 39 of them.
+    let mut digits = [0u8; 39];
+    let mut len = 0;
+    let mut rest = amount;
+    loop {
+        digits[len] = b'0' + (rest % 10) as u8;
+        rest /= 10;
+        len += 1;
+        if rest == 0 {
+            break;
+        }
+    }
+    let digit = |i: usize| if i < len { digits[i] as char } else { '0' };
+
+    let decimals = format.decimals as usize;
+    let total = len.max(decimals + 1);
+    let mut result = String::new();
+    for i in (decimals..total).rev() {
+        result.push(digit(i)).ok()?;
+        let position = i - decimals;
+        if let Some(separator) = format.group_separator.filter(|_| position > 0) {
+            if position % 3 == 0 {
+                result.push(separator).ok()?;
+            }
+        }
+    }
+    if let Some(last) = (0..decimals).find(|i| digit(*i) != '0') {
+        result.push(format.decimal_point).ok()?;
+        for i in (last..decimals).rev() {
+            result.push(digit(i)).ok()?;
+        }
+    }
+    if !format.unit.is_empty() {
+        result.push(' ').ok()?;
+        result.push_str(format.unit).ok()?;
+    }
+    Some(result)
+}
+
+/// Convert a C string to `&str`, treating null as an empty string.
+///
+/// # Safety
+///
+/// `ptr` must be either null or point to a 0-terminated UTF-8 string that
+/// stays valid for the lifetime of the returned reference.
+pub unsafe fn from_c_str<'a>(ptr: *const c_char) -> &'a str {
+    if ptr.is_null() {
+        return "";
+    }
+    // SAFETY: Upheld by the caller.
+    unsafe { str::from_utf8_unchecked(CStr::from_ptr(ptr).to_bytes()) }
+}
+
+/// Like `from_c_str`, but checking that the string is valid UTF-8, `None` if
+/// it is not.
+///
+/// # Safety
+///
+/// `ptr` must be either null or point to a 0-terminated string that stays
+/// valid for the lifetime of the returned reference.
+pub unsafe fn try_from_c_str<'a>(ptr: *const c_char) -> Option<&'a str> {
+    if ptr.is_null() {
+        return Some("");
+    }
+    // SAFETY: Upheld by the caller.
+    str::from_utf8(unsafe { CStr::from_ptr(ptr).to_bytes() }).ok()
+}
+
+/// Compose the error message, the failed expression, its location and the
+/// firmware version into a single string, e.g. to be encoded in the fatal
+/// error screen QR code. Expression and location are omitted if empty.
+/// Overlong message and expression are truncated so that the location and
+/// version always fit.
+pub fn error_details(
+    code: ErrorCode,
+    expr: &str,
+    msg: &str,
+    file: &str,
+    line: u32,
+) -> String<ERROR_DETAILS_LEN> {
+    let mut line_buf = [0; 10];
+    let line = unwrap!(u32_to_str(line, &mut line_buf));
+
+    let mut suffix: String<ERROR_DETAILS_LEN> = String::new();
+    if !file.is_empty() {
+        for part in ["\n", file, ":", line] {
+            push_truncated(&mut suffix, part);
+        }
+    }
+    for part in ["\nv", FIRMWARE_VERSION] {
+        push_truncated(&mut suffix, part);
+    }
+
+    let mut details = String::new();
+    push_truncated(&mut details, code.as_str());
+    push_truncated(&mut details, " ");
+    let msg_len = ERROR_DETAILS_LEN.saturating_sub(suffix.len());
+    let expr_sep = if expr.is_empty() { "" } else { "\n" };
+    for c in msg.chars().chain(expr_sep.chars()).chain(expr.chars()) {
+        if details.len() + c.len_utf8() > msg_len {
+            break;
+        }
+        unwrap!(details.push(c));
+    }
+    push_truncated(&mut details, &suffix);
+    details
+}
+
+/// Append as much of `s` as fits into `buffer`, respecting grapheme cluster
+/// boundaries.
+fn push_truncated<const N: usize>(buffer: &mut String<N>, s: &str) {
+    let available = buffer.capacity() - buffer.len();
+    unwrap!(buffer.push_str(grapheme::truncate(s, available)));
+}
+
 #[cfg(feature = "ui_debug")]
 static mut DISABLE_ANIMATION: bool = false;
 
@@ -108,6 +276,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn try_from_c_str_validates() {
+        let valid = b"ok\0";
+        let invalid = b"\xff\xfe\0";
+        unsafe {
+            assert_eq!(try_from_c_str(core::ptr::null()), Some(""));
+            assert_eq!(try_from_c_str(valid.as_ptr() as *const c_char), Some("ok"));
+            assert_eq!(try_from_c_str(invalid.as_ptr() as *const c_char), None);
+        }
+    }
+
+    #[test]
+    fn error_details_truncate() {
+        let details = error_details(ErrorCode::RustPanic, "", "oops", "src/lib.rs", 42);
+        assert_eq!(
+            details.as_str(),
+            format!("E02 oops\nsrc/lib.rs:42\nv{}", FIRMWARE_VERSION)
+        );
+
+        let details = error_details(ErrorCode::AssertFailed, "x == 1", "assert failed", "a.c", 7);
+        assert_eq!(
+            details.as_str(),
+            format!("E04 assert failed\nx == 1\na.c:7\nv{}", FIRMWARE_VERSION)
+        );
+
+        let details = error_details(ErrorCode::RustPanic, "", "oops", "", 0);
+        assert_eq!(details.as_str(), format!("E02 oops\nv{}", FIRMWARE_VERSION));
+
+        let long = "x".repeat(ERROR_DETAILS_LEN * 2);
+        let details = error_details(ErrorCode::RustPanic, "x == 1", &long, "src/lib.rs", 42);
+        assert_eq!(details.len(), ERROR_DETAILS_LEN);
+        assert!(details.ends_with(FIRMWARE_VERSION));
+    }
+
     #[test]
     fn u32_to_str_small_buffer() {
         let testcases = [1000, 31337, u32::MAX];
@@ -118,4 +320,42 @@ mod tests {
             assert_eq!(converted, None)
         }
     }
+
+    #[test]
+    fn format_amount_decimals() {
+        const BTC: AmountFormat = AmountFormat::new(8, "BTC");
+        let f = |amount, format| format_amount::<40>(amount, &format).unwrap();
+        assert_eq!(f(123456789, BTC).as_str(), "1.23456789 BTC");
+        assert_eq!(f(100000000, BTC).as_str(), "1 BTC");
+        assert_eq!(f(1, BTC).as_str(), "0.00000001 BTC");
+        assert_eq!(f(0, BTC).as_str(), "0 BTC");
+        assert_eq!(f(1050, AmountFormat::new(2, "")).as_str(), "10.5");
+        assert_eq!(f(42, AmountFormat::new(0, "sat")).as_str(), "42 sat");
+        // amounts over u64, e.g. wei
+        assert_eq!(
+            f(123 * 10u128.pow(18), AmountFormat::new(18, "ETH")).as_str(),
+            "123 ETH"
+        );
+    }
+
+    #[test]
+    fn format_amount_separators() {
+        let format = AmountFormat::new(8, "BTC").with_group_separator(',');
+        let f = |amount| format_amount::<40>(amount, &format).unwrap();
+        assert_eq!(f(123456789012345678).as_str(), "1,234,567,890.12345678 BTC");
+        assert_eq!(f(100000000000).as_str(), "1,000 BTC");
+        assert_eq!(f(99900000000).as_str(), "999 BTC");
+
+        let format = AmountFormat::new(2, "CZK")
+            .with_group_separator(' ')
+            .with_decimal_point(',');
+        assert_eq!(
+            format_amount::<20>(123456789, &format).unwrap().as_str(),
+            "1 234 567,89 CZK"
+        );
+        assert_eq!(
+            format_amount::<16>(u128::MAX, &AmountFormat::new(0, "")),
+            None
+        );
+    }
 }
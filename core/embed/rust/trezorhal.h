@@ -1,4 +1,6 @@
 #include TREZOR_BOARD
+#include "ambient_light.h"
+#include "battery.h"
 #include "buffers.h"
 #include "common.h"
 #include "display.h"
@@ -9,6 +11,7 @@
 #include "rgb_led.h"
 #include "secbool.h"
 #include "storage.h"
+#include "touch.h"
 #include "usb.h"
 
 #include "bip39.h"
@@ -0,0 +1,20 @@
+#ifndef TREZORHAL_BATTERY_H
+#define TREZORHAL_BATTERY_H
+
+#include <stdint.h>
+#include "secbool.h"
+
+// Implemented only by boards powered by a battery.
+
+void battery_init(void);
+
+// Returns the state of charge in percent, or a negative value on failure.
+int32_t battery_soc(void);
+
+// Returns the battery voltage in millivolts, or a negative value on failure.
+int32_t battery_voltage_mv(void);
+
+// Returns sectrue while the battery is being charged.
+secbool battery_charging(void);
+
+#endif
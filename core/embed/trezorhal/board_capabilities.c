@@ -21,8 +21,9 @@
 #include <string.h>
 #include "common.h"
 
-#define handle_fault(msg) \
-  (__fatal_error("Fault detected", msg, __FILE__, __LINE__, __func__))
+#define handle_fault(msg)                                             \
+  (__fatal_error_kind(ERROR_KIND_FAULT_DETECTED, "Fault detected", msg, \
+                      __FILE__, __LINE__, __func__))
 
 static uint32_t board_name = 0;
 
@@ -28,6 +28,10 @@
 #include "stm32.h"
 #include "supervise.h"
 
+#ifdef TREZOR_UI2
+#include "rust_ui.h"
+#endif
+
 #include "stm32f4xx_ll_utils.h"
 
 #ifdef RGB16
@@ -51,8 +55,19 @@ void shutdown(void) {
 void __attribute__((noreturn))
 __fatal_error(const char *expr, const char *msg, const char *file, int line,
               const char *func) {
+  __fatal_error_kind(ERROR_KIND_FATAL, expr, msg, file, line, func);
+}
+
+void __attribute__((noreturn))
+__fatal_error_kind(error_kind_t kind, const char *expr, const char *msg,
+                   const char *file, int line, const char *func) {
   display_orientation(0);
   display_backlight(255);
+#ifdef TREZOR_UI2
+  screen_fatal_error_rust(kind, expr, msg, file, line);
+  (void)func;
+#else
+  (void)kind;
   display_print_color(COLOR_WHITE, COLOR_FATAL_ERROR);
   display_printf("\nFATAL ERROR:\n");
   if (expr) {
@@ -73,6 +88,7 @@ __fatal_error(const char *expr, const char *msg, const char *file, int line,
                  rev[4]);
 #endif
   display_printf("\nPlease contact Trezor support.\n");
+#endif
   shutdown();
   for (;;)
     ;
@@ -81,8 +97,17 @@ __fatal_error(const char *expr, const char *msg, const char *file, int line,
 void __attribute__((noreturn))
 error_shutdown(const char *line1, const char *line2, const char *line3,
                const char *line4) {
+  error_shutdown_kind(ERROR_KIND_FATAL, line1, line2, line3, line4);
+}
+
+void __attribute__((noreturn))
+error_shutdown_kind(error_kind_t kind, const char *line1, const char *line2,
+                    const char *line3, const char *line4) {
   display_orientation(0);
-#ifdef TREZOR_FONT_NORMAL_ENABLE
+#if defined TREZOR_UI2
+  screen_error_shutdown_rust(kind, line1, line2, line3, line4);
+#elif defined TREZOR_FONT_NORMAL_ENABLE
+  (void)kind;
   display_clear();
   display_bar(0, 0, DISPLAY_RESX, DISPLAY_RESY, COLOR_FATAL_ERROR);
   int y = 32;
@@ -106,6 +131,7 @@ error_shutdown(const char *line1, const char *line2, const char *line3,
   display_text(8, y, "Please unplug the device.", -1, FONT_NORMAL, COLOR_WHITE,
                COLOR_FATAL_ERROR);
 #else
+  (void)kind;
   display_print_color(COLOR_WHITE, COLOR_FATAL_ERROR);
   if (line1) {
     display_printf("%s\n", line1);
@@ -130,7 +156,8 @@ error_shutdown(const char *line1, const char *line2, const char *line3,
 #ifndef NDEBUG
 void __assert_func(const char *file, int line, const char *func,
                    const char *expr) {
-  __fatal_error(expr, "assert failed", file, line, func);
+  __fatal_error_kind(ERROR_KIND_ASSERT_FAILED, expr, "assert failed", file,
+                     line, func);
 }
 #endif
 
@@ -157,7 +184,8 @@ void clear_otg_hs_memory(void) {
 uint32_t __stack_chk_guard = 0;
 
 void __attribute__((noreturn)) __stack_chk_fail(void) {
-  error_shutdown("Internal error", "(SS)", NULL, NULL);
+  error_shutdown_kind(ERROR_KIND_STACK_SMASHING, "Internal error", "(SS)", NULL,
+                      NULL);
 }
 
 uint8_t HW_ENTROPY_DATA[HW_ENTROPY_LEN];
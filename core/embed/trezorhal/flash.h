@@ -38,7 +38,9 @@
 //                                           1
 #define FLASH_SECTOR_BOARDLOADER_END 2
 
-//                                           3
+#if defined TREZOR_MODEL_T || defined TREZOR_MODEL_R
+#define FLASH_SECTOR_SETTINGS 3
+#endif
 
 #if defined TREZOR_MODEL_T || defined TREZOR_MODEL_R
 #define FLASH_SECTOR_STORAGE_1 4
@@ -184,7 +184,10 @@ void rdi_handler(uint32_t uw_tick) {
     wait(drbg_random8() + session_delay);
 
   } else {  // if rdi disabled or rdi_disabled corrupted
-    ensure(rdi_disabled, "Fault detected");
+    if (rdi_disabled != sectrue) {
+      __fatal_error_kind(ERROR_KIND_FAULT_DETECTED, "rdi_disabled",
+                         "Fault detected", __FILE__, __LINE__, __func__);
+    }
   }
 }
 
@@ -28,7 +28,10 @@
 
 #define TOUCH_ADDRESS \
   (0x38U << 1)  // the HAL requires the 7-bit address to be shifted by one bit
-#define TOUCH_PACKET_SIZE 7U
+#define TOUCH_PACKET_SIZE 9U
+// only changes of the first part of the packet (event and position) are
+// reported as new events
+#define TOUCH_POSITION_DATA_SIZE 7U
 #define EVENT_PRESS_DOWN 0x00U
 #define EVENT_CONTACT 0x80U
 #define EVENT_LIFT_UP 0x40U
@@ -38,6 +41,8 @@
 #define X_POS_LSB (touch_data[4])
 #define Y_POS_MSB (touch_data[5] & 0x0FU)
 #define Y_POS_LSB (touch_data[6])
+#define CONTACT_WEIGHT (touch_data[7])
+#define CONTACT_AREA ((touch_data[8] & 0xF0U) >> 4)
 
 #define EVENT_OLD_TIMEOUT_MS 50
 #define EVENT_MISSING_TIMEOUT_MS 50
@@ -286,6 +291,11 @@ uint32_t check_timeout(uint32_t prev, uint32_t timeout) {
   return 0;
 }
 
+// contact size and pressure of the last read event, see touch_get_contact()
+static uint32_t contact = 0;
+
+uint32_t touch_get_contact(void) { return contact; }
+
 uint32_t touch_read(void) {
   static uint8_t touch_data[TOUCH_PACKET_SIZE],
       previous_touch_data[TOUCH_PACKET_SIZE];
@@ -293,16 +303,26 @@ uint32_t touch_read(void) {
   static uint32_t last_check_time = 0;
   static uint32_t last_event_time = 0;
   static int touching = 0;
+  // set after a second finger landed, until all fingers are lifted
+  static int multitouch = 0;
 
   uint32_t detected = touch_is_detected();
 
   if (detected == 0) {
     last_check_time = hal_ticks_ms();
 
+    if (multitouch &&
+        check_timeout(last_event_time, EVENT_MISSING_TIMEOUT_MS)) {
+      // we probably missed the lift of the last finger
+      multitouch = 0;
+    }
+
     if (touching && check_timeout(last_event_time, EVENT_MISSING_TIMEOUT_MS)) {
       // we didn't detect an event for a long time, but there was an active
       // touch: send END event, as we probably missed the END event
       touching = 0;
+      // there is no fresh packet, do not report the contact of the last one
+      contact = 0;
       return TOUCH_END | xy;
     }
 
@@ -334,16 +354,35 @@ uint32_t touch_read(void) {
 
   last_event_time = hal_ticks_ms();
 
-  if (0 == memcmp(previous_touch_data, touch_data, TOUCH_PACKET_SIZE)) {
+  if (0 == memcmp(previous_touch_data, touch_data, TOUCH_POSITION_DATA_SIZE)) {
     return 0;  // same data, filter it out
   } else {
-    memcpy(previous_touch_data, touch_data, TOUCH_PACKET_SIZE);
+    memcpy(previous_touch_data, touch_data, TOUCH_POSITION_DATA_SIZE);
   }
 
+  contact = (CONTACT_AREA << 8) | CONTACT_WEIGHT;
+
   const uint32_t number_of_touch_points =
       touch_data[2] & 0x0F;  // valid values are 0, 1, 2 (invalid 0xF before
                              // first touch) (tested with FT6206)
   const uint32_t event_flag = touch_data[3] & 0xC0;
+  if (multitouch) {
+    // ignore all reports until every finger leaves the panel, the position of
+    // the first touch point is unreliable in the meantime
+    if (number_of_touch_points == 0) {
+      multitouch = 0;
+    }
+    return 0;
+  }
+  if (number_of_touch_points == 2) {
+    // secondary contact: abort the interaction in progress, if any
+    multitouch = 1;
+    if (touching) {
+      touching = 0;
+      return TOUCH_CANCEL | xy;
+    }
+    return 0;
+  }
   if (touch_data[1] == GESTURE_NO_GESTURE) {
     xy = touch_pack_xy((X_POS_MSB << 8) | X_POS_LSB,
                        (Y_POS_MSB << 8) | Y_POS_LSB);
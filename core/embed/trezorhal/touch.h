@@ -25,12 +25,19 @@
 #define TOUCH_START (1U << 24)
 #define TOUCH_MOVE (1U << 25)
 #define TOUCH_END (1U << 26)
+// touch was interrupted, e.g. by a second finger, and must not be treated
+// as a regular end of the interaction
+#define TOUCH_CANCEL (1U << 27)
 
 void touch_init(void);
 void touch_power_on(void);
 void touch_power_off(void);
 void touch_sensitivity(uint8_t value);
 uint32_t touch_read(void);
+// contact of the last event read by touch_read(), packed as (area << 8) |
+// weight, both in controller-specific units; 0 if the controller does not
+// report them
+uint32_t touch_get_contact(void);
 uint32_t touch_click(void);
 uint32_t touch_is_detected(void);
 static inline uint16_t touch_unpack_x(uint32_t evt) {
@@ -0,0 +1,36 @@
+/*
+ * This file is part of the Trezor project, https://trezor.io/
+ *
+ * Copyright (c) SatoshiLabs
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+#include <stdlib.h>
+
+#include "ambient_light.h"
+
+// The emulated sensor is configured by an environment variable, the sensor is
+// unavailable unless TREZOR_AMBIENT_LIGHT_LUX is set.
+
+static int32_t lux = -1;
+
+void ambient_light_init(void) {
+  const char *lux_str = getenv("TREZOR_AMBIENT_LIGHT_LUX");
+  if (lux_str != NULL) {
+    lux = atoi(lux_str);
+  }
+}
+
+int32_t ambient_light_read(void) { return lux; }
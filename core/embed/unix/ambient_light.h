@@ -0,0 +1,13 @@
+#ifndef TREZORHAL_AMBIENT_LIGHT_H
+#define TREZORHAL_AMBIENT_LIGHT_H
+
+#include <stdint.h>
+
+// Implemented only by boards equipped with an ambient light sensor.
+
+void ambient_light_init(void);
+
+// Returns the measured illuminance in lux, or a negative value on failure.
+int32_t ambient_light_read(void);
+
+#endif
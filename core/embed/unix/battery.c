@@ -0,0 +1,51 @@
+/*
+ * This file is part of the Trezor project, https://trezor.io/
+ *
+ * Copyright (c) SatoshiLabs
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+#include <stdlib.h>
+
+#include "battery.h"
+
+// The emulated battery is configured by environment variables, the battery is
+// unavailable unless TREZOR_BATTERY_SOC is set.
+
+static int32_t soc = -1;
+static secbool charging = secfalse;
+
+void battery_init(void) {
+  const char *soc_str = getenv("TREZOR_BATTERY_SOC");
+  if (soc_str != NULL) {
+    soc = atoi(soc_str);
+  }
+  const char *charging_str = getenv("TREZOR_BATTERY_CHARGING");
+  if (charging_str != NULL && atoi(charging_str) != 0) {
+    charging = sectrue;
+  }
+}
+
+int32_t battery_soc(void) { return soc; }
+
+int32_t battery_voltage_mv(void) {
+  if (soc < 0) {
+    return -1;
+  }
+  // linear approximation of a Li-ion cell between 3.3 V and 4.2 V
+  return 3300 + soc * 9;
+}
+
+secbool battery_charging(void) { return charging; }
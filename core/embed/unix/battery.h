@@ -0,0 +1,2 @@
+// The emulator implements the same interface as the hardware.
+#include "../trezorhal/battery.h"
@@ -115,6 +115,20 @@ error_shutdown(const char *line1, const char *line2, const char *line3,
   exit(4);
 }
 
+void __attribute__((noreturn))
+__fatal_error_kind(error_kind_t kind, const char *expr, const char *msg,
+                   const char *file, int line, const char *func) {
+  (void)kind;
+  __fatal_error(expr, msg, file, line, func);
+}
+
+void __attribute__((noreturn))
+error_shutdown_kind(error_kind_t kind, const char *line1, const char *line2,
+                    const char *line3, const char *line4) {
+  (void)kind;
+  error_shutdown(line1, line2, line3, line4);
+}
+
 void hal_delay(uint32_t ms) { usleep(1000 * ms); }
 
 uint32_t hal_ticks_ms() {
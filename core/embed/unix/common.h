@@ -21,6 +21,7 @@
 #define __TREZORUNIX_COMMON_H__
 
 #include <stdint.h>
+#include "error_kind.h"
 #include "secbool.h"
 
 #ifndef MIN
@@ -46,6 +47,12 @@ __fatal_error(const char *expr, const char *msg, const char *file, int line,
 void __attribute__((noreturn))
 error_shutdown(const char *line1, const char *line2, const char *line3,
                const char *line4);
+void __attribute__((noreturn))
+__fatal_error_kind(error_kind_t kind, const char *expr, const char *msg,
+                   const char *file, int line, const char *func);
+void __attribute__((noreturn))
+error_shutdown_kind(error_kind_t kind, const char *line1, const char *line2,
+                    const char *line3, const char *line4);
 
 #define ensure(expr, msg) \
   (((expr) == sectrue)    \
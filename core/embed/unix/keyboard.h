@@ -0,0 +1,36 @@
+/*
+ * This file is part of the Trezor project, https://trezor.io/
+ *
+ * Copyright (c) SatoshiLabs
+ *
+ * This program is free software: you can redistribute it and/or modify
+ * it under the terms of the GNU General Public License as published by
+ * the Free Software Foundation, either version 3 of the License, or
+ * (at your option) any later version.
+ *
+ * This program is distributed in the hope that it will be useful,
+ * but WITHOUT ANY WARRANTY; without even the implied warranty of
+ * MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ * GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License
+ * along with this program.  If not, see <http://www.gnu.org/licenses/>.
+ */
+
+#ifndef TREZORHAL_KEYBOARD_H
+#define TREZORHAL_KEYBOARD_H
+
+#include <stdint.h>
+
+// keys recognized by the layouts, see KeyEvent in rust/src/ui/event.rs
+#define KEY_LEFT 1
+#define KEY_RIGHT 2
+#define KEY_UP 3
+#define KEY_DOWN 4
+#define KEY_ENTER 5
+#define KEY_BACK 6
+
+// returns the last key pressed in the emulator window, 0 if there is none
+uint32_t keyboard_read(void);
+
+#endif  // TREZORHAL_KEYBOARD_H
@@ -37,6 +37,8 @@
 #include <sys/types.h>
 #include <unistd.h>
 
+#include "ambient_light.h"
+#include "battery.h"
 #include "extmod/misc.h"
 #include "extmod/vfs_posix.h"
 #include "flash.h"
@@ -482,6 +484,12 @@ MP_NOINLINE int main_(int argc, char **argv) {
 
   // Map trezor.flash to memory.
   flash_init();
+#ifdef USE_BATTERY
+  battery_init();
+#endif
+#ifdef USE_AMBIENT_LIGHT
+  ambient_light_init();
+#endif
 
 #if MICROPY_ENABLE_GC
   char *heap = malloc(heap_size);
@@ -26,11 +26,35 @@ extern const char *display_save(const char *prefix);
 
 #if defined TREZOR_MODEL_T
 
+#include "keyboard.h"
 #include "touch.h"
 
 extern int sdl_display_res_x, sdl_display_res_y;
 extern int sdl_touch_offset_x, sdl_touch_offset_y;
 
+// key pressed since the last keyboard_read(), SDL events are consumed by
+// touch_read() so it has to be stored until then
+static uint32_t pending_key = 0;
+
+static uint32_t keyboard_map(SDL_Keycode sym) {
+  switch (sym) {
+    case SDLK_LEFT:
+      return KEY_LEFT;
+    case SDLK_RIGHT:
+      return KEY_RIGHT;
+    case SDLK_UP:
+      return KEY_UP;
+    case SDLK_DOWN:
+      return KEY_DOWN;
+    case SDLK_RETURN:
+    case SDLK_KP_ENTER:
+      return KEY_ENTER;
+    case SDLK_BACKSPACE:
+      return KEY_BACK;
+  }
+  return 0;
+}
+
 uint32_t touch_read(void) {
   SDL_Event event;
   SDL_PumpEvents();
@@ -72,11 +96,28 @@ uint32_t touch_read(void) {
         }
         break;
       }
+      case SDL_KEYDOWN:
+        if (!event.key.repeat) {
+          const uint32_t key = keyboard_map(event.key.keysym.sym);
+          if (key) {
+            pending_key = key;
+          }
+        }
+        break;
     }
   }
   return 0;
 }
 
+uint32_t keyboard_read(void) {
+  const uint32_t key = pending_key;
+  pending_key = 0;
+  return key;
+}
+
+// mouse does not provide contact size nor pressure
+uint32_t touch_get_contact(void) { return 0; }
+
 #elif defined TREZOR_MODEL_1 || defined TREZOR_MODEL_R
 
 #include "button.h"
@@ -197,10 +197,12 @@ TOUCH: int  # interface id of the touch events
 TOUCH_START: int  # event id of touch start event
 TOUCH_MOVE: int  # event id of touch move event
 TOUCH_END: int  # event id of touch end event
+TOUCH_CANCEL: int  # event id of touch cancelled by a second contact
 BUTTON: int  # interface id of button events
 BUTTON_PRESSED: int  # button down event
 BUTTON_RELEASED: int  # button up event
 BUTTON_LEFT: int  # button number of left button
 BUTTON_RIGHT: int  # button number of right button
 USB_CHECK: int # interface id for check of USB data connection
+KEYBOARD: int  # interface id of keyboard events (emulator only)
 WireInterface = Union[HID, WebUSB]
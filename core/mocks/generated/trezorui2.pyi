@@ -8,6 +8,39 @@ def disable_animation(disable: bool) -> None:
     """Disable animations, debug builds only."""
 
 
+# rust/src/ui/model_tr/layout.rs
+def set_reduced_motion(enable: bool) -> None:
+    """Skip animations and transitions to their end states."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def set_bold_text(enable: bool) -> None:
+    """Use heavier fonts in the layouts created from now on."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def enable_auto_brightness(min: int, max: int) -> None:
+    """Adjust the backlight between `min` and `max` according to the ambient
+    light, if the device has a sensor."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def is_lookalike(text: str) -> bool:
+    """Whether the name could be mistaken for a different one, e.g. when
+    spelled with Cyrillic letters resembling the Latin ones."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def set_manual_clock(enable: bool) -> None:
+    """Stop the animation clock, to be stepped by `advance_clock`. Debug
+    builds only."""
+
+
+# rust/src/ui/model_tr/layout.rs
+def advance_clock(millis: int) -> None:
+    """Move the stopped animation clock forward. Debug builds only."""
+
+
 # rust/src/ui/model_tr/layout.rs
 def confirm_action(
     *,
@@ -41,6 +74,44 @@ def disable_animation(disable: bool) -> None:
     """Disable animations, debug builds only."""
 
 
+# rust/src/ui/model_tt/layout.rs
+def set_color_blind(enable: bool) -> None:
+    """Use the color-blind friendly palette in the layouts created from now on."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def set_reduced_motion(enable: bool) -> None:
+    """Skip animations and transitions to their end states."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def set_bold_text(enable: bool) -> None:
+    """Use heavier fonts in the layouts created from now on."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def enable_auto_brightness(min: int, max: int) -> None:
+    """Adjust the backlight between `min` and `max` according to the ambient
+    light, if the device has a sensor."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def is_lookalike(text: str) -> bool:
+    """Whether the name could be mistaken for a different one, e.g. when
+    spelled with Cyrillic letters resembling the Latin ones."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def set_manual_clock(enable: bool) -> None:
+    """Stop the animation clock, to be stepped by `advance_clock`. Debug
+    builds only."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def advance_clock(millis: int) -> None:
+    """Move the stopped animation clock forward. Debug builds only."""
+
+
 # rust/src/ui/model_tt/layout.rs
 def jpeg_info(data: bytes) -> (width: int, height: int, mcu_height: int):
     """Get JPEG image dimensions."""
@@ -88,6 +159,18 @@ def confirm_blob(
     """Confirm byte sequence data."""
 
 
+# rust/src/ui/model_tt/layout.rs
+def confirm_address(
+    *,
+    title: str,
+    address: str,
+    verb_cancel: str | None = None,
+    identicon: str | None = None,
+) -> object:
+    """Confirm address, split into chunks of four characters. The identicon
+    seeded with `identicon`, if given, is drawn next to it."""
+
+
 # rust/src/ui/model_tt/layout.rs
 def confirm_properties(
     *,
@@ -119,6 +202,20 @@ def show_qr(
     """Show QR code."""
 
 
+# rust/src/ui/model_tt/layout.rs
+def show_animated_qr(
+    *,
+    title: str,
+    frames: Iterable[str],
+    time_ms: int = 0,
+    button: str = "DONE",
+) -> object:
+    """Show a sequence of QR codes, e.g. the parts of an UR or BBQr
+    encoded payload, changing every `time_ms` milliseconds. At most 16
+    frames are supported. Tapping the code pauses and resumes the
+    sequence."""
+
+
 # rust/src/ui/model_tt/layout.rs
 def confirm_value(
     *,
@@ -328,8 +425,10 @@ def request_number(
     min_count: int,
     max_count: int,
     description: Callable[[int], str] | None = None,
+    keypad: bool = False,
 ) -> object:
-   """Number input with + and - buttons, description, and info button."""
+   """Number input with + and - buttons, description, and info button. The
+   value can be dragged, and typed in on a keypad if `keypad` is set."""
 
 
 # rust/src/ui/model_tt/layout.rs
@@ -380,6 +479,34 @@ def show_remaining_shares(
    """Shows SLIP39 state after info button is pressed on `confirm_recovery`."""
 
 
+# rust/src/ui/model_tt/layout.rs
+def show_group_grid(
+    *,
+    title: str,
+    group_threshold: int,
+    groups: Iterable[tuple[int, int]],
+    button: str = "CONTINUE",
+) -> object:
+   """Shows SLIP39 advanced backup progress, one row of shares per group.
+   `groups` are pairs of entered shares and the threshold of the group,
+   the threshold is zero for groups without any share entered."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def show_recovery_progress(
+    *,
+    title: str,
+    group_threshold: int,
+    groups: Iterable[tuple[int, int]],
+    words_entered: int = 0,
+    word_count: int = 0,
+    button: str = "CONTINUE",
+) -> object:
+   """Shows SLIP39 recovery progress: words of the current share, groups still
+   needed and the shares entered in every group. Words are not shown if
+   `word_count` is zero."""
+
+
 # rust/src/ui/model_tt/layout.rs
 def show_progress(
     *,
@@ -423,3 +550,29 @@ def show_busyscreen(
     skip_first_paint: bool,
 ) -> CANCELLED:
     """Homescreen used for indicating coinjoin in progress."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def show_battery_charge(
+    *,
+    soc: int,
+    charging: bool = False,
+) -> CANCELLED:
+    """Charge of the battery, updated by the `status_event` of the layout.
+    Dismissed by a touch."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def bootloader_screens() -> list[str]:
+    """Names of the bootloader screens known to `render_bootloader_screen`.
+    Debug builds only."""
+
+
+# rust/src/ui/model_tt/layout.rs
+def render_bootloader_screen(
+    *,
+    screen: str,
+    trace: Callable[[str], None],
+) -> bool:
+    """Paint the bootloader screen with sample arguments and trace it into
+    `trace`, False if there is no such screen. Debug builds only."""
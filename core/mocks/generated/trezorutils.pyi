@@ -73,6 +73,15 @@ def usb_data_connected() -> bool:
     Returns whether USB has been enumerated/configured
     (and is not just connected by cable without data pins)
     """
+
+
+# extmod/modtrezorutils/modtrezorutils.c
+def battery_status() -> tuple[int, bool] | None:
+    """
+    Returns the state of charge of the battery in percent and whether it is
+    being charged, or None if the device has no battery or the fuel gauge
+    could not be read.
+    """
 SCM_REVISION: bytes
 VERSION_MAJOR: int
 VERSION_MINOR: int
@@ -285,6 +285,8 @@ apps.common.writers
 import apps.common.writers
 apps.debug
 import apps.debug
+apps.debug.layouts
+import apps.debug.layouts
 apps.debug.load_device
 import apps.debug.load_device
 apps.homescreen
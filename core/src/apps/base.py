@@ -367,6 +367,7 @@ def get_pinlocked_handler(
 
 # this function is also called when handling ApplySettings
 def reload_settings_from_storage() -> None:
+    import trezorui2
     from trezor import ui
 
     workflow.idle_timer.set(
@@ -374,6 +375,11 @@ def reload_settings_from_storage() -> None:
     )
     wire.experimental_enabled = storage_device.get_experimental_features()
     ui.display.orientation(storage_device.get_rotation())
+    trezorui2.set_reduced_motion(storage_device.get_reduced_motion())
+    trezorui2.set_bold_text(storage_device.get_bold_text())
+    trezorui2.enable_auto_brightness(ui.style.BACKLIGHT_LOW, ui.style.BACKLIGHT_MAX)
+    if utils.MODEL in ("T",):
+        trezorui2.set_color_blind(storage_device.get_color_blind())
 
 
 def boot() -> None:
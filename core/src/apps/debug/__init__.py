@@ -21,13 +21,19 @@ if __debug__:
     from typing import TYPE_CHECKING
 
     if TYPE_CHECKING:
+        from typing import Any
+
         from trezor.ui import Layout
         from trezor.messages import (
             DebugLinkDecision,
             DebugLinkEraseSdCard,
             DebugLinkGetState,
+            DebugLinkLayoutNames,
+            DebugLinkListLayouts,
             DebugLinkRecordScreen,
+            DebugLinkRenderLayout,
             DebugLinkReseedRandom,
+            DebugLinkSetClock,
             DebugLinkState,
             DebugLinkWatchLayout,
         )
@@ -53,12 +59,20 @@ if __debug__:
     LAYOUT_WATCHER_LAYOUT = 2
 
     def screenshot() -> bool:
-        if storage.save_screen:
+        if storage.save_screen and not (
+            storage.skip_sensitive_screen and storage.sensitive_screen
+        ):
             display.save(storage.save_screen_directory + "/refresh-")
             return True
         return False
 
+    # Layout of the running workflow, repainted after DebugLinkRenderLayout.
+    current_layout: Layout | None = None
+
     def notify_layout_change(layout: Layout) -> None:
+        global current_layout
+
+        current_layout = layout
         storage.current_content[:] = layout.read_content()
         if storage.watch_layout_changes or layout_change_chan.takers:
             layout_change_chan.publish(storage.current_content)
@@ -187,12 +201,69 @@ if __debug__:
         if msg.target_directory:
             storage.save_screen_directory = msg.target_directory
             storage.save_screen = True
+            storage.skip_sensitive_screen = bool(msg.skip_sensitive)
         else:
             storage.save_screen = False
             display.clear_save()  # clear C buffers
 
         return Success()
 
+    async def dispatch_DebugLinkListLayouts(
+        ctx: wire.Context, msg: DebugLinkListLayouts
+    ) -> DebugLinkLayoutNames:
+        from trezor.messages import DebugLinkLayoutNames
+
+        from .layouts import names
+
+        return DebugLinkLayoutNames(names=names())
+
+    async def dispatch_DebugLinkRenderLayout(
+        ctx: wire.Context, msg: DebugLinkRenderLayout
+    ) -> DebugLinkLayout:
+        from .layouts import render
+
+        kwargs = {}
+        for arg in msg.arguments:
+            value = arg.string_value
+            if arg.int_value is not None:
+                value = arg.int_value
+            elif arg.bool_value is not None:
+                value = arg.bool_value
+            elif arg.bytes_value is not None:
+                value = arg.bytes_value
+            kwargs[arg.key] = value
+
+        try:
+            lines = render(msg.name, kwargs)
+        except Exception as e:
+            raise wire.DataError(str(e))
+        finally:
+            _restore_layout()
+        if lines is None:
+            raise wire.DataError("Unknown layout")
+        return DebugLinkLayout(lines=lines)
+
+    def _restore_layout() -> None:
+        # Paint the screen of the running workflow over the rendered layout.
+        from trezor import ui
+
+        layout = current_layout
+        display.clear()
+        if layout is not None and hasattr(layout, "request_complete_repaint"):
+            layout.request_complete_repaint()
+            layout._paint()
+        else:
+            ui.refresh()
+
+    async def dispatch_DebugLinkSetClock(
+        ctx: wire.Context, msg: DebugLinkSetClock
+    ) -> Success:
+        if msg.manual is not None:
+            trezorui2.set_manual_clock(msg.manual)
+        if msg.advance_ms is not None:
+            trezorui2.advance_clock(msg.advance_ms)
+        return Success()
+
     async def dispatch_DebugLinkReseedRandom(
         ctx: wire.Context, msg: DebugLinkReseedRandom
     ) -> Success:
@@ -235,6 +306,9 @@ if __debug__:
         register(MessageType.DebugLinkRecordScreen, dispatch_DebugLinkRecordScreen)
         register(MessageType.DebugLinkEraseSdCard, dispatch_DebugLinkEraseSdCard)
         register(MessageType.DebugLinkWatchLayout, dispatch_DebugLinkWatchLayout)
+        register(MessageType.DebugLinkListLayouts, dispatch_DebugLinkListLayouts)
+        register(MessageType.DebugLinkRenderLayout, dispatch_DebugLinkRenderLayout)
+        register(MessageType.DebugLinkSetClock, dispatch_DebugLinkSetClock)
 
         loop.schedule(debuglink_decision_dispatcher())
         if storage.layout_watcher is not LAYOUT_WATCHER_NONE:
@@ -0,0 +1,196 @@
+"""Registry of the layouts shown by DebugLinkRenderLayout.
+
+Every variant of a screen is registered under `<constructor>-<variant>` with
+sample arguments of the `trezorui2` constructor, so that the host can inspect
+all of the screens without walking the flows leading to them. The bootloader
+screens are painted by the Rust bootloader code, under `bootloader-<screen>`.
+"""
+
+import trezorui2
+from trezor import utils
+
+if __debug__:
+    from typing import TYPE_CHECKING
+
+    if TYPE_CHECKING:
+        from typing import Any
+
+    _BOOTLOADER = "bootloader"
+
+    _ADDRESS = "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq"
+
+    # Sample keyword arguments of the firmware layouts of Trezor T.
+    _LAYOUTS_TT: dict[str, dict[str, Any]] = {
+        "confirm_action-default": dict(
+            title="CONFIRM ACTION", action="Do the thing?", description=None
+        ),
+        "confirm_action-hold_danger": dict(
+            title="WIPE DEVICE",
+            action="Do you really want to wipe the device?",
+            description="All data will be lost.",
+            verb="HOLD TO CONFIRM",
+            hold=True,
+            hold_danger=True,
+        ),
+        "confirm_action-reverse": dict(
+            title="CONFIRM ACTION",
+            action="Do the thing?",
+            description="This cannot be undone.",
+            reverse=True,
+        ),
+        "confirm_blob-text": dict(
+            title="CONFIRM MESSAGE",
+            data="Hello World!",
+            description=None,
+            extra=None,
+        ),
+        "confirm_blob-bytes": dict(
+            title="CONFIRM DATA",
+            data=bytes(range(32)),
+            description="Size: 32 bytes",
+            extra=None,
+            hold=True,
+        ),
+        "confirm_reset_device-default": dict(title="CREATE WALLET", button="CREATE"),
+        "show_qr-default": dict(
+            title="RECEIVE ADDRESS",
+            address=_ADDRESS,
+            verb_cancel="X",
+            case_sensitive=False,
+        ),
+        "show_animated_qr-default": dict(
+            title="EXPORT PSBT",
+            frames=[
+                "UR:BYTES/1-3/LPADAXCF",
+                "UR:BYTES/2-3/LPAOAXCF",
+                "UR:BYTES/3-3/LPAXAXCF",
+            ],
+        ),
+        "confirm_value-default": dict(
+            title="CONFIRM VALUE", description="Value:", value="1.2345 BTC"
+        ),
+        "confirm_joint_total-default": dict(
+            spending_amount="0.1 BTC", total_amount="1.2 BTC"
+        ),
+        "confirm_modify_output-decrease": dict(
+            address=_ADDRESS,
+            sign=-1,
+            amount_change="0.01 BTC",
+            amount_new="0.99 BTC",
+        ),
+        "confirm_modify_fee-increase": dict(
+            sign=1, user_fee_change="0.0001 BTC", total_fee_new="0.0002 BTC"
+        ),
+        "confirm_coinjoin-default": dict(max_rounds="20", max_feerate="10 sats/vbyte"),
+        "show_error-default": dict(title="Error", description="Something went wrong."),
+        "show_warning-cancel": dict(
+            title="Warning", description="Be careful.", allow_cancel=True
+        ),
+        "show_success-default": dict(title="Success", description="All done."),
+        "show_info-default": dict(title="Info", description="Good to know."),
+        "show_simple-default": dict(
+            title=None, description="Simple message.", button="OK"
+        ),
+        "request_pin-default": dict(prompt="Enter PIN", subprompt=""),
+        "request_pin-wrong": dict(
+            prompt="Enter PIN", subprompt="1 try left", wrong_pin=True
+        ),
+        "request_passphrase-default": dict(prompt="Enter passphrase", max_len=50),
+        "request_bip39-default": dict(prompt="Type word 1 of 12"),
+        "request_slip39-default": dict(prompt="Type word 1 of 20"),
+        "confirm_recovery-default": dict(
+            title="RECOVERY",
+            description="Enter the recovery seed.",
+            button="CONTINUE",
+            dry_run=False,
+            info_button=False,
+        ),
+        "select_word_count-default": dict(dry_run=False),
+        "show_group_grid-default": dict(
+            title="RECOVERY PROGRESS",
+            group_threshold=2,
+            groups=[(2, 2), (1, 3), (0, 0)],
+        ),
+        "show_recovery_progress-default": dict(
+            title="SHARE ENTERED",
+            group_threshold=2,
+            groups=[(2, 2), (1, 3), (0, 0)],
+        ),
+        "show_progress-default": dict(title="PLEASE WAIT"),
+        "show_progress-indeterminate": dict(title="PLEASE WAIT", indeterminate=True),
+        "show_homescreen-default": dict(
+            label="My Trezor",
+            hold=False,
+            notification=None,
+            skip_first_paint=False,
+        ),
+        "show_homescreen-notification": dict(
+            label="My Trezor",
+            hold=False,
+            notification="Experimental mode",
+            notification_level=1,
+            skip_first_paint=False,
+        ),
+        "show_lockscreen-default": dict(
+            label="My Trezor", bootscreen=False, skip_first_paint=False
+        ),
+        "show_lockscreen-bootscreen": dict(
+            label="My Trezor", bootscreen=True, skip_first_paint=False
+        ),
+        "show_busyscreen-default": dict(
+            title="CoinJoin in progress",
+            description="Do not disconnect your Trezor.",
+            time_ms=60_000,
+            skip_first_paint=False,
+        ),
+        "show_battery_charge-low": dict(soc=10),
+        "show_battery_charge-charging": dict(soc=42, charging=True),
+    }
+
+    def _layouts() -> dict[str, dict[str, Any]]:
+        if utils.MODEL in ("T",):
+            return _LAYOUTS_TT
+        return {}
+
+    def names() -> list[str]:
+        result = sorted(_layouts())
+        if utils.MODEL in ("T",):
+            result.extend(f"{_BOOTLOADER}-{s}" for s in trezorui2.bootloader_screens())
+        return result
+
+    def render(name: str, kwargs: dict[str, Any]) -> list[str] | None:
+        """Paint the layout registered under `name` and return its trace ending
+        with its bounds, None if there is no such layout. The sample arguments are
+        updated by `kwargs`."""
+        import storage.debug as storage_debug
+        from trezor import ui
+        from trezor.ui import display
+
+        lines: list[str] = []
+
+        def callback(*args: Any) -> None:
+            for arg in args:
+                lines.append(str(arg))
+
+        constructor, _, variant = name.partition("-")
+        if constructor == _BOOTLOADER and utils.MODEL in ("T",):
+            if not trezorui2.render_bootloader_screen(screen=variant, trace=callback):
+                return None
+            storage_debug.sensitive_screen = False
+        else:
+            sample = _layouts().get(name)
+            if sample is None:
+                return None
+            args = dict(sample)
+            args.update(kwargs)
+            layout = getattr(trezorui2, constructor)(**args)
+            # Timers are not delivered, the layout is only painted once.
+            layout.attach_timer_fn(lambda token, deadline: None)
+            display.clear()
+            layout.paint()
+            layout.trace(callback)
+            layout.trace_bounds(callback)
+            storage_debug.sensitive_screen = layout.is_sensitive()
+        ui.refresh()
+
+        return " ".join(lines).split("\n")
@@ -33,6 +33,7 @@ async def get_address(
 
     if msg.show_display:
         title = paths.address_n_to_str(address_n)
-        await show_address(ctx, address, title=title)
+        # seeded the same as the identicons of wallets and block explorers
+        await show_address(ctx, address, title=title, identicon=address.lower())
 
     return EthereumAddress(address=address)
@@ -6,6 +6,7 @@ from trezor.strings import format_plural
 from trezor.ui.layouts import (
     confirm_amount,
     confirm_blob,
+    confirm_lookalike_warning,
     confirm_text,
     confirm_total,
     should_show_more,
@@ -162,9 +163,18 @@ def confirm_empty_typed_message(ctx: Context) -> Awaitable[None]:
     )
 
 
+async def confirm_genuine_name(ctx: Context, name: str) -> None:
+    """Warn before showing a name, e.g. an ENS one, which may imitate another."""
+    import trezorui2
+
+    if trezorui2.is_lookalike(name):
+        await confirm_lookalike_warning(ctx, name)
+
+
 async def should_show_domain(ctx: Context, name: bytes, version: bytes) -> bool:
     domain_name = decode_typed_data(name, "string")
     domain_version = decode_typed_data(version, "string")
+    await confirm_genuine_name(ctx, domain_name)
 
     para = (
         (ui.NORMAL, "Name and version"),
@@ -252,6 +262,8 @@ async def confirm_typed_value(
             ask_pagination=True,
         )
     else:
+        if field.data_type == EthereumDataType.STRING:
+            await confirm_genuine_name(ctx, data)
         await confirm_text(
             ctx,
             "confirm_typed_value",
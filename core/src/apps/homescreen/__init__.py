@@ -1,8 +1,13 @@
 import storage
 import storage.cache
 import storage.device
-from trezor import config, wire
-from trezor.ui.layouts.homescreen import Busyscreen, Homescreen, Lockscreen
+from trezor import config, utils, wire
+from trezor.ui.layouts.homescreen import (
+    BatteryCharge,
+    Busyscreen,
+    Homescreen,
+    Lockscreen,
+)
 
 from apps.base import busy_expiry_ms, lock_device
 
@@ -47,6 +52,11 @@ async def lockscreen() -> None:
 
     # Only show the lockscreen UI if the device can in fact be locked.
     if can_lock_device():
+        battery = utils.battery_status()
+        if battery is not None and battery[1]:
+            # Show the charge while the locked device is charging, a touch
+            # continues to the lockscreen.
+            await BatteryCharge(*battery)
         await Lockscreen(label=storage.device.get_label())
     # Otherwise proceed directly to unlock() call. If the device is already unlocked,
     # it should be a no-op storage-wise, but it resets the internal configuration
@@ -59,6 +59,9 @@ async def apply_settings(ctx: Context, msg: ApplySettings) -> Success:
     msg_safety_checks = msg.safety_checks  # local_cache_attribute
     experimental_features = msg.experimental_features  # local_cache_attribute
     hide_passphrase_from_host = msg.hide_passphrase_from_host  # local_cache_attribute
+    color_blind = msg.color_blind  # local_cache_attribute
+    reduced_motion = msg.reduced_motion  # local_cache_attribute
+    bold_text = msg.bold_text  # local_cache_attribute
 
     if (
         homescreen is None
@@ -70,6 +73,9 @@ async def apply_settings(ctx: Context, msg: ApplySettings) -> Success:
         and msg_safety_checks is None
         and experimental_features is None
         and hide_passphrase_from_host is None
+        and color_blind is None
+        and reduced_motion is None
+        and bold_text is None
     ):
         raise ProcessError("No setting provided")
 
@@ -125,6 +131,18 @@ async def apply_settings(ctx: Context, msg: ApplySettings) -> Success:
         await _require_confirm_hide_passphrase_from_host(ctx, hide_passphrase_from_host)
         storage_device.set_hide_passphrase_from_host(hide_passphrase_from_host)
 
+    if color_blind is not None:
+        await _require_confirm_color_blind(ctx, color_blind)
+        storage_device.set_color_blind(color_blind)
+
+    if reduced_motion is not None:
+        await _require_confirm_reduced_motion(ctx, reduced_motion)
+        storage_device.set_reduced_motion(reduced_motion)
+
+    if bold_text is not None:
+        await _require_confirm_bold_text(ctx, bold_text)
+        storage_device.set_bold_text(bold_text)
+
     reload_settings_from_storage()
 
     return Success(message="Settings applied")
@@ -290,3 +308,36 @@ async def _require_confirm_hide_passphrase_from_host(
             description="Hide passphrase coming from host?",
             br_code=BRT_PROTECT_CALL,
         )
+
+
+async def _require_confirm_color_blind(ctx: GenericContext, enable: bool) -> None:
+    await confirm_action(
+        ctx,
+        "set_color_blind",
+        "Color-blind mode",
+        description="Do you really want to {} color-blind friendly colors?",
+        description_param="use" if enable else "stop using",
+        br_code=BRT_PROTECT_CALL,
+    )
+
+
+async def _require_confirm_reduced_motion(ctx: GenericContext, enable: bool) -> None:
+    await confirm_action(
+        ctx,
+        "set_reduced_motion",
+        "Reduce motion",
+        description="Do you really want to {} animations?",
+        description_param="turn off" if enable else "turn on",
+        br_code=BRT_PROTECT_CALL,
+    )
+
+
+async def _require_confirm_bold_text(ctx: GenericContext, enable: bool) -> None:
+    await confirm_action(
+        ctx,
+        "set_bold_text",
+        "Bold text",
+        description="Do you really want to {} bold text?",
+        description_param="use" if enable else "stop using",
+        br_code=BRT_PROTECT_CALL,
+    )
@@ -179,7 +179,14 @@ async def _process_words(
     if secret is None:  # SLIP-39
         assert share is not None
         if share.group_count and share.group_count > 1:
-            await layout.show_group_share_success(ctx, share.index, share.group_index)
+            shares_remaining = storage_recovery.fetch_slip39_remaining_shares()
+            assert shares_remaining  # stored with the share
+            await layout.show_group_share_success(
+                ctx,
+                _group_progress(shares_remaining),
+                share.group_threshold,
+                share.group_index,
+            )
         await _request_share_next_screen(ctx)
 
     return secret, backup_type
@@ -253,6 +260,27 @@ async def _show_remaining_groups_and_shares(ctx: GenericContext) -> None:
             groups.add((remaining, tuple(identifier)))
 
     assert share  # share needs to be set
+    await layout.show_group_progress(
+        ctx, _group_progress(shares_remaining), share.group_threshold
+    )
     return await layout.show_remaining_shares(
         ctx, groups, shares_remaining, share.group_threshold
     )
+
+
+def _group_progress(shares_remaining: list[int]) -> list[tuple[int, int]]:
+    """
+    Entered shares and the threshold of every group, the threshold is 0 for
+    groups without any share entered.
+    """
+    from trezor.crypto.slip39 import MAX_SHARE_COUNT
+    import storage.recovery_shares as storage_recovery_shares
+
+    progress: list[tuple[int, int]] = []
+    for index, remaining in enumerate(shares_remaining):
+        if remaining == MAX_SHARE_COUNT:
+            progress.append((0, 0))
+        else:
+            entered = len(storage_recovery_shares.fetch_group(index))
+            progress.append((entered, entered + remaining))
+    return progress
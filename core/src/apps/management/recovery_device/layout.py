@@ -4,6 +4,7 @@ from trezor.enums import ButtonRequestType
 from trezor.ui.layouts import show_warning
 from trezor.ui.layouts.recovery import (  # noqa: F401
     request_word_count,
+    show_group_progress,
     show_group_share_success,
     show_remaining_shares,
 )
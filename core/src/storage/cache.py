@@ -44,6 +44,7 @@ APP_MISC_COSI_COMMITMENT = const(6 | _SESSIONLESS_FLAG)
 HOMESCREEN_ON = object()
 LOCKSCREEN_ON = object()
 BUSYSCREEN_ON = object()
+BATTERY_CHARGE_ON = object()
 homescreen_shown: object | None = None
 
 
@@ -6,6 +6,10 @@ if not __debug__:
 if __debug__:
     save_screen = False
     save_screen_directory = "."
+    # set while a layout showing secrets (PIN, seed words) is on screen
+    sensitive_screen = False
+    # whether such layouts are left out of the recording
+    skip_sensitive_screen = False
 
     current_content: list[str] = [""] * 20
     current_content.clear()
@@ -35,6 +35,9 @@ INITIALIZED                = const(0x13)  # bool (0x01 or empty)
 _SAFETY_CHECK_LEVEL        = const(0x14)  # int
 _EXPERIMENTAL_FEATURES     = const(0x15)  # bool (0x01 or empty)
 _HIDE_PASSPHRASE_FROM_HOST = const(0x16)  # bool (0x01 or empty)
+_COLOR_BLIND               = const(0x17)  # bool (0x01 or empty)
+_REDUCED_MOTION            = const(0x18)  # bool (0x01 or empty)
+_BOLD_TEXT                 = const(0x19)  # bool (0x01 or empty)
 
 SAFETY_CHECK_LEVEL_STRICT  : Literal[0] = const(0)
 SAFETY_CHECK_LEVEL_PROMPT  : Literal[1] = const(1)
@@ -349,3 +352,45 @@ def get_hide_passphrase_from_host() -> bool:
     Whether we should hide the passphrase from the host.
     """
     return common.get_bool(_NAMESPACE, _HIDE_PASSPHRASE_FROM_HOST)
+
+
+def set_color_blind(enable: bool) -> None:
+    """
+    Whether the UI uses the color-blind friendly palette.
+    """
+    common.set_bool(_NAMESPACE, _COLOR_BLIND, enable, public=True)
+
+
+def get_color_blind() -> bool:
+    """
+    Whether the UI uses the color-blind friendly palette.
+    """
+    return common.get_bool(_NAMESPACE, _COLOR_BLIND, public=True)
+
+
+def set_reduced_motion(enable: bool) -> None:
+    """
+    Whether the UI skips animations and transitions.
+    """
+    common.set_bool(_NAMESPACE, _REDUCED_MOTION, enable, public=True)
+
+
+def get_reduced_motion() -> bool:
+    """
+    Whether the UI skips animations and transitions.
+    """
+    return common.get_bool(_NAMESPACE, _REDUCED_MOTION, public=True)
+
+
+def set_bold_text(enable: bool) -> None:
+    """
+    Whether the UI uses heavier fonts.
+    """
+    common.set_bool(_NAMESPACE, _BOLD_TEXT, enable, public=True)
+
+
+def get_bold_text() -> bool:
+    """
+    Whether the UI uses heavier fonts.
+    """
+    return common.get_bool(_NAMESPACE, _BOLD_TEXT, public=True)
@@ -89,6 +89,10 @@ DebugLinkReseedRandom = 9002
 DebugLinkRecordScreen = 9003
 DebugLinkEraseSdCard = 9005
 DebugLinkWatchLayout = 9006
+DebugLinkRenderLayout = 9007
+DebugLinkSetClock = 9008
+DebugLinkListLayouts = 9009
+DebugLinkLayoutNames = 9010
 if not utils.BITCOIN_ONLY:
     SetU2FCounter = 63
     GetNextU2FCounter = 80
@@ -112,6 +112,10 @@ if TYPE_CHECKING:
         DebugLinkRecordScreen = 9003
         DebugLinkEraseSdCard = 9005
         DebugLinkWatchLayout = 9006
+        DebugLinkRenderLayout = 9007
+        DebugLinkSetClock = 9008
+        DebugLinkListLayouts = 9009
+        DebugLinkLayoutNames = 9010
         EthereumGetPublicKey = 450
         EthereumPublicKey = 451
         EthereumGetAddress = 56
@@ -2193,6 +2193,9 @@ if TYPE_CHECKING:
         safety_checks: "SafetyCheckLevel | None"
         experimental_features: "bool | None"
         hide_passphrase_from_host: "bool | None"
+        color_blind: "bool | None"
+        reduced_motion: "bool | None"
+        bold_text: "bool | None"
 
         def __init__(
             self,
@@ -2207,6 +2210,9 @@ if TYPE_CHECKING:
             safety_checks: "SafetyCheckLevel | None" = None,
             experimental_features: "bool | None" = None,
             hide_passphrase_from_host: "bool | None" = None,
+            color_blind: "bool | None" = None,
+            reduced_motion: "bool | None" = None,
+            bold_text: "bool | None" = None,
         ) -> None:
             pass
 
@@ -2664,11 +2670,13 @@ if TYPE_CHECKING:
 
     class DebugLinkRecordScreen(protobuf.MessageType):
         target_directory: "str | None"
+        skip_sensitive: "bool | None"
 
         def __init__(
             self,
             *,
             target_directory: "str | None" = None,
+            skip_sensitive: "bool | None" = None,
         ) -> None:
             pass
 
@@ -2846,6 +2854,80 @@ if TYPE_CHECKING:
         def is_type_of(cls, msg: Any) -> TypeGuard["DebugLinkWatchLayout"]:
             return isinstance(msg, cls)
 
+    class DebugLinkLayoutArgument(protobuf.MessageType):
+        key: "str"
+        string_value: "str | None"
+        int_value: "int | None"
+        bool_value: "bool | None"
+        bytes_value: "bytes | None"
+
+        def __init__(
+            self,
+            *,
+            key: "str",
+            string_value: "str | None" = None,
+            int_value: "int | None" = None,
+            bool_value: "bool | None" = None,
+            bytes_value: "bytes | None" = None,
+        ) -> None:
+            pass
+
+        @classmethod
+        def is_type_of(cls, msg: Any) -> TypeGuard["DebugLinkLayoutArgument"]:
+            return isinstance(msg, cls)
+
+    class DebugLinkRenderLayout(protobuf.MessageType):
+        name: "str"
+        arguments: "list[DebugLinkLayoutArgument]"
+
+        def __init__(
+            self,
+            *,
+            name: "str",
+            arguments: "list[DebugLinkLayoutArgument] | None" = None,
+        ) -> None:
+            pass
+
+        @classmethod
+        def is_type_of(cls, msg: Any) -> TypeGuard["DebugLinkRenderLayout"]:
+            return isinstance(msg, cls)
+
+    class DebugLinkSetClock(protobuf.MessageType):
+        manual: "bool | None"
+        advance_ms: "int | None"
+
+        def __init__(
+            self,
+            *,
+            manual: "bool | None" = None,
+            advance_ms: "int | None" = None,
+        ) -> None:
+            pass
+
+        @classmethod
+        def is_type_of(cls, msg: Any) -> TypeGuard["DebugLinkSetClock"]:
+            return isinstance(msg, cls)
+
+    class DebugLinkListLayouts(protobuf.MessageType):
+
+        @classmethod
+        def is_type_of(cls, msg: Any) -> TypeGuard["DebugLinkListLayouts"]:
+            return isinstance(msg, cls)
+
+    class DebugLinkLayoutNames(protobuf.MessageType):
+        names: "list[str]"
+
+        def __init__(
+            self,
+            *,
+            names: "list[str] | None" = None,
+        ) -> None:
+            pass
+
+        @classmethod
+        def is_type_of(cls, msg: Any) -> TypeGuard["DebugLinkLayoutNames"]:
+            return isinstance(msg, cls)
+
     class EosGetPublicKey(protobuf.MessageType):
         address_n: "list[int]"
         show_display: "bool | None"
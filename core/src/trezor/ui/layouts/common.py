@@ -37,6 +37,9 @@ async def interact(
     br_type: str,
     br_code: ButtonRequestType = ButtonRequestType.Other,
 ) -> Any:
+    if hasattr(layout, "request_attention"):
+        # The host is waiting for the user, pulse the backlight if they are away.
+        layout.request_attention()  # type: ignore [Cannot access member "request_attention" for type "LayoutType"]
     if hasattr(layout, "page_count") and layout.page_count() > 1:  # type: ignore [Cannot access member "page_count" for type "LayoutType"]
         await button_request(ctx, br_type, br_code, pages=layout.page_count())  # type: ignore [Cannot access member "page_count" for type "LayoutType"]
         return await ctx.wait(layout)
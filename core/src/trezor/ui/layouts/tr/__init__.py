@@ -32,6 +32,9 @@ class RustLayout(ui.Layout):
     def set_timer(self, token: int, deadline: int) -> None:
         self.timer.schedule(deadline, token)
 
+    def request_attention(self) -> None:
+        self.layout.request_attention()
+
     def create_tasks(self) -> tuple[loop.Task, ...]:
         return self.handle_input_and_rendering(), self.handle_timers()
 
@@ -109,6 +112,17 @@ async def confirm_action(
         raise exc
 
 
+async def confirm_lookalike_warning(ctx: wire.GenericContext, name: str) -> None:
+    await confirm_action(
+        ctx,
+        "lookalike_warning",
+        "Suspicious name",
+        description="{} may imitate a different name.",
+        description_param=name,
+        br_code=ButtonRequestType.Warning,
+    )
+
+
 async def confirm_text(
     ctx: wire.GenericContext,
     br_type: str,
@@ -166,6 +180,7 @@ async def show_address(
     xpubs: Sequence[str] = (),
     address_extra: str | None = None,
     title_qr: str | None = None,
+    identicon: str | None = None,  # not shown on this model
 ) -> None:
     result = await interact(
         ctx,
@@ -1,6 +1,6 @@
 from typing import TYPE_CHECKING
 
-from trezor import io, log, loop, ui
+from trezor import io, log, loop, ui, utils
 from trezor.enums import ButtonRequestType
 from trezor.wire import ActionCancelled
 
@@ -48,11 +48,19 @@ class RustLayout(ui.Layout):
         msg = self.layout.request_complete_repaint()
         assert msg is None
 
+    def request_attention(self) -> None:
+        self.layout.request_attention()
+
     def _paint(self) -> None:
         import storage.cache as storage_cache
 
         painted = self.layout.paint()
 
+        if __debug__:
+            import storage.debug as storage_debug
+
+            storage_debug.sensitive_screen = self.layout.is_sensitive()
+
         ui.refresh()
         if storage_cache.homescreen_shown is not None and painted:
             storage_cache.homescreen_shown = None
@@ -62,13 +70,16 @@ class RustLayout(ui.Layout):
         def create_tasks(self) -> tuple[loop.AwaitableTask, ...]:
             from apps.debug import confirm_signal, input_signal
 
-            return (
+            tasks: tuple[loop.AwaitableTask, ...] = (
                 self.handle_timers(),
                 self.handle_input_and_rendering(),
                 self.handle_swipe(),
                 confirm_signal(),
                 input_signal(),
             )
+            if utils.EMULATOR:
+                tasks += (self.handle_keyboard(),)
+            return tasks
 
         def read_content(self) -> list[str]:
             result: list[str] = []
@@ -114,6 +125,18 @@ class RustLayout(ui.Layout):
                     self.notify_backup()
                 notify_layout_change(self)
 
+        async def handle_keyboard(self):
+            from apps.debug import notify_layout_change
+
+            keyboard = loop.wait(io.KEYBOARD)
+            while True:
+                key = await keyboard
+                msg = self.layout.key_event(key)
+                self._paint()
+                if msg is not None:
+                    raise ui.Result(msg)
+                notify_layout_change(self)
+
         def notify_backup(self):
             from apps.debug import reset_current_words
 
@@ -125,7 +148,8 @@ class RustLayout(ui.Layout):
             words: list[str] = []
             for line in content[start_pos + len(start) : end_pos].split("\n"):
                 line = line.strip()
-                if not line:
+                if not line or line.startswith("---"):
+                    # skip page markers
                     continue
                 space_pos = line.index(" ")
                 words.append(line[space_pos + 1 :])
@@ -158,17 +182,30 @@ class RustLayout(ui.Layout):
         from trezor import workflow
 
         touch = loop.wait(io.TOUCH)
+        touch_ifaces = (io.TOUCH,)
+        pending = [0, 0]
         self._first_paint()
         # self.layout.bounds()
         while True:
             # Using `yield` instead of `await` to avoid allocations.
             event, x, y = yield touch
             workflow.idle_timer.touch()
-            msg = None
-            if event in (io.TOUCH_START, io.TOUCH_MOVE, io.TOUCH_END):
-                msg = self.layout.touch_event(event, x, y)
-            if msg is not None:
-                raise ui.Result(msg)
+            while True:
+                msg = None
+                if event in (
+                    io.TOUCH_START,
+                    io.TOUCH_MOVE,
+                    io.TOUCH_END,
+                    io.TOUCH_CANCEL,
+                ):
+                    msg = self.layout.touch_event(event, x, y)
+                if msg is not None:
+                    raise ui.Result(msg)
+                # Handle events which arrived in the meantime before painting, so
+                # that a fast drag results in a single paint pass.
+                if not io.poll(touch_ifaces, pending, 0):
+                    break
+                event, x, y = pending[1]
             self._paint()
             # self.layout.bounds()
 
@@ -334,6 +371,23 @@ async def confirm_path_warning(
     )
 
 
+async def confirm_lookalike_warning(ctx: GenericContext, name: str) -> None:
+    await raise_if_not_confirmed(
+        interact(
+            ctx,
+            RustLayout(
+                trezorui2.show_warning(
+                    title="Suspicious name",
+                    description=f"{name} may imitate a different name.",
+                    allow_cancel=True,
+                )
+            ),
+            "lookalike_warning",
+            ButtonRequestType.Warning,
+        )
+    )
+
+
 async def confirm_homescreen(
     ctx: GenericContext,
     image: bytes,
@@ -389,20 +443,28 @@ async def show_address(
     xpubs: Sequence[str] = (),
     address_extra: str | None = None,
     title_qr: str | None = None,
+    identicon: str | None = None,
 ) -> None:
     is_multisig = len(xpubs) > 0
     while True:
+        if network or address_extra:
+            layout = trezorui2.confirm_blob(
+                title=title.upper(),
+                data=address,
+                description=network or "",
+                extra=address_extra or "",
+                verb_cancel="QR",
+            )
+        else:
+            layout = trezorui2.confirm_address(
+                title=title.upper(),
+                address=address,
+                verb_cancel="QR",
+                identicon=identicon,
+            )
         result = await interact(
             ctx,
-            RustLayout(
-                trezorui2.confirm_blob(
-                    title=title.upper(),
-                    data=address,
-                    description=network or "",
-                    extra=address_extra or "",
-                    verb_cancel="QR",
-                )
-            ),
+            RustLayout(layout),
             "show_address",
             ButtonRequestType.Address,
         )
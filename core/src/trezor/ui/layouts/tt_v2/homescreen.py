@@ -1,3 +1,4 @@
+from micropython import const
 from typing import TYPE_CHECKING
 
 import storage.cache as storage_cache
@@ -11,6 +12,13 @@ if TYPE_CHECKING:
     from trezor import loop
     from typing import Any, Tuple
 
+# Indicators of the status bar, see `StatusEvent` in the Rust UI.
+_STATUS_LOCKED = const(1)
+_STATUS_BATTERY = const(2)
+_STATUS_CHARGING = const(3)
+
+_STATUS_POLL_MS = const(5_000)
+
 
 class HomescreenBase(RustLayout):
     RENDER_INDICATOR: object | None = None
@@ -18,6 +26,39 @@ class HomescreenBase(RustLayout):
     def __init__(self, layout: Any) -> None:
         super().__init__(layout=layout)
 
+    def set_locked(self, locked: bool) -> None:
+        self._status_event(_STATUS_LOCKED, int(locked))
+
+    def set_battery(self, level: int) -> None:
+        self._status_event(_STATUS_BATTERY, level)
+
+    def set_charging(self, charging: bool) -> None:
+        self._status_event(_STATUS_CHARGING, int(charging))
+
+    def is_locked(self) -> bool:
+        from trezor import config
+
+        return not config.is_unlocked()
+
+    async def status_task(self) -> None:
+        from trezor import loop, utils
+
+        # The layout repaints only the indicators that have changed.
+        while True:
+            self.set_locked(self.is_locked())
+            status = utils.battery_status()
+            if status is not None:
+                soc, charging = status
+                self.set_battery(soc)
+                self.set_charging(charging)
+            await loop.sleep(_STATUS_POLL_MS)
+
+    def _status_event(self, indicator: int, value: int) -> None:
+        # Repaints only the changed indicator.
+        self.layout.status_event(indicator, value)
+        self.layout.paint()
+        ui.refresh()
+
     def _paint(self) -> None:
         self.layout.paint()
         ui.refresh()
@@ -74,7 +115,10 @@ class Homescreen(HomescreenBase):
             ui.refresh()
 
     def create_tasks(self) -> Tuple[loop.AwaitableTask, ...]:
-        return super().create_tasks() + (self.usb_checker_task(),)
+        return super().create_tasks() + (
+            self.usb_checker_task(),
+            self.status_task(),
+        )
 
 
 class Lockscreen(HomescreenBase):
@@ -101,6 +145,13 @@ class Lockscreen(HomescreenBase):
             ),
         )
 
+    def is_locked(self) -> bool:
+        # The bootscreen is shown before the first unlock.
+        return not self.bootscreen and super().is_locked()
+
+    def create_tasks(self) -> Tuple[loop.AwaitableTask, ...]:
+        return super().create_tasks() + (self.status_task(),)
+
     async def __iter__(self) -> Any:
         result = await super().__iter__()
         if self.bootscreen:
@@ -108,6 +159,18 @@ class Lockscreen(HomescreenBase):
         return result
 
 
+class BatteryCharge(HomescreenBase):
+    RENDER_INDICATOR = storage_cache.BATTERY_CHARGE_ON
+
+    def __init__(self, soc: int, charging: bool) -> None:
+        super().__init__(
+            layout=trezorui2.show_battery_charge(soc=soc, charging=charging),
+        )
+
+    def create_tasks(self) -> Tuple[loop.AwaitableTask, ...]:
+        return super().create_tasks() + (self.status_task(),)
+
+
 class Busyscreen(HomescreenBase):
     RENDER_INDICATOR = storage_cache.BUSYSCREEN_ON
 
@@ -1,3 +1,4 @@
+from micropython import const
 from typing import TYPE_CHECKING
 
 from trezor.enums import ButtonRequestType
@@ -9,13 +10,45 @@ from ..common import interact
 from . import RustLayout
 
 if TYPE_CHECKING:
-    from typing import Iterable, Callable
+    from typing import Any, Iterable, Callable
+    from trezor import loop
     from trezor.wire import GenericContext
 
 
 CONFIRMED = trezorui2.CONFIRMED  # global_import_cache
 INFO = trezorui2.INFO  # global_import_cache
 
+# Time the progress is shown before the share just entered is filled in.
+_REVEAL_DELAY_MS = const(500)
+
+
+class RecoveryProgress(RustLayout):
+    def __init__(self, layout: Any, reveal: tuple[int, int] | None = None) -> None:
+        super().__init__(layout=layout)
+        # Group and its entered shares to show after the first paint.
+        self.reveal = reveal
+
+    def set_shares_entered(self, group: int, entered: int) -> None:
+        from trezor import ui
+
+        # Repaints only the group and the summary.
+        self.layout.recovery_event(group, entered)
+        self.layout.paint()
+        ui.refresh()
+
+    async def reveal_task(self) -> None:
+        from trezor import loop
+
+        if self.reveal is not None:
+            await loop.sleep(_REVEAL_DELAY_MS)
+            self.set_shares_entered(*self.reveal)
+            self.reveal = None
+        # Returning would restart the other tasks and paint the screen again.
+        await loop.chan().take()
+
+    def create_tasks(self) -> tuple[loop.AwaitableTask, ...]:
+        return super().create_tasks() + (self.reveal_task(),)
+
 
 async def _is_confirmed_info(
     ctx: GenericContext,
@@ -52,6 +85,27 @@ async def request_word(
     return word
 
 
+async def show_group_progress(
+    ctx: GenericContext,
+    groups: Iterable[tuple[int, int]],  # entered + threshold, 0 if not known yet
+    group_threshold: int,
+) -> None:
+    result = await interact(
+        ctx,
+        RustLayout(
+            trezorui2.show_group_grid(
+                title="RECOVERY PROGRESS",
+                group_threshold=group_threshold,
+                groups=groups,
+            )
+        ),
+        "show_group_progress",
+        ButtonRequestType.Other,
+    )
+    if result is not CONFIRMED:
+        raise ActionCancelled
+
+
 async def show_remaining_shares(
     ctx: GenericContext,
     groups: Iterable[tuple[int, tuple[str, ...]]],  # remaining + list 3 words
@@ -90,19 +144,24 @@ async def show_remaining_shares(
 
 
 async def show_group_share_success(
-    ctx: GenericContext, share_index: int, group_index: int
+    ctx: GenericContext,
+    groups: list[tuple[int, int]],  # entered + threshold, 0 if not known yet
+    group_threshold: int,
+    group_index: int,
 ) -> None:
+    # The share just entered is filled in after the screen is shown.
+    entered, threshold = groups[group_index]
+    before = list(groups)
+    before[group_index] = (entered - 1, threshold)
     result = await interact(
         ctx,
-        RustLayout(
-            trezorui2.show_group_share_success(
-                lines=[
-                    "You have entered",
-                    f"Share {share_index + 1}",
-                    "from",
-                    f"Group {group_index + 1}",
-                ],
-            )
+        RecoveryProgress(
+            trezorui2.show_recovery_progress(
+                title="SHARE ENTERED",
+                group_threshold=group_threshold,
+                groups=before,
+            ),
+            reveal=(group_index, entered),
         ),
         "share_success",
         ButtonRequestType.Other,
@@ -166,6 +166,7 @@ async def _prompt_number(
     min_count: int,
     max_count: int,
     br_name: str,
+    keypad: bool = False,
 ) -> int:
     num_input = RustLayout(
         trezorui2.request_number(
@@ -174,6 +175,7 @@ async def _prompt_number(
             count=count,
             min_count=min_count,
             max_count=max_count,
+            keypad=keypad,
         )
     )
 
@@ -290,6 +292,7 @@ async def slip39_prompt_number_of_shares(
         min_count,
         max_count,
         "slip39_shares",
+        keypad=True,
     )
 
 
@@ -8,6 +8,7 @@ from trezorutils import (  # noqa: F401
     VERSION_MAJOR,
     VERSION_MINOR,
     VERSION_PATCH,
+    battery_status,
     consteq,
     firmware_hash,
     firmware_vendor,
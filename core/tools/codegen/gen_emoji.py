@@ -0,0 +1,270 @@
+#!/usr/bin/env python3
+
+# script used to generate /embed/extmod/modtrezorui/fonts/font_emoji.c
+
+# Curated set of emoji which commonly appear in device labels and account
+# names. Glyphs are drawn as 14x14 pixel art for the 4-bit fonts (model T) and
+# downscaled to 7x7 for the 1-bit fonts (model 1 and R).
+
+EMOJI = {
+    0x2714: (  # heavy check mark
+        "..............",
+        "............##",
+        "...........###",
+        "..........###.",
+        ".........###..",
+        "........###...",
+        "##.....###....",
+        "###...###.....",
+        ".###.###......",
+        "..#####.......",
+        "...###........",
+        "....#.........",
+        "..............",
+        "..............",
+    ),
+    0x26A1: (  # high voltage
+        "........###...",
+        ".......###....",
+        "......###.....",
+        ".....###......",
+        "....###.......",
+        "...#########..",
+        "..#########...",
+        ".......###....",
+        "......###.....",
+        ".....###......",
+        "....###.......",
+        "...###........",
+        "..##..........",
+        "..............",
+    ),
+    0x2764: (  # heavy black heart
+        "..............",
+        "..###....###..",
+        ".#####..#####.",
+        "##############",
+        "##############",
+        "##############",
+        ".############.",
+        "..##########..",
+        "...########...",
+        "....######....",
+        ".....####.....",
+        "......##......",
+        "..............",
+        "..............",
+    ),
+    0x2B50: (  # white medium star
+        "......##......",
+        "......##......",
+        ".....####.....",
+        ".....####.....",
+        "##############",
+        ".############.",
+        "..##########..",
+        "...########...",
+        "...########...",
+        "..####..####..",
+        "..###....###..",
+        ".##........##.",
+        ".#..........#.",
+        "..............",
+    ),
+    0x1F44D: (  # thumbs up
+        "......##......",
+        ".....###......",
+        ".....###......",
+        "....###.......",
+        "...##########.",
+        "##.##########.",
+        "##.#########..",
+        "##.##########.",
+        "##.#########..",
+        "##.##########.",
+        "##.#########..",
+        "##.########...",
+        "..............",
+        "..............",
+    ),
+    0x1F4B0: (  # money bag
+        "....######....",
+        ".....####.....",
+        "......##......",
+        "....######....",
+        "..##########..",
+        ".#####..#####.",
+        "#####.##.#####",
+        "######..######",
+        "#######.#.####",
+        "#####.##.#####",
+        "######..######",
+        ".############.",
+        "..##########..",
+        "..............",
+    ),
+    0x1F511: (  # key
+        "..####........",
+        ".######.......",
+        "###..###......",
+        "###..###......",
+        ".######.......",
+        "..#####.......",
+        "....###.......",
+        "....####......",
+        "....###.......",
+        "....#####.....",
+        "....###.......",
+        "....####......",
+        "....###.......",
+        "..............",
+    ),
+    0x1F512: (  # lock
+        "....######....",
+        "...##....##...",
+        "..##......##..",
+        "..##......##..",
+        "..##......##..",
+        ".############.",
+        ".############.",
+        ".#####..#####.",
+        ".#####..#####.",
+        ".######.#####.",
+        ".######.#####.",
+        ".############.",
+        ".############.",
+        "..............",
+    ),
+    0x1F525: (  # fire
+        "......#.......",
+        "......##......",
+        ".....###......",
+        "....####...#..",
+        "...#####..##..",
+        "..#######.###.",
+        "..###########.",
+        ".#####.######.",
+        ".####...#####.",
+        ".####...#####.",
+        ".###.....####.",
+        "..###...####..",
+        "...########...",
+        "..............",
+    ),
+    0x1F600: (  # grinning face
+        "....######....",
+        "..##########..",
+        ".############.",
+        ".###.####.###.",
+        "####.####.####",
+        "##############",
+        "##############",
+        "##..........##",
+        "###........###",
+        ".###......###.",
+        ".############.",
+        "..##########..",
+        "....######....",
+        "..............",
+    ),
+    0x1F680: (  # rocket
+        "...........###",
+        ".........#####",
+        "........######",
+        "......#######.",
+        ".....####..##.",
+        "...#####...#..",
+        ".#########....",
+        "...######.....",
+        "..#.####......",
+        ".##..##.......",
+        ".###.#........",
+        "####..........",
+        "###...........",
+        "..............",
+    ),
+}
+
+SIZE = 14
+
+# metrics relative to the 20px (model T) and 8px (model 1, R) fonts
+METRICS = {
+    4: dict(size=SIZE, advance=16, bearing_x=1, bearing_y=14),
+    1: dict(size=SIZE // 2, advance=8, bearing_x=0, bearing_y=7),
+}
+
+
+def pixels(art, bpp):
+    if bpp == 4:
+        return [15 if c == "#" else 0 for row in art for c in row]
+    # downscale 2x2 blocks, pixel is set if at least half of the block is
+    res = []
+    for y in range(0, SIZE, 2):
+        for x in range(0, SIZE, 2):
+            block = art[y][x : x + 2] + art[y + 1][x : x + 2]
+            res.append(1 if block.count("#") >= 2 else 0)
+    return res
+
+
+def pack(values, bpp):
+    per_byte = 8 // bpp
+    values = values + [0] * (-len(values) % per_byte)
+    res = []
+    for i in range(0, len(values), per_byte):
+        byte = 0
+        for v in values[i : i + per_byte]:
+            byte = (byte << bpp) | v
+        res.append(byte)
+    return res
+
+
+def glyph_data(art, bpp):
+    assert len(art) == SIZE and all(len(row) == SIZE for row in art)
+    m = METRICS[bpp]
+    header = [m["size"], m["size"], m["advance"], m["bearing_x"], m["bearing_y"]]
+    return header + pack(pixels(art, bpp), bpp)
+
+
+def main():
+    with open("../../embed/extmod/modtrezorui/fonts/font_emoji.c", "wt") as f:
+        f.write("// This file is generated by core/tools/codegen/gen_emoji.py\n\n")
+        f.write("#include <stddef.h>\n\n")
+        f.write('#include "font_emoji.h"\n')
+        f.write('#include "fonts.h"\n\n')
+        f.write("// clang-format off\n\n")
+        f.write("// - the first two bytes are width and height of the glyph\n")
+        f.write(
+            "// - the third, fourth and fifth bytes are advance, bearingX and bearingY of the horizontal metrics of the glyph\n"
+        )
+        f.write("// - the rest is packed glyph data in the bit depth of the fonts\n\n")
+        for bpp, cond in ((4, "TREZOR_FONT_BPP == 4"), (1, "TREZOR_FONT_BPP == 1")):
+            f.write("#%s %s\n" % ("if" if bpp == 4 else "elif", cond))
+            for codepoint, art in sorted(EMOJI.items()):
+                data = ", ".join(str(x) for x in glyph_data(art, bpp))
+                f.write(
+                    "static const uint8_t Font_Emoji_glyph_%X[] = { %s };\n"
+                    % (codepoint, data)
+                )
+            f.write("\n")
+        f.write("#else\n#error Unsupported TREZOR_FONT_BPP value\n#endif\n\n")
+        f.write("static const struct {\n")
+        f.write("  uint32_t codepoint;\n")
+        f.write("  const uint8_t *glyph;\n")
+        f.write("} Font_Emoji[] = {\n")
+        for codepoint in sorted(EMOJI):
+            f.write("    {0x%X, Font_Emoji_glyph_%X},\n" % (codepoint, codepoint))
+        f.write("};\n\n")
+        f.write("const uint8_t *font_emoji_get_glyph(uint32_t codepoint) {\n")
+        f.write(
+            "  for (size_t i = 0; i < sizeof(Font_Emoji) / sizeof(Font_Emoji[0]); i++) {\n"
+        )
+        f.write("    if (Font_Emoji[i].codepoint == codepoint) {\n")
+        f.write("      return Font_Emoji[i].glyph;\n")
+        f.write("    }\n")
+        f.write("  }\n")
+        f.write("  return NULL;\n")
+        f.write("}\n")
+
+
+if __name__ == "__main__":
+    main()
@@ -164,6 +164,7 @@ def process_face(name, style, size, bpp=4, shave_bearingX=0, ext="ttf"):
         f.write("#define Font_%s_%s_%d_HEIGHT %d\n" % (name, style, size, size))
         f.write("#define Font_%s_%s_%d_MAX_HEIGHT %d\n" % (name, style, size, font_ymax - font_ymin))
         f.write("#define Font_%s_%s_%d_BASELINE %d\n" % (name, style, size, -font_ymin))
+        f.write("#define Font_%s_%s_%d_BPP %d\n" % (name, style, size, bpp))
         f.write(
             "extern const uint8_t* const Font_%s_%s_%d[%d + 1 - %d];\n"
             % (name, style, size, MAX_GLYPH, MIN_GLYPH)
@@ -296,6 +296,39 @@ def experimental_features(client: "TrezorClient", enable: bool) -> str:
     return device.apply_settings(client, experimental_features=enable)
 
 
+@cli.command()
+@click.argument("enable", type=ChoiceType({"on": True, "off": False}))
+@with_client
+def color_blind(client: "TrezorClient", enable: bool) -> str:
+    """Enable or disable color-blind friendly colors.
+
+    Success and failure are then shown in blue and orange instead of green and red.
+    """
+    return device.apply_settings(client, color_blind=enable)
+
+
+@cli.command()
+@click.argument("enable", type=ChoiceType({"on": True, "off": False}))
+@with_client
+def reduced_motion(client: "TrezorClient", enable: bool) -> str:
+    """Enable or disable reduced motion.
+
+    Decorative animations then skip to their end, hold-to-confirm is unchanged.
+    """
+    return device.apply_settings(client, reduced_motion=enable)
+
+
+@cli.command()
+@click.argument("enable", type=ChoiceType({"on": True, "off": False}))
+@with_client
+def bold_text(client: "TrezorClient", enable: bool) -> str:
+    """Enable or disable bold text.
+
+    Text is then shown in heavier fonts, which are easier to read.
+    """
+    return device.apply_settings(client, bold_text=enable)
+
+
 #
 # passphrase operations
 #
@@ -58,6 +58,10 @@ if TYPE_CHECKING:
 
 EXPECTED_RESPONSES_CONTEXT_LINES = 3
 
+# Delimiters of paginated text content in layout traces
+PAGE_MARKER = re.compile(r"--- page (\d+)/(\d+) ---")
+PAGE_BREAK = "--- page break ---"
+
 LOG = logging.getLogger(__name__)
 
 
@@ -115,6 +119,17 @@ class LayoutContent:
         """Get current page index of the layout."""
         return self._get_number("active_page")
 
+    def get_paragraphs_page(self) -> Tuple[int, int]:
+        """Get zero-based index of the displayed page and number of pages
+        of the paginated text content, as marked in its trace.
+
+        Example marker: "--- page 2/3 ---" -> (1, 3)
+        """
+        match = PAGE_MARKER.search(self.text)
+        if not match:
+            return 0, 1
+        return int(match.group(1)) - 1, int(match.group(2))
+
     def _get_number(self, key: str) -> int:
         """Get number connected with a specific key."""
         match = re.search(rf"{key} : +(\d+)", self.text)
@@ -135,6 +150,13 @@ class LayoutContent:
         else:
             all_lines = self.lines[1:-1]
 
+        # Page markers are not part of the content
+        all_lines = [
+            line
+            for line in all_lines
+            if not PAGE_MARKER.search(line) and PAGE_BREAK not in line
+        ]
+
         if raw:
             return all_lines
         else:
@@ -259,6 +281,56 @@ class DebugLink:
         """
         self._call(messages.DebugLinkWatchLayout(watch=watch))
 
+    def list_layouts(self) -> List[str]:
+        """Names of the layouts which can be shown by `render_layout`.
+
+        Trezor T only.
+        """
+        obj = self._call(messages.DebugLinkListLayouts())
+        return list(obj.names)
+
+    def render_layout(self, name: str, **kwargs: Any) -> LayoutContent:
+        """Show the layout registered under `name`, regardless of the running
+        workflow, which is restored afterwards.
+
+        The layout is created with the sample arguments of the registry, the
+        given keyword arguments replace them. Only str, int, bool, bytes and None
+        arguments are supported. Trezor T only.
+        """
+        arguments = []
+        for key, value in kwargs.items():
+            arg = messages.DebugLinkLayoutArgument(key=key)
+            if isinstance(value, bool):
+                arg.bool_value = value
+            elif isinstance(value, int):
+                arg.int_value = value
+            elif isinstance(value, str):
+                arg.string_value = value
+            elif isinstance(value, bytes):
+                arg.bytes_value = value
+            elif value is not None:
+                raise ValueError(f"Unsupported argument type: {key}")
+            arguments.append(arg)
+
+        obj = self._call(
+            messages.DebugLinkRenderLayout(name=name, arguments=arguments)
+        )
+        if isinstance(obj, messages.Failure):
+            raise TrezorFailure(obj)
+        return LayoutContent(obj.lines)
+
+    def set_manual_clock(self, manual: bool = True) -> None:
+        """Stop the clock of the UI animations, so that they move only by
+        `advance_clock`. Passing False lets the clock follow the real time again.
+
+        Trezor T only.
+        """
+        self._call(messages.DebugLinkSetClock(manual=manual))
+
+    def advance_clock(self, ms: int) -> None:
+        """Move the stopped clock of the UI animations forward by `ms`."""
+        self._call(messages.DebugLinkSetClock(advance_ms=ms))
+
     def encode_pin(self, pin: str, matrix: Optional[str] = None) -> str:
         """Transform correct PIN according to the displayed matrix."""
         if matrix is None:
@@ -382,10 +454,17 @@ class DebugLink:
     def reseed(self, value: int) -> protobuf.MessageType:
         return self._call(messages.DebugLinkReseedRandom(value=value))
 
-    def start_recording(self, directory: str) -> None:
+    def start_recording(self, directory: str, skip_sensitive: bool = False) -> None:
+        """Save the screens into `directory`. With `skip_sensitive`, the layouts
+        showing secrets, e.g. PIN or seed words, are not saved (Trezor T only).
+        """
         # Different recording logic between TT and T1
         if self.model == "T":
-            self._call(messages.DebugLinkRecordScreen(target_directory=directory))
+            self._call(
+                messages.DebugLinkRecordScreen(
+                    target_directory=directory, skip_sensitive=skip_sensitive
+                )
+            )
         else:
             self.t1_screenshot_directory = Path(directory)
             self.t1_screenshot_counter = 0
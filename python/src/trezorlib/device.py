@@ -44,6 +44,9 @@ def apply_settings(
     safety_checks: Optional[messages.SafetyCheckLevel] = None,
     experimental_features: Optional[bool] = None,
     hide_passphrase_from_host: Optional[bool] = None,
+    color_blind: Optional[bool] = None,
+    reduced_motion: Optional[bool] = None,
+    bold_text: Optional[bool] = None,
 ) -> "MessageType":
     settings = messages.ApplySettings(
         label=label,
@@ -56,6 +59,9 @@ def apply_settings(
         safety_checks=safety_checks,
         experimental_features=experimental_features,
         hide_passphrase_from_host=hide_passphrase_from_host,
+        color_blind=color_blind,
+        reduced_motion=reduced_motion,
+        bold_text=bold_text,
     )
 
     out = client.call(settings)
@@ -120,6 +120,10 @@ class MessageType(IntEnum):
     DebugLinkRecordScreen = 9003
     DebugLinkEraseSdCard = 9005
     DebugLinkWatchLayout = 9006
+    DebugLinkRenderLayout = 9007
+    DebugLinkSetClock = 9008
+    DebugLinkListLayouts = 9009
+    DebugLinkLayoutNames = 9010
     EthereumGetPublicKey = 450
     EthereumPublicKey = 451
     EthereumGetAddress = 56
@@ -3270,6 +3274,9 @@ class ApplySettings(protobuf.MessageType):
         9: protobuf.Field("safety_checks", "SafetyCheckLevel", repeated=False, required=False, default=None),
         10: protobuf.Field("experimental_features", "bool", repeated=False, required=False, default=None),
         11: protobuf.Field("hide_passphrase_from_host", "bool", repeated=False, required=False, default=None),
+        12: protobuf.Field("color_blind", "bool", repeated=False, required=False, default=None),
+        13: protobuf.Field("reduced_motion", "bool", repeated=False, required=False, default=None),
+        14: protobuf.Field("bold_text", "bool", repeated=False, required=False, default=None),
     }
 
     def __init__(
@@ -3286,6 +3293,9 @@ class ApplySettings(protobuf.MessageType):
         safety_checks: Optional["SafetyCheckLevel"] = None,
         experimental_features: Optional["bool"] = None,
         hide_passphrase_from_host: Optional["bool"] = None,
+        color_blind: Optional["bool"] = None,
+        reduced_motion: Optional["bool"] = None,
+        bold_text: Optional["bool"] = None,
     ) -> None:
         self.language = language
         self.label = label
@@ -3298,6 +3308,9 @@ class ApplySettings(protobuf.MessageType):
         self.safety_checks = safety_checks
         self.experimental_features = experimental_features
         self.hide_passphrase_from_host = hide_passphrase_from_host
+        self.color_blind = color_blind
+        self.reduced_motion = reduced_motion
+        self.bold_text = bold_text
 
 
 class ApplyFlags(protobuf.MessageType):
@@ -3765,14 +3778,17 @@ class DebugLinkRecordScreen(protobuf.MessageType):
     MESSAGE_WIRE_TYPE = 9003
     FIELDS = {
         1: protobuf.Field("target_directory", "string", repeated=False, required=False, default=None),
+        2: protobuf.Field("skip_sensitive", "bool", repeated=False, required=False, default=None),
     }
 
     def __init__(
         self,
         *,
         target_directory: Optional["str"] = None,
+        skip_sensitive: Optional["bool"] = None,
     ) -> None:
         self.target_directory = target_directory
+        self.skip_sensitive = skip_sensitive
 
 
 class DebugLinkGetState(protobuf.MessageType):
@@ -3962,6 +3978,84 @@ class DebugLinkWatchLayout(protobuf.MessageType):
         self.watch = watch
 
 
+class DebugLinkLayoutArgument(protobuf.MessageType):
+    MESSAGE_WIRE_TYPE = None
+    FIELDS = {
+        1: protobuf.Field("key", "string", repeated=False, required=True),
+        2: protobuf.Field("string_value", "string", repeated=False, required=False, default=None),
+        3: protobuf.Field("int_value", "sint64", repeated=False, required=False, default=None),
+        4: protobuf.Field("bool_value", "bool", repeated=False, required=False, default=None),
+        5: protobuf.Field("bytes_value", "bytes", repeated=False, required=False, default=None),
+    }
+
+    def __init__(
+        self,
+        *,
+        key: "str",
+        string_value: Optional["str"] = None,
+        int_value: Optional["int"] = None,
+        bool_value: Optional["bool"] = None,
+        bytes_value: Optional["bytes"] = None,
+    ) -> None:
+        self.key = key
+        self.string_value = string_value
+        self.int_value = int_value
+        self.bool_value = bool_value
+        self.bytes_value = bytes_value
+
+
+class DebugLinkRenderLayout(protobuf.MessageType):
+    MESSAGE_WIRE_TYPE = 9007
+    FIELDS = {
+        1: protobuf.Field("name", "string", repeated=False, required=True),
+        2: protobuf.Field("arguments", "DebugLinkLayoutArgument", repeated=True, required=False, default=None),
+    }
+
+    def __init__(
+        self,
+        *,
+        name: "str",
+        arguments: Optional[Sequence["DebugLinkLayoutArgument"]] = None,
+    ) -> None:
+        self.arguments: Sequence["DebugLinkLayoutArgument"] = arguments if arguments is not None else []
+        self.name = name
+
+
+class DebugLinkSetClock(protobuf.MessageType):
+    MESSAGE_WIRE_TYPE = 9008
+    FIELDS = {
+        1: protobuf.Field("manual", "bool", repeated=False, required=False, default=None),
+        2: protobuf.Field("advance_ms", "uint32", repeated=False, required=False, default=None),
+    }
+
+    def __init__(
+        self,
+        *,
+        manual: Optional["bool"] = None,
+        advance_ms: Optional["int"] = None,
+    ) -> None:
+        self.manual = manual
+        self.advance_ms = advance_ms
+
+
+class DebugLinkListLayouts(protobuf.MessageType):
+    MESSAGE_WIRE_TYPE = 9009
+
+
+class DebugLinkLayoutNames(protobuf.MessageType):
+    MESSAGE_WIRE_TYPE = 9010
+    FIELDS = {
+        1: protobuf.Field("names", "string", repeated=True, required=False, default=None),
+    }
+
+    def __init__(
+        self,
+        *,
+        names: Optional[Sequence["str"]] = None,
+    ) -> None:
+        self.names: Sequence["str"] = names if names is not None else []
+
+
 class EosGetPublicKey(protobuf.MessageType):
     MESSAGE_WIRE_TYPE = 600
     FIELDS = {
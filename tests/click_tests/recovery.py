@@ -62,7 +62,10 @@ def enter_share(
     if legacy_ui:
         assert layout.text == "Slip39Keyboard"
     else:
-        assert layout.text == "< MnemonicKeyboard >"
+        assert (
+            layout.text
+            == "< MnemonicKeyboard input :  < Slip39Input content :  <redacted> > >"
+        )
 
     for word in share.split(" "):
         layout = enter_word(debug, word, is_slip39=True)
@@ -47,7 +47,7 @@ def set_autolock_delay(device_handler: "BackgroundDeviceHandler", delay_ms: int)
     device_handler.run(device.apply_settings, auto_lock_delay_ms=delay_ms)
 
     layout = debug.wait_layout()
-    assert layout.text == "< PinKeyboard >"
+    assert layout.text == "< PinKeyboard pin :  <redacted> >"
     debug.input("1234")
 
     layout = debug.wait_layout()
@@ -108,7 +108,7 @@ def test_autolock_passphrase_keyboard(device_handler: "BackgroundDeviceHandler")
 
     # enter passphrase - slowly
     layout = debug.wait_layout()
-    assert layout.text == "< PassphraseKeyboard >"
+    assert layout.text == "< PassphraseKeyboard passphrase :  <redacted> >"
 
     CENTER_BUTTON = buttons.grid35(1, 2)
     # keep clicking for long enough to trigger the autolock if it incorrectly ignored key presses
@@ -130,7 +130,7 @@ def test_autolock_interrupts_passphrase(device_handler: "BackgroundDeviceHandler
 
     # enter passphrase - slowly
     layout = debug.wait_layout()
-    assert layout.text == "< PassphraseKeyboard >"
+    assert layout.text == "< PassphraseKeyboard passphrase :  <redacted> >"
 
     CENTER_BUTTON = buttons.grid35(1, 2)
     # autolock must activate even if we pressed some buttons
@@ -157,7 +157,7 @@ def test_dryrun_locks_at_number_of_words(device_handler: "BackgroundDeviceHandle
     layout = debug.wait_layout()
     assert "Do you really want to check the recovery seed?" in layout.get_content()
     layout = debug.click(buttons.OK, wait=True)
-    assert layout.text == "< PinKeyboard >"
+    assert layout.text == "< PinKeyboard pin :  <redacted> >"
     layout = debug.input(PIN4, wait=True)
     assert "Select number of words " in layout.get_content()
 
@@ -170,7 +170,7 @@ def test_dryrun_locks_at_number_of_words(device_handler: "BackgroundDeviceHandle
 
     # unlock
     layout = debug.click(buttons.OK, wait=True)
-    assert layout.text == "< PinKeyboard >"
+    assert layout.text == "< PinKeyboard pin :  <redacted> >"
     layout = debug.input(PIN4, wait=True)
 
     # we are back at homescreen
@@ -188,7 +188,7 @@ def test_dryrun_locks_at_word_entry(device_handler: "BackgroundDeviceHandler"):
     layout = debug.wait_layout()
     assert "Do you really want to check the recovery seed?" in layout.get_content()
     layout = debug.click(buttons.OK, wait=True)
-    assert layout.text == "< PinKeyboard >"
+    assert layout.text == "< PinKeyboard pin :  <redacted> >"
     layout = debug.input(PIN4, wait=True)
 
     # select 20 words
@@ -196,7 +196,10 @@ def test_dryrun_locks_at_word_entry(device_handler: "BackgroundDeviceHandler"):
 
     layout = debug.click(buttons.OK, wait=True)
     # make sure keyboard locks
-    assert layout.text == "< MnemonicKeyboard >"
+    assert (
+        layout.text
+        == "< MnemonicKeyboard input :  < Slip39Input content :  <redacted> > >"
+    )
     time.sleep(10.1)
     layout = debug.wait_layout()
     assert layout.text.startswith("< Lockscreen")
@@ -215,7 +218,7 @@ def test_dryrun_enter_word_slowly(device_handler: "BackgroundDeviceHandler"):
     layout = debug.wait_layout()
     assert "Do you really want to check the recovery seed?" in layout.get_content()
     layout = debug.click(buttons.OK, wait=True)
-    assert layout.text == "< PinKeyboard >"
+    assert layout.text == "< PinKeyboard pin :  <redacted> >"
     layout = debug.input(PIN4, wait=True)
 
     # select 20 words
@@ -223,11 +226,17 @@ def test_dryrun_enter_word_slowly(device_handler: "BackgroundDeviceHandler"):
 
     layout = debug.click(buttons.OK, wait=True)
     # type the word OCEAN slowly
-    assert layout.text == "< MnemonicKeyboard >"
+    assert (
+        layout.text
+        == "< MnemonicKeyboard input :  < Slip39Input content :  <redacted> > >"
+    )
     for coords in buttons.type_word("ocea", is_slip39=True):
         time.sleep(9)
         debug.click(coords)
     layout = debug.click(buttons.CONFIRM_WORD, wait=True)
     # should not have locked, even though we took 9 seconds to type each letter
-    assert layout.text == "< MnemonicKeyboard >"
+    assert (
+        layout.text
+        == "< MnemonicKeyboard input :  < Slip39Input content :  <redacted> > >"
+    )
     device_handler.kill_task()
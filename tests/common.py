@@ -170,6 +170,10 @@ def recovery_enter_shares(
         if click_info:
             # Moving through the INFO button
             debug.press_info()
+            # Progress of the groups
+            yield
+            debug.press_yes()
+            # Remaining shares
             yield
             debug.swipe_up()
             debug.press_yes()
@@ -136,7 +136,10 @@ def test_invalid_seed_core(client: Client):
 
         yield
         for _ in range(12):
-            assert layout().text == "< MnemonicKeyboard >"
+            assert (
+                layout().text
+                == "< MnemonicKeyboard input :  < Bip39Input content :  <redacted> > >"
+            )
             client.debug.input("stick")
 
         br = yield
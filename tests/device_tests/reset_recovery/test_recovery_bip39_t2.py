@@ -35,11 +35,11 @@ def test_tt_pin_passphrase(client: Client):
         client.debug.press_yes()
 
         yield
-        assert layout().text == "< PinKeyboard >"
+        assert layout().text == "< PinKeyboard pin :  <redacted> >"
         client.debug.input("654")
 
         yield
-        assert layout().text == "< PinKeyboard >"
+        assert layout().text == "< PinKeyboard pin :  <redacted> >"
         client.debug.input("654")
 
         yield
@@ -56,7 +56,10 @@ def test_tt_pin_passphrase(client: Client):
 
         yield
         for word in mnemonic:
-            assert layout().text == "< MnemonicKeyboard >"
+            assert (
+                layout().text
+                == "< MnemonicKeyboard input :  < Bip39Input content :  <redacted> > >"
+            )
             client.debug.input(word)
 
         yield
@@ -102,7 +105,10 @@ def test_tt_nopin_nopassphrase(client: Client):
 
         yield
         for word in mnemonic:
-            assert layout().text == "< MnemonicKeyboard >"
+            assert (
+                layout().text
+                == "< MnemonicKeyboard input :  < Bip39Input content :  <redacted> > >"
+            )
             client.debug.input(word)
 
         yield
@@ -14,13 +14,18 @@
 # You should have received a copy of the License along with this library.
 # If not, see <https://www.gnu.org/licenses/lgpl-3.0.html>.
 
+import time
+from pathlib import Path
+
 import pytest
 
 from trezorlib import debuglink, device, messages, misc
 from trezorlib.debuglink import TrezorClientDebugLink as Client
+from trezorlib.exceptions import TrezorFailure
 from trezorlib.tools import parse_path
 from trezorlib.transport import udp
 
+from .. import buttons
 from ..common import MNEMONIC12
 
 
@@ -92,3 +97,83 @@ def test_softlock_instability(client: Client):
     # If the device actually called config.unlock(), it would use additional randomness.
     # That is undesirable. Assert that the returned entropy is still the same.
     assert misc.get_entropy(client, 16) == entropy_after_wipe
+
+
+@pytest.mark.skip_t1
+def test_manual_clock(client: Client):
+    """The hold-to-confirm loader does not move while the clock is stopped and
+    completes once the host steps the clock."""
+
+    def input_flow():
+        yield  # confirm wipe
+        client.debug.set_manual_clock(True)
+        try:
+            # Held for longer than the loader takes, but no time passes.
+            client.debug.input(x=buttons.OK[0], y=buttons.OK[1], hold_ms=2000)
+            time.sleep(2.5)
+            assert client.debug.read_layout().get_title().upper() == "WIPE DEVICE"
+
+            # Step the clock in the middle of the hold.
+            client.debug.input(x=buttons.OK[0], y=buttons.OK[1], hold_ms=1000)
+            time.sleep(0.3)
+            client.debug.advance_clock(5000)
+        finally:
+            client.debug.set_manual_clock(False)
+
+    with client:
+        client.set_input_flow(input_flow)
+        device.wipe(client)
+
+    assert client.features.initialized is False
+
+
+@pytest.mark.skip_t1
+def test_render_layout(client: Client):
+    """Every registered layout is rendered, including the bootloader screens, and
+    the running workflow is left as it was."""
+    names = client.debug.list_layouts()
+    assert "confirm_action-default" in names
+    assert "bootloader-wipe_confirm" in names
+
+    homescreen = client.debug.read_layout().lines
+    for name in names:
+        assert client.debug.render_layout(name).lines, name
+
+    layout = client.debug.render_layout("confirm_action-default", title="RENDERED")
+    assert layout.get_title() == "RENDERED"
+    layout = client.debug.render_layout("bootloader-wipe_confirm")
+    assert "WIPE DEVICE" in " ".join(layout.lines)
+
+    with pytest.raises(TrezorFailure, match="Unknown layout"):
+        client.debug.render_layout("confirm_action-nonexistent")
+    with pytest.raises(TrezorFailure, match="Unknown layout"):
+        client.debug.render_layout("bootloader-nonexistent")
+
+    assert client.debug.read_layout().lines == homescreen
+    assert client.ping("still running") == "still running"
+
+
+@pytest.mark.skip_t1
+def test_record_screen_skip_sensitive(client: Client, tmp_path: Path):
+    """Layouts marked as sensitive are left out of the recording on request."""
+    if client.features.fw_vendor != "EMULATOR":
+        pytest.skip("Only for emulator")
+
+    def recorded(name: str) -> int:
+        before = set(tmp_path.iterdir())
+        client.debug.render_layout(name)
+        return len(set(tmp_path.iterdir()) - before)
+
+    client.debug.start_recording(str(tmp_path), skip_sensitive=True)
+    try:
+        # Only the restored homescreen is saved after the PIN keyboard.
+        assert recorded("request_pin-default") == 1
+        assert recorded("show_success-default") == 2
+    finally:
+        client.debug.stop_recording()
+
+    client.debug.start_recording(str(tmp_path))
+    try:
+        assert recorded("request_pin-default") == 2
+    finally:
+        client.debug.stop_recording()
@@ -62,6 +62,39 @@ def test_apply_settings_rotation(client: Client):
     assert client.features.display_rotation == 270
 
 
+@pytest.mark.skip_t1
+def test_apply_settings_color_blind(client: Client):
+    with client:
+        _set_expected_responses(client)
+        device.apply_settings(client, color_blind=True)
+
+    with client:
+        client.set_expected_responses(EXPECTED_RESPONSES_NOPIN)
+        device.apply_settings(client, color_blind=False)
+
+
+@pytest.mark.skip_t1
+def test_apply_settings_reduced_motion(client: Client):
+    with client:
+        _set_expected_responses(client)
+        device.apply_settings(client, reduced_motion=True)
+
+    with client:
+        client.set_expected_responses(EXPECTED_RESPONSES_NOPIN)
+        device.apply_settings(client, reduced_motion=False)
+
+
+@pytest.mark.skip_t1
+def test_apply_settings_bold_text(client: Client):
+    with client:
+        _set_expected_responses(client)
+        device.apply_settings(client, bold_text=True)
+
+    with client:
+        client.set_expected_responses(EXPECTED_RESPONSES_NOPIN)
+        device.apply_settings(client, bold_text=False)
+
+
 @pytest.mark.skip_t2
 def test_invalid_language(client: Client):
     assert client.features.language == "en-US"
@@ -139,7 +139,10 @@ def test_recovery_on_old_wallet(emulator: Emulator):
     assert "Enter any share" in layout.text
     debug.press_yes()
     layout = debug.wait_layout()
-    assert layout.text == "< MnemonicKeyboard >"
+    assert (
+        layout.text
+        == "< MnemonicKeyboard input :  < Slip39Input content :  <redacted> > >"
+    )
 
     # enter first word
     debug.input(words[0])
@@ -151,7 +154,10 @@ def test_recovery_on_old_wallet(emulator: Emulator):
 
     # try entering remaining 19 words
     for word in words[1:]:
-        assert layout.text == "< MnemonicKeyboard >"
+        assert (
+            layout.text
+            == "< MnemonicKeyboard input :  < Slip39Input content :  <redacted> > >"
+        )
         debug.input(word)
         layout = debug.wait_layout()
 
@@ -181,7 +187,7 @@ def test_recovery_multiple_resets(emulator: Emulator):
             assert expected_text in layout.text
             layout = recovery.enter_share(debug, share)
             remaining -= 1
-            expected_text = "You have entered"
+            expected_text = "SHARE ENTERED"
             debug = _restart(device_handler, emulator)
 
         assert "You have successfully recovered your wallet" in layout.get_content()
@@ -0,0 +1,138 @@
+#!/usr/bin/env python3
+"""Inventory of the screens of the core firmware.
+
+Every layout of the debuglink registry (`DebugLink.list_layouts`) is rendered
+on a running debug build (usually the emulator) through
+`DebugLink.render_layout`, and its trace and the bounds of its components are
+written to a JSON file. Comparing the inventories of two releases lists the
+screens that changed, so that no UI change slips through without a review.
+
+The layouts are rendered with the sample arguments of the registry, screens
+which are not registered are not part of the inventory.
+"""
+
+import hashlib
+import json
+import os
+import re
+import sys
+from pathlib import Path
+from typing import Any, Dict, Tuple
+
+import click
+
+from trezorlib.debuglink import TrezorClientDebugLink as Client
+from trezorlib.transport import enumerate_devices, get_transport
+
+HERE = Path(__file__).resolve().parent
+DEFAULT_INVENTORY = HERE / "ui_tests" / "screens.json"
+
+# Bounds traced after the layout, see `trace_bounds` in the Rust UI code.
+BOUNDS_RE = re.compile(
+    r"< Bounds x0 : +(-?\d+) y0 : +(-?\d+) x1 : +(-?\d+) y1 : +(-?\d+) >"
+)
+
+
+def _find_client() -> Client:
+    path = os.environ.get("TREZOR_PATH")
+    if path:
+        return Client(get_transport(path))
+    for device in enumerate_devices():
+        try:
+            return Client(device)
+        except Exception:
+            pass
+    raise click.ClickException("No debuggable Trezor found")
+
+
+def _render(client: Client, name: str) -> Dict[str, Any]:
+    layout = client.debug.render_layout(name)
+    match = BOUNDS_RE.search(layout.text)
+    # Layouts whose components report no bounds trace an empty `Bounds`.
+    x0, y0, x1, y1 = (int(g) for g in match.groups()) if match else (0, 0, 0, 0)
+    # Layouts without pagination do not trace the page count.
+    pages = layout.get_page_count() or 1
+    trace = "\n".join(layout.lines)
+    return {
+        "dimensions": {
+            "x": x0,
+            "y": y0,
+            "width": x1 - x0,
+            "height": y1 - y0,
+            "pages": pages,
+        },
+        "trace": layout.lines,
+        "hash": hashlib.sha256(trace.encode()).hexdigest(),
+    }
+
+
+def _load(path: Path) -> Dict[str, Any]:
+    return json.loads(path.read_text())["screens"]
+
+
+@click.group()
+def cli() -> None:
+    pass
+
+
+@cli.command()
+@click.option(
+    "-o", "--output", type=click.Path(path_type=Path), default=DEFAULT_INVENTORY
+)
+@click.option("-s", "--screen", "only", multiple=True, help="Render only these")
+def generate(output: Path, only: Tuple[str, ...]) -> None:
+    """Render every screen and write the inventory."""
+    client = _find_client()
+    if client.features.model != "T":
+        raise click.ClickException("Only Trezor T is supported")
+
+    screens = {}
+    for name in client.debug.list_layouts():
+        if only and name not in only:
+            continue
+        try:
+            screens[name] = _render(client, name)
+        except Exception as e:
+            raise click.ClickException(f"{name}: {e}") from e
+        click.echo(f"{name}: {screens[name]['hash'][:16]}")
+
+    inventory = {"model": client.features.model, "screens": screens}
+    output.write_text(json.dumps(inventory, indent=1, sort_keys=True) + "\n")
+    click.echo(f"Wrote {len(screens)} screens to {output}")
+
+
+@cli.command()
+@click.argument("old", type=click.Path(exists=True, path_type=Path))
+@click.argument(
+    "new", type=click.Path(exists=True, path_type=Path), default=DEFAULT_INVENTORY
+)
+def compare(old: Path, new: Path) -> None:
+    """List the screens that differ between two inventories.
+
+    Exits with a non-zero status if there are any.
+    """
+    old_screens = _load(old)
+    new_screens = _load(new)
+
+    changes = 0
+    for key in sorted(old_screens.keys() | new_screens.keys()):
+        if key not in new_screens:
+            click.echo(f"removed  {key}")
+        elif key not in old_screens:
+            click.echo(f"added    {key}")
+        elif old_screens[key]["dimensions"] != new_screens[key]["dimensions"]:
+            click.echo(f"resized  {key}")
+        elif old_screens[key]["hash"] != new_screens[key]["hash"]:
+            click.echo(f"changed  {key}")
+        else:
+            continue
+        changes += 1
+
+    if changes:
+        click.echo(f"{changes} screens to review")
+        sys.exit(1)
+    click.echo("No changes")
+
+
+if __name__ == "__main__":
+    cli()